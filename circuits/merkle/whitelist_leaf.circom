@@ -0,0 +1,38 @@
+/**
+ * Whitelist leaf with a KYC expiry check
+ * For RWA Dark Pool settlement proofs
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "circomlib/circuits/comparators.circom";
+include "circomlib/circuits/bitify.circom";
+
+/**
+ * Computes the registry's whitelist leaf, Poseidon(idHash, kycExpiry, category),
+ * and constrains the participant's KYC to be valid at validAt (validAt < kycExpiry)
+ */
+template WhitelistLeaf() {
+    signal input idHash;
+    signal input kycExpiry;
+    signal input category;
+    signal input validAt;
+    signal output leaf;
+
+    /** Timestamps are 64-bit so the comparison cannot wrap */
+    component expiryBits = Num2Bits(64);
+    expiryBits.in <== kycExpiry;
+    component validAtBits = Num2Bits(64);
+    validAtBits.in <== validAt;
+
+    component kycValid = LessThan(64);
+    kycValid.in[0] <== validAt;
+    kycValid.in[1] <== kycExpiry;
+    kycValid.out === 1;
+
+    component hasher = Poseidon(3);
+    hasher.inputs[0] <== idHash;
+    hasher.inputs[1] <== kycExpiry;
+    hasher.inputs[2] <== category;
+    leaf <== hasher.out;
+}
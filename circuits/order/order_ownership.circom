@@ -0,0 +1,53 @@
+/**
+ * Order Ownership Circuit for RWA Dark Pool
+ *
+ * Proves knowledge of the opening of an order commitment, in particular its
+ * secret, without revealing any of the order details. Used by the orderbook to
+ * authorize cancellations and withdrawals from a relayed order's note.
+ *
+ * The proof is bound to an action tag (1 = cancel, or the orderbook's
+ * get_withdraw_action(recipient) to withdraw to that recipient) and to the order's current
+ * cancellation nonce on the orderbook, so it authorizes exactly one action and
+ * cannot be replayed once the orderbook consumes it.
+ *
+ * Compatible with Stellar X-Ray Protocol (BN254)
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+
+template OrderOwnership() {
+    /** PRIVATE INPUTS (known only to prover) */
+    signal input assetHash;
+    signal input side;
+    signal input quantity;
+    signal input price;
+    signal input nonce;
+    signal input secret;
+
+    /** PUBLIC INPUTS (visible on-chain) */
+    signal input commitment;
+    signal input action;
+    signal input cancelNonce;
+
+    /** Side is 0 (buy) or 1 (sell) */
+    side * (side - 1) === 0;
+
+    /** Recompute Poseidon(asset, side, qty, price, nonce, secret) */
+    component hasher = Poseidon(6);
+    hasher.inputs[0] <== assetHash;
+    hasher.inputs[1] <== side;
+    hasher.inputs[2] <== quantity;
+    hasher.inputs[3] <== price;
+    hasher.inputs[4] <== nonce;
+    hasher.inputs[5] <== secret;
+    hasher.out === commitment;
+
+    /** Bind the action tag and nonce to the proof */
+    signal actionSquare;
+    signal nonceSquare;
+    actionSquare <== action * action;
+    nonceSquare <== cancelNonce * cancelNonce;
+}
+
+component main {public [commitment, action, cancelNonce]} = OrderOwnership();
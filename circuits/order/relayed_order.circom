@@ -0,0 +1,107 @@
+/**
+ * Relayed Order Circuit for RWA Dark Pool
+ *
+ * Lets a relayer submit an order on behalf of a trader without revealing the
+ * trader's address. The collateral comes from a note keyed by the commitment,
+ * and ownership rests on the commitment secret. Verifies:
+ * 1. The trader's whitelist leaf, Poseidon(idHash, kycExpiry, category), is in
+ *    the whitelist tree and their KYC is still valid at validAt
+ * 2. The prover knows the opening of the order commitment
+ * 3. The collateral covers the order: the quantity for sells, the total price for buys
+ *
+ * Compatible with Stellar X-Ray Protocol (BN254)
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "circomlib/circuits/comparators.circom";
+include "circomlib/circuits/bitify.circom";
+include "../merkle/merkle_proof.circom";
+
+/**
+ * Relayed Order Template
+ * @param TREE_DEPTH - Whitelist Merkle tree depth (matches registry)
+ */
+template RelayedOrder(TREE_DEPTH) {
+    /** PRIVATE INPUTS (known only to prover) */
+
+    /** Trader's whitelist leaf and proof */
+    signal input idHash;
+    signal input kycExpiry;
+    signal input category;
+    signal input merkleProof[TREE_DEPTH];
+    signal input merkleIndices[TREE_DEPTH];
+
+    /** Order opening */
+    signal input assetHash;
+    signal input quantity;
+    signal input price;
+    signal input nonce;
+    signal input secret;
+
+    /** PUBLIC INPUTS (visible on-chain) */
+    signal input commitment;
+    signal input whitelistRoot;
+    signal input side;
+    signal input collateralAmount;
+    signal input validAt;
+
+    /** Range checks so the comparisons below cannot wrap */
+    component bits[4];
+    for (var i = 0; i < 4; i++) {
+        bits[i] = Num2Bits(64);
+    }
+    bits[0].in <== kycExpiry;
+    bits[1].in <== validAt;
+    bits[2].in <== quantity;
+    bits[3].in <== price;
+    component collateralBits = Num2Bits(127);
+    collateralBits.in <== collateralAmount;
+
+    /** 1. Verify the trader is on the whitelist with KYC valid at validAt */
+    component leafHasher = Poseidon(3);
+    leafHasher.inputs[0] <== idHash;
+    leafHasher.inputs[1] <== kycExpiry;
+    leafHasher.inputs[2] <== category;
+
+    component merkle = MerkleTreeVerifier(TREE_DEPTH);
+    merkle.leaf <== leafHasher.out;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        merkle.pathElements[i] <== merkleProof[i];
+        merkle.pathIndices[i] <== merkleIndices[i];
+    }
+    merkle.expectedRoot <== whitelistRoot;
+
+    component kycValid = LessThan(64);
+    kycValid.in[0] <== validAt;
+    kycValid.in[1] <== kycExpiry;
+    kycValid.out === 1;
+
+    /** 2. Verify the order commitment: Poseidon(asset, side, qty, price, nonce, secret) */
+    side * (side - 1) === 0;
+    component commitHasher = Poseidon(6);
+    commitHasher.inputs[0] <== assetHash;
+    commitHasher.inputs[1] <== side;
+    commitHasher.inputs[2] <== quantity;
+    commitHasher.inputs[3] <== price;
+    commitHasher.inputs[4] <== nonce;
+    commitHasher.inputs[5] <== secret;
+    commitHasher.out === commitment;
+
+    /** 3. Collateral covers what the order can spend: price for buys (side 0), quantity for sells */
+    signal required;
+    required <== price + (quantity - price) * side;
+    component covered = LessEqThan(128);
+    covered.in[0] <== required;
+    covered.in[1] <== collateralAmount;
+    covered.out === 1;
+}
+
+/** Tree depth = 20 (supports up to 2^20 = 1M participants) */
+component main {public [
+    commitment,
+    whitelistRoot,
+    side,
+    collateralAmount,
+    validAt
+]} = RelayedOrder(20);
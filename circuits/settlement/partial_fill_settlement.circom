@@ -0,0 +1,267 @@
+/**
+ * Partial Fill Settlement Circuit for RWA Dark Pool
+ *
+ * Settles one fill of a pair of orders that may each be matched in parts.
+ * Verifies:
+ * 1. Buyer and seller are on the whitelist with KYC valid at validAt, and the
+ *    asset is listed (Merkle proofs)
+ * 2. Order commitments open to the committed total quantity and limit price
+ * 3. Each order's running fill commitment advances by the matched quantity
+ *    and the cumulative fill stays within the committed quantity
+ * 4. Each order's completion flag says whether this fill fills it completely
+ * 5. The execution price respects both limits, pro rata to the fill
+ * 6. Nullifier is correctly computed
+ *
+ * Compatible with Stellar X-Ray Protocol (BN254)
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "circomlib/circuits/comparators.circom";
+include "circomlib/circuits/bitify.circom";
+include "../merkle/merkle_proof.circom";
+include "../merkle/whitelist_leaf.circom";
+
+/**
+ * One order's side of a fill
+ * @param SIDE - 0 for the buy order, 1 for the sell order
+ *
+ * The order commits to Poseidon(asset, side, quantity, price, nonce, secret),
+ * where price is the total payment for the whole quantity. Its running fill
+ * commitment is Poseidon(filled, secret), or 0 before the first fill.
+ */
+template OrderFill(SIDE) {
+    signal input assetHash;
+    signal input quantity;
+    signal input price;
+    signal input nonce;
+    signal input secret;
+    signal input filledBefore;
+    signal input matchedQuantity;
+    signal input executionPrice;
+    signal input commitment;
+    signal input fillBefore;
+    signal input fillAfter;
+    signal input complete;
+
+    signal output filledAfter;
+
+    /** Amounts are 64-bit so the products below cannot wrap */
+    component bits[5];
+    for (var i = 0; i < 5; i++) {
+        bits[i] = Num2Bits(64);
+    }
+    bits[0].in <== quantity;
+    bits[1].in <== price;
+    bits[2].in <== filledBefore;
+    bits[3].in <== matchedQuantity;
+    bits[4].in <== executionPrice;
+
+    /** Opening of the order commitment */
+    component commitHasher = Poseidon(6);
+    commitHasher.inputs[0] <== assetHash;
+    commitHasher.inputs[1] <== SIDE;
+    commitHasher.inputs[2] <== quantity;
+    commitHasher.inputs[3] <== price;
+    commitHasher.inputs[4] <== nonce;
+    commitHasher.inputs[5] <== secret;
+    commitHasher.out === commitment;
+
+    /** Fill commitment before this fill: 0 for a fresh order */
+    component isFresh = IsZero();
+    isFresh.in <== filledBefore;
+    component beforeHasher = Poseidon(2);
+    beforeHasher.inputs[0] <== filledBefore;
+    beforeHasher.inputs[1] <== secret;
+    fillBefore === (1 - isFresh.out) * beforeHasher.out;
+
+    /** Cumulative fill stays within the committed quantity */
+    filledAfter <== filledBefore + matchedQuantity;
+    component withinQuantity = LessEqThan(65);
+    withinQuantity.in[0] <== filledAfter;
+    withinQuantity.in[1] <== quantity;
+    withinQuantity.out === 1;
+
+    component afterHasher = Poseidon(2);
+    afterHasher.inputs[0] <== filledAfter;
+    afterHasher.inputs[1] <== secret;
+    afterHasher.out === fillAfter;
+
+    /** The order is complete exactly when the cumulative fill reaches its quantity */
+    component isComplete = IsEqual();
+    isComplete.in[0] <== filledAfter;
+    isComplete.in[1] <== quantity;
+    isComplete.out === complete;
+
+    /**
+     * Price limit, pro rata: executionPrice / matchedQuantity against
+     * price / quantity. Buys pay at most their limit, sells receive at least theirs.
+     */
+    signal paid;
+    signal limit;
+    paid <== executionPrice * quantity;
+    limit <== price * matchedQuantity;
+    component withinLimit = LessEqThan(128);
+    withinLimit.in[0] <== SIDE == 0 ? paid : limit;
+    withinLimit.in[1] <== SIDE == 0 ? limit : paid;
+    withinLimit.out === 1;
+}
+
+/**
+ * Partial Fill Settlement Template
+ * @param TREE_DEPTH - Whitelist Merkle tree depth (matches registry)
+ * @param ASSET_TREE_DEPTH - Asset tree depth (matches registry)
+ */
+template PartialFillSettlement(TREE_DEPTH, ASSET_TREE_DEPTH) {
+    /** PRIVATE INPUTS (known only to prover) */
+
+    /** Buyer's whitelist leaf and proof */
+    signal input buyerIdHash;
+    signal input buyerKycExpiry;
+    signal input buyerCategory;
+    signal input buyerMerkleProof[TREE_DEPTH];
+    signal input buyerMerkleIndices[TREE_DEPTH];
+
+    /** Seller's whitelist leaf and proof */
+    signal input sellerIdHash;
+    signal input sellerKycExpiry;
+    signal input sellerCategory;
+    signal input sellerMerkleProof[TREE_DEPTH];
+    signal input sellerMerkleIndices[TREE_DEPTH];
+
+    /** Asset's listing proof */
+    signal input assetMerkleProof[ASSET_TREE_DEPTH];
+    signal input assetMerkleIndices[ASSET_TREE_DEPTH];
+
+    /** Order openings and quantities filled before this fill */
+    signal input buyOrderQuantity;
+    signal input buyOrderPrice;
+    signal input buyOrderSecret;
+    signal input buyOrderNonce;
+    signal input buyFilledBefore;
+    signal input sellOrderQuantity;
+    signal input sellOrderPrice;
+    signal input sellOrderSecret;
+    signal input sellOrderNonce;
+    signal input sellFilledBefore;
+
+    /** PUBLIC INPUTS (visible on-chain) */
+    signal input buyCommitment;
+    signal input sellCommitment;
+    signal input assetHash;
+    signal input matchedQuantity;
+    signal input executionPrice;
+    signal input whitelistRoot;
+    signal input assetRoot;
+    signal input validAt;
+    signal input buyFillBefore;
+    signal input buyFillAfter;
+    signal input sellFillBefore;
+    signal input sellFillAfter;
+    signal input buyComplete;
+    signal input sellComplete;
+
+    /** PUBLIC OUTPUT */
+    signal output nullifierHash;
+
+    /** 1. Verify buyer is on whitelist with KYC valid at validAt */
+    component buyerLeaf = WhitelistLeaf();
+    buyerLeaf.idHash <== buyerIdHash;
+    buyerLeaf.kycExpiry <== buyerKycExpiry;
+    buyerLeaf.category <== buyerCategory;
+    buyerLeaf.validAt <== validAt;
+
+    component buyerMerkle = MerkleTreeVerifier(TREE_DEPTH);
+    buyerMerkle.leaf <== buyerLeaf.leaf;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        buyerMerkle.pathElements[i] <== buyerMerkleProof[i];
+        buyerMerkle.pathIndices[i] <== buyerMerkleIndices[i];
+    }
+    buyerMerkle.expectedRoot <== whitelistRoot;
+
+    /** 2. Verify seller is on whitelist with KYC valid at validAt */
+    component sellerLeaf = WhitelistLeaf();
+    sellerLeaf.idHash <== sellerIdHash;
+    sellerLeaf.kycExpiry <== sellerKycExpiry;
+    sellerLeaf.category <== sellerCategory;
+    sellerLeaf.validAt <== validAt;
+
+    component sellerMerkle = MerkleTreeVerifier(TREE_DEPTH);
+    sellerMerkle.leaf <== sellerLeaf.leaf;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        sellerMerkle.pathElements[i] <== sellerMerkleProof[i];
+        sellerMerkle.pathIndices[i] <== sellerMerkleIndices[i];
+    }
+    sellerMerkle.expectedRoot <== whitelistRoot;
+
+    /** 3. Verify the asset is listed: its leaf Poseidon(assetHash) is in the asset tree */
+    component assetLeaf = Poseidon(1);
+    assetLeaf.inputs[0] <== assetHash;
+    component assetMerkle = MerkleTreeVerifier(ASSET_TREE_DEPTH);
+    assetMerkle.leaf <== assetLeaf.out;
+    for (var i = 0; i < ASSET_TREE_DEPTH; i++) {
+        assetMerkle.pathElements[i] <== assetMerkleProof[i];
+        assetMerkle.pathIndices[i] <== assetMerkleIndices[i];
+    }
+    assetMerkle.expectedRoot <== assetRoot;
+
+    /** 4. Verify the buy order's commitment, fill and limit */
+    component buyFill = OrderFill(0);
+    buyFill.assetHash <== assetHash;
+    buyFill.quantity <== buyOrderQuantity;
+    buyFill.price <== buyOrderPrice;
+    buyFill.nonce <== buyOrderNonce;
+    buyFill.secret <== buyOrderSecret;
+    buyFill.filledBefore <== buyFilledBefore;
+    buyFill.matchedQuantity <== matchedQuantity;
+    buyFill.executionPrice <== executionPrice;
+    buyFill.commitment <== buyCommitment;
+    buyFill.fillBefore <== buyFillBefore;
+    buyFill.fillAfter <== buyFillAfter;
+    buyFill.complete <== buyComplete;
+
+    /** 5. Verify the sell order's commitment, fill and limit */
+    component sellFill = OrderFill(1);
+    sellFill.assetHash <== assetHash;
+    sellFill.quantity <== sellOrderQuantity;
+    sellFill.price <== sellOrderPrice;
+    sellFill.nonce <== sellOrderNonce;
+    sellFill.secret <== sellOrderSecret;
+    sellFill.filledBefore <== sellFilledBefore;
+    sellFill.matchedQuantity <== matchedQuantity;
+    sellFill.executionPrice <== executionPrice;
+    sellFill.commitment <== sellCommitment;
+    sellFill.fillBefore <== sellFillBefore;
+    sellFill.fillAfter <== sellFillAfter;
+    sellFill.complete <== sellComplete;
+
+    /**
+     * 6. Compute nullifier: Poseidon(buyCommit, sellCommit, buyFilled, sellFilled, combinedSecret)
+     * Each fill of the same orders has different cumulative fills, so a distinct nullifier.
+     */
+    component nullifierHasher = Poseidon(5);
+    nullifierHasher.inputs[0] <== buyCommitment;
+    nullifierHasher.inputs[1] <== sellCommitment;
+    nullifierHasher.inputs[2] <== buyFill.filledAfter;
+    nullifierHasher.inputs[3] <== sellFill.filledAfter;
+    nullifierHasher.inputs[4] <== buyOrderSecret + sellOrderSecret;
+    nullifierHash <== nullifierHasher.out;
+}
+
+/** Whitelist depth 20 (up to 2^20 = 1M participants), asset tree depth 10 (1024 assets) */
+component main {public [
+    buyCommitment,
+    sellCommitment,
+    assetHash,
+    matchedQuantity,
+    executionPrice,
+    whitelistRoot,
+    assetRoot,
+    validAt,
+    buyFillBefore,
+    buyFillAfter,
+    sellFillBefore,
+    sellFillAfter,
+    buyComplete,
+    sellComplete
+]} = PartialFillSettlement(20, 10);
@@ -2,7 +2,8 @@
  * Settlement Proof Circuit for RWA Dark Pool
  *
  * Verifies:
- * 1. Buyer and seller are on the whitelist (Merkle proofs)
+ * 1. Buyer and seller are on the whitelist with KYC valid at validAt, and the
+ *    asset is listed (Merkle proofs)
  * 2. Order commitments are valid
  * 3. Trade details match commitments
  * 4. Nullifier is correctly computed
@@ -13,24 +14,34 @@ pragma circom 2.1.0;
 
 include "circomlib/circuits/poseidon.circom";
 include "../merkle/merkle_proof.circom";
+include "../merkle/whitelist_leaf.circom";
 
 /**
  * Settlement Proof Template
  * @param TREE_DEPTH - Whitelist Merkle tree depth (matches registry)
+ * @param ASSET_TREE_DEPTH - Asset tree depth (matches registry)
  */
-template SettlementProof(TREE_DEPTH) {
+template SettlementProof(TREE_DEPTH, ASSET_TREE_DEPTH) {
     /** PRIVATE INPUTS (known only to prover) */
 
-    /** Buyer's whitelist proof */
+    /** Buyer's whitelist leaf and proof */
     signal input buyerIdHash;
+    signal input buyerKycExpiry;
+    signal input buyerCategory;
     signal input buyerMerkleProof[TREE_DEPTH];
     signal input buyerMerkleIndices[TREE_DEPTH];
 
-    /** Seller's whitelist proof */
+    /** Seller's whitelist leaf and proof */
     signal input sellerIdHash;
+    signal input sellerKycExpiry;
+    signal input sellerCategory;
     signal input sellerMerkleProof[TREE_DEPTH];
     signal input sellerMerkleIndices[TREE_DEPTH];
 
+    /** Asset's listing proof */
+    signal input assetMerkleProof[ASSET_TREE_DEPTH];
+    signal input assetMerkleIndices[ASSET_TREE_DEPTH];
+
     /** Order secrets for commitment verification */
     signal input buyOrderSecret;
     signal input buyOrderNonce;
@@ -44,29 +55,54 @@ template SettlementProof(TREE_DEPTH) {
     signal input matchedQuantity;
     signal input executionPrice;
     signal input whitelistRoot;
+    signal input assetRoot;
+    signal input validAt;
 
     /** PUBLIC OUTPUT */
     signal output nullifierHash;
 
-    /** 1. Verify buyer is on whitelist */
+    /** 1. Verify buyer is on whitelist with KYC valid at validAt */
+    component buyerLeaf = WhitelistLeaf();
+    buyerLeaf.idHash <== buyerIdHash;
+    buyerLeaf.kycExpiry <== buyerKycExpiry;
+    buyerLeaf.category <== buyerCategory;
+    buyerLeaf.validAt <== validAt;
+
     component buyerMerkle = MerkleTreeVerifier(TREE_DEPTH);
-    buyerMerkle.leaf <== buyerIdHash;
+    buyerMerkle.leaf <== buyerLeaf.leaf;
     for (var i = 0; i < TREE_DEPTH; i++) {
         buyerMerkle.pathElements[i] <== buyerMerkleProof[i];
         buyerMerkle.pathIndices[i] <== buyerMerkleIndices[i];
     }
     buyerMerkle.expectedRoot <== whitelistRoot;
 
-    /** 2. Verify seller is on whitelist */
+    /** 2. Verify seller is on whitelist with KYC valid at validAt */
+    component sellerLeaf = WhitelistLeaf();
+    sellerLeaf.idHash <== sellerIdHash;
+    sellerLeaf.kycExpiry <== sellerKycExpiry;
+    sellerLeaf.category <== sellerCategory;
+    sellerLeaf.validAt <== validAt;
+
     component sellerMerkle = MerkleTreeVerifier(TREE_DEPTH);
-    sellerMerkle.leaf <== sellerIdHash;
+    sellerMerkle.leaf <== sellerLeaf.leaf;
     for (var i = 0; i < TREE_DEPTH; i++) {
         sellerMerkle.pathElements[i] <== sellerMerkleProof[i];
         sellerMerkle.pathIndices[i] <== sellerMerkleIndices[i];
     }
     sellerMerkle.expectedRoot <== whitelistRoot;
 
-    /** 3. Verify buy order commitment: Poseidon(asset, side=0, qty, price, nonce, secret) */
+    /** 3. Verify the asset is listed: its leaf Poseidon(assetHash) is in the asset tree */
+    component assetLeaf = Poseidon(1);
+    assetLeaf.inputs[0] <== assetHash;
+    component assetMerkle = MerkleTreeVerifier(ASSET_TREE_DEPTH);
+    assetMerkle.leaf <== assetLeaf.out;
+    for (var i = 0; i < ASSET_TREE_DEPTH; i++) {
+        assetMerkle.pathElements[i] <== assetMerkleProof[i];
+        assetMerkle.pathIndices[i] <== assetMerkleIndices[i];
+    }
+    assetMerkle.expectedRoot <== assetRoot;
+
+    /** 4. Verify buy order commitment: Poseidon(asset, side=0, qty, price, nonce, secret) */
     component buyCommitHasher = Poseidon(6);
     buyCommitHasher.inputs[0] <== assetHash;
     buyCommitHasher.inputs[1] <== 0;
@@ -76,7 +112,7 @@ template SettlementProof(TREE_DEPTH) {
     buyCommitHasher.inputs[5] <== buyOrderSecret;
     buyCommitHasher.out === buyCommitment;
 
-    /** 4. Verify sell order commitment: Poseidon(asset, side=1, qty, price, nonce, secret) */
+    /** 5. Verify sell order commitment: Poseidon(asset, side=1, qty, price, nonce, secret) */
     component sellCommitHasher = Poseidon(6);
     sellCommitHasher.inputs[0] <== assetHash;
     sellCommitHasher.inputs[1] <== 1;
@@ -86,7 +122,7 @@ template SettlementProof(TREE_DEPTH) {
     sellCommitHasher.inputs[5] <== sellOrderSecret;
     sellCommitHasher.out === sellCommitment;
 
-    /** 5. Compute nullifier: Poseidon(buyCommit, sellCommit, qty, combinedSecret) */
+    /** 6. Compute nullifier: Poseidon(buyCommit, sellCommit, qty, combinedSecret) */
     component nullifierHasher = Poseidon(4);
     nullifierHasher.inputs[0] <== buyCommitment;
     nullifierHasher.inputs[1] <== sellCommitment;
@@ -95,12 +131,14 @@ template SettlementProof(TREE_DEPTH) {
     nullifierHash <== nullifierHasher.out;
 }
 
-/** Tree depth = 20 (supports up to 2^20 = 1M participants) */
+/** Whitelist depth 20 (up to 2^20 = 1M participants), asset tree depth 10 (1024 assets) */
 component main {public [
     buyCommitment,
     sellCommitment,
     assetHash,
     matchedQuantity,
     executionPrice,
-    whitelistRoot
-]} = SettlementProof(20);
+    whitelistRoot,
+    assetRoot,
+    validAt
+]} = SettlementProof(20, 10);
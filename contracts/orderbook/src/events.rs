@@ -0,0 +1,157 @@
+//! Orderbook events
+//!
+//! Each event's first topic is its name in snake_case (e.g. `order_submitted`),
+//! followed by the fields marked `#[topic]`. The remaining fields form the data map.
+
+use soroban_sdk::{contractevent, Address, BytesN, Vec};
+
+use crate::OrderSide;
+
+/// An order commitment was added to the book
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderSubmitted {
+    #[topic]
+    pub asset_address: Address,
+    #[topic]
+    pub commitment: BytesN<32>,
+    pub trader: Address,
+    pub side: OrderSide,
+    pub expiry: u64,
+    pub tree_index: u32,
+}
+
+/// An order was cancelled by its trader
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderCancelled {
+    #[topic]
+    pub commitment: BytesN<32>,
+    pub trader: Address,
+}
+
+/// An active order passed its expiry and its collateral was released
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderExpired {
+    #[topic]
+    pub asset_address: Address,
+    #[topic]
+    pub commitment: BytesN<32>,
+    pub trader: Address,
+}
+
+/// A buy and a sell order were matched
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderMatched {
+    #[topic]
+    pub asset_address: Address,
+    #[topic]
+    pub match_id: BytesN<32>,
+    pub buy_commitment: BytesN<32>,
+    pub sell_commitment: BytesN<32>,
+    pub payment_asset: Address,
+    pub quantity: i128,
+    pub price: i128,
+    /// Operator (or admin) that recorded the match
+    pub operator: Address,
+}
+
+/// A commitment was added to the order tree, giving it a new root
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderRootChanged {
+    #[topic]
+    pub epoch: u32,
+    pub root: BytesN<32>,
+}
+
+/// A match and both of its orders were marked settled
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderSettled {
+    #[topic]
+    pub match_id: BytesN<32>,
+    pub buy_commitment: BytesN<32>,
+    pub sell_commitment: BytesN<32>,
+}
+
+/// An unsettled match was dropped and its reservations returned to both orders
+#[contractevent]
+#[derive(Clone)]
+pub struct MatchDropped {
+    #[topic]
+    pub match_id: BytesN<32>,
+    pub buy_commitment: BytesN<32>,
+    pub sell_commitment: BytesN<32>,
+    pub dropped_by: Address,
+}
+
+/// The verifier and verification key for order ownership proofs were set
+#[contractevent]
+#[derive(Clone)]
+pub struct OwnershipVkSet {
+    #[topic]
+    pub verifier_address: Address,
+}
+
+/// The verifier and verification key for relayed order proofs were set
+#[contractevent]
+#[derive(Clone)]
+pub struct RelayVkSet {
+    #[topic]
+    pub verifier_address: Address,
+}
+
+/// A matcher operator was registered or its asset scope changed
+#[contractevent]
+#[derive(Clone)]
+pub struct OperatorSet {
+    #[topic]
+    pub operator: Address,
+    pub assets: Vec<Address>,
+}
+
+/// A matcher operator was suspended or reinstated
+#[contractevent]
+#[derive(Clone)]
+pub struct OperatorStatusChanged {
+    #[topic]
+    pub operator: Address,
+    pub is_active: bool,
+}
+
+/// A matcher operator was removed
+#[contractevent]
+#[derive(Clone)]
+pub struct OperatorRemoved {
+    #[topic]
+    pub operator: Address,
+}
+
+/// The contract code was replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgraded {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Stored data was migrated to a new schema version
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigrated {
+    #[topic]
+    pub version: u32,
+}
+
+/// Open orders and unsettled matches moved to a rotated trading address
+#[contractevent]
+#[derive(Clone)]
+pub struct KeyRotationApplied {
+    #[topic]
+    pub old_address: Address,
+    #[topic]
+    pub new_address: Address,
+    pub orders_moved: u32,
+}
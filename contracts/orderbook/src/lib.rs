@@ -1,20 +1,88 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
+use lean_imt_bn254::{frontier_insert, LeanIMTBN254};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec,
-    Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Map, Symbol, TryFromVal, Val, Vec,
 };
 
+mod events;
+
+pub use events::*;
+
 #[cfg(test)]
 mod test;
 
+// Import the verifier contract
+mod verifier_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/groth16_verifier_bn254.wasm"
+    );
+}
+
+// Import the registry contract
+mod registry_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/darkpool_registry.wasm"
+    );
+}
+
+// Import the settlement contract
+mod settlement_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/darkpool_settlement.wasm"
+    );
+}
+
 // Storage keys
 const ADMIN_KEY: Symbol = symbol_short!("admin");
 const REGISTRY_KEY: Symbol = symbol_short!("registry");
 const SETTLEMENT_KEY: Symbol = symbol_short!("settl");
+const VERIFIER_KEY: Symbol = symbol_short!("verifier");
+const OWNERSHIP_VK_KEY: Symbol = symbol_short!("own_vk");
+const RELAY_VK_KEY: Symbol = symbol_short!("relay_vk");
+const OPERATORS_KEY: Symbol = symbol_short!("operators");
+const ORDER_COUNT_KEY: Symbol = symbol_short!("ord_count");
+const SEQUENCE_KEY: Symbol = symbol_short!("seq");
+const SWEEP_CURSOR_KEY: Symbol = symbol_short!("sweep");
+const ORDER_ROOT_KEY: Symbol = symbol_short!("ord_root");
+const ORDER_TREE_SIZE_KEY: Symbol = symbol_short!("tree_size");
+const ROOT_HISTORY_KEY: Symbol = symbol_short!("root_hist");
+const ROOT_EPOCH_KEY: Symbol = symbol_short!("root_ep");
+const SCHEMA_VERSION_KEY: Symbol = symbol_short!("schema");
+
+// Schema 1 kept every order and match in these instance vectors; only the migration reads them
 const ORDERS_KEY: Symbol = symbol_short!("orders");
 const MATCHES_KEY: Symbol = symbol_short!("matches");
 
+// Storage layout version written by this code; bump it with a new migration step
+const SCHEMA_VERSION: u32 = 6;
+
+// Order commitment tree depth (2^20 = ~1M orders)
+const ORDER_TREE_DEPTH: u32 = 20;
+
+// Number of recent order tree roots kept for proofs generated against a slightly older book
+const ROOT_HISTORY_SIZE: u32 = 64;
+
+// Persistent entries are extended to ~90 days whenever fewer than ~30 days remain
+// (ledgers close about every 5 seconds)
+const DAY_IN_LEDGERS: u32 = 17280;
+const ENTRY_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+const ENTRY_TTL_EXTEND: u32 = 90 * DAY_IN_LEDGERS;
+
+// Change feed entries are temporary and kept for ~7 days
+const CHANGE_TTL: u32 = 7 * DAY_IN_LEDGERS;
+
+// Largest number of entries returned by one page or change feed read
+const MAX_PAGE_SIZE: u32 = 100;
+
+// Action tag of order ownership proofs that authorize a cancellation
+const CANCEL_ACTION: u128 = 1;
+
+// Age after which anyone may drop an unsettled match (one day)
+const STALE_MATCH_AGE: u64 = 86_400;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -29,10 +97,42 @@ pub enum OrderbookError {
     MatchNotFound = 8,
     InvalidOrderSide = 9,
     AssetMismatch = 10,
+    NotEligibleForAsset = 11,
+    PairNotListed = 12,
+    TradeConstraintViolation = 13,
+    UnsupportedSchemaVersion = 14,
+    AgentNotAuthorized = 15,
+    KeyNotRotated = 16,
+    MarketHalted = 17,
+    MarketClosed = 18,
+    DuplicateCommitment = 19,
+    MatchAlreadyExists = 20,
+    ChangeFeedExpired = 21,
+    OwnershipVkNotSet = 22,
+    ParticipantNotEligible = 23,
+    AssetNotEligible = 24,
+    InvalidCollateral = 25,
+    InsufficientCollateral = 26,
+    CollateralLockFailed = 27,
+    OrderNotExpired = 28,
+    OrderTreeFull = 29,
+    RelayVkNotSet = 30,
+    UnknownWhitelistRoot = 31,
+    ProofExpired = 32,
+    OperatorNotAuthorized = 33,
+    OperatorSuspended = 34,
+    OperatorNotFound = 35,
+    TraderMismatch = 36,
+    MatchAlreadySettled = 37,
+    SettlementMismatch = 38,
+    MatchesStillOpen = 39,
+    PaymentAssetMissing = 40,
+    FillExceedsCollateral = 41,
+    MatchNotStale = 42,
 }
 
 /// Order side (buy or sell)
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum OrderSide {
@@ -41,7 +141,7 @@ pub enum OrderSide {
 }
 
 /// Order status
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum OrderStatus {
@@ -64,9 +164,19 @@ pub struct OrderCommitment {
     pub timestamp: u64,
     pub expiry: u64,
     pub status: OrderStatus,
+    /// Position of the commitment in the order commitment tree
     pub tree_index: u32,
 }
 
+/// An order tree root together with the epoch and time it became current
+#[derive(Clone)]
+#[contracttype]
+pub struct RootRecord {
+    pub root: BytesN<32>,
+    pub epoch: u32,
+    pub timestamp: u64,
+}
+
 /// Matched trade record
 #[derive(Clone)]
 #[contracttype]
@@ -75,6 +185,7 @@ pub struct MatchRecord {
     pub buy_commitment: BytesN<32>,
     pub sell_commitment: BytesN<32>,
     pub asset_address: Address,
+    pub payment_asset: Address,
     pub buyer: Address,
     pub seller: Address,
     pub quantity: i128,
@@ -83,6 +194,127 @@ pub struct MatchRecord {
     pub is_settled: bool,
 }
 
+/// What a change feed entry refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum ChangeKind {
+    Order = 0,
+    Match = 1,
+}
+
+/// One order or match mutation; read the order or match by `id` for its new state
+#[derive(Clone)]
+#[contracttype]
+pub struct Change {
+    pub seq: u64,
+    pub kind: ChangeKind,
+    pub id: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// A page of orders; pass `next_cursor` to get the following page
+#[derive(Clone)]
+#[contracttype]
+pub struct OrderPage {
+    pub orders: Vec<OrderCommitment>,
+    pub next_cursor: Option<u32>,
+}
+
+/// A page of matches; pass `next_cursor` to get the following page
+#[derive(Clone)]
+#[contracttype]
+pub struct MatchPage {
+    pub matches: Vec<MatchRecord>,
+    pub next_cursor: Option<u32>,
+}
+
+/// Progress of a key rotation; call again from `next_cursor` until it is none
+#[derive(Clone)]
+#[contracttype]
+pub struct KeyRotationPage {
+    pub orders_moved: u32,
+    pub matches_moved: u32,
+    pub next_cursor: Option<u32>,
+}
+
+/// A matching engine key allowed to record and settle matches
+#[derive(Clone)]
+#[contracttype]
+pub struct MatcherOperator {
+    /// Assets the operator may match; empty allows all
+    pub assets: Vec<Address>,
+    /// Suspended operators keep their registration but cannot act
+    pub is_active: bool,
+}
+
+/// Running totals for an order matched in parts
+///
+/// Quantities stay hidden in the order commitment; the orderbook only tracks
+/// what its matches consume so the collateral behind unsettled fills stays locked.
+#[derive(Clone, Default)]
+#[contracttype]
+pub struct OrderFill {
+    /// Total quantity matched so far
+    pub filled: i128,
+    /// Collateral spent by matches not yet settled
+    pub reserved: i128,
+    /// Number of matches not yet settled
+    pub open_matches: u32,
+}
+
+/// Ordered list of order commitments or match ids in persistent storage
+#[derive(Clone)]
+#[contracttype]
+pub enum IndexKey {
+    /// Orders for an asset on one side, in submission order
+    AssetSide(Address, OrderSide),
+    /// Orders for an asset currently in a status
+    AssetStatus(Address, OrderStatus),
+    /// Orders placed by or moved to a trader (entries moved away are skipped on read)
+    Trader(Address),
+    /// All matches, in recording order
+    Matches,
+    /// Matches not yet settled
+    PendingMatches,
+    /// Matches not yet settled in which a trader is the buyer or seller
+    TraderPendingMatches(Address),
+    /// Active orders across all assets, swept by `expire_orders`
+    ActiveOrders,
+}
+
+/// Persistent storage keys
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Order(BytesN<32>),
+    Match(BytesN<32>),
+    /// Number of entries in an index
+    IndexLen(IndexKey),
+    /// Entry at a position in an index
+    IndexEntry(IndexKey, u32),
+    /// Position of an entry in a removable index (status, active and pending match indexes)
+    IndexPos(IndexKey, BytesN<32>),
+    /// Change feed entry by sequence number (temporary storage)
+    Change(u64),
+    /// Fill totals for an order that has been matched
+    Fill(BytesN<32>),
+    /// Schema 4 kept every order tree leaf in this vector; only the migration reads it
+    OrderTreeLeaves,
+    /// Order commitment tree leaf at an index
+    OrderLeaf(u32),
+    /// Roots of the order tree's filled left subtrees, one per level
+    OrderTreeFrontier,
+    /// Marks an order submitted through a relayer
+    Relayed(BytesN<32>),
+    /// Operator (or admin) that recorded a match
+    MatchOperator(BytesN<32>),
+    /// Number of ownership proofs consumed for an order
+    CancelNonce(BytesN<32>),
+    /// Marks a match id dropped before settlement, so it is not recorded again
+    DroppedMatch(BytesN<32>),
+}
+
 #[contract]
 pub struct DarkPoolOrderbook;
 
@@ -101,24 +333,28 @@ impl DarkPoolOrderbook {
         settlement_address: Address,
     ) {
         env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &SCHEMA_VERSION);
         env.storage().instance().set(&REGISTRY_KEY, &registry_address);
         env.storage().instance().set(&SETTLEMENT_KEY, &settlement_address);
 
-        // Initialize empty orders and matches
-        let orders: Vec<OrderCommitment> = vec![&env];
-        let matches: Vec<MatchRecord> = vec![&env];
-        env.storage().instance().set(&ORDERS_KEY, &orders);
-        env.storage().instance().set(&MATCHES_KEY, &matches);
+        // Record the empty order tree's root as epoch 0
+        let tree = LeanIMTBN254::new(&env, ORDER_TREE_DEPTH);
+        Self::record_order_root(&env, tree.get_root());
     }
 
     /// Submit a new order commitment
     ///
+    /// Locks `collateral_amount` of the trader's settlement escrow for this order in
+    /// the same transaction; the lock is released if the order is cancelled or expires.
+    ///
     /// # Arguments
     /// * `trader` - Address of the trader (must authenticate)
     /// * `commitment` - Hash commitment of the order (Poseidon(asset, side, qty, price, nonce, secret))
     /// * `asset_address` - The RWA token address (public for matching)
     /// * `side` - Buy or Sell (public for matching)
     /// * `expiry_seconds` - How many seconds until order expires
+    /// * `collateral_asset` - Token to lock: the payment asset for buys, the RWA asset for sells
+    /// * `collateral_amount` - Amount to lock (the most the order can pay or deliver)
     ///
     /// # Returns
     /// * The index of the order in the orderbook
@@ -129,172 +365,522 @@ impl DarkPoolOrderbook {
         asset_address: Address,
         side: OrderSide,
         expiry_seconds: u64,
+        collateral_asset: Address,
+        collateral_amount: i128,
     ) -> Result<u32, OrderbookError> {
         trader.require_auth();
+        Self::place_order(
+            &env,
+            trader,
+            commitment,
+            asset_address,
+            side,
+            expiry_seconds,
+            collateral_asset,
+            collateral_amount,
+            false,
+        )
+    }
 
-        let current_time = env.ledger().timestamp();
-        let expiry = current_time + expiry_seconds;
-
-        let mut orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
-
-        let tree_index = orders.len() as u32;
+    /// Submit an order commitment for a participant as their authorized agent
+    ///
+    /// The order belongs to `principal`; the agent's registry scope must cover the asset.
+    ///
+    /// # Arguments
+    /// * `agent` - Agent address (must authenticate)
+    /// * `principal` - Participant the order is placed for
+    /// * `commitment` - Hash commitment of the order
+    /// * `asset_address` - The RWA token address
+    /// * `side` - Buy or Sell
+    /// * `expiry_seconds` - How many seconds until order expires
+    /// * `collateral_asset` - Token to lock from the principal's escrow
+    /// * `collateral_amount` - Amount to lock
+    pub fn submit_order_for(
+        env: Env,
+        agent: Address,
+        principal: Address,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        side: OrderSide,
+        expiry_seconds: u64,
+        collateral_asset: Address,
+        collateral_amount: i128,
+    ) -> Result<u32, OrderbookError> {
+        agent.require_auth();
+        Self::require_agent(&env, &principal, &agent, &asset_address)?;
+        Self::place_order(
+            &env,
+            principal,
+            commitment,
+            asset_address,
+            side,
+            expiry_seconds,
+            collateral_asset,
+            collateral_amount,
+            false,
+        )
+    }
 
-        let order = OrderCommitment {
-            commitment: commitment.clone(),
-            trader: trader.clone(),
-            asset_address: asset_address.clone(),
+    /// Submit an order through a relayer without naming its owner
+    ///
+    /// The order carries a Groth16 proof (`circuits/order/relayed_order.circom`)
+    /// that its owner is on the registry whitelist, knows the commitment's
+    /// opening, and that `collateral_amount` covers the committed order. No
+    /// trader address is stored: the order's `trader` is the settlement
+    /// contract, and its collateral is locked from the commitment's note in
+    /// settlement (`deposit_note`), which anyone, typically the relayer, can
+    /// fund. The order belongs to whoever holds the commitment secret, who can
+    /// cancel it with `cancel_order_by_proof` and withdraw the note's funds with
+    /// `withdraw_relayed_funds`.
+    ///
+    /// # Arguments
+    /// * `relayer` - Account submitting the order (must authenticate)
+    /// * `commitment` - Hash commitment of the order
+    /// * `asset_address` - The RWA token address
+    /// * `side` - Buy or Sell
+    /// * `expiry_seconds` - How many seconds until order expires
+    /// * `collateral_asset` - Token to lock: the payment asset for buys, the RWA asset for sells
+    /// * `collateral_amount` - Amount of `collateral_asset` to lock from the note
+    /// * `proof_bytes` - Relayed order proof
+    /// * `pub_signals_bytes` - `[commitment, whitelistRoot, side, collateralAmount, validAt]`
+    pub fn submit_relayed_order(
+        env: Env,
+        relayer: Address,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        side: OrderSide,
+        expiry_seconds: u64,
+        collateral_asset: Address,
+        collateral_amount: i128,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<u32, OrderbookError> {
+        relayer.require_auth();
+        Self::verify_relayed_order(
+            &env,
+            &commitment,
             side,
-            timestamp: current_time,
-            expiry,
-            status: OrderStatus::Active,
-            tree_index,
-        };
+            collateral_amount,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )?;
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        Self::place_order(
+            &env,
+            settlement_address,
+            commitment,
+            asset_address,
+            side,
+            expiry_seconds,
+            collateral_asset,
+            collateral_amount,
+            true,
+        )
+    }
 
-        orders.push_back(order);
-        env.storage().instance().set(&ORDERS_KEY, &orders);
+    /// Withdraw the unlocked funds of a commitment's note to any account
+    ///
+    /// Collateral a relayed order no longer needs, and what its trades pay it,
+    /// stay in the commitment's note in settlement. The ownership proof's
+    /// public signals are `[commitment, get_withdraw_action(recipient), nonce]`,
+    /// with the commitment's current `get_cancel_nonce`, so the proof cannot be
+    /// redirected to another recipient or replayed.
+    ///
+    /// # Arguments
+    /// * `commitment` - Commitment whose note to withdraw from
+    /// * `asset` - Token to withdraw; the note's whole unlocked balance is paid out
+    /// * `recipient` - Account receiving the tokens
+    /// * `proof_bytes` - ZK proof of order ownership
+    /// * `pub_signals_bytes` - `[commitment, get_withdraw_action(recipient), nonce]`
+    ///
+    /// # Returns
+    /// * The amount withdrawn
+    pub fn withdraw_relayed_funds(
+        env: Env,
+        commitment: BytesN<32>,
+        asset: Address,
+        recipient: Address,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<i128, OrderbookError> {
+        let action = Self::get_withdraw_action(env.clone(), recipient.clone());
+        Self::verify_ownership(
+            &env,
+            &commitment,
+            &Bytes::from_array(&env, &action.to_array()),
+            &proof_bytes,
+            &pub_signals_bytes,
+        )?;
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let settlement_client = settlement_wasm::Client::new(&env, &settlement_address);
+        Ok(settlement_client.withdraw_note(&commitment, &asset, &recipient))
+    }
 
-        Ok(tree_index)
+    /// Get the action signal of an ownership proof that withdraws to `recipient`
+    ///
+    /// It is the SHA-256 of the recipient's XDR encoding with the first byte
+    /// cleared, so it fits the BN254 scalar field.
+    pub fn get_withdraw_action(env: Env, recipient: Address) -> BytesN<32> {
+        let mut action = env.crypto().sha256(&recipient.to_xdr(&env)).to_array();
+        action[0] = 0;
+        BytesN::from_array(&env, &action)
     }
 
     /// Cancel an order with ownership proof
     ///
+    /// The proof shows knowledge of the order's secret. Its public signals are
+    /// `[commitment, CANCEL_ACTION, nonce]`, where `nonce` is the order's
+    /// current `get_cancel_nonce`, so a proof cannot be replayed. See
+    /// `set_ownership_vk`.
+    ///
     /// # Arguments
     /// * `trader` - Address of the trader (must authenticate)
     /// * `commitment` - The order commitment to cancel
     /// * `proof_bytes` - ZK proof of order ownership
-    /// * `pub_signals_bytes` - Public signals for the proof
+    /// * `pub_signals_bytes` - `[commitment, CANCEL_ACTION, nonce]`
     pub fn cancel_order(
         env: Env,
         trader: Address,
         commitment: BytesN<32>,
-        _proof_bytes: Bytes,
-        _pub_signals_bytes: Bytes,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
     ) -> Result<(), OrderbookError> {
         trader.require_auth();
+        Self::cancel(&env, Some(trader), commitment, proof_bytes, pub_signals_bytes)
+    }
 
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
+    /// Cancel a participant's order as their authorized agent
+    ///
+    /// # Arguments
+    /// * `agent` - Agent address (must authenticate)
+    /// * `principal` - Participant that owns the order
+    /// * `commitment` - The order commitment to cancel
+    /// * `proof_bytes` - ZK proof of order ownership
+    /// * `pub_signals_bytes` - `[commitment, CANCEL_ACTION, nonce]`
+    pub fn cancel_order_for(
+        env: Env,
+        agent: Address,
+        principal: Address,
+        commitment: BytesN<32>,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<(), OrderbookError> {
+        agent.require_auth();
 
-        let mut found = false;
-        let mut updated_orders: Vec<OrderCommitment> = vec![&env];
+        let order = Self::get_order(env.clone(), commitment.clone())
+            .ok_or(OrderbookError::OrderNotFound)?;
+        Self::require_agent(&env, &principal, &agent, &order.asset_address)?;
+        Self::cancel(&env, Some(principal), commitment, proof_bytes, pub_signals_bytes)
+    }
 
-        for order in orders.iter() {
-            if order.commitment == commitment {
-                // Verify trader owns the order
-                if order.trader != trader {
-                    return Err(OrderbookError::UnauthorizedCancellation);
-                }
+    /// Cancel an order with the ownership proof alone, without a trader signature
+    ///
+    /// Whoever knows the order's secret can cancel it, so the proof can be sent
+    /// from any account.
+    ///
+    /// # Arguments
+    /// * `commitment` - The order commitment to cancel
+    /// * `proof_bytes` - ZK proof of order ownership
+    /// * `pub_signals_bytes` - `[commitment, CANCEL_ACTION, nonce]`
+    pub fn cancel_order_by_proof(
+        env: Env,
+        commitment: BytesN<32>,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<(), OrderbookError> {
+        Self::cancel(&env, None, commitment, proof_bytes, pub_signals_bytes)
+    }
 
-                // Check order is still active
-                match order.status {
-                    OrderStatus::Matched | OrderStatus::Settled => {
-                        return Err(OrderbookError::OrderAlreadyMatched);
-                    }
-                    OrderStatus::Cancelled => {
-                        return Err(OrderbookError::OrderAlreadyCancelled);
-                    }
-                    _ => {}
-                }
+    /// Mark an active order past its expiry as expired and release its collateral
+    ///
+    /// Anyone may call this.
+    ///
+    /// # Arguments
+    /// * `commitment` - The expired order's commitment
+    pub fn expire_order(env: Env, commitment: BytesN<32>) -> Result<(), OrderbookError> {
+        let mut order = Self::load_order(&env, &commitment)?;
+        if !Self::expire_if_due(&env, &mut order) {
+            return Err(OrderbookError::OrderNotExpired);
+        }
+        Ok(())
+    }
 
-                // TODO: In production, verify the ZK proof of ownership
-                // For now, we just check the trader address matches
+    /// Expire active orders past their expiry, across all assets
+    ///
+    /// Anyone may call this. Each call checks up to `limit` active orders (at most
+    /// 100), continuing where the previous call stopped and wrapping around.
+    ///
+    /// # Arguments
+    /// * `limit` - Number of active orders to check
+    ///
+    /// # Returns
+    /// * The number of orders expired
+    pub fn expire_orders(env: Env, limit: u32) -> u32 {
+        let index = IndexKey::ActiveOrders;
+        let mut len = Self::index_len(&env, &index);
+        let mut pos: u32 = env.storage().instance().get(&SWEEP_CURSOR_KEY).unwrap_or(0);
 
-                let mut cancelled_order = order.clone();
-                cancelled_order.status = OrderStatus::Cancelled;
-                updated_orders.push_back(cancelled_order);
-                found = true;
+        let mut expired = 0u32;
+        for _ in 0..limit.min(MAX_PAGE_SIZE) {
+            if len == 0 {
+                break;
+            }
+            if pos >= len {
+                pos = 0;
+            }
+            let commitment: BytesN<32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IndexEntry(index.clone(), pos))
+                .unwrap();
+            let mut order = Self::load_order(&env, &commitment).unwrap();
+            // An expired order leaves the index and the last entry takes its slot
+            if Self::expire_if_due(&env, &mut order) {
+                expired += 1;
+                len -= 1;
             } else {
-                updated_orders.push_back(order);
+                pos += 1;
             }
         }
 
-        if !found {
-            return Err(OrderbookError::OrderNotFound);
+        env.storage().instance().set(&SWEEP_CURSOR_KEY, &pos);
+        expired
+    }
+
+    /// Set the verifier contract and verification key for order ownership proofs
+    ///
+    /// Cancellations fail with `OwnershipVkNotSet` until this is called.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `verifier_address` - Address of the Groth16 verifier contract
+    /// * `vk_bytes` - Serialized verification key of the ownership circuit
+    pub fn set_ownership_vk(
+        env: Env,
+        admin: Address,
+        verifier_address: Address,
+        vk_bytes: Bytes,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&VERIFIER_KEY, &verifier_address);
+        env.storage().instance().set(&OWNERSHIP_VK_KEY, &vk_bytes);
+
+        OwnershipVkSet { verifier_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the verifier contract and verification key for relayed order proofs
+    ///
+    /// Relayed submissions fail with `RelayVkNotSet` until this is called.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `verifier_address` - Address of the Groth16 verifier contract
+    /// * `vk_bytes` - Serialized verification key of the relayed order circuit
+    pub fn set_relay_vk(
+        env: Env,
+        admin: Address,
+        verifier_address: Address,
+        vk_bytes: Bytes,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&VERIFIER_KEY, &verifier_address);
+        env.storage().instance().set(&RELAY_VK_KEY, &vk_bytes);
+
+        RelayVkSet { verifier_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Register a matcher operator, or replace an existing operator's asset scope
+    ///
+    /// New operators start active; updating a suspended operator's scope keeps it suspended.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `operator` - Matching engine key to register
+    /// * `assets` - Assets the operator may match; empty allows all
+    pub fn set_operator(
+        env: Env,
+        admin: Address,
+        operator: Address,
+        assets: Vec<Address>,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut operators = Self::operators(&env);
+        let is_active = operators.get(operator.clone()).is_none_or(|o| o.is_active);
+        operators.set(
+            operator.clone(),
+            MatcherOperator {
+                assets: assets.clone(),
+                is_active,
+            },
+        );
+        env.storage().instance().set(&OPERATORS_KEY, &operators);
+
+        OperatorSet { operator, assets }.publish(&env);
+        Ok(())
+    }
+
+    /// Suspend or reinstate a matcher operator
+    ///
+    /// A suspended operator cannot record or settle matches until reinstated.
+    /// Matches it already recorded are unaffected.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `operator` - Registered operator
+    /// * `is_active` - `false` to suspend, `true` to reinstate
+    pub fn set_operator_active(
+        env: Env,
+        admin: Address,
+        operator: Address,
+        is_active: bool,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut operators = Self::operators(&env);
+        let mut record = operators
+            .get(operator.clone())
+            .ok_or(OrderbookError::OperatorNotFound)?;
+        record.is_active = is_active;
+        operators.set(operator.clone(), record);
+        env.storage().instance().set(&OPERATORS_KEY, &operators);
+
+        OperatorStatusChanged { operator, is_active }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove a matcher operator
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `operator` - Registered operator
+    pub fn remove_operator(
+        env: Env,
+        admin: Address,
+        operator: Address,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut operators = Self::operators(&env);
+        if !operators.contains_key(operator.clone()) {
+            return Err(OrderbookError::OperatorNotFound);
         }
+        operators.remove(operator.clone());
+        env.storage().instance().set(&OPERATORS_KEY, &operators);
 
-        env.storage().instance().set(&ORDERS_KEY, &updated_orders);
+        OperatorRemoved { operator }.publish(&env);
         Ok(())
     }
 
+    /// Get a matcher operator's registration, if any
+    pub fn get_operator(env: Env, operator: Address) -> Option<MatcherOperator> {
+        Self::operators(&env).get(operator)
+    }
+
+    /// Get all registered matcher operators
+    pub fn get_operators(env: Env) -> Vec<Address> {
+        Self::operators(&env).keys()
+    }
+
     /// Record a matched trade (called by matching engine)
     ///
+    /// The match is attributed to `matcher` (see `get_match_operator`).
+    ///
     /// # Arguments
-    /// * `admin` - Must be admin
+    /// * `matcher` - An active matcher operator in scope for the asset, or the admin
     /// * `match_id` - Unique identifier for the match
     /// * `buy_commitment` - The buy order commitment
     /// * `sell_commitment` - The sell order commitment
     /// * `asset_address` - The RWA token being traded
+    /// * `payment_asset` - The payment token (must form a listed pair with the asset)
     /// * `buyer` - Buyer address
     /// * `seller` - Seller address
     /// * `quantity` - Matched quantity
     /// * `price` - Execution price
+    ///
+    /// Both orders stay active for further matches. The orderbook cannot see an
+    /// order's total quantity, so it only learns that an order is filled from the
+    /// settlement proof (see `mark_settled`). Each order's unsettled matches must
+    /// not spend more than its locked collateral (`FillExceedsCollateral`).
     pub fn record_match(
         env: Env,
-        admin: Address,
+        matcher: Address,
         match_id: BytesN<32>,
         buy_commitment: BytesN<32>,
         sell_commitment: BytesN<32>,
         asset_address: Address,
+        payment_asset: Address,
         buyer: Address,
         seller: Address,
         quantity: i128,
         price: i128,
     ) -> Result<(), OrderbookError> {
-        admin.require_auth();
-        Self::require_admin(&env, &admin)?;
+        matcher.require_auth();
+        Self::require_matcher(&env, &matcher, &asset_address)?;
 
-        // Update order statuses
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
-
-        let mut updated_orders: Vec<OrderCommitment> = vec![&env];
-        let mut buy_found = false;
-        let mut sell_found = false;
-
-        for order in orders.iter() {
-            if order.commitment == buy_commitment {
-                if order.asset_address != asset_address {
-                    return Err(OrderbookError::AssetMismatch);
-                }
-                let mut matched_order = order.clone();
-                matched_order.status = OrderStatus::Matched;
-                updated_orders.push_back(matched_order);
-                buy_found = true;
-            } else if order.commitment == sell_commitment {
-                if order.asset_address != asset_address {
-                    return Err(OrderbookError::AssetMismatch);
-                }
-                let mut matched_order = order.clone();
-                matched_order.status = OrderStatus::Matched;
-                updated_orders.push_back(matched_order);
-                sell_found = true;
-            } else {
-                updated_orders.push_back(order);
+        // Check the pair is listed and the trade respects its tick/lot sizes
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(&env, &registry_address);
+        match registry_client.try_validate_trade(&asset_address, &payment_asset, &quantity, &price) {
+            Ok(Ok(())) => {}
+            Err(Ok(registry_wasm::RegistryError::PairNotFound))
+            | Err(Ok(registry_wasm::RegistryError::PairNotActive)) => {
+                return Err(OrderbookError::PairNotListed);
             }
+            _ => return Err(OrderbookError::TradeConstraintViolation),
         }
+        Self::require_market_open(&env, &asset_address)?;
 
-        if !buy_found || !sell_found {
+        if env.storage().persistent().has(&DataKey::Match(match_id.clone()))
+            || env.storage().persistent().has(&DataKey::DroppedMatch(match_id.clone()))
+        {
+            return Err(OrderbookError::MatchAlreadyExists);
+        }
+        if buy_commitment == sell_commitment {
             return Err(OrderbookError::OrderNotFound);
         }
 
-        env.storage().instance().set(&ORDERS_KEY, &updated_orders);
+        // Update order statuses
+        let buy_order = Self::load_order(&env, &buy_commitment)?;
+        let sell_order = Self::load_order(&env, &sell_commitment)?;
+        if buy_order.asset_address != asset_address || sell_order.asset_address != asset_address {
+            return Err(OrderbookError::AssetMismatch);
+        }
+        if buy_order.side != OrderSide::Buy || sell_order.side != OrderSide::Sell {
+            return Err(OrderbookError::InvalidOrderSide);
+        }
+        if buy_order.trader != buyer || sell_order.trader != seller {
+            return Err(OrderbookError::TraderMismatch);
+        }
+        if Self::is_expired(&env, &buy_order) || Self::is_expired(&env, &sell_order) {
+            return Err(OrderbookError::OrderExpired);
+        }
+        Self::require_matchable(&buy_order)?;
+        Self::require_matchable(&sell_order)?;
+        // Eligibility may have lapsed since the orders were placed
+        Self::require_order_eligible(&env, &buy_order)?;
+        Self::require_order_eligible(&env, &sell_order)?;
+
+        // Buys spend the payment asset, sells the asset itself
+        Self::add_fill(&env, &buy_order, quantity, price)?;
+        Self::add_fill(&env, &sell_order, quantity, quantity)?;
 
         // Create match record
         let match_record = MatchRecord {
             match_id: match_id.clone(),
-            buy_commitment,
-            sell_commitment,
-            asset_address,
+            buy_commitment: buy_commitment.clone(),
+            sell_commitment: sell_commitment.clone(),
+            asset_address: asset_address.clone(),
+            payment_asset: payment_asset.clone(),
             buyer,
             seller,
             quantity,
@@ -302,183 +888,514 @@ impl DarkPoolOrderbook {
             timestamp: env.ledger().timestamp(),
             is_settled: false,
         };
+        Self::insert_match(&env, &match_record);
+        Self::persist(&env, &DataKey::MatchOperator(match_id.clone()), &matcher);
 
-        let mut matches: Vec<MatchRecord> = env
-            .storage()
-            .instance()
-            .get(&MATCHES_KEY)
-            .unwrap_or(vec![&env]);
-        matches.push_back(match_record);
-        env.storage().instance().set(&MATCHES_KEY, &matches);
+        OrderMatched {
+            asset_address,
+            match_id,
+            buy_commitment,
+            sell_commitment,
+            payment_asset,
+            quantity,
+            price,
+            operator: matcher,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    /// Mark a match as settled (called after successful settlement)
+    /// Mark a match as settled
+    ///
+    /// Only the settlement contract may call this, from the trade that settled
+    /// the match. The settled orders and amounts must be the recorded match's.
+    /// An order the settlement proof shows completely filled moves to `Settled`
+    /// and its remaining collateral is released; it must have no other pending
+    /// matches, which could no longer settle. Such a match can be removed with
+    /// `drop_match` and the settlement retried.
+    ///
+    /// # Arguments
+    /// * `match_id` - Match that was settled
+    /// * `buy_commitment` - Buy order commitment proven in the settlement
+    /// * `sell_commitment` - Sell order commitment proven in the settlement
+    /// * `quantity` - Settled quantity
+    /// * `price` - Settled price
+    /// * `buy_complete` - Whether the proof shows the buy order completely filled
+    /// * `sell_complete` - Whether the proof shows the sell order completely filled
     pub fn mark_settled(
         env: Env,
-        admin: Address,
         match_id: BytesN<32>,
-    ) -> Result<(), OrderbookError> {
-        admin.require_auth();
-        Self::require_admin(&env, &admin)?;
+        buy_commitment: BytesN<32>,
+        sell_commitment: BytesN<32>,
+        quantity: i128,
+        price: i128,
+        buy_complete: bool,
+        sell_complete: bool,
+    ) -> Result<(), OrderbookError> {
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        settlement_address.require_auth();
 
-        let matches: Vec<MatchRecord> = env
-            .storage()
-            .instance()
-            .get(&MATCHES_KEY)
-            .unwrap_or(vec![&env]);
-
-        let mut found = false;
-        let mut updated_matches: Vec<MatchRecord> = vec![&env];
-
-        for m in matches.iter() {
-            if m.match_id == match_id {
-                let mut settled = m.clone();
-                settled.is_settled = true;
-                updated_matches.push_back(settled);
-                found = true;
-            } else {
-                updated_matches.push_back(m);
+        let mut match_record = Self::get_match(env.clone(), match_id.clone())
+            .ok_or(OrderbookError::MatchNotFound)?;
+        if match_record.is_settled {
+            return Err(OrderbookError::MatchAlreadySettled);
+        }
+        if match_record.buy_commitment != buy_commitment
+            || match_record.sell_commitment != sell_commitment
+            || match_record.quantity != quantity
+            || match_record.price != price
+        {
+            return Err(OrderbookError::SettlementMismatch);
+        }
+        match_record.is_settled = true;
+        Self::save_match(&env, &match_record);
+        Self::remove_pending_match(&env, &match_record);
+
+        // Orders become settled once the proof shows them filled
+        let spent = [
+            (&match_record.buy_commitment, match_record.price, buy_complete),
+            (&match_record.sell_commitment, match_record.quantity, sell_complete),
+        ];
+        for (commitment, amount, complete) in spent {
+            let Ok(mut order) = Self::load_order(&env, commitment) else {
+                continue;
+            };
+            let fill_key = DataKey::Fill(commitment.clone());
+            let Some(mut fill) = env.storage().persistent().get::<_, OrderFill>(&fill_key) else {
+                // Orders matched before partial fills were supported
+                Self::set_order_status(&env, &mut order, OrderStatus::Settled);
+                continue;
+            };
+            fill.reserved -= amount;
+            fill.open_matches -= 1;
+            Self::persist(&env, &fill_key, &fill);
+            if !complete {
+                continue;
+            }
+            if fill.open_matches > 0 {
+                return Err(OrderbookError::MatchesStillOpen);
+            }
+            if matches!(order.status, OrderStatus::Active | OrderStatus::Matched) {
+                Self::set_order_status(&env, &mut order, OrderStatus::Settled);
+                Self::release_collateral(&env, commitment, 0);
             }
         }
 
-        if !found {
-            return Err(OrderbookError::MatchNotFound);
+        OrderSettled {
+            match_id,
+            buy_commitment: match_record.buy_commitment,
+            sell_commitment: match_record.sell_commitment,
         }
+        .publish(&env);
 
-        env.storage().instance().set(&MATCHES_KEY, &updated_matches);
+        Ok(())
+    }
 
-        // Also update order statuses to Settled
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
+    /// Drop an unsettled match, returning what it reserved to both orders
+    ///
+    /// The admin or the operator that recorded the match may drop it at any
+    /// time. Anyone else may drop it once it is `STALE_MATCH_AGE` (one day) old.
+    /// The orders' fill totals no longer count the match; a cancelled or expired
+    /// order gets back the collateral the match was holding. The match id cannot
+    /// be recorded again.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin, recording operator, or anyone for a stale match
+    /// * `match_id` - Unsettled match to drop
+    pub fn drop_match(
+        env: Env,
+        caller: Address,
+        match_id: BytesN<32>,
+    ) -> Result<(), OrderbookError> {
+        caller.require_auth();
+
+        let match_record = Self::get_match(env.clone(), match_id.clone())
+            .ok_or(OrderbookError::MatchNotFound)?;
+        if match_record.is_settled {
+            return Err(OrderbookError::MatchAlreadySettled);
+        }
+        let recorder = Self::get_match_operator(env.clone(), match_id.clone());
+        let stale = env.ledger().timestamp() >= match_record.timestamp + STALE_MATCH_AGE;
+        if !stale && recorder != Some(caller.clone()) && Self::require_admin(&env, &caller).is_err()
+        {
+            return Err(OrderbookError::MatchNotStale);
+        }
 
-        // Find the match to get commitments
-        let match_record = Self::get_match(env.clone(), match_id.clone()).unwrap();
+        env.storage().persistent().remove(&DataKey::Match(match_id.clone()));
+        env.storage().persistent().remove(&DataKey::MatchOperator(match_id.clone()));
+        Self::persist(&env, &DataKey::DroppedMatch(match_id.clone()), &true);
+        Self::remove_pending_match(&env, &match_record);
+        Self::record_change(&env, ChangeKind::Match, &match_id);
 
-        let mut updated_orders: Vec<OrderCommitment> = vec![&env];
-        for order in orders.iter() {
-            if order.commitment == match_record.buy_commitment
-                || order.commitment == match_record.sell_commitment
+        let reserved = [
+            (&match_record.buy_commitment, match_record.price),
+            (&match_record.sell_commitment, match_record.quantity),
+        ];
+        for (commitment, amount) in reserved {
+            let fill_key = DataKey::Fill(commitment.clone());
+            let Some(mut fill) = env.storage().persistent().get::<_, OrderFill>(&fill_key) else {
+                continue;
+            };
+            fill.filled -= match_record.quantity;
+            fill.reserved -= amount;
+            fill.open_matches -= 1;
+            Self::persist(&env, &fill_key, &fill);
+            Self::record_change(&env, ChangeKind::Order, commitment);
+
+            // Closed orders only kept the collateral their pending matches spend
+            if let Ok(order) = Self::load_order(&env, commitment)
+                && matches!(order.status, OrderStatus::Cancelled | OrderStatus::Expired)
             {
-                let mut settled_order = order.clone();
-                settled_order.status = OrderStatus::Settled;
-                updated_orders.push_back(settled_order);
-            } else {
-                updated_orders.push_back(order);
+                Self::release_collateral(&env, commitment, fill.reserved);
             }
         }
 
-        env.storage().instance().set(&ORDERS_KEY, &updated_orders);
-
+        MatchDropped {
+            match_id,
+            buy_commitment: match_record.buy_commitment,
+            sell_commitment: match_record.sell_commitment,
+            dropped_by: caller,
+        }
+        .publish(&env);
         Ok(())
     }
 
+    /// Move a rotated participant's open orders to their new trading address
+    ///
+    /// Anyone may call this once the registry has approved the rotation. Active
+    /// and matched orders, and unsettled matches, are reassigned; commitments
+    /// and tree indexes are unchanged. Each call reads up to `limit` (at most
+    /// 100) of the old address's orders from `cursor` and moves up to `limit`
+    /// of its unsettled matches. Start with cursor 0 and call again with
+    /// `next_cursor` until it is none.
+    ///
+    /// # Arguments
+    /// * `old_address` - Participant's retired trading address
+    /// * `cursor` - Position in the old address's orders to continue from
+    /// * `limit` - Largest number of orders and of matches to handle
+    ///
+    /// # Returns
+    /// * The number of orders and matches reassigned, and where to continue
+    pub fn apply_key_rotation(
+        env: Env,
+        old_address: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<KeyRotationPage, OrderbookError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(&env, &registry_address);
+        let new_address = registry_client
+            .get_rotated_address(&old_address)
+            .ok_or(OrderbookError::KeyNotRotated)?;
+
+        let trader_index = IndexKey::Trader(old_address.clone());
+        let (commitments, next_order) = Self::index_page(&env, &trader_index, cursor, limit);
+        let mut orders_moved = 0u32;
+        for commitment in commitments.iter() {
+            let mut order = Self::load_order(&env, &commitment)?;
+            Self::expire_if_due(&env, &mut order);
+            let open = matches!(order.status, OrderStatus::Active | OrderStatus::Matched);
+            if open && order.trader == old_address {
+                order.trader = new_address.clone();
+                Self::save_order(&env, &order);
+                Self::index_push(&env, IndexKey::Trader(new_address.clone()), &commitment);
+                orders_moved += 1;
+            }
+        }
+
+        // Moved matches leave the old address's index, so take them from its end
+        let match_index = IndexKey::TraderPendingMatches(old_address.clone());
+        let mut matches_moved = 0u32;
+        while matches_moved < limit.min(MAX_PAGE_SIZE) {
+            let len = Self::index_len(&env, &match_index);
+            if len == 0 {
+                break;
+            }
+            let match_id: BytesN<32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IndexEntry(match_index.clone(), len - 1))
+                .unwrap();
+            Self::index_remove(&env, match_index.clone(), &match_id);
+            let mut m = Self::get_match(env.clone(), match_id).unwrap();
+            if m.buyer == old_address {
+                m.buyer = new_address.clone();
+            }
+            if m.seller == old_address {
+                m.seller = new_address.clone();
+            }
+            Self::save_match(&env, &m);
+            Self::index_trader_match(&env, &m);
+            matches_moved += 1;
+        }
+
+        let next_cursor = match next_order {
+            None if Self::index_len(&env, &match_index) == 0 => None,
+            None => Some(Self::index_len(&env, &trader_index)),
+            next => next,
+        };
+
+        KeyRotationApplied {
+            old_address,
+            new_address,
+            orders_moved,
+        }
+        .publish(&env);
+        Ok(KeyRotationPage {
+            orders_moved,
+            matches_moved,
+            next_cursor,
+        })
+    }
+
     /// Get all orders for an asset and side
     pub fn get_orders_by_asset(
         env: Env,
         asset_address: Address,
         side: Option<OrderSide>,
     ) -> Vec<OrderCommitment> {
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
-
-        let mut filtered: Vec<OrderCommitment> = vec![&env];
-        for order in orders.iter() {
-            if order.asset_address == asset_address {
-                match side {
-                    Some(s) if order.side == s => filtered.push_back(order),
-                    None => filtered.push_back(order),
-                    _ => {}
-                }
+        match side {
+            Some(side) => Self::orders_in(&env, &IndexKey::AssetSide(asset_address, side)),
+            None => {
+                let mut orders = Self::orders_in(
+                    &env,
+                    &IndexKey::AssetSide(asset_address.clone(), OrderSide::Buy),
+                );
+                orders.append(&Self::orders_in(
+                    &env,
+                    &IndexKey::AssetSide(asset_address, OrderSide::Sell),
+                ));
+                orders
             }
         }
-        filtered
     }
 
     /// Get active orders only
     pub fn get_active_orders(env: Env, asset_address: Address) -> Vec<OrderCommitment> {
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
-
         let current_time = env.ledger().timestamp();
         let mut active: Vec<OrderCommitment> = vec![&env];
 
-        for order in orders.iter() {
-            if order.asset_address == asset_address
-                && order.status == OrderStatus::Active
-                && order.expiry > current_time
-            {
+        let index = IndexKey::AssetStatus(asset_address, OrderStatus::Active);
+        for order in Self::orders_in(&env, &index).iter() {
+            if order.expiry > current_time {
                 active.push_back(order);
             }
         }
         active
     }
 
-    /// Get an order by commitment
-    pub fn get_order(env: Env, commitment: BytesN<32>) -> Option<OrderCommitment> {
-        let orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
+    /// Get an asset's orders currently in a status
+    ///
+    /// Active orders past their expiry are listed as expired, even before
+    /// `expire_order` or `expire_orders` records it.
+    pub fn get_orders_by_status(
+        env: Env,
+        asset_address: Address,
+        status: OrderStatus,
+    ) -> Vec<OrderCommitment> {
+        let mut orders: Vec<OrderCommitment> = vec![&env];
+        let index = IndexKey::AssetStatus(asset_address.clone(), status);
+        for order in Self::orders_in(&env, &index).iter() {
+            if order.status == status {
+                orders.push_back(order);
+            }
+        }
+        if status == OrderStatus::Expired {
+            let index = IndexKey::AssetStatus(asset_address, OrderStatus::Active);
+            for order in Self::orders_in(&env, &index).iter() {
+                if order.status == OrderStatus::Expired {
+                    orders.push_back(order);
+                }
+            }
+        }
+        orders
+    }
 
-        for order in orders.iter() {
-            if order.commitment == commitment {
-                return Some(order);
+    /// Get all orders owned by a trader
+    pub fn get_orders_by_trader(env: Env, trader: Address) -> Vec<OrderCommitment> {
+        let mut owned: Vec<OrderCommitment> = vec![&env];
+        for order in Self::orders_in(&env, &IndexKey::Trader(trader.clone())).iter() {
+            if order.trader == trader {
+                owned.push_back(order);
             }
         }
-        None
+        owned
+    }
+
+    /// Get an order by commitment
+    ///
+    /// An active order past its expiry is reported as expired, even before
+    /// `expire_order` or `expire_orders` records it. The same holds for every
+    /// getter that returns orders.
+    pub fn get_order(env: Env, commitment: BytesN<32>) -> Option<OrderCommitment> {
+        Self::load_order(&env, &commitment)
+            .ok()
+            .map(|order| Self::effective_order(&env, order))
+    }
+
+    /// Get an order's fill totals; zero until its first match
+    pub fn get_order_fill(env: Env, commitment: BytesN<32>) -> OrderFill {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Fill(commitment))
+            .unwrap_or_default()
+    }
+
+    /// Get the nonce the next cancellation proof for an order must carry
+    pub fn get_cancel_nonce(env: Env, commitment: BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CancelNonce(commitment))
+            .unwrap_or(0)
+    }
+
+    /// Check whether an order was submitted through a relayer
+    pub fn is_relayed_order(env: Env, commitment: BytesN<32>) -> bool {
+        env.storage().persistent().has(&DataKey::Relayed(commitment))
+    }
+
+    /// Get the operator (or admin) that recorded a match
+    ///
+    /// Matches recorded before operators existed have none.
+    pub fn get_match_operator(env: Env, match_id: BytesN<32>) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::MatchOperator(match_id))
     }
 
     /// Get all matches
     pub fn get_matches(env: Env) -> Vec<MatchRecord> {
-        env.storage()
-            .instance()
-            .get(&MATCHES_KEY)
-            .unwrap_or(vec![&env])
+        Self::matches_in(&env, &IndexKey::Matches)
     }
 
     /// Get a specific match
     pub fn get_match(env: Env, match_id: BytesN<32>) -> Option<MatchRecord> {
-        let matches: Vec<MatchRecord> = env
-            .storage()
-            .instance()
-            .get(&MATCHES_KEY)
-            .unwrap_or(vec![&env]);
+        env.storage().persistent().get(&DataKey::Match(match_id))
+    }
 
-        for m in matches.iter() {
-            if m.match_id == match_id {
-                return Some(m);
+    /// Get pending (unsettled) matches
+    pub fn get_pending_matches(env: Env) -> Vec<MatchRecord> {
+        Self::matches_in(&env, &IndexKey::PendingMatches)
+    }
+
+    /// Get a page of an asset's orders on one side, in submission order
+    ///
+    /// # Arguments
+    /// * `asset_address` - The RWA token address
+    /// * `side` - Buy or Sell
+    /// * `cursor` - Position to start from (0 for the first page)
+    /// * `limit` - Maximum number of orders (capped at 100)
+    pub fn get_orders_by_asset_page(
+        env: Env,
+        asset_address: Address,
+        side: OrderSide,
+        cursor: u32,
+        limit: u32,
+    ) -> OrderPage {
+        Self::order_page(&env, &IndexKey::AssetSide(asset_address, side), cursor, limit)
+    }
+
+    /// Get a page of an asset's unexpired active orders
+    ///
+    /// Orders leave the active index as they are matched or cancelled, which can
+    /// shift positions between pages; follow `get_changes_since` to catch up.
+    /// Expired orders are skipped, so a page may hold fewer than `limit` orders.
+    pub fn get_active_orders_page(
+        env: Env,
+        asset_address: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> OrderPage {
+        let index = IndexKey::AssetStatus(asset_address, OrderStatus::Active);
+        let mut page = Self::order_page(&env, &index, cursor, limit);
+
+        let current_time = env.ledger().timestamp();
+        let mut active: Vec<OrderCommitment> = vec![&env];
+        for order in page.orders.iter() {
+            if order.expiry > current_time {
+                active.push_back(order);
             }
         }
-        None
+        page.orders = active;
+        page
     }
 
-    /// Get pending (unsettle) matches
-    pub fn get_pending_matches(env: Env) -> Vec<MatchRecord> {
-        let matches: Vec<MatchRecord> = env
-            .storage()
-            .instance()
-            .get(&MATCHES_KEY)
-            .unwrap_or(vec![&env]);
+    /// Get a page of all matches, in recording order
+    pub fn get_matches_page(env: Env, cursor: u32, limit: u32) -> MatchPage {
+        Self::match_page(&env, &IndexKey::Matches, cursor, limit)
+    }
 
-        let mut pending: Vec<MatchRecord> = vec![&env];
-        for m in matches.iter() {
-            if !m.is_settled {
-                pending.push_back(m);
-            }
+    /// Get a page of unsettled matches
+    ///
+    /// Settled matches leave the index, which can shift positions between pages.
+    pub fn get_pending_matches_page(env: Env, cursor: u32, limit: u32) -> MatchPage {
+        Self::match_page(&env, &IndexKey::PendingMatches, cursor, limit)
+    }
+
+    /// Get the sequence number of the latest order or match change
+    pub fn get_sequence(env: Env) -> u64 {
+        env.storage().instance().get(&SEQUENCE_KEY).unwrap_or(0)
+    }
+
+    /// Get the order and match changes after sequence number `seq`, oldest first
+    ///
+    /// Feed entries are kept for about 7 days. If an entry the caller still needs
+    /// has expired, this fails with `ChangeFeedExpired` and the caller should
+    /// resync with the paginated queries.
+    ///
+    /// # Arguments
+    /// * `seq` - Last sequence number the caller has seen (0 to start from the beginning)
+    /// * `limit` - Maximum number of changes (capped at 100)
+    pub fn get_changes_since(
+        env: Env,
+        seq: u64,
+        limit: u32,
+    ) -> Result<Vec<Change>, OrderbookError> {
+        let latest = Self::get_sequence(env.clone());
+        let end = latest.min(seq.saturating_add(u64::from(limit.min(MAX_PAGE_SIZE))));
+
+        let mut changes: Vec<Change> = vec![&env];
+        for next in seq.saturating_add(1)..=end {
+            let change = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Change(next))
+                .ok_or(OrderbookError::ChangeFeedExpired)?;
+            changes.push_back(change);
         }
-        pending
+        Ok(changes)
+    }
+
+    /// Get the current root of the order commitment tree
+    pub fn get_order_root(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&ORDER_ROOT_KEY).unwrap()
+    }
+
+    /// Get the number of commitments in the order tree
+    pub fn get_order_tree_size(env: Env) -> u32 {
+        env.storage().instance().get(&ORDER_TREE_SIZE_KEY).unwrap_or(0)
+    }
+
+    /// Get the order tree leaf at an index
+    pub fn get_order_tree_leaf(env: Env, index: u32) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::OrderLeaf(index))
+    }
+
+    /// Get the current order tree root epoch
+    pub fn get_order_root_epoch(env: Env) -> u32 {
+        env.storage().instance().get(&ROOT_EPOCH_KEY).unwrap_or(0)
+    }
+
+    /// Get the most recent order tree roots, oldest first
+    pub fn get_order_root_history(env: Env) -> Vec<RootRecord> {
+        env.storage()
+            .instance()
+            .get(&ROOT_HISTORY_KEY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Check whether a root is the current order tree root or one still in the history
+    pub fn is_known_order_root(env: Env, root: BytesN<32>) -> bool {
+        Self::get_order_root_history(env).iter().any(|r| r.root == root)
     }
 
     /// Get admin address
@@ -496,6 +1413,76 @@ impl DarkPoolOrderbook {
         env.storage().instance().get(&SETTLEMENT_KEY).unwrap()
     }
 
+    /// Get the verifier address used for ownership proofs, if set
+    pub fn get_verifier(env: Env) -> Option<Address> {
+        env.storage().instance().get(&VERIFIER_KEY)
+    }
+
+    /// Upgrade the contract code, keeping its storage
+    ///
+    /// Call `migrate` afterwards when the new code expects a newer storage schema.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm to switch to
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded {
+            wasm_hash: new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Convert storage from the stored schema version to `SCHEMA_VERSION`
+    ///
+    /// Runs one migration step per version, recording progress after each.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `payment_assets` - Payment asset of each asset's schema 1 matches, which
+    ///   did not record it; only read when migrating from schema 1
+    ///
+    /// # Returns
+    /// * The schema version after migrating
+    pub fn migrate(
+        env: Env,
+        admin: Address,
+        payment_assets: Map<Address, Address>,
+    ) -> Result<u32, OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut version = Self::get_schema_version(env.clone());
+        if version > SCHEMA_VERSION {
+            return Err(OrderbookError::UnsupportedSchemaVersion);
+        }
+
+        while version < SCHEMA_VERSION {
+            Self::migrate_step(&env, version, &payment_assets)?;
+            version += 1;
+            env.storage().instance().set(&SCHEMA_VERSION_KEY, &version);
+            SchemaMigrated { version }.publish(&env);
+        }
+        Ok(version)
+    }
+
+    /// Get the schema version of the stored data
+    ///
+    /// Orderbooks deployed before schema versioning have no stored version and
+    /// hold the schema 1 layout.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1)
+    }
+
     // Internal helper
     fn require_admin(env: &Env, caller: &Address) -> Result<(), OrderbookError> {
         let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
@@ -504,4 +1491,849 @@ impl DarkPoolOrderbook {
         }
         Ok(())
     }
+
+    /// Verify the caller is the admin or an active matcher operator in scope for the asset
+    fn require_matcher(env: &Env, caller: &Address, asset: &Address) -> Result<(), OrderbookError> {
+        if Self::require_admin(env, caller).is_ok() {
+            return Ok(());
+        }
+        let operator = Self::operators(env)
+            .get(caller.clone())
+            .ok_or(OrderbookError::OperatorNotAuthorized)?;
+        if !operator.is_active {
+            return Err(OrderbookError::OperatorSuspended);
+        }
+        if !operator.assets.is_empty() && !operator.assets.contains(asset) {
+            return Err(OrderbookError::OperatorNotAuthorized);
+        }
+        Ok(())
+    }
+
+    fn operators(env: &Env) -> Map<Address, MatcherOperator> {
+        env.storage()
+            .instance()
+            .get(&OPERATORS_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Convert storage from schema `from_version` to `from_version + 1`
+    ///
+    /// Version 1 is the original layout; each bump of `SCHEMA_VERSION` adds the
+    /// step converting from the previous version here.
+    fn migrate_step(
+        env: &Env,
+        from_version: u32,
+        payment_assets: &Map<Address, Address>,
+    ) -> Result<(), OrderbookError> {
+        match from_version {
+            // 1 -> 2: move orders and matches from instance vectors to keyed
+            // persistent entries and build their indexes
+            1 => {
+                let orders: Vec<OrderCommitment> = env
+                    .storage()
+                    .instance()
+                    .get(&ORDERS_KEY)
+                    .unwrap_or(vec![env]);
+                for order in orders.iter() {
+                    // Lookups returned the first of any duplicate commitments
+                    if Self::load_order(env, &order.commitment).is_err() {
+                        Self::insert_order(env, &order);
+                    }
+                }
+                env.storage().instance().set(&ORDER_COUNT_KEY, &orders.len());
+
+                // Schema 1 matches did not record their payment asset; the admin supplies it
+                let matches: Vec<Map<Symbol, Val>> = env
+                    .storage()
+                    .instance()
+                    .get(&MATCHES_KEY)
+                    .unwrap_or(vec![env]);
+                for mut fields in matches.iter() {
+                    let asset: Address =
+                        fields.get_unchecked(Symbol::new(env, "asset_address")).into_val(env);
+                    let payment_asset = payment_assets
+                        .get(asset)
+                        .ok_or(OrderbookError::PaymentAssetMissing)?;
+                    fields.set(Symbol::new(env, "payment_asset"), payment_asset.to_val());
+                    let m = MatchRecord::try_from_val(env, &fields.to_val()).unwrap();
+                    if Self::get_match(env.clone(), m.match_id.clone()).is_none() {
+                        Self::insert_match(env, &m);
+                    }
+                }
+
+                env.storage().instance().remove(&ORDERS_KEY);
+                env.storage().instance().remove(&MATCHES_KEY);
+                Ok(())
+            }
+            // 2 -> 3: index active orders across assets for the expiry sweeper
+            2 => {
+                let registry_address: Address =
+                    env.storage().instance().get(&REGISTRY_KEY).unwrap();
+                let registry_client = registry_wasm::Client::new(env, &registry_address);
+                for asset in registry_client.get_assets().iter() {
+                    let index = IndexKey::AssetStatus(asset.token_address, OrderStatus::Active);
+                    for commitment in Self::index_ids(env, &index).iter() {
+                        Self::index_insert(env, IndexKey::ActiveOrders, &commitment);
+                    }
+                }
+                Ok(())
+            }
+            // 3 -> 4: add existing commitments to the order tree in submission order
+            3 => {
+                let registry_address: Address =
+                    env.storage().instance().get(&REGISTRY_KEY).unwrap();
+                let registry_client = registry_wasm::Client::new(env, &registry_address);
+                let mut by_index: Map<u32, BytesN<32>> = Map::new(env);
+                for asset in registry_client.get_assets().iter() {
+                    for side in [OrderSide::Buy, OrderSide::Sell] {
+                        let index = IndexKey::AssetSide(asset.token_address.clone(), side);
+                        for order in Self::orders_in(env, &index).iter() {
+                            by_index.set(order.tree_index, order.commitment);
+                        }
+                    }
+                }
+
+                // Build the tree from scratch; the first leaf rewrites the whole frontier
+                env.storage().instance().remove(&ORDER_TREE_SIZE_KEY);
+                let mut root = LeanIMTBN254::new(env, ORDER_TREE_DEPTH).get_root();
+                for commitment in by_index.values().iter() {
+                    let leaf_index;
+                    (leaf_index, root) = Self::push_order_leaf(env, &commitment)?;
+                    // Duplicates dropped by the first migration leave gaps to close
+                    let mut order = Self::load_order(env, &commitment)?;
+                    if order.tree_index != leaf_index {
+                        order.tree_index = leaf_index;
+                        Self::save_order(env, &order);
+                    }
+                }
+                Self::record_order_root(env, root);
+                env.storage().instance().remove(&ORDER_COUNT_KEY);
+                Ok(())
+            }
+            // 4 -> 5: split the order tree's leaf vector into per-index leaves and a frontier
+            4 => {
+                let leaves: Vec<BytesN<32>> = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::OrderTreeLeaves)
+                    .unwrap_or(vec![env]);
+                // The root is unchanged, so no new epoch is recorded
+                for commitment in leaves.iter() {
+                    Self::push_order_leaf(env, &commitment)?;
+                }
+                env.storage().persistent().remove(&DataKey::OrderTreeLeaves);
+                Ok(())
+            }
+            // 5 -> 6: index pending matches by buyer and seller for key rotations
+            5 => {
+                for m in Self::matches_in(env, &IndexKey::PendingMatches).iter() {
+                    Self::index_trader_match(env, &m);
+                }
+                Ok(())
+            }
+            _ => Err(OrderbookError::UnsupportedSchemaVersion),
+        }
+    }
+
+    /// Verify `agent` may act for `principal` on `asset` under the registry
+    fn require_agent(
+        env: &Env,
+        principal: &Address,
+        agent: &Address,
+        asset: &Address,
+    ) -> Result<(), OrderbookError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if !registry_client.is_agent_authorized(principal, agent, asset, &0) {
+            return Err(OrderbookError::AgentNotAuthorized);
+        }
+        Ok(())
+    }
+
+    /// Verify the asset is tradable under the registry
+    fn require_asset_eligible(env: &Env, asset: &Address) -> Result<(), OrderbookError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if !registry_client.is_asset_eligible(asset) {
+            return Err(OrderbookError::AssetNotEligible);
+        }
+        Ok(())
+    }
+
+    /// Verify an order may still trade: its trader's eligibility, or only the
+    /// asset's for relayed orders, whose owner is not known
+    fn require_order_eligible(env: &Env, order: &OrderCommitment) -> Result<(), OrderbookError> {
+        if Self::is_relayed_order(env.clone(), order.commitment.clone()) {
+            Self::require_asset_eligible(env, &order.asset_address)
+        } else {
+            Self::require_eligible(env, &order.trader, &order.asset_address)
+        }
+    }
+
+    /// Verify the asset is tradable and the trader may trade it under the registry
+    ///
+    /// Checks the asset first, then the trader's registration and KYC, then the
+    /// asset's eligibility policy.
+    fn require_eligible(
+        env: &Env,
+        trader: &Address,
+        asset: &Address,
+    ) -> Result<(), OrderbookError> {
+        Self::require_asset_eligible(env, asset)?;
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if !registry_client.is_participant_eligible(trader) {
+            return Err(OrderbookError::ParticipantNotEligible);
+        }
+        if !registry_client.is_eligible_for(trader, asset) {
+            return Err(OrderbookError::NotEligibleForAsset);
+        }
+        Ok(())
+    }
+
+    /// Verify the asset's market is open in settlement (not halted, closed or out of hours)
+    fn require_market_open(env: &Env, asset: &Address) -> Result<(), OrderbookError> {
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let settlement_client = settlement_wasm::Client::new(env, &settlement_address);
+        match settlement_client.try_check_market_open(asset) {
+            Ok(Ok(())) => Ok(()),
+            Err(Ok(settlement_wasm::SettlementError::MarketHalted)) => {
+                Err(OrderbookError::MarketHalted)
+            }
+            _ => Err(OrderbookError::MarketClosed),
+        }
+    }
+
+    /// Add an order commitment for an eligible trader
+    fn place_order(
+        env: &Env,
+        trader: Address,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        side: OrderSide,
+        expiry_seconds: u64,
+        collateral_asset: Address,
+        collateral_amount: i128,
+        relayed: bool,
+    ) -> Result<u32, OrderbookError> {
+        // A relayed order's proof stands in for its owner's registry checks
+        if relayed {
+            Self::require_asset_eligible(env, &asset_address)?;
+        } else {
+            Self::require_eligible(env, &trader, &asset_address)?;
+        }
+        Self::require_market_open(env, &asset_address)?;
+
+        if Self::load_order(env, &commitment).is_ok() {
+            return Err(OrderbookError::DuplicateCommitment);
+        }
+
+        let current_time = env.ledger().timestamp();
+        let expiry = current_time + expiry_seconds;
+
+        let tree_index = Self::append_commitment(env, &commitment)?;
+
+        let order = OrderCommitment {
+            commitment: commitment.clone(),
+            trader: trader.clone(),
+            asset_address: asset_address.clone(),
+            side,
+            timestamp: current_time,
+            expiry,
+            status: OrderStatus::Active,
+            tree_index,
+        };
+
+        Self::insert_order(env, &order);
+        if relayed {
+            Self::persist(env, &DataKey::Relayed(commitment.clone()), &true);
+        }
+        Self::lock_collateral(env, &order, &collateral_asset, collateral_amount, relayed)?;
+
+        OrderSubmitted {
+            asset_address,
+            commitment,
+            trader,
+            side,
+            expiry,
+            tree_index,
+        }
+        .publish(env);
+
+        Ok(tree_index)
+    }
+
+    /// Cancel an active order after checking its ownership proof
+    ///
+    /// When `trader` is given it must also be the order's owner.
+    fn cancel(
+        env: &Env,
+        trader: Option<Address>,
+        commitment: BytesN<32>,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<(), OrderbookError> {
+        let mut order = Self::load_order(env, &commitment)?;
+
+        // Verify trader owns the order
+        if trader.is_some_and(|t| t != order.trader) {
+            return Err(OrderbookError::UnauthorizedCancellation);
+        }
+
+        // Check order is still active
+        match order.status {
+            OrderStatus::Matched | OrderStatus::Settled => {
+                return Err(OrderbookError::OrderAlreadyMatched);
+            }
+            OrderStatus::Cancelled => {
+                return Err(OrderbookError::OrderAlreadyCancelled);
+            }
+            OrderStatus::Expired => {
+                return Err(OrderbookError::OrderExpired);
+            }
+            OrderStatus::Active => {}
+        }
+
+        // An order past its expiry is expired instead; its collateral is released either way
+        if Self::expire_if_due(env, &mut order) {
+            return Ok(());
+        }
+
+        let action = Self::field_element(env, CANCEL_ACTION);
+        Self::verify_ownership(env, &commitment, &action, &proof_bytes, &pub_signals_bytes)?;
+
+        Self::set_order_status(env, &mut order, OrderStatus::Cancelled);
+        Self::release_unreserved(env, &commitment);
+
+        OrderCancelled {
+            commitment,
+            trader: order.trader,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Lock an order's collateral in settlement escrow
+    ///
+    /// Sells must lock the asset being sold; buys lock the payment asset they pay with.
+    /// Relayed orders lock from their commitment's note instead of a trader's escrow.
+    fn lock_collateral(
+        env: &Env,
+        order: &OrderCommitment,
+        collateral_asset: &Address,
+        collateral_amount: i128,
+        relayed: bool,
+    ) -> Result<(), OrderbookError> {
+        if collateral_amount <= 0
+            || (order.side == OrderSide::Sell) != (*collateral_asset == order.asset_address)
+        {
+            return Err(OrderbookError::InvalidCollateral);
+        }
+
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let settlement_client = settlement_wasm::Client::new(env, &settlement_address);
+        let result = if relayed {
+            settlement_client.try_lock_note_escrow(
+                &order.commitment,
+                collateral_asset,
+                &collateral_amount,
+            )
+        } else {
+            settlement_client.try_lock_order_escrow(
+                &order.commitment,
+                &order.trader,
+                collateral_asset,
+                &collateral_amount,
+            )
+        };
+        match result {
+            Ok(Ok(())) => Ok(()),
+            Err(Ok(settlement_wasm::SettlementError::InsufficientEscrow)) => {
+                Err(OrderbookError::InsufficientCollateral)
+            }
+            _ => Err(OrderbookError::CollateralLockFailed),
+        }
+    }
+
+    /// Release an order's collateral lock in settlement above `retain`, if it has one
+    fn release_collateral(env: &Env, commitment: &BytesN<32>, retain: i128) {
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let settlement_client = settlement_wasm::Client::new(env, &settlement_address);
+        settlement_client.release_order_escrow(commitment, &retain);
+    }
+
+    /// Release the collateral of a closed order, keeping what its pending matches spend
+    fn release_unreserved(env: &Env, commitment: &BytesN<32>) {
+        let fill = Self::get_order_fill(env.clone(), commitment.clone());
+        Self::release_collateral(env, commitment, fill.reserved);
+    }
+
+    /// Check an order can take another match
+    fn require_matchable(order: &OrderCommitment) -> Result<(), OrderbookError> {
+        match order.status {
+            OrderStatus::Active => Ok(()),
+            OrderStatus::Matched | OrderStatus::Settled => Err(OrderbookError::OrderAlreadyMatched),
+            OrderStatus::Cancelled => Err(OrderbookError::OrderAlreadyCancelled),
+            OrderStatus::Expired => Err(OrderbookError::OrderExpired),
+        }
+    }
+
+    /// Add a match to an order's fill totals
+    ///
+    /// The collateral reserved by the order's unsettled matches, this one
+    /// included, must fit in what is still locked for the order in settlement.
+    ///
+    /// # Arguments
+    /// * `quantity` - Quantity matched
+    /// * `spent` - Collateral the match will spend at settlement
+    fn add_fill(
+        env: &Env,
+        order: &OrderCommitment,
+        quantity: i128,
+        spent: i128,
+    ) -> Result<(), OrderbookError> {
+        let mut fill = Self::get_order_fill(env.clone(), order.commitment.clone());
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let locked = settlement_wasm::Client::new(env, &settlement_address)
+            .get_order_lock(&order.commitment)
+            .map_or(0, |lock| lock.amount);
+        if fill.reserved + spent > locked {
+            return Err(OrderbookError::FillExceedsCollateral);
+        }
+        fill.filled += quantity;
+        fill.reserved += spent;
+        fill.open_matches += 1;
+        Self::persist(env, &DataKey::Fill(order.commitment.clone()), &fill);
+        Self::record_change(env, ChangeKind::Order, &order.commitment);
+        Ok(())
+    }
+
+    /// Verify and consume an ownership proof for `commitment`
+    ///
+    /// The public signals must be exactly `[commitment, action, cancelNonce]`, with
+    /// the given action signal and the order's current cancellation nonce. The nonce
+    /// is incremented once the proof verifies, so each proof is accepted only once.
+    fn verify_ownership(
+        env: &Env,
+        commitment: &BytesN<32>,
+        action: &Bytes,
+        proof_bytes: &Bytes,
+        pub_signals_bytes: &Bytes,
+    ) -> Result<(), OrderbookError> {
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&OWNERSHIP_VK_KEY)
+            .ok_or(OrderbookError::OwnershipVkNotSet)?;
+        let verifier_address: Address = env.storage().instance().get(&VERIFIER_KEY).unwrap();
+        let nonce = Self::get_cancel_nonce(env.clone(), commitment.clone());
+
+        // Signals are serialized as a big-endian count followed by 32-byte field elements
+        let mut expected_signals = Bytes::from_array(env, &3u32.to_be_bytes());
+        expected_signals.append(&Bytes::from_array(env, &commitment.to_array()));
+        expected_signals.append(action);
+        expected_signals.append(&Self::field_element(env, u128::from(nonce)));
+        if *pub_signals_bytes != expected_signals {
+            return Err(OrderbookError::InvalidProof);
+        }
+
+        let verifier_client = verifier_wasm::Client::new(env, &verifier_address);
+        match verifier_client.try_verify_proof_bytes(&vk_bytes, proof_bytes, pub_signals_bytes) {
+            Ok(Ok(true)) => {
+                Self::persist(env, &DataKey::CancelNonce(commitment.clone()), &(nonce + 1));
+                Ok(())
+            }
+            _ => Err(OrderbookError::InvalidProof),
+        }
+    }
+
+    /// Append an order commitment to the order tree, returning its leaf index
+    fn append_commitment(env: &Env, commitment: &BytesN<32>) -> Result<u32, OrderbookError> {
+        let (leaf_index, root) = Self::push_order_leaf(env, commitment)?;
+        Self::record_order_root(env, root);
+        Ok(leaf_index)
+    }
+
+    /// Store a leaf and update the order tree frontier, returning the leaf index and new root
+    ///
+    /// Only the frontier is read, so appending costs one hash per level however
+    /// many orders the tree holds.
+    fn push_order_leaf(
+        env: &Env,
+        commitment: &BytesN<32>,
+    ) -> Result<(u32, BytesN<32>), OrderbookError> {
+        let leaf_index = Self::get_order_tree_size(env.clone());
+        let mut frontier: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrderTreeFrontier)
+            .unwrap_or(vec![env]);
+        let root = frontier_insert(
+            env,
+            &mut frontier,
+            leaf_index,
+            commitment.clone(),
+            ORDER_TREE_DEPTH,
+        )
+        .map_err(|_| OrderbookError::OrderTreeFull)?;
+
+        Self::persist(env, &DataKey::OrderLeaf(leaf_index), commitment);
+        Self::persist(env, &DataKey::OrderTreeFrontier, &frontier);
+        env.storage().instance().set(&ORDER_TREE_SIZE_KEY, &(leaf_index + 1));
+        Ok((leaf_index, root))
+    }
+
+    /// Set the order tree root and append it to the bounded root history
+    fn record_order_root(env: &Env, root: BytesN<32>) {
+        env.storage().instance().set(&ORDER_ROOT_KEY, &root);
+
+        let mut history = Self::get_order_root_history(env.clone());
+        let epoch = if history.is_empty() {
+            0
+        } else {
+            Self::get_order_root_epoch(env.clone()) + 1
+        };
+        history.push_back(RootRecord {
+            root: root.clone(),
+            epoch,
+            timestamp: env.ledger().timestamp(),
+        });
+        while history.len() > ROOT_HISTORY_SIZE {
+            history.pop_front();
+        }
+        env.storage().instance().set(&ROOT_HISTORY_KEY, &history);
+        env.storage().instance().set(&ROOT_EPOCH_KEY, &epoch);
+
+        OrderRootChanged { epoch, root }.publish(env);
+    }
+
+    /// Verify a relayed order proof
+    ///
+    /// The public signals must be `[commitment, whitelistRoot, side, collateralAmount,
+    /// validAt]`. The whitelist root must be current in the registry or replaced
+    /// within settlement's root freshness window, and `validAt`, up to which the
+    /// circuit checked the owner's KYC, must not have passed.
+    fn verify_relayed_order(
+        env: &Env,
+        commitment: &BytesN<32>,
+        side: OrderSide,
+        collateral_amount: i128,
+        proof_bytes: &Bytes,
+        pub_signals_bytes: &Bytes,
+    ) -> Result<(), OrderbookError> {
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&RELAY_VK_KEY)
+            .ok_or(OrderbookError::RelayVkNotSet)?;
+        let verifier_address: Address = env.storage().instance().get(&VERIFIER_KEY).unwrap();
+
+        // Signals are serialized as a big-endian count followed by 32-byte field elements
+        if pub_signals_bytes.len() != 4 + 5 * 32 || collateral_amount < 0 {
+            return Err(OrderbookError::InvalidProof);
+        }
+        let whitelist_root: BytesN<32> = pub_signals_bytes.slice(36..68).try_into().unwrap();
+        let mut valid_at = [0u8; 8];
+        pub_signals_bytes.slice(156..).copy_into_slice(&mut valid_at);
+        let valid_at = u64::from_be_bytes(valid_at);
+
+        let mut expected_signals = Bytes::from_array(env, &5u32.to_be_bytes());
+        expected_signals.append(&Bytes::from_array(env, &commitment.to_array()));
+        expected_signals.append(&Bytes::from_array(env, &whitelist_root.to_array()));
+        expected_signals.append(&Self::field_element(env, side as u128));
+        expected_signals.append(&Self::field_element(env, collateral_amount as u128));
+        expected_signals.append(&Self::field_element(env, u128::from(valid_at)));
+        if *pub_signals_bytes != expected_signals {
+            return Err(OrderbookError::InvalidProof);
+        }
+        if valid_at < env.ledger().timestamp() {
+            return Err(OrderbookError::ProofExpired);
+        }
+
+        // Same freshness window as settlement applies to its proofs
+        let settlement_address: Address = env.storage().instance().get(&SETTLEMENT_KEY).unwrap();
+        let settlement_client = settlement_wasm::Client::new(env, &settlement_address);
+        let root_window = settlement_client.get_root_freshness_window();
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if !registry_client.is_root_fresh(&whitelist_root, &root_window) {
+            return Err(OrderbookError::UnknownWhitelistRoot);
+        }
+
+        let verifier_client = verifier_wasm::Client::new(env, &verifier_address);
+        match verifier_client.try_verify_proof_bytes(&vk_bytes, proof_bytes, pub_signals_bytes) {
+            Ok(Ok(true)) => Ok(()),
+            _ => Err(OrderbookError::InvalidProof),
+        }
+    }
+
+    /// Encode a value as a 32-byte big-endian field element
+    fn field_element(env: &Env, value: u128) -> Bytes {
+        let mut bytes = Bytes::from_array(env, &[0u8; 16]);
+        bytes.append(&Bytes::from_array(env, &value.to_be_bytes()));
+        bytes
+    }
+
+    /// Write a persistent entry and extend its lifetime
+    fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, ENTRY_TTL_THRESHOLD, ENTRY_TTL_EXTEND);
+    }
+
+    fn load_order(env: &Env, commitment: &BytesN<32>) -> Result<OrderCommitment, OrderbookError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Order(commitment.clone()))
+            .ok_or(OrderbookError::OrderNotFound)
+    }
+
+    fn save_order(env: &Env, order: &OrderCommitment) {
+        Self::persist(env, &DataKey::Order(order.commitment.clone()), order);
+        Self::record_change(env, ChangeKind::Order, &order.commitment);
+    }
+
+    fn save_match(env: &Env, record: &MatchRecord) {
+        Self::persist(env, &DataKey::Match(record.match_id.clone()), record);
+        Self::record_change(env, ChangeKind::Match, &record.match_id);
+    }
+
+    /// Append an entry to the change feed under the next sequence number
+    fn record_change(env: &Env, kind: ChangeKind, id: &BytesN<32>) {
+        let seq = Self::get_sequence(env.clone()) + 1;
+        env.storage().instance().set(&SEQUENCE_KEY, &seq);
+
+        let key = DataKey::Change(seq);
+        let change = Change {
+            seq,
+            kind,
+            id: id.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&key, &change);
+        env.storage().temporary().extend_ttl(&key, CHANGE_TTL, CHANGE_TTL);
+    }
+
+    /// Store a new order and add it to the asset/side, trader and status indexes
+    fn insert_order(env: &Env, order: &OrderCommitment) {
+        Self::save_order(env, order);
+        let asset = order.asset_address.clone();
+        Self::index_push(env, IndexKey::AssetSide(asset.clone(), order.side), &order.commitment);
+        Self::index_push(env, IndexKey::Trader(order.trader.clone()), &order.commitment);
+        Self::index_insert(env, IndexKey::AssetStatus(asset, order.status), &order.commitment);
+        if order.status == OrderStatus::Active {
+            Self::index_insert(env, IndexKey::ActiveOrders, &order.commitment);
+        }
+    }
+
+    /// Change an order's status, moving it between status indexes
+    fn set_order_status(env: &Env, order: &mut OrderCommitment, status: OrderStatus) {
+        let asset = order.asset_address.clone();
+        let old_index = IndexKey::AssetStatus(asset.clone(), order.status);
+        Self::index_remove(env, old_index, &order.commitment);
+        if order.status == OrderStatus::Active {
+            Self::index_remove(env, IndexKey::ActiveOrders, &order.commitment);
+        }
+        order.status = status;
+        Self::index_insert(env, IndexKey::AssetStatus(asset, status), &order.commitment);
+        if status == OrderStatus::Active {
+            Self::index_insert(env, IndexKey::ActiveOrders, &order.commitment);
+        }
+        Self::save_order(env, order);
+    }
+
+    /// Whether an order is expired, or still active but past its expiry
+    fn is_expired(env: &Env, order: &OrderCommitment) -> bool {
+        match order.status {
+            OrderStatus::Expired => true,
+            OrderStatus::Active => order.expiry <= env.ledger().timestamp(),
+            _ => false,
+        }
+    }
+
+    /// An order as getters report it: active past its expiry reads as expired
+    fn effective_order(env: &Env, mut order: OrderCommitment) -> OrderCommitment {
+        if Self::is_expired(env, &order) {
+            order.status = OrderStatus::Expired;
+        }
+        order
+    }
+
+    /// Expire an active order past its expiry and release its collateral
+    ///
+    /// Returns whether the order was expired.
+    fn expire_if_due(env: &Env, order: &mut OrderCommitment) -> bool {
+        if order.status != OrderStatus::Active || order.expiry > env.ledger().timestamp() {
+            return false;
+        }
+
+        Self::set_order_status(env, order, OrderStatus::Expired);
+        Self::release_unreserved(env, &order.commitment);
+
+        OrderExpired {
+            asset_address: order.asset_address.clone(),
+            commitment: order.commitment.clone(),
+            trader: order.trader.clone(),
+        }
+        .publish(env);
+        true
+    }
+
+    /// Store a new match and add it to the match indexes
+    fn insert_match(env: &Env, record: &MatchRecord) {
+        Self::save_match(env, record);
+        Self::index_push(env, IndexKey::Matches, &record.match_id);
+        if !record.is_settled {
+            Self::index_insert(env, IndexKey::PendingMatches, &record.match_id);
+            Self::index_trader_match(env, record);
+        }
+    }
+
+    /// Add a pending match to its buyer's and seller's match indexes
+    fn index_trader_match(env: &Env, record: &MatchRecord) {
+        for trader in [&record.buyer, &record.seller] {
+            let index = IndexKey::TraderPendingMatches(trader.clone());
+            Self::index_insert(env, index, &record.match_id);
+        }
+    }
+
+    /// Remove a match from the pending match indexes
+    fn remove_pending_match(env: &Env, record: &MatchRecord) {
+        Self::index_remove(env, IndexKey::PendingMatches, &record.match_id);
+        for trader in [&record.buyer, &record.seller] {
+            let index = IndexKey::TraderPendingMatches(trader.clone());
+            Self::index_remove(env, index, &record.match_id);
+        }
+    }
+
+    fn index_len(env: &Env, index: &IndexKey) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::IndexLen(index.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Append an id to an index, returning its position
+    fn index_push(env: &Env, index: IndexKey, id: &BytesN<32>) -> u32 {
+        let len = Self::index_len(env, &index);
+        Self::persist(env, &DataKey::IndexEntry(index.clone(), len), id);
+        Self::persist(env, &DataKey::IndexLen(index), &(len + 1));
+        len
+    }
+
+    /// Add an id to a removable index, recording its position
+    fn index_insert(env: &Env, index: IndexKey, id: &BytesN<32>) {
+        let pos_key = DataKey::IndexPos(index.clone(), id.clone());
+        if env.storage().persistent().has(&pos_key) {
+            return;
+        }
+        let pos = Self::index_push(env, index, id);
+        Self::persist(env, &pos_key, &pos);
+    }
+
+    /// Remove an id from a removable index, moving the last entry into its slot
+    fn index_remove(env: &Env, index: IndexKey, id: &BytesN<32>) {
+        let storage = env.storage().persistent();
+        let pos_key = DataKey::IndexPos(index.clone(), id.clone());
+        let Some(pos) = storage.get::<_, u32>(&pos_key) else {
+            return;
+        };
+
+        let last = Self::index_len(env, &index) - 1;
+        if pos != last {
+            let moved: BytesN<32> = storage
+                .get(&DataKey::IndexEntry(index.clone(), last))
+                .unwrap();
+            Self::persist(env, &DataKey::IndexEntry(index.clone(), pos), &moved);
+            Self::persist(env, &DataKey::IndexPos(index.clone(), moved), &pos);
+        }
+        storage.remove(&DataKey::IndexEntry(index.clone(), last));
+        storage.remove(&pos_key);
+        Self::persist(env, &DataKey::IndexLen(index), &last);
+    }
+
+    /// Read up to `limit` ids of an index starting at position `cursor`
+    fn index_page(
+        env: &Env,
+        index: &IndexKey,
+        cursor: u32,
+        limit: u32,
+    ) -> (Vec<BytesN<32>>, Option<u32>) {
+        let len = Self::index_len(env, index);
+        let end = len.min(cursor.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut ids: Vec<BytesN<32>> = vec![env];
+        for i in cursor..end {
+            let id = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IndexEntry(index.clone(), i))
+                .unwrap();
+            ids.push_back(id);
+        }
+        (ids, if end < len { Some(end) } else { None })
+    }
+
+    fn order_page(env: &Env, index: &IndexKey, cursor: u32, limit: u32) -> OrderPage {
+        let (ids, next_cursor) = Self::index_page(env, index, cursor, limit);
+        let mut orders: Vec<OrderCommitment> = vec![env];
+        for commitment in ids.iter() {
+            if let Ok(order) = Self::load_order(env, &commitment) {
+                orders.push_back(Self::effective_order(env, order));
+            }
+        }
+        OrderPage {
+            orders,
+            next_cursor,
+        }
+    }
+
+    fn match_page(env: &Env, index: &IndexKey, cursor: u32, limit: u32) -> MatchPage {
+        let (ids, next_cursor) = Self::index_page(env, index, cursor, limit);
+        let mut matches: Vec<MatchRecord> = vec![env];
+        for match_id in ids.iter() {
+            if let Some(m) = Self::get_match(env.clone(), match_id) {
+                matches.push_back(m);
+            }
+        }
+        MatchPage {
+            matches,
+            next_cursor,
+        }
+    }
+
+    /// Read every id in an index
+    fn index_ids(env: &Env, index: &IndexKey) -> Vec<BytesN<32>> {
+        let mut ids: Vec<BytesN<32>> = vec![env];
+        for i in 0..Self::index_len(env, index) {
+            let id = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IndexEntry(index.clone(), i))
+                .unwrap();
+            ids.push_back(id);
+        }
+        ids
+    }
+
+    fn orders_in(env: &Env, index: &IndexKey) -> Vec<OrderCommitment> {
+        let mut orders: Vec<OrderCommitment> = vec![env];
+        for commitment in Self::index_ids(env, index).iter() {
+            if let Ok(order) = Self::load_order(env, &commitment) {
+                orders.push_back(Self::effective_order(env, order));
+            }
+        }
+        orders
+    }
+
+    fn matches_in(env: &Env, index: &IndexKey) -> Vec<MatchRecord> {
+        let mut matches: Vec<MatchRecord> = vec![env];
+        for match_id in Self::index_ids(env, index).iter() {
+            if let Some(m) = Self::get_match(env.clone(), match_id) {
+                matches.push_back(m);
+            }
+        }
+        matches
+    }
 }
@@ -1,7 +1,186 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    BytesN, Env, Event, String, Symbol,
+};
+
+// Collateral locked by orders placed with `submit`
+const COLLATERAL: i128 = 1_000_000_000;
+
+// The registry keeps its Merkle trees in a trees contract
+mod trees_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/darkpool_trees.wasm"
+    );
+}
+
+/// Stands in for the Groth16 verifier: accepts proofs whose first byte is 1
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn verify_proof_bytes(
+        _env: Env,
+        _vk_bytes: Bytes,
+        proof_bytes: Bytes,
+        _pub_signals_bytes: Bytes,
+    ) -> bool {
+        proof_bytes.get(0) == Some(1)
+    }
+}
+
+fn enable_ownership_proofs(env: &Env, client: &DarkPoolOrderbookClient, admin: &Address) {
+    let verifier = env.register(MockVerifier, ());
+    client.set_ownership_vk(admin, &verifier, &Bytes::from_slice(env, &[0u8; 100]));
+}
+
+/// Proof and public signals authorizing the first cancellation of `commitment`
+fn ownership_proof(env: &Env, commitment: &BytesN<32>) -> (Bytes, Bytes) {
+    cancel_proof(env, commitment, 1, 0)
+}
+
+/// Proof and public signals `[commitment, action, cancelNonce]`
+fn cancel_proof(env: &Env, commitment: &BytesN<32>, action: u8, nonce: u8) -> (Bytes, Bytes) {
+    let mut signals = Bytes::from_array(env, &3u32.to_be_bytes());
+    signals.append(&Bytes::from_array(env, &commitment.to_array()));
+    for value in [action, nonce] {
+        let mut element = [0u8; 32];
+        element[31] = value;
+        signals.append(&Bytes::from_array(env, &element));
+    }
+    (Bytes::from_slice(env, &[1u8; 256]), signals)
+}
+
+/// Public signals of a relayed order proof
+fn relay_signals(
+    env: &Env,
+    commitment: &BytesN<32>,
+    whitelist_root: &BytesN<32>,
+    side: OrderSide,
+    collateral_amount: i128,
+    valid_at: u64,
+) -> Bytes {
+    let mut signals = Bytes::from_array(env, &5u32.to_be_bytes());
+    signals.append(&Bytes::from_array(env, &commitment.to_array()));
+    signals.append(&Bytes::from_array(env, &whitelist_root.to_array()));
+    for value in [side as u128, collateral_amount as u128, u128::from(valid_at)] {
+        signals.append(&Bytes::from_array(env, &[0u8; 16]));
+        signals.append(&Bytes::from_array(env, &value.to_be_bytes()));
+    }
+    signals
+}
+
+fn create_registry(env: &Env, admin: &Address) -> Address {
+    let verifier = Address::generate(env);
+    let trees = env.register(trees_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    env.register(registry_wasm::WASM, (admin, &verifier, &trees, &vk_bytes))
+}
+
+fn create_settlement(env: &Env, admin: &Address, registry: &Address) -> Address {
+    let verifier = Address::generate(env);
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    env.register(settlement_wasm::WASM, (admin, registry, &verifier, &vk_bytes))
+}
+
+fn create_orderbook(
+    env: &Env,
+    admin: &Address,
+    registry: &Address,
+    settlement: &Address,
+) -> Address {
+    let orderbook = env.register(DarkPoolOrderbook, (admin, registry, settlement));
+    settlement_wasm::Client::new(env, settlement).set_orderbook(admin, &orderbook);
+    orderbook
+}
+
+/// Mint `amount` of `token` to `trader` and deposit it into settlement escrow
+fn fund(env: &Env, settlement: &Address, trader: &Address, token: &Address, amount: i128) {
+    StellarAssetClient::new(env, token).mint(trader, &amount);
+    settlement_wasm::Client::new(env, settlement).deposit(trader, token, &amount);
+}
+
+/// Fund `COLLATERAL` of `collateral` and submit an order locking it
+fn submit(
+    env: &Env,
+    client: &DarkPoolOrderbookClient,
+    trader: &Address,
+    commitment: &BytesN<32>,
+    asset: &Address,
+    side: OrderSide,
+    collateral: &Address,
+) -> u32 {
+    fund(env, &client.get_settlement(), trader, collateral, COLLATERAL);
+    client.submit_order(trader, commitment, asset, &side, &3600, collateral, &COLLATERAL)
+}
+
+fn register_trader(env: &Env, registry: &Address, admin: &Address, id: u8) -> Address {
+    let registry_client = registry_wasm::Client::new(env, registry);
+    let trader = Address::generate(env);
+    registry_client.register_participant(
+        admin,
+        &registry_wasm::Participant {
+            id_hash: BytesN::from_array(env, &[id; 32]),
+            trading_address: trader.clone(),
+            category: registry_wasm::ParticipantCategory::BrokerDealer,
+            kyc_expiry: env.ledger().timestamp() + 31536000,
+            is_active: true,
+            tree_index: 0,
+            jurisdiction: Symbol::new(env, "US"),
+            is_qualified_investor: true,
+        },
+    );
+    trader
+}
+
+fn register_pair(env: &Env, registry: &Address, admin: &Address, asset: &Address) -> Address {
+    let registry_client = registry_wasm::Client::new(env, registry);
+    let payment_asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    registry_client.register_pair(
+        admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 100,
+            lot_size: 1_000,
+            min_notional: 1_000,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+    payment_asset
+}
+
+fn register_asset(env: &Env, registry: &Address, admin: &Address) -> Address {
+    let registry_client = registry_wasm::Client::new(env, registry);
+    let asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    registry_client.register_asset(
+        admin,
+        &registry_wasm::RWAAsset {
+            token_address: asset.clone(),
+            symbol: Symbol::new(env, "TBOND25"),
+            asset_type: registry_wasm::AssetType::TreasuryBond,
+            min_trade_size: 1_000_000,
+            max_order_size: 100_000_000_000,
+            is_active: true,
+            metadata: registry_wasm::AssetMetadata {
+                isin: None,
+                cusip: None,
+                issuer: String::from_str(env, "US Treasury"),
+                decimals: 7,
+                maturity_date: None,
+                coupon_rate_bps: 0,
+                face_value: 10_000_000_000,
+            },
+        },
+    );
+    asset
+}
 
 #[test]
 fn test_constructor() {
@@ -26,17 +205,18 @@ fn test_submit_order() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let registry = Address::generate(&env);
-    let settlement = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
 
-    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
     let client = DarkPoolOrderbookClient::new(&env, &contract_id);
 
-    let trader = Address::generate(&env);
-    let asset = Address::generate(&env);
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
     let commitment = BytesN::from_array(&env, &[1u8; 32]);
 
-    let index = client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &3600);
+    let index = submit(&env, &client, &trader, &commitment, &asset, OrderSide::Buy, &payment_asset);
     assert_eq!(index, 0);
 
     let order = client.get_order(&commitment);
@@ -53,21 +233,22 @@ fn test_cancel_order() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let registry = Address::generate(&env);
-    let settlement = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
 
-    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
     let client = DarkPoolOrderbookClient::new(&env, &contract_id);
 
-    let trader = Address::generate(&env);
-    let asset = Address::generate(&env);
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
     let commitment = BytesN::from_array(&env, &[1u8; 32]);
 
-    client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &3600);
+    submit(&env, &client, &trader, &commitment, &asset, OrderSide::Buy, &payment_asset);
 
     // Cancel the order
-    let proof = Bytes::from_slice(&env, &[0u8; 100]);
-    let signals = Bytes::from_slice(&env, &[0u8; 100]);
+    enable_ownership_proofs(&env, &client, &admin);
+    let (proof, signals) = ownership_proof(&env, &commitment);
     client.cancel_order(&trader, &commitment, &proof, &signals);
 
     let order = client.get_order(&commitment).unwrap();
@@ -80,22 +261,23 @@ fn test_record_match() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let registry = Address::generate(&env);
-    let settlement = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
 
-    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
     let client = DarkPoolOrderbookClient::new(&env, &contract_id);
 
-    let buyer = Address::generate(&env);
-    let seller = Address::generate(&env);
-    let asset = Address::generate(&env);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
     let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
     let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
     let match_id = BytesN::from_array(&env, &[3u8; 32]);
 
     // Submit both orders
-    client.submit_order(&buyer, &buy_commitment, &asset, &OrderSide::Buy, &3600);
-    client.submit_order(&seller, &sell_commitment, &asset, &OrderSide::Sell, &3600);
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
 
     // Record match
     client.record_match(
@@ -104,17 +286,19 @@ fn test_record_match() {
         &buy_commitment,
         &sell_commitment,
         &asset,
+        &payment_asset,
         &buyer,
         &seller,
-        &1000,
-        &50000,
+        &1_000_000,
+        &50_000_000,
     );
 
-    // Check orders are marked as matched
+    // Orders stay active until settlement reports them filled
     let buy_order = client.get_order(&buy_commitment).unwrap();
     let sell_order = client.get_order(&sell_commitment).unwrap();
-    assert_eq!(buy_order.status, OrderStatus::Matched);
-    assert_eq!(sell_order.status, OrderStatus::Matched);
+    assert_eq!(buy_order.status, OrderStatus::Active);
+    assert_eq!(sell_order.status, OrderStatus::Active);
+    assert_eq!(client.get_order_fill(&buy_commitment).open_matches, 1);
 
     // Check match record exists
     let match_record = client.get_match(&match_id);
@@ -128,21 +312,22 @@ fn test_get_active_orders() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let registry = Address::generate(&env);
-    let settlement = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
 
-    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
     let client = DarkPoolOrderbookClient::new(&env, &contract_id);
 
-    let trader = Address::generate(&env);
-    let asset = Address::generate(&env);
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
 
     // Submit multiple orders
     for i in 0..5 {
         let mut commitment_arr = [0u8; 32];
         commitment_arr[0] = i;
         let commitment = BytesN::from_array(&env, &commitment_arr);
-        client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &3600);
+        submit(&env, &client, &trader, &commitment, &asset, OrderSide::Buy, &payment_asset);
     }
 
     let active_orders = client.get_active_orders(&asset);
@@ -155,21 +340,22 @@ fn test_get_orders_by_side() {
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
-    let registry = Address::generate(&env);
-    let settlement = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
 
-    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
     let client = DarkPoolOrderbookClient::new(&env, &contract_id);
 
-    let trader = Address::generate(&env);
-    let asset = Address::generate(&env);
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
 
     // Submit buy orders
     for i in 0..3 {
         let mut commitment_arr = [0u8; 32];
         commitment_arr[0] = i;
         let commitment = BytesN::from_array(&env, &commitment_arr);
-        client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &3600);
+        submit(&env, &client, &trader, &commitment, &asset, OrderSide::Buy, &payment_asset);
     }
 
     // Submit sell orders
@@ -177,7 +363,7 @@ fn test_get_orders_by_side() {
         let mut commitment_arr = [0u8; 32];
         commitment_arr[0] = i;
         let commitment = BytesN::from_array(&env, &commitment_arr);
-        client.submit_order(&trader, &commitment, &asset, &OrderSide::Sell, &3600);
+        submit(&env, &client, &trader, &commitment, &asset, OrderSide::Sell, &asset);
     }
 
     let buy_orders = client.get_orders_by_asset(&asset, &Some(OrderSide::Buy));
@@ -186,3 +372,1601 @@ fn test_get_orders_by_side() {
     assert_eq!(buy_orders.len(), 3);
     assert_eq!(sell_orders.len(), 2);
 }
+
+#[test]
+fn test_submit_order_requires_eligibility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let submit_buy = |trader: &Address, asset: &Address| {
+        client.try_submit_order(
+            trader, &commitment, asset, &OrderSide::Buy, &3600, &payment_asset, &COLLATERAL,
+        )
+    };
+
+    // Unregistered trader
+    let outsider = Address::generate(&env);
+    let result = submit_buy(&outsider, &asset);
+    assert_eq!(result, Err(Ok(OrderbookError::ParticipantNotEligible)));
+
+    // Unregistered asset
+    let unlisted = Address::generate(&env);
+    let result = submit_buy(&trader, &unlisted);
+    assert_eq!(result, Err(Ok(OrderbookError::AssetNotEligible)));
+
+    // Asset restricted to banks
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    registry_client.set_asset_policy(
+        &admin,
+        &asset,
+        &registry_wasm::AssetEligibilityPolicy {
+            allowed_categories: soroban_sdk::vec![&env, registry_wasm::ParticipantCategory::Bank],
+            allowed_jurisdictions: soroban_sdk::vec![&env],
+            blocked_jurisdictions: soroban_sdk::vec![&env],
+            requires_qualified_investor: false,
+        },
+    );
+    let result = submit_buy(&trader, &asset);
+    assert_eq!(result, Err(Ok(OrderbookError::NotEligibleForAsset)));
+}
+
+#[test]
+fn test_record_match_enforces_pair_constraints() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let match_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+
+    // Unlisted payment asset
+    let unlisted = Address::generate(&env);
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &unlisted, &buyer, &seller,
+        &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::PairNotListed)));
+
+    // Quantity not a multiple of the lot size
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &seller, &1_000_500, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::TradeConstraintViolation)));
+
+    // Price not a multiple of the tick size
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &seller, &1_000_000, &50_000_050,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::TradeConstraintViolation)));
+
+    // Suspended pair
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    registry_client.set_pair_status(
+        &admin,
+        &asset,
+        &payment_asset,
+        &registry_wasm::PairStatus::Suspended,
+    );
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &seller, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::PairNotListed)));
+}
+
+#[test]
+fn test_record_match_checks_sides_and_traders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let other_sell = BytesN::from_array(&env, &[4u8; 32]);
+    let match_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    submit(&env, &client, &seller, &other_sell, &asset, OrderSide::Sell, &asset);
+
+    // Two sell orders, or the orders passed the wrong way round
+    let result = client.try_record_match(
+        &admin, &match_id, &other_sell, &sell_commitment, &asset, &payment_asset, &seller,
+        &seller, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::InvalidOrderSide)));
+    let result = client.try_record_match(
+        &admin, &match_id, &sell_commitment, &buy_commitment, &asset, &payment_asset, &seller,
+        &buyer, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::InvalidOrderSide)));
+
+    // Buyer and seller must be the orders' traders
+    let stranger = Address::generate(&env);
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &stranger,
+        &seller, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::TraderMismatch)));
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &buyer, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::TraderMismatch)));
+    assert!(client.get_match(&match_id).is_none());
+}
+
+#[test]
+fn test_record_match_rechecks_eligibility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let match_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+
+    // Seller deactivated after placing the order
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    registry_client.deactivate_participant(&admin, &seller);
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &seller, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::ParticipantNotEligible)));
+
+    // Asset delisted after the orders were placed
+    registry_client.deactivate_asset(&admin, &asset);
+    let result = client.try_record_match(
+        &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+        &seller, &1_000_000, &50_000_000,
+    );
+    assert_eq!(result, Err(Ok(OrderbookError::AssetNotEligible)));
+
+    // Both orders are still open
+    assert_eq!(client.get_order(&buy_commitment).unwrap().status, OrderStatus::Active);
+    assert_eq!(client.get_order(&sell_commitment).unwrap().status, OrderStatus::Active);
+}
+
+#[test]
+fn test_order_lifecycle_emits_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let match_id = BytesN::from_array(&env, &[3u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            OrderRootChanged {
+                epoch: 1,
+                root: client.get_order_root(),
+            }
+            .to_xdr(&env, &contract_id),
+            OrderSubmitted {
+                asset_address: asset.clone(),
+                commitment: buy_commitment.clone(),
+                trader: buyer.clone(),
+                side: OrderSide::Buy,
+                expiry: env.ledger().timestamp() + 3600,
+                tree_index: 0,
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    client.record_match(
+        &admin,
+        &match_id,
+        &buy_commitment,
+        &sell_commitment,
+        &asset,
+        &payment_asset,
+        &buyer,
+        &seller,
+        &1_000_000,
+        &50_000_000,
+    );
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OrderMatched {
+            asset_address: asset.clone(),
+            match_id: match_id.clone(),
+            buy_commitment: buy_commitment.clone(),
+            sell_commitment: sell_commitment.clone(),
+            payment_asset: payment_asset.clone(),
+            quantity: 1_000_000,
+            price: 50_000_000,
+            operator: admin.clone(),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+
+    client.mark_settled(
+        &match_id,
+        &buy_commitment,
+        &sell_commitment,
+        &1_000_000,
+        &50_000_000,
+        &true,
+        &true,
+    );
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OrderSettled {
+            match_id,
+            buy_commitment,
+            sell_commitment,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+
+    let commitment = BytesN::from_array(&env, &[4u8; 32]);
+    submit(&env, &client, &buyer, &commitment, &asset, OrderSide::Buy, &payment_asset);
+    enable_ownership_proofs(&env, &client, &admin);
+    let (proof, signals) = ownership_proof(&env, &commitment);
+    client.cancel_order(&buyer, &commitment, &proof, &signals);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OrderCancelled {
+            commitment,
+            trader: buyer,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let settlement = Address::generate(&env);
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_upgrade(&stranger, &BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(OrderbookError::OnlyAdmin))
+    );
+    assert_eq!(
+        client.try_migrate(&stranger, &Map::new(&env)),
+        Err(Ok(OrderbookError::OnlyAdmin))
+    );
+
+    // Already on the current schema: nothing to run
+    assert_eq!(client.migrate(&admin, &Map::new(&env)), SCHEMA_VERSION);
+    assert_eq!(env.events().all().filter_by_contract(&contract_id), []);
+
+    // Data written by newer code cannot be migrated down
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(
+        client.try_migrate(&admin, &Map::new(&env)),
+        Err(Ok(OrderbookError::UnsupportedSchemaVersion))
+    );
+}
+
+
+#[test]
+fn test_agent_submits_and_cancels_for_principal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let principal = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let agent = Address::generate(&env);
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    fund(&env, &settlement, &principal, &payment_asset, COLLATERAL);
+    let submit_buy_for = |agent: &Address| {
+        client.try_submit_order_for(
+            agent, &principal, &commitment, &asset, &OrderSide::Buy, &3600, &payment_asset,
+            &COLLATERAL,
+        )
+    };
+
+    // Without an authorization the agent cannot trade for the principal
+    assert_eq!(submit_buy_for(&agent), Err(Ok(OrderbookError::AgentNotAuthorized)));
+
+    registry_client.authorize_agent(
+        &principal,
+        &agent,
+        &registry_wasm::AgentScope {
+            assets: vec![&env, asset.clone()],
+            max_order_size: None,
+            expires_at: env.ledger().timestamp() + 3600,
+        },
+    );
+    assert_eq!(submit_buy_for(&agent), Ok(Ok(0)));
+    assert_eq!(client.get_order(&commitment).unwrap().trader, principal);
+
+    // The scope is limited to the listed asset
+    let other_asset = register_asset(&env, &registry, &admin);
+    assert_eq!(
+        client.try_submit_order_for(
+            &agent,
+            &principal,
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &other_asset,
+            &OrderSide::Sell,
+            &3600,
+            &other_asset,
+            &COLLATERAL,
+        ),
+        Err(Ok(OrderbookError::AgentNotAuthorized))
+    );
+
+    enable_ownership_proofs(&env, &client, &admin);
+    let (proof, signals) = ownership_proof(&env, &commitment);
+    registry_client.revoke_agent(&principal, &agent);
+    assert_eq!(
+        client.try_cancel_order_for(&agent, &principal, &commitment, &proof, &signals),
+        Err(Ok(OrderbookError::AgentNotAuthorized))
+    );
+
+    registry_client.authorize_agent(
+        &principal,
+        &agent,
+        &registry_wasm::AgentScope {
+            assets: vec![&env],
+            max_order_size: None,
+            expires_at: env.ledger().timestamp() + 3600,
+        },
+    );
+    client.cancel_order_for(&agent, &principal, &commitment, &proof, &signals);
+    assert_eq!(client.get_order(&commitment).unwrap().status, OrderStatus::Cancelled);
+}
+
+#[test]
+fn test_apply_key_rotation_moves_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let open_commitment = BytesN::from_array(&env, &[3u8; 32]);
+    let cancelled_commitment = BytesN::from_array(&env, &[4u8; 32]);
+    let match_id = BytesN::from_array(&env, &[5u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    submit(&env, &client, &buyer, &open_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &buyer, &cancelled_commitment, &asset, OrderSide::Buy, &payment_asset);
+    enable_ownership_proofs(&env, &client, &admin);
+    let (proof, signals) = ownership_proof(&env, &cancelled_commitment);
+    client.cancel_order(&buyer, &cancelled_commitment, &proof, &signals);
+    client.record_match(
+        &admin,
+        &match_id,
+        &buy_commitment,
+        &sell_commitment,
+        &asset,
+        &payment_asset,
+        &buyer,
+        &seller,
+        &1_000_000,
+        &50_000_000,
+    );
+
+    assert_eq!(
+        client.try_apply_key_rotation(&buyer, &0, &10).err(),
+        Some(Ok(OrderbookError::KeyNotRotated))
+    );
+
+    let new_address = Address::generate(&env);
+    registry_client.propose_key_rotation(&buyer, &new_address);
+    registry_client.approve_key_rotation(&admin, &buyer);
+    // Two of the buyer's three orders per call: the first call also moves the match
+    let page = client.apply_key_rotation(&buyer, &0, &2);
+    assert_eq!((page.orders_moved, page.matches_moved, page.next_cursor), (2, 1, Some(2)));
+    let page = client.apply_key_rotation(&buyer, &2, &2);
+    assert_eq!((page.orders_moved, page.matches_moved, page.next_cursor), (0, 0, None));
+
+    // Open and matched orders follow the participant; history stays as it was
+    assert_eq!(client.get_order(&buy_commitment).unwrap().trader, new_address);
+    assert_eq!(client.get_order(&open_commitment).unwrap().trader, new_address);
+    assert_eq!(client.get_order(&cancelled_commitment).unwrap().trader, buyer);
+    assert_eq!(client.get_order(&sell_commitment).unwrap().trader, seller);
+    let match_record = client.get_match(&match_id).unwrap();
+    assert_eq!(match_record.buyer, new_address);
+    assert_eq!(match_record.seller, seller);
+
+    // The new key can manage the moved orders
+    let (proof, signals) = ownership_proof(&env, &open_commitment);
+    client.cancel_order(&new_address, &open_commitment, &proof, &signals);
+    assert_eq!(
+        client.get_order(&open_commitment).unwrap().status,
+        OrderStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_market_status_and_hours_gate_orders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Monday 1970-01-05, 10:00 UTC
+    env.ledger().set_timestamp(4 * 86400 + 10 * 3600);
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let match_id = BytesN::from_array(&env, &[3u8; 32]);
+    let expiry = 30 * 86400;
+
+    settlement_client.set_trading_status(
+        &admin,
+        &asset,
+        &settlement_wasm::TradingStatus::Halted,
+        &symbol_short!("news"),
+    );
+    fund(&env, &settlement, &buyer, &payment_asset, COLLATERAL);
+    fund(&env, &settlement, &seller, &asset, COLLATERAL);
+    let submit = |trader: &Address, commitment: &BytesN<32>, side: OrderSide, collateral| {
+        client.try_submit_order(
+            trader, commitment, &asset, &side, &expiry, collateral, &COLLATERAL,
+        )
+    };
+    assert_eq!(
+        submit(&buyer, &buy_commitment, OrderSide::Buy, &payment_asset),
+        Err(Ok(OrderbookError::MarketHalted))
+    );
+
+    settlement_client.set_trading_status(
+        &admin,
+        &asset,
+        &settlement_wasm::TradingStatus::Open,
+        &symbol_short!("resumed"),
+    );
+    // Weekdays, 09:00 to 17:00 UTC
+    settlement_client.set_trading_window(
+        &admin,
+        &asset,
+        &settlement_wasm::TradingWindow {
+            days: 0b001_1111,
+            open_time: 9 * 3600,
+            close_time: 17 * 3600,
+        },
+    );
+    assert_eq!(submit(&buyer, &buy_commitment, OrderSide::Buy, &payment_asset), Ok(Ok(0)));
+    assert_eq!(submit(&seller, &sell_commitment, OrderSide::Sell, &asset), Ok(Ok(1)));
+
+    let record_match = || {
+        client.try_record_match(
+            &admin,
+            &match_id,
+            &buy_commitment,
+            &sell_commitment,
+            &asset,
+            &payment_asset,
+            &buyer,
+            &seller,
+            &1_000_000,
+            &50_000_000,
+        )
+    };
+
+    // Monday after the close
+    env.ledger().set_timestamp(4 * 86400 + 18 * 3600);
+    assert_eq!(record_match(), Err(Ok(OrderbookError::MarketClosed)));
+
+    // Saturday during trading hours
+    env.ledger().set_timestamp(9 * 86400 + 10 * 3600);
+    assert_eq!(record_match(), Err(Ok(OrderbookError::MarketClosed)));
+
+    // Next Monday during trading hours
+    env.ledger().set_timestamp(11 * 86400 + 10 * 3600);
+    assert_eq!(record_match(), Ok(Ok(())));
+}
+
+#[test]
+fn test_indexes_follow_order_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let cancelled_commitment = BytesN::from_array(&env, &[3u8; 32]);
+    let match_id = BytesN::from_array(&env, &[4u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    assert_eq!(
+        submit(
+            &env, &client, &buyer, &cancelled_commitment, &asset, OrderSide::Buy, &payment_asset,
+        ),
+        2
+    );
+    assert_eq!(
+        client.try_submit_order(
+            &seller, &buy_commitment, &asset, &OrderSide::Sell, &3600, &asset, &COLLATERAL,
+        ),
+        Err(Ok(OrderbookError::DuplicateCommitment))
+    );
+
+    assert_eq!(client.get_orders_by_trader(&buyer).len(), 2);
+    assert_eq!(client.get_orders_by_asset(&asset, &Some(OrderSide::Buy)).len(), 2);
+    assert_eq!(client.get_orders_by_asset(&asset, &None).len(), 3);
+
+    enable_ownership_proofs(&env, &client, &admin);
+    let (proof, signals) = ownership_proof(&env, &cancelled_commitment);
+    client.cancel_order(&buyer, &cancelled_commitment, &proof, &signals);
+    let cancelled = client.get_orders_by_status(&asset, &OrderStatus::Cancelled);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled.get(0).unwrap().commitment, cancelled_commitment);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Active).len(), 2);
+
+    let record_match = || {
+        client.try_record_match(
+            &admin,
+            &match_id,
+            &buy_commitment,
+            &sell_commitment,
+            &asset,
+            &payment_asset,
+            &buyer,
+            &seller,
+            &1_000_000,
+            &50_000_000,
+        )
+    };
+    assert_eq!(record_match(), Ok(Ok(())));
+    assert_eq!(record_match(), Err(Ok(OrderbookError::MatchAlreadyExists)));
+    assert_eq!(client.get_active_orders(&asset).len(), 2);
+    assert_eq!(client.get_pending_matches().len(), 1);
+
+    // Only the settlement contract reports settlements, with the match's orders and amounts
+    let mark_settled = |price: i128| {
+        client.try_mark_settled(
+            &match_id,
+            &buy_commitment,
+            &sell_commitment,
+            &1_000_000,
+            &price,
+            &true,
+            &true,
+        )
+    };
+    assert_eq!(mark_settled(49_000_000), Err(Ok(OrderbookError::SettlementMismatch)));
+    assert_eq!(mark_settled(50_000_000), Ok(Ok(())));
+    assert_eq!(env.auths()[0].0, settlement);
+    assert_eq!(mark_settled(50_000_000), Err(Ok(OrderbookError::MatchAlreadySettled)));
+    assert_eq!(client.get_pending_matches().len(), 0);
+    assert_eq!(client.get_matches().len(), 1);
+    assert_eq!(client.get_active_orders(&asset).len(), 0);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Settled).len(), 2);
+}
+
+#[test]
+fn test_migrate_moves_orders_to_keyed_storage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = Address::generate(&env);
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let trader = Address::generate(&env);
+    let asset = register_asset(&env, &registry, &admin);
+    register_pair(&env, &registry, &admin, &asset);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let order = |id: u8, side: OrderSide, status: OrderStatus, tree_index: u32| OrderCommitment {
+        commitment: BytesN::from_array(&env, &[id; 32]),
+        trader: trader.clone(),
+        asset_address: asset.clone(),
+        side,
+        timestamp: 0,
+        expiry: 3600,
+        status,
+        tree_index,
+    };
+    let match_record = MatchRecord {
+        match_id: BytesN::from_array(&env, &[9u8; 32]),
+        buy_commitment: BytesN::from_array(&env, &[2u8; 32]),
+        sell_commitment: BytesN::from_array(&env, &[3u8; 32]),
+        asset_address: asset.clone(),
+        payment_asset: payment_asset.clone(),
+        buyer: trader.clone(),
+        seller: trader.clone(),
+        quantity: 1_000_000,
+        price: 50_000_000,
+        timestamp: 0,
+        is_settled: false,
+    };
+
+    // Lay out storage the way schema 1 stored it
+    env.as_contract(&contract_id, || {
+        let orders = vec![
+            &env,
+            order(1, OrderSide::Buy, OrderStatus::Active, 0),
+            order(2, OrderSide::Buy, OrderStatus::Matched, 1),
+            order(3, OrderSide::Sell, OrderStatus::Matched, 2),
+            order(1, OrderSide::Sell, OrderStatus::Cancelled, 3),
+        ];
+        env.storage().instance().set(&ORDERS_KEY, &orders);
+        // Schema 1 matches had no payment asset
+        let fields: Val = match_record.into_val(&env);
+        let mut legacy_match = Map::<Symbol, Val>::try_from_val(&env, &fields).unwrap();
+        legacy_match.remove(Symbol::new(&env, "payment_asset"));
+        env.storage().instance().set(&MATCHES_KEY, &vec![&env, legacy_match]);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &1u32);
+    });
+
+    // The asset has two pairs, so the admin must say which one its matches used
+    assert_eq!(
+        client.try_migrate(&admin, &Map::new(&env)),
+        Err(Ok(OrderbookError::PaymentAssetMissing))
+    );
+    assert_eq!(client.get_schema_version(), 1);
+    let payment_assets = Map::from_array(&env, [(asset.clone(), payment_asset.clone())]);
+    assert_eq!(client.migrate(&admin, &payment_assets), 6);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            SchemaMigrated { version: 2 }.to_xdr(&env, &contract_id),
+            SchemaMigrated { version: 3 }.to_xdr(&env, &contract_id),
+            OrderRootChanged {
+                epoch: 1,
+                root: client.get_order_root(),
+            }
+            .to_xdr(&env, &contract_id),
+            SchemaMigrated { version: 4 }.to_xdr(&env, &contract_id),
+            SchemaMigrated { version: 5 }.to_xdr(&env, &contract_id),
+            SchemaMigrated { version: 6 }.to_xdr(&env, &contract_id),
+        ]
+    );
+
+    // The first of the duplicate commitments wins, as with the old lookup
+    let first = client.get_order(&BytesN::from_array(&env, &[1u8; 32])).unwrap();
+    assert_eq!(first.status, OrderStatus::Active);
+    assert_eq!(client.get_active_orders(&asset).len(), 1);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Matched).len(), 2);
+    assert_eq!(client.get_orders_by_trader(&trader).len(), 3);
+    assert_eq!(client.get_orders_by_asset(&asset, &Some(OrderSide::Sell)).len(), 1);
+    assert_eq!(client.get_pending_matches().len(), 1);
+    let migrated_match = client.get_match(&match_record.match_id).unwrap();
+    assert_eq!(migrated_match.price, 50_000_000);
+    assert_eq!(migrated_match.payment_asset, payment_asset);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&ORDERS_KEY));
+        assert!(!env.storage().instance().has(&MATCHES_KEY));
+        assert!(!env.storage().instance().has(&ORDER_COUNT_KEY));
+        // The trader is both buyer and seller of the match, which is indexed once
+        let index = IndexKey::TraderPendingMatches(trader.clone());
+        assert_eq!(DarkPoolOrderbook::index_len(&env, &index), 1);
+    });
+
+    // Migrated commitments fill the order tree in submission order, without gaps
+    let mut tree = LeanIMTBN254::new(&env, ORDER_TREE_DEPTH);
+    for id in 1..=3u8 {
+        let commitment = BytesN::from_array(&env, &[id; 32]);
+        assert_eq!(client.get_order(&commitment).unwrap().tree_index, u32::from(id) - 1);
+        assert_eq!(client.get_order_tree_leaf(&(u32::from(id) - 1)), Some(commitment.clone()));
+        tree.insert(commitment).unwrap();
+    }
+    assert_eq!(client.get_order_tree_size(), 3);
+    assert_eq!(client.get_order_root(), tree.get_root());
+}
+
+#[test]
+fn test_pagination_and_change_feed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let commitment = |id: u8| BytesN::from_array(&env, &[id; 32]);
+    let match_id = commitment(9);
+
+    for id in 1..=3 {
+        submit(&env, &client, &buyer, &commitment(id), &asset, OrderSide::Buy, &payment_asset);
+    }
+    submit(&env, &client, &seller, &commitment(4), &asset, OrderSide::Sell, &asset);
+
+    let page = client.get_orders_by_asset_page(&asset, &OrderSide::Buy, &0, &2);
+    assert_eq!(page.orders.len(), 2);
+    assert_eq!(page.orders.get(1).unwrap().commitment, commitment(2));
+    assert_eq!(page.next_cursor, Some(2));
+    let page = client.get_orders_by_asset_page(&asset, &OrderSide::Buy, &2, &2);
+    assert_eq!(page.orders.len(), 1);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(client.get_active_orders_page(&asset, &0, &10).orders.len(), 4);
+
+    // Every submission is in the feed
+    assert_eq!(client.get_sequence(), 4);
+    let changes = client.get_changes_since(&0, &10);
+    assert_eq!(changes.len(), 4);
+    assert_eq!(changes.get(3).unwrap().seq, 4);
+    assert_eq!(changes.get(3).unwrap().id, commitment(4));
+    assert_eq!(client.get_changes_since(&4, &10).len(), 0);
+
+    client.record_match(
+        &admin,
+        &match_id,
+        &commitment(1),
+        &commitment(4),
+        &asset,
+        &payment_asset,
+        &buyer,
+        &seller,
+        &1_000_000,
+        &50_000_000,
+    );
+
+    // Both orders and the new match changed
+    let changes = client.get_changes_since(&4, &2);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes.get(0).unwrap().kind, ChangeKind::Order);
+    let changes = client.get_changes_since(&6, &10);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes.get(0).unwrap().kind, ChangeKind::Match);
+    assert_eq!(changes.get(0).unwrap().id, match_id);
+
+    assert_eq!(client.get_active_orders_page(&asset, &0, &10).orders.len(), 4);
+    assert_eq!(client.get_matches_page(&0, &10).matches.len(), 1);
+    assert_eq!(client.get_pending_matches_page(&0, &10).matches.len(), 1);
+    let (buy, sell) = (commitment(1), commitment(4));
+    client.mark_settled(&match_id, &buy, &sell, &1_000_000, &50_000_000, &true, &true);
+    assert_eq!(client.get_pending_matches_page(&0, &10).matches.len(), 0);
+    assert_eq!(client.get_active_orders_page(&asset, &0, &10).orders.len(), 2);
+
+    // Old feed entries expire; callers must resync
+    env.as_contract(&contract_id, || {
+        env.storage().instance().extend_ttl(2 * CHANGE_TTL, 2 * CHANGE_TTL);
+    });
+    env.ledger().with_mut(|l| l.sequence_number += CHANGE_TTL + 1);
+    assert_eq!(
+        client.try_get_changes_since(&0, &10).err(),
+        Some(Ok(OrderbookError::ChangeFeedExpired))
+    );
+}
+
+#[test]
+fn test_cancel_requires_ownership_proof() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let other_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    submit(&env, &client, &trader, &commitment, &asset, OrderSide::Buy, &payment_asset);
+
+    let (proof, signals) = ownership_proof(&env, &commitment);
+    assert_eq!(
+        client.try_cancel_order(&trader, &commitment, &proof, &signals),
+        Err(Ok(OrderbookError::OwnershipVkNotSet))
+    );
+
+    enable_ownership_proofs(&env, &client, &admin);
+    assert!(client.get_verifier().is_some());
+
+    // The proof must be about this commitment and must verify
+    let (_, wrong_signals) = ownership_proof(&env, &other_commitment);
+    assert_eq!(
+        client.try_cancel_order(&trader, &commitment, &proof, &wrong_signals),
+        Err(Ok(OrderbookError::InvalidProof))
+    );
+    let bad_proof = Bytes::from_slice(&env, &[0u8; 256]);
+    assert_eq!(
+        client.try_cancel_order_by_proof(&commitment, &bad_proof, &signals),
+        Err(Ok(OrderbookError::InvalidProof))
+    );
+
+    // Only proofs tagged as cancellations with the order's current nonce are accepted
+    let (_, other_action) = cancel_proof(&env, &commitment, 2, 0);
+    let (_, future_nonce) = cancel_proof(&env, &commitment, 1, 1);
+    for signals in [other_action, future_nonce] {
+        assert_eq!(
+            client.try_cancel_order_by_proof(&commitment, &proof, &signals),
+            Err(Ok(OrderbookError::InvalidProof))
+        );
+    }
+    assert_eq!(client.get_cancel_nonce(&commitment), 0);
+
+    // The secret holder can cancel without the trader's signature
+    client.cancel_order_by_proof(&commitment, &proof, &signals);
+    assert_eq!(env.auths(), []);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OrderCancelled {
+            commitment: commitment.clone(),
+            trader,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(client.get_order(&commitment).unwrap().status, OrderStatus::Cancelled);
+
+    // The proof is consumed and cannot be replayed
+    assert_eq!(client.get_cancel_nonce(&commitment), 1);
+    assert_eq!(
+        client.try_cancel_order_by_proof(&commitment, &proof, &signals),
+        Err(Ok(OrderbookError::OrderAlreadyCancelled))
+    );
+}
+
+#[test]
+fn test_orders_lock_and_release_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+    enable_ownership_proofs(&env, &client, &admin);
+
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let other_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    fund(&env, &settlement, &trader, &payment_asset, 1_000);
+
+    let submit = |commitment: &BytesN<32>, side: OrderSide, collateral: &Address, amount| {
+        client.try_submit_order(&trader, commitment, &asset, &side, &3600, collateral, &amount)
+    };
+
+    // Buys lock the payment asset, sells the asset itself
+    assert_eq!(
+        submit(&commitment, OrderSide::Sell, &payment_asset, 600),
+        Err(Ok(OrderbookError::InvalidCollateral))
+    );
+    assert_eq!(
+        submit(&commitment, OrderSide::Buy, &payment_asset, 0),
+        Err(Ok(OrderbookError::InvalidCollateral))
+    );
+    assert_eq!(
+        submit(&commitment, OrderSide::Buy, &payment_asset, 1_001),
+        Err(Ok(OrderbookError::InsufficientCollateral))
+    );
+    assert!(client.get_order(&commitment).is_none());
+
+    assert_eq!(submit(&commitment, OrderSide::Buy, &payment_asset, 600), Ok(Ok(0)));
+    assert_eq!(settlement_client.get_order_lock(&commitment).unwrap().amount, 600);
+    assert_eq!(settlement_client.get_available_balance(&trader, &payment_asset), 400);
+    assert_eq!(
+        submit(&other_commitment, OrderSide::Buy, &payment_asset, 600),
+        Err(Ok(OrderbookError::InsufficientCollateral))
+    );
+
+    // Cancelling releases the order's lock
+    let (proof, signals) = ownership_proof(&env, &commitment);
+    client.cancel_order(&trader, &commitment, &proof, &signals);
+    assert!(settlement_client.get_order_lock(&commitment).is_none());
+    assert_eq!(settlement_client.get_available_balance(&trader, &payment_asset), 1_000);
+
+    // So does expiry, once the order is past it
+    assert_eq!(submit(&other_commitment, OrderSide::Buy, &payment_asset, 1_000), Ok(Ok(1)));
+    assert_eq!(
+        client.try_expire_order(&other_commitment),
+        Err(Ok(OrderbookError::OrderNotExpired))
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3600);
+    client.expire_order(&other_commitment);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OrderExpired {
+            asset_address: asset.clone(),
+            commitment: other_commitment.clone(),
+            trader: trader.clone(),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(client.get_order(&other_commitment).unwrap().status, OrderStatus::Expired);
+    assert_eq!(settlement_client.get_available_balance(&trader, &payment_asset), 1_000);
+    assert_eq!(
+        client.try_expire_order(&other_commitment),
+        Err(Ok(OrderbookError::OrderNotExpired))
+    );
+}
+
+#[test]
+fn test_expired_orders_are_swept_and_not_matched() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+    enable_ownership_proofs(&env, &client, &admin);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let long_commitment = BytesN::from_array(&env, &[3u8; 32]);
+    let match_id = BytesN::from_array(&env, &[4u8; 32]);
+
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    fund(&env, &settlement, &seller, &asset, COLLATERAL);
+    client.submit_order(
+        &seller, &long_commitment, &asset, &OrderSide::Sell, &7200, &asset, &COLLATERAL,
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3600);
+
+    // Getters report orders past their expiry as expired before anything records it
+    assert_eq!(client.get_order(&buy_commitment).unwrap().status, OrderStatus::Expired);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Expired).len(), 2);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Active).len(), 1);
+    let page = client.get_orders_by_asset_page(&asset, &OrderSide::Sell, &0, &10);
+    assert_eq!(page.orders.get(0).unwrap().status, OrderStatus::Expired);
+    assert_eq!(page.orders.get(1).unwrap().status, OrderStatus::Active);
+
+    // Orders past their expiry cannot be matched
+    assert_eq!(
+        client.try_record_match(
+            &admin, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset, &buyer,
+            &seller, &1_000_000, &50_000_000,
+        ),
+        Err(Ok(OrderbookError::OrderExpired))
+    );
+
+    // Touching an order past its expiry expires it
+    let (proof, signals) = ownership_proof(&env, &buy_commitment);
+    client.cancel_order(&buyer, &buy_commitment, &proof, &signals);
+    assert_eq!(client.get_order(&buy_commitment).unwrap().status, OrderStatus::Expired);
+    assert_eq!(settlement_client.get_available_balance(&buyer, &payment_asset), COLLATERAL);
+    assert_eq!(
+        client.try_cancel_order(&buyer, &buy_commitment, &proof, &signals),
+        Err(Ok(OrderbookError::OrderExpired))
+    );
+
+    // The sweeper expires the rest and leaves live orders alone
+    assert_eq!(client.expire_orders(&10), 1);
+    assert_eq!(client.get_order(&sell_commitment).unwrap().status, OrderStatus::Expired);
+    assert_eq!(client.get_order(&long_commitment).unwrap().status, OrderStatus::Active);
+    assert_eq!(client.expire_orders(&10), 0);
+    assert_eq!(client.get_orders_by_status(&asset, &OrderStatus::Expired).len(), 2);
+
+    // Migrating from schema 2 indexes orders placed before the sweeper existed
+    env.as_contract(&contract_id, || {
+        DarkPoolOrderbook::index_remove(&env, IndexKey::ActiveOrders, &long_commitment);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &2u32);
+    });
+    assert_eq!(client.migrate(&admin, &Map::new(&env)), 6);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3600);
+    assert_eq!(client.expire_orders(&10), 1);
+    assert_eq!(client.get_order(&long_commitment).unwrap().status, OrderStatus::Expired);
+    assert_eq!(settlement_client.get_locked_balance(&seller, &asset), 0);
+}
+
+#[test]
+fn test_partial_fills_keep_orders_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+    enable_ownership_proofs(&env, &client, &admin);
+
+    let seller = register_trader(&env, &registry, &admin, 1);
+    let buyer = register_trader(&env, &registry, &admin, 2);
+    let other_buyer = register_trader(&env, &registry, &admin, 3);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+
+    // A 10M block sell order against two smaller buys
+    let sell_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let buy_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    let other_buy_commitment = BytesN::from_array(&env, &[3u8; 32]);
+    fund(&env, &settlement, &seller, &asset, 10_000_000);
+    client.submit_order(
+        &seller, &sell_commitment, &asset, &OrderSide::Sell, &3600, &asset, &10_000_000,
+    );
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    let other = &other_buyer;
+    submit(&env, &client, other, &other_buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+
+    let record_match = |id: u8, buy: &BytesN<32>, buyer: &Address, quantity: i128| {
+        client.try_record_match(
+            &admin,
+            &BytesN::from_array(&env, &[id; 32]),
+            buy,
+            &sell_commitment,
+            &asset,
+            &payment_asset,
+            buyer,
+            &seller,
+            &quantity,
+            &100_000_000,
+        )
+    };
+
+    // Both orders stay active after the first buy is filled against part of the sell
+    assert_eq!(record_match(10, &buy_commitment, &buyer, 2_000_000), Ok(Ok(())));
+    assert_eq!(client.get_order(&buy_commitment).unwrap().status, OrderStatus::Active);
+    assert_eq!(client.get_order(&sell_commitment).unwrap().status, OrderStatus::Active);
+    let fill = client.get_order_fill(&sell_commitment);
+    assert_eq!((fill.filled, fill.reserved, fill.open_matches), (2_000_000, 2_000_000, 1));
+    assert_eq!(record_match(11, &other_buy_commitment, other, 3_000_000), Ok(Ok(())));
+    assert_eq!(client.get_order_fill(&sell_commitment).filled, 5_000_000);
+
+    // Unsettled matches cannot spend more than the sell order's 10M lock
+    assert_eq!(
+        record_match(12, &other_buy_commitment, other, 6_000_000),
+        Err(Ok(OrderbookError::FillExceedsCollateral))
+    );
+
+    // An order cannot be completed while another of its matches is still pending
+    let match_id = BytesN::from_array(&env, &[10u8; 32]);
+    let (buy, sell) = (&buy_commitment, &sell_commitment);
+    assert_eq!(
+        client.try_mark_settled(&match_id, buy, sell, &2_000_000, &100_000_000, &true, &true),
+        Err(Ok(OrderbookError::MatchesStillOpen))
+    );
+
+    // Settling a match settles the buy it filled and frees what it did not spend
+    client.mark_settled(&match_id, buy, sell, &2_000_000, &100_000_000, &true, &false);
+    assert_eq!(client.get_order(&buy_commitment).unwrap().status, OrderStatus::Settled);
+    assert_eq!(
+        record_match(12, &buy_commitment, &buyer, 1_000_000),
+        Err(Ok(OrderbookError::OrderAlreadyMatched))
+    );
+    assert!(settlement_client.get_order_lock(&buy_commitment).is_none());
+    assert_eq!(client.get_order(&sell_commitment).unwrap().status, OrderStatus::Active);
+    let fill = client.get_order_fill(&sell_commitment);
+    assert_eq!((fill.reserved, fill.open_matches), (3_000_000, 1));
+
+    // Cancelling keeps the collateral the pending match will spend
+    let (proof, signals) = ownership_proof(&env, &sell_commitment);
+    client.cancel_order(&seller, &sell_commitment, &proof, &signals);
+    assert_eq!(settlement_client.get_order_lock(&sell_commitment).unwrap().amount, 3_000_000);
+    assert_eq!(settlement_client.get_available_balance(&seller, &asset), 7_000_000);
+    assert_eq!(
+        record_match(12, &other_buy_commitment, other, 1_000_000),
+        Err(Ok(OrderbookError::OrderAlreadyCancelled))
+    );
+
+    let match_id = BytesN::from_array(&env, &[11u8; 32]);
+    let buy = &other_buy_commitment;
+    client.mark_settled(&match_id, buy, sell, &3_000_000, &100_000_000, &false, &true);
+    assert_eq!(client.get_order(&sell_commitment).unwrap().status, OrderStatus::Cancelled);
+    assert_eq!(client.get_order(&other_buy_commitment).unwrap().status, OrderStatus::Active);
+    assert_eq!(client.get_order_fill(&sell_commitment).open_matches, 0);
+}
+
+#[test]
+fn test_drop_match_returns_reservations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+    enable_ownership_proofs(&env, &client, &admin);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let operator = Address::generate(&env);
+    client.set_operator(&admin, &operator, &Vec::new(&env));
+
+    let buy_commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let sell_commitment = BytesN::from_array(&env, &[2u8; 32]);
+    submit(&env, &client, &buyer, &buy_commitment, &asset, OrderSide::Buy, &payment_asset);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    let record_match = |id: u8| {
+        let match_id = BytesN::from_array(&env, &[id; 32]);
+        client.try_record_match(
+            &operator, &match_id, &buy_commitment, &sell_commitment, &asset, &payment_asset,
+            &buyer, &seller, &1_000_000, &50_000_000,
+        )
+    };
+
+    // A fresh match can only be dropped by the admin or the operator that recorded it
+    assert_eq!(record_match(10), Ok(Ok(())));
+    let match_id = BytesN::from_array(&env, &[10u8; 32]);
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_drop_match(&stranger, &match_id),
+        Err(Ok(OrderbookError::MatchNotStale))
+    );
+    client.drop_match(&operator, &match_id);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [MatchDropped {
+            match_id: match_id.clone(),
+            buy_commitment: buy_commitment.clone(),
+            sell_commitment: sell_commitment.clone(),
+            dropped_by: operator.clone(),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert!(client.get_match(&match_id).is_none());
+    assert!(client.get_pending_matches().is_empty());
+    let fill = client.get_order_fill(&buy_commitment);
+    assert_eq!((fill.filled, fill.reserved, fill.open_matches), (0, 0, 0));
+
+    // The dropped id cannot be recorded again, nor settled
+    assert_eq!(record_match(10), Err(Ok(OrderbookError::MatchAlreadyExists)));
+    let (buy, sell) = (&buy_commitment, &sell_commitment);
+    assert_eq!(
+        client.try_mark_settled(&match_id, buy, sell, &1_000_000, &50_000_000, &true, &true),
+        Err(Ok(OrderbookError::MatchNotFound))
+    );
+
+    // The pending match keeps collateral locked after the buy is cancelled
+    assert_eq!(record_match(11), Ok(Ok(())));
+    let (proof, signals) = ownership_proof(&env, &buy_commitment);
+    client.cancel_order(&buyer, &buy_commitment, &proof, &signals);
+    assert_eq!(settlement_client.get_order_lock(&buy_commitment).unwrap().amount, 50_000_000);
+
+    // Once a day old, anyone may drop it, which releases the rest of the cancelled order
+    let match_id = BytesN::from_array(&env, &[11u8; 32]);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    client.drop_match(&stranger, &match_id);
+    assert!(settlement_client.get_order_lock(&buy_commitment).is_none());
+    assert_eq!(settlement_client.get_available_balance(&buyer, &payment_asset), COLLATERAL);
+    assert_eq!(client.get_order_fill(&sell_commitment).open_matches, 0);
+    assert_eq!(
+        client.try_drop_match(&stranger, &match_id),
+        Err(Ok(OrderbookError::MatchNotFound))
+    );
+}
+
+#[test]
+fn test_order_tree_tracks_commitments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+
+    // The empty tree's root is epoch 0
+    let mut tree = LeanIMTBN254::new(&env, ORDER_TREE_DEPTH);
+    let empty_root = client.get_order_root();
+    assert_eq!(empty_root, tree.get_root());
+    assert_eq!(client.get_order_root_epoch(), 0);
+
+    // Each commitment is appended at its tree index and starts a new root epoch
+    for id in 1..=3u8 {
+        let commitment = BytesN::from_array(&env, &[id; 32]);
+        let side = if id % 2 == 0 { OrderSide::Sell } else { OrderSide::Buy };
+        let collateral = if side == OrderSide::Sell { &asset } else { &payment_asset };
+        let tree_index = submit(&env, &client, &trader, &commitment, &asset, side, collateral);
+        assert_eq!(tree_index, u32::from(id) - 1);
+        tree.insert(commitment).unwrap();
+        assert_eq!(client.get_order_root(), tree.get_root());
+    }
+    assert_eq!(client.get_order_root_epoch(), 3);
+
+    // Earlier roots stay known so proofs against a slightly older book still verify
+    let history = client.get_order_root_history();
+    assert_eq!(history.len(), 4);
+    assert_eq!(history.get(0).unwrap().root, empty_root);
+    assert_eq!(history.last().unwrap().epoch, 3);
+    assert!(client.is_known_order_root(&empty_root));
+    assert!(!client.is_known_order_root(&BytesN::from_array(&env, &[9u8; 32])));
+
+    // Rejected submissions leave the tree untouched
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    assert_eq!(
+        client.try_submit_order(
+            &trader, &commitment, &asset, &OrderSide::Buy, &3600, &payment_asset, &COLLATERAL,
+        ),
+        Err(Ok(OrderbookError::DuplicateCommitment))
+    );
+    assert_eq!(client.get_order_root(), tree.get_root());
+    assert_eq!(client.get_order_root_epoch(), 3);
+    assert_eq!(client.get_order_tree_size(), 3);
+
+    // Schema 4 kept the leaves in one vector; migrating splits them back out
+    env.as_contract(&contract_id, || {
+        let mut leaves = vec![&env];
+        for index in 0..3 {
+            let key = DataKey::OrderLeaf(index);
+            let leaf: BytesN<32> = env.storage().persistent().get(&key).unwrap();
+            leaves.push_back(leaf);
+            env.storage().persistent().remove(&key);
+        }
+        env.storage().persistent().set(&DataKey::OrderTreeLeaves, &leaves);
+        env.storage().persistent().remove(&DataKey::OrderTreeFrontier);
+        env.storage().instance().remove(&ORDER_TREE_SIZE_KEY);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &4u32);
+    });
+    assert_eq!(client.migrate(&admin, &Map::new(&env)), 6);
+    assert_eq!(client.get_order_tree_size(), 3);
+    assert_eq!(client.get_order_tree_leaf(&2), Some(BytesN::from_array(&env, &[3u8; 32])));
+    assert_eq!(client.get_order_root(), tree.get_root());
+    assert_eq!(client.get_order_root_epoch(), 3);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::OrderTreeLeaves));
+    });
+
+    // The rebuilt frontier carries on where the old tree stopped
+    let commitment = BytesN::from_array(&env, &[4u8; 32]);
+    let side = OrderSide::Buy;
+    let tree_index = submit(&env, &client, &trader, &commitment, &asset, side, &payment_asset);
+    assert_eq!(tree_index, 3);
+    tree.insert(commitment).unwrap();
+    assert_eq!(client.get_order_root(), tree.get_root());
+}
+
+#[test]
+fn test_relayed_orders_are_anonymous() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let settlement_client = settlement_wasm::Client::new(&env, &settlement);
+
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    // The relayer is not a registered participant and holds no escrow
+    let relayer = Address::generate(&env);
+    let seller = register_trader(&env, &registry, &admin, 1);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let root = registry_wasm::Client::new(&env, &registry).get_whitelist_root();
+
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let proof = Bytes::from_slice(&env, &[1u8; 256]);
+    let submit_relayed = |commitment: &BytesN<32>, signals: &Bytes| {
+        client.try_submit_relayed_order(
+            &relayer,
+            commitment,
+            &asset,
+            &OrderSide::Buy,
+            &3600,
+            &payment_asset,
+            &500_000_000,
+            &proof,
+            signals,
+        )
+    };
+    let signals = relay_signals(&env, &commitment, &root, OrderSide::Buy, 500_000_000, 2_000);
+    assert_eq!(submit_relayed(&commitment, &signals), Err(Ok(OrderbookError::RelayVkNotSet)));
+
+    let verifier = env.register(MockVerifier, ());
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    client.set_relay_vk(&admin, &verifier, &vk_bytes);
+    client.set_ownership_vk(&admin, &verifier, &vk_bytes);
+
+    // The proof must cover this order's collateral, a known root and an unexpired KYC check
+    let wrong_amount = relay_signals(&env, &commitment, &root, OrderSide::Buy, 1, 2_000);
+    assert_eq!(submit_relayed(&commitment, &wrong_amount), Err(Ok(OrderbookError::InvalidProof)));
+    let unknown_root = BytesN::from_array(&env, &[9u8; 32]);
+    let stale = relay_signals(&env, &commitment, &unknown_root, OrderSide::Buy, 500_000_000, 2_000);
+    assert_eq!(
+        submit_relayed(&commitment, &stale),
+        Err(Ok(OrderbookError::UnknownWhitelistRoot))
+    );
+    // A replaced root is only accepted within settlement's freshness window
+    register_trader(&env, &registry, &admin, 2);
+    settlement_client.set_root_freshness_window(&admin, &0);
+    env.ledger().set_timestamp(1_001);
+    assert_eq!(
+        submit_relayed(&commitment, &signals),
+        Err(Ok(OrderbookError::UnknownWhitelistRoot))
+    );
+    settlement_client.set_root_freshness_window(&admin, &3600);
+    let expired = relay_signals(&env, &commitment, &root, OrderSide::Buy, 500_000_000, 999);
+    assert_eq!(submit_relayed(&commitment, &expired), Err(Ok(OrderbookError::ProofExpired)));
+
+    // The collateral comes from the commitment's note, which the relayer funds
+    assert_eq!(
+        submit_relayed(&commitment, &signals),
+        Err(Ok(OrderbookError::InsufficientCollateral))
+    );
+    StellarAssetClient::new(&env, &payment_asset).mint(&relayer, &COLLATERAL);
+    settlement_client.deposit_note(&relayer, &commitment, &payment_asset, &COLLATERAL);
+
+    // Only the relayer signs, and no trader address is stored
+    assert_eq!(submit_relayed(&commitment, &signals), Ok(Ok(0)));
+    assert!(env.auths().iter().all(|(address, _)| *address == relayer));
+    let order = client.get_order(&commitment).unwrap();
+    assert_eq!(order.trader, settlement);
+    assert!(client.is_relayed_order(&commitment));
+    assert_eq!(settlement_client.get_order_lock(&commitment).unwrap().owner, settlement);
+    assert_eq!(
+        settlement_client.get_note_balance(&commitment, &payment_asset),
+        COLLATERAL - 500_000_000
+    );
+    assert_eq!(settlement_client.get_escrow_balance(&relayer, &payment_asset), 0);
+
+    // Relayed orders match with the settlement contract standing in for their owner
+    let sell_commitment = BytesN::from_array(&env, &[3u8; 32]);
+    submit(&env, &client, &seller, &sell_commitment, &asset, OrderSide::Sell, &asset);
+    client.record_match(
+        &admin,
+        &BytesN::from_array(&env, &[4u8; 32]),
+        &commitment,
+        &sell_commitment,
+        &asset,
+        &payment_asset,
+        &settlement,
+        &seller,
+        &1_000_000,
+        &50_000_000,
+    );
+
+    // The secret holder cancels from any account; unused collateral returns to the note
+    let (proof, signals) = ownership_proof(&env, &commitment);
+    client.cancel_order_by_proof(&commitment, &proof, &signals);
+    assert_eq!(client.get_order(&commitment).unwrap().status, OrderStatus::Cancelled);
+    assert_eq!(settlement_client.get_order_lock(&commitment).unwrap().amount, 50_000_000);
+    let unlocked = COLLATERAL - 50_000_000;
+    assert_eq!(settlement_client.get_note_balance(&commitment, &payment_asset), unlocked);
+
+    // and withdraws it to any account with a proof bound to that account
+    let recipient = Address::generate(&env);
+    let withdraw_signals = |recipient: &Address| {
+        let mut signals = Bytes::from_array(&env, &3u32.to_be_bytes());
+        signals.append(&Bytes::from_array(&env, &commitment.to_array()));
+        let action = client.get_withdraw_action(recipient);
+        signals.append(&Bytes::from_array(&env, &action.to_array()));
+        let mut nonce = [0u8; 32];
+        nonce[31] = 1;
+        signals.append(&Bytes::from_array(&env, &nonce));
+        signals
+    };
+    let withdraw = |signals: &Bytes| {
+        client.try_withdraw_relayed_funds(&commitment, &payment_asset, &recipient, &proof, signals)
+    };
+    let redirected = withdraw_signals(&Address::generate(&env));
+    assert_eq!(withdraw(&redirected), Err(Ok(OrderbookError::InvalidProof)));
+    let signals = withdraw_signals(&recipient);
+    assert_eq!(withdraw(&signals), Ok(Ok(unlocked)));
+    assert_eq!(TokenClient::new(&env, &payment_asset).balance(&recipient), unlocked);
+    assert_eq!(settlement_client.get_note_balance(&commitment, &payment_asset), 0);
+    assert_eq!(withdraw(&signals), Err(Ok(OrderbookError::InvalidProof)));
+}
+
+#[test]
+fn test_matcher_operators_record_matches() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let settlement = create_settlement(&env, &admin, &registry);
+    let contract_id = create_orderbook(&env, &admin, &registry, &settlement);
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let asset = register_asset(&env, &registry, &admin);
+    let payment_asset = register_pair(&env, &registry, &admin, &asset);
+    let other_asset = register_asset(&env, &registry, &admin);
+    let other_payment = register_pair(&env, &registry, &admin, &other_asset);
+
+    let pairs = [(1u8, &asset, &payment_asset), (3u8, &other_asset, &other_payment)];
+    for (id, asset, payment) in pairs {
+        let buy = BytesN::from_array(&env, &[id; 32]);
+        let sell = BytesN::from_array(&env, &[id + 1; 32]);
+        submit(&env, &client, &buyer, &buy, asset, OrderSide::Buy, payment);
+        submit(&env, &client, &seller, &sell, asset, OrderSide::Sell, asset);
+    }
+
+    let operator = Address::generate(&env);
+    client.set_operator(&admin, &operator, &vec![&env, asset.clone()]);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [OperatorSet {
+            operator: operator.clone(),
+            assets: vec![&env, asset.clone()],
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(client.get_operators(), vec![&env, operator.clone()]);
+    assert!(client.get_operator(&operator).unwrap().is_active);
+
+    let record_match = |matcher: &Address, id: u8, asset: &Address, payment: &Address| {
+        client.try_record_match(
+            matcher,
+            &BytesN::from_array(&env, &[id + 10; 32]),
+            &BytesN::from_array(&env, &[id; 32]),
+            &BytesN::from_array(&env, &[id + 1; 32]),
+            asset,
+            payment,
+            &buyer,
+            &seller,
+            &1_000_000,
+            &50_000_000,
+        )
+    };
+
+    // Unregistered keys and assets outside the operator's scope are refused
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        record_match(&stranger, 1, &asset, &payment_asset),
+        Err(Ok(OrderbookError::OperatorNotAuthorized))
+    );
+    assert_eq!(
+        record_match(&operator, 3, &other_asset, &other_payment),
+        Err(Ok(OrderbookError::OperatorNotAuthorized))
+    );
+
+    // Matches are attributed to the operator that recorded them
+    assert_eq!(record_match(&operator, 1, &asset, &payment_asset), Ok(Ok(())));
+    let match_id = BytesN::from_array(&env, &[11u8; 32]);
+    assert_eq!(client.get_match_operator(&match_id), Some(operator.clone()));
+
+    // A suspended operator cannot record matches until reinstated
+    client.set_operator_active(&admin, &operator, &false);
+    client.set_operator(&admin, &operator, &Vec::new(&env));
+    assert!(!client.get_operator(&operator).unwrap().is_active);
+    assert_eq!(
+        record_match(&operator, 3, &other_asset, &other_payment),
+        Err(Ok(OrderbookError::OperatorSuspended))
+    );
+    client.set_operator_active(&admin, &operator, &true);
+
+    // Widening the scope to all assets lets the operator match the other pair
+    assert_eq!(record_match(&operator, 3, &other_asset, &other_payment), Ok(Ok(())));
+
+    client.remove_operator(&admin, &operator);
+    assert!(client.get_operator(&operator).is_none());
+    assert_eq!(
+        client.try_set_operator_active(&admin, &operator, &true),
+        Err(Ok(OrderbookError::OperatorNotFound))
+    );
+    assert_eq!(
+        client.try_set_operator(&stranger, &operator, &Vec::new(&env)),
+        Err(Ok(OrderbookError::OnlyAdmin))
+    );
+}
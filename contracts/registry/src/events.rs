@@ -0,0 +1,245 @@
+//! Registry events
+//!
+//! Each event's first topic is its name in snake_case (e.g. `participant_registered`),
+//! followed by the fields marked `#[topic]`. The remaining fields form the data map.
+
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol};
+
+use crate::{AssetType, PairStatus, ParticipantCategory};
+
+/// A participant was added to the whitelist
+#[contractevent]
+#[derive(Clone)]
+pub struct ParticipantRegistered {
+    #[topic]
+    pub trading_address: Address,
+    pub tree_index: u32,
+    pub category: ParticipantCategory,
+    pub kyc_expiry: u64,
+}
+
+/// A participant was deactivated and its whitelist leaf cleared
+#[contractevent]
+#[derive(Clone)]
+pub struct ParticipantDeactivated {
+    #[topic]
+    pub trading_address: Address,
+}
+
+/// A participant's KYC expiry was extended
+#[contractevent]
+#[derive(Clone)]
+pub struct KycRenewed {
+    #[topic]
+    pub trading_address: Address,
+    pub kyc_expiry: u64,
+}
+
+/// A KYC provider was approved for attestations
+#[contractevent]
+#[derive(Clone)]
+pub struct KycProviderAdded {
+    #[topic]
+    pub public_key: BytesN<32>,
+    pub name: String,
+}
+
+/// A KYC provider's attestations are no longer accepted
+#[contractevent]
+#[derive(Clone)]
+pub struct KycProviderRevoked {
+    #[topic]
+    pub public_key: BytesN<32>,
+}
+
+/// An RWA asset was registered
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetListed {
+    #[topic]
+    pub token_address: Address,
+    pub symbol: Symbol,
+    pub asset_type: AssetType,
+}
+
+/// An RWA asset was deactivated
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetDeactivated {
+    #[topic]
+    pub token_address: Address,
+}
+
+/// An asset's metadata was replaced; `version` is its index in the history
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetMetadataUpdated {
+    #[topic]
+    pub token_address: Address,
+    pub version: u32,
+}
+
+/// An asset's eligibility policy was set or replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetPolicySet {
+    #[topic]
+    pub token_address: Address,
+}
+
+/// An asset's eligibility policy was removed
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetPolicyRemoved {
+    #[topic]
+    pub token_address: Address,
+}
+
+/// A participant authorized an agent to act on their behalf
+#[contractevent]
+#[derive(Clone)]
+pub struct AgentAuthorized {
+    #[topic]
+    pub principal: Address,
+    #[topic]
+    pub agent: Address,
+    pub expires_at: u64,
+}
+
+/// A participant revoked an agent's authorization
+#[contractevent]
+#[derive(Clone)]
+pub struct AgentRevoked {
+    #[topic]
+    pub principal: Address,
+    #[topic]
+    pub agent: Address,
+}
+
+/// An issuer was approved to propose listings
+#[contractevent]
+#[derive(Clone)]
+pub struct IssuerAdded {
+    #[topic]
+    pub issuer: Address,
+    pub name: String,
+}
+
+/// An issuer was revoked
+#[contractevent]
+#[derive(Clone)]
+pub struct IssuerRevoked {
+    #[topic]
+    pub issuer: Address,
+}
+
+/// An issuer proposed listing an asset
+#[contractevent]
+#[derive(Clone)]
+pub struct ListingProposed {
+    #[topic]
+    pub token_address: Address,
+    #[topic]
+    pub issuer: Address,
+}
+
+/// A pending listing was approved and the asset registered
+#[contractevent]
+#[derive(Clone)]
+pub struct ListingApproved {
+    #[topic]
+    pub token_address: Address,
+    #[topic]
+    pub issuer: Address,
+}
+
+/// A pending listing was rejected
+#[contractevent]
+#[derive(Clone)]
+pub struct ListingRejected {
+    #[topic]
+    pub token_address: Address,
+}
+
+/// An issuer suspended or resumed trading in its asset
+#[contractevent]
+#[derive(Clone)]
+pub struct AssetSuspensionChanged {
+    #[topic]
+    pub token_address: Address,
+    pub suspended: bool,
+}
+
+/// A participant proposed moving their record to a new trading address
+#[contractevent]
+#[derive(Clone)]
+pub struct KeyRotationProposed {
+    #[topic]
+    pub trading_address: Address,
+    #[topic]
+    pub new_address: Address,
+}
+
+/// A participant's record moved to a new trading address
+#[contractevent]
+#[derive(Clone)]
+pub struct KeyRotated {
+    #[topic]
+    pub old_address: Address,
+    #[topic]
+    pub new_address: Address,
+}
+
+/// A tradable pair was listed
+#[contractevent]
+#[derive(Clone)]
+pub struct PairRegistered {
+    #[topic]
+    pub asset: Address,
+    #[topic]
+    pub payment_asset: Address,
+    pub tick_size: i128,
+    pub lot_size: i128,
+}
+
+/// A tradable pair's status changed
+#[contractevent]
+#[derive(Clone)]
+pub struct PairStatusChanged {
+    #[topic]
+    pub asset: Address,
+    #[topic]
+    pub payment_asset: Address,
+    pub status: PairStatus,
+}
+
+/// A participant's trading limits were set or replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct TradingLimitsSet {
+    #[topic]
+    pub trading_address: Address,
+}
+
+/// A participant's trading limits were removed
+#[contractevent]
+#[derive(Clone)]
+pub struct TradingLimitsRemoved {
+    #[topic]
+    pub trading_address: Address,
+}
+
+/// The contract code was replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgraded {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Stored data was migrated to a new schema version
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigrated {
+    #[topic]
+    pub version: u32,
+}
@@ -1,25 +1,85 @@
 #![no_std]
 
+use soroban_poseidon::Field;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec,
-    Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, crypto::BnScalar, symbol_short, token,
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val,
+    Vec, I256, U256,
 };
 
-use lean_imt_bn254::{LeanIMTBN254, TREE_DEPTH_KEY, TREE_LEAVES_KEY, TREE_ROOT_KEY};
+mod events;
+
+pub use events::*;
 
 #[cfg(test)]
 mod test;
 
+// Import the trees contract
+mod trees_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/darkpool_trees.wasm"
+    );
+}
+
 // Storage keys
 const ADMIN_KEY: Symbol = symbol_short!("admin");
 const VERIFIER_KEY: Symbol = symbol_short!("verifier");
 const ELIGIBILITY_VK_KEY: Symbol = symbol_short!("elig_vk");
 const PARTICIPANTS_KEY: Symbol = symbol_short!("parts");
 const ASSETS_KEY: Symbol = symbol_short!("assets");
+const POLICIES_KEY: Symbol = symbol_short!("policies");
+const METADATA_HISTORY_KEY: Symbol = symbol_short!("meta_hist");
+const PAIRS_KEY: Symbol = symbol_short!("pairs");
+const KYC_PROVIDERS_KEY: Symbol = symbol_short!("kyc_provs");
+const KYC_NONCES_KEY: Symbol = symbol_short!("kyc_nonce");
+const TREES_KEY: Symbol = symbol_short!("trees");
+const LIMITS_KEY: Symbol = symbol_short!("limits");
+const ASSET_INDEX_KEY: Symbol = symbol_short!("a_index");
+const CATEGORY_INDEX_KEY: Symbol = symbol_short!("c_index");
+const AGENTS_KEY: Symbol = symbol_short!("agents");
+const PENDING_ROTATIONS_KEY: Symbol = symbol_short!("rot_pend");
+const ROTATED_KEY: Symbol = symbol_short!("rotated");
+const ISSUERS_KEY: Symbol = symbol_short!("issuers");
+const LISTINGS_KEY: Symbol = symbol_short!("listings");
+const ASSET_ISSUERS_KEY: Symbol = symbol_short!("a_issuer");
+const SUSPENDED_KEY: Symbol = symbol_short!("a_susp");
+const SCHEMA_VERSION_KEY: Symbol = symbol_short!("schema");
+
+// Schema 2 kept each Merkle tree in instance entries under these keys, leaves first
+// (category trees under `(key, category)`); only the migration reads them
+const LEGACY_WHITELIST_TREE_KEYS: [Symbol; 5] = [
+    symbol_short!("leaves"),
+    symbol_short!("depth"),
+    symbol_short!("root"),
+    symbol_short!("root_hist"),
+    symbol_short!("root_ep"),
+];
+const LEGACY_ASSET_TREE_KEYS: [Symbol; 3] = [
+    symbol_short!("a_leaves"),
+    symbol_short!("a_depth"),
+    symbol_short!("a_root"),
+];
+const LEGACY_CATEGORY_TREE_KEYS: [Symbol; 3] = [
+    symbol_short!("c_leaves"),
+    symbol_short!("c_depth"),
+    symbol_short!("c_root"),
+];
+
+// Storage layout version written by this code; bump it with a new migration step
+const SCHEMA_VERSION: u32 = 3;
+
+// Numbers of the registry's trees in the trees contract; category trees follow
+// at `CATEGORY_TREE_BASE + category`
+pub const WHITELIST_TREE: u32 = 0;
+pub const ASSET_TREE: u32 = 1;
+pub const CATEGORY_TREE_BASE: u32 = 2;
 
 // Merkle tree depth for whitelist
 const WHITELIST_TREE_DEPTH: u32 = 20;
 
+// Merkle tree depth for the active asset list
+const ASSET_TREE_DEPTH: u32 = 10;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -33,10 +93,39 @@ pub enum RegistryError {
     InvalidKYCExpiry = 7,
     ParticipantNotActive = 8,
     AssetNotActive = 9,
+    PolicyNotFound = 10,
+    InvalidMaturityDate = 11,
+    PairAlreadyExists = 12,
+    PairNotFound = 13,
+    PairNotActive = 14,
+    InvalidPairParameters = 15,
+    InvalidLotSize = 16,
+    InvalidTickSize = 17,
+    TradeSizeOutOfRange = 18,
+    NotionalOutOfRange = 19,
+    KycProviderNotFound = 20,
+    KycProviderAlreadyExists = 21,
+    KycProviderRevoked = 22,
+    AttestationNonceUsed = 23,
+    InvalidIdHash = 24,
+    InvalidTradingLimits = 25,
+    TradingLimitsNotFound = 26,
+    UnsupportedSchemaVersion = 27,
+    InvalidAgentScope = 28,
+    AgentNotFound = 29,
+    KeyRotationNotFound = 30,
+    IssuerNotFound = 31,
+    IssuerAlreadyExists = 32,
+    IssuerRevoked = 33,
+    ListingNotFound = 34,
+    NotAssetIssuer = 35,
+    TreesAlreadySet = 36,
+    TreesNotSet = 37,
+    UnsupportedAddress = 38,
 }
 
 /// Participant category for institutional classification
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum ParticipantCategory {
@@ -51,7 +140,7 @@ pub enum ParticipantCategory {
 }
 
 /// RWA Asset type classification
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum AssetType {
@@ -74,6 +163,96 @@ pub struct Participant {
     pub kyc_expiry: u64,
     pub is_active: bool,
     pub tree_index: u32,
+    /// ISO 3166-1 alpha-2 country code (e.g. "US", "GB")
+    pub jurisdiction: Symbol,
+    pub is_qualified_investor: bool,
+}
+
+/// A whitelist root together with the epoch and time it became current
+#[derive(Clone)]
+#[contracttype]
+pub struct RootRecord {
+    pub root: BytesN<32>,
+    pub epoch: u32,
+    pub timestamp: u64,
+}
+
+/// Approved KYC provider whose attestations allow self-service onboarding
+#[derive(Clone)]
+#[contracttype]
+pub struct KycProvider {
+    pub public_key: BytesN<32>,
+    pub name: String,
+    pub is_active: bool,
+    pub added_at: u64,
+}
+
+/// KYC attestation signed by an approved provider
+///
+/// The provider signs the XDR encoding of `(registry_address, attestation)`
+/// with its ed25519 key. `provider_nonce` may only be used once per provider.
+#[derive(Clone)]
+#[contracttype]
+pub struct KycAttestation {
+    pub id_hash: BytesN<32>,
+    pub trading_address: Address,
+    pub category: ParticipantCategory,
+    pub kyc_expiry: u64,
+    pub jurisdiction: Symbol,
+    pub is_qualified_investor: bool,
+    pub provider_nonce: u64,
+}
+
+/// Trading pair status
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum PairStatus {
+    Active = 0,
+    Suspended = 1,
+    Delisted = 2,
+}
+
+/// Tradable pair of an RWA asset against a payment asset
+///
+/// Quantities are in RWA asset units and must be a multiple of `lot_size`.
+/// The trade price is the total amount of payment asset exchanged; it must be
+/// a multiple of `tick_size` and lie within `min_notional..=max_notional`.
+#[derive(Clone)]
+#[contracttype]
+pub struct TradingPair {
+    pub asset: Address,
+    pub payment_asset: Address,
+    pub tick_size: i128,
+    pub lot_size: i128,
+    pub min_notional: i128,
+    pub max_notional: i128,
+    pub status: PairStatus,
+}
+
+/// Descriptive and economic terms of an RWA asset
+#[derive(Clone)]
+#[contracttype]
+pub struct AssetMetadata {
+    pub isin: Option<String>,
+    pub cusip: Option<String>,
+    pub issuer: String,
+    pub decimals: u32,
+    /// Unix timestamp after which the asset can no longer be traded
+    pub maturity_date: Option<u64>,
+    /// Annual coupon rate in basis points
+    pub coupon_rate_bps: u32,
+    /// Reference/face value per unit, in the asset's smallest denomination
+    pub face_value: i128,
+}
+
+/// A recorded version of an asset's metadata
+#[derive(Clone)]
+#[contracttype]
+pub struct AssetMetadataRecord {
+    pub metadata: AssetMetadata,
+    pub updated_at: u64,
+    pub updated_by: Address,
 }
 
 /// Registered RWA asset
@@ -86,6 +265,68 @@ pub struct RWAAsset {
     pub min_trade_size: i128,
     pub max_order_size: i128,
     pub is_active: bool,
+    pub metadata: AssetMetadata,
+}
+
+/// Approved issuer allowed to propose listings for tokens it controls
+#[derive(Clone)]
+#[contracttype]
+pub struct Issuer {
+    pub address: Address,
+    pub name: String,
+    pub is_active: bool,
+    pub added_at: u64,
+}
+
+/// Asset listing submitted by an issuer, pending compliance approval
+#[derive(Clone)]
+#[contracttype]
+pub struct ListingProposal {
+    pub issuer: Address,
+    pub asset: RWAAsset,
+    pub proposed_at: u64,
+}
+
+/// Per-asset eligibility rules
+/// Empty allow-lists place no restriction on that dimension
+#[derive(Clone)]
+#[contracttype]
+pub struct AssetEligibilityPolicy {
+    pub allowed_categories: Vec<ParticipantCategory>,
+    pub allowed_jurisdictions: Vec<Symbol>,
+    pub blocked_jurisdictions: Vec<Symbol>,
+    pub requires_qualified_investor: bool,
+}
+
+/// Per-participant risk limits on trade notional (in payment asset units)
+/// `None` places no limit on that dimension
+#[derive(Clone)]
+#[contracttype]
+pub struct TradingLimits {
+    pub max_trade_notional: Option<i128>,
+    pub max_daily_notional: Option<i128>,
+    /// Daily notional limit per RWA asset
+    pub max_daily_asset_notional: Map<Address, i128>,
+}
+
+/// What a participant allows a trading agent to do on their behalf
+#[derive(Clone)]
+#[contracttype]
+pub struct AgentScope {
+    /// Assets the agent may place orders or manage escrow for; empty allows all
+    pub assets: Vec<Address>,
+    /// Largest escrow amount per operation, `None` for no cap
+    pub max_order_size: Option<i128>,
+    /// Timestamp from which the authorization no longer applies
+    pub expires_at: u64,
+}
+
+/// Key for an agent authorization
+#[derive(Clone)]
+#[contracttype(export = false)]
+pub struct AgentKey {
+    pub principal: Address,
+    pub agent: Address,
 }
 
 #[contract]
@@ -98,15 +339,18 @@ impl DarkPoolRegistry {
     /// # Arguments
     /// * `admin` - Admin address with management privileges
     /// * `verifier_address` - Address of the Groth16 verifier contract
+    /// * `trees_address` - Address of the trees contract holding the registry's Merkle trees
     /// * `eligibility_vk_bytes` - Serialized verification key for eligibility proofs
     pub fn __constructor(
         env: Env,
         admin: Address,
         verifier_address: Address,
+        trees_address: Address,
         eligibility_vk_bytes: Bytes,
     ) {
         // Store admin
         env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &SCHEMA_VERSION);
 
         // Store verifier address
         env.storage().instance().set(&VERIFIER_KEY, &verifier_address);
@@ -114,18 +358,17 @@ impl DarkPoolRegistry {
         // Store eligibility verification key
         env.storage().instance().set(&ELIGIBILITY_VK_KEY, &eligibility_vk_bytes);
 
-        // Initialize empty merkle tree for whitelist
-        let tree = LeanIMTBN254::new(&env, WHITELIST_TREE_DEPTH);
-        let (leaves, depth, root) = tree.to_storage();
-        env.storage().instance().set(&TREE_LEAVES_KEY, &leaves);
-        env.storage().instance().set(&TREE_DEPTH_KEY, &depth);
-        env.storage().instance().set(&TREE_ROOT_KEY, &root);
+        // Create the empty whitelist, asset and category trees
+        env.storage().instance().set(&TREES_KEY, &trees_address);
+        Self::create_trees(&env);
 
         // Initialize empty participants and assets lists
         let participants: Vec<Participant> = vec![&env];
         let assets: Vec<RWAAsset> = vec![&env];
         env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
         env.storage().instance().set(&ASSETS_KEY, &assets);
+
+        // Policies, pairs, limits and indexes start empty on first use
     }
 
     /// Register a new participant in the whitelist
@@ -144,35 +387,133 @@ impl DarkPoolRegistry {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        // Check participant doesn't already exist
-        let mut participants: Vec<Participant> = env
+        Self::add_participant(&env, participant)
+    }
+
+    /// Register yourself using an attestation signed by an approved KYC provider
+    ///
+    /// Aborts if the signature does not verify against `provider_key`.
+    ///
+    /// # Arguments
+    /// * `attestation` - Attested participant details (trading address must authenticate)
+    /// * `provider_key` - ed25519 public key of the issuing KYC provider
+    /// * `signature` - Provider signature over the attestation
+    ///
+    /// # Returns
+    /// * The tree index where the participant was added
+    pub fn register_with_attestation(
+        env: Env,
+        attestation: KycAttestation,
+        provider_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<u32, RegistryError> {
+        attestation.trading_address.require_auth();
+
+        let provider = Self::get_kyc_provider(env.clone(), provider_key.clone())
+            .ok_or(RegistryError::KycProviderNotFound)?;
+        if !provider.is_active {
+            return Err(RegistryError::KycProviderRevoked);
+        }
+
+        let mut nonces: Map<(BytesN<32>, u64), bool> = env
             .storage()
             .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env]);
+            .get(&KYC_NONCES_KEY)
+            .unwrap_or(Map::new(&env));
+        let nonce_key = (provider_key.clone(), attestation.provider_nonce);
+        if nonces.contains_key(nonce_key.clone()) {
+            return Err(RegistryError::AttestationNonceUsed);
+        }
 
-        for p in participants.iter() {
-            if p.trading_address == participant.trading_address {
-                return Err(RegistryError::ParticipantAlreadyExists);
-            }
+        let message = Self::attestation_message(&env, &attestation);
+        env.crypto().ed25519_verify(&provider_key, &message, &signature);
+
+        let participant = Participant {
+            id_hash: attestation.id_hash,
+            trading_address: attestation.trading_address,
+            category: attestation.category,
+            kyc_expiry: attestation.kyc_expiry,
+            is_active: true,
+            tree_index: 0,
+            jurisdiction: attestation.jurisdiction,
+            is_qualified_investor: attestation.is_qualified_investor,
+        };
+        let tree_index = Self::add_participant(&env, participant)?;
+
+        nonces.set(nonce_key, true);
+        env.storage().instance().set(&KYC_NONCES_KEY, &nonces);
+
+        Ok(tree_index)
+    }
+
+    /// Approve a KYC provider key for attestation-based onboarding
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `public_key` - Provider's ed25519 public key
+    /// * `name` - Human-readable provider name
+    pub fn add_kyc_provider(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        name: String,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut providers: Map<BytesN<32>, KycProvider> = env
+            .storage()
+            .instance()
+            .get(&KYC_PROVIDERS_KEY)
+            .unwrap_or(Map::new(&env));
+
+        if providers.contains_key(public_key.clone()) {
+            return Err(RegistryError::KycProviderAlreadyExists);
         }
 
-        // Validate KYC expiry is in the future
-        let current_time = env.ledger().timestamp();
-        if participant.kyc_expiry <= current_time {
-            return Err(RegistryError::InvalidKYCExpiry);
+        let provider = KycProvider {
+            public_key: public_key.clone(),
+            name,
+            is_active: true,
+            added_at: env.ledger().timestamp(),
+        };
+        providers.set(public_key.clone(), provider.clone());
+        env.storage().instance().set(&KYC_PROVIDERS_KEY, &providers);
+
+        KycProviderAdded {
+            public_key,
+            name: provider.name,
         }
+        .publish(&env);
+        Ok(())
+    }
 
-        // Add participant's id_hash to the Merkle tree
-        let tree_index = Self::add_to_whitelist_tree(&env, participant.id_hash.clone())?;
+    /// Revoke a KYC provider; its attestations are no longer accepted
+    ///
+    /// Participants already onboarded through the provider are not affected.
+    pub fn revoke_kyc_provider(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
 
-        // Store participant with tree index
-        let mut new_participant = participant.clone();
-        new_participant.tree_index = tree_index;
-        participants.push_back(new_participant);
-        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+        let mut providers: Map<BytesN<32>, KycProvider> = env
+            .storage()
+            .instance()
+            .get(&KYC_PROVIDERS_KEY)
+            .unwrap_or(Map::new(&env));
 
-        Ok(tree_index)
+        let mut provider = providers
+            .get(public_key.clone())
+            .ok_or(RegistryError::KycProviderNotFound)?;
+        provider.is_active = false;
+        providers.set(public_key.clone(), provider);
+        env.storage().instance().set(&KYC_PROVIDERS_KEY, &providers);
+
+        KycProviderRevoked { public_key }.publish(&env);
+        Ok(())
     }
 
     /// Deactivate a participant (soft delete)
@@ -188,31 +529,59 @@ impl DarkPoolRegistry {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let participants: Vec<Participant> = env
-            .storage()
-            .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env]);
+        let (index, participant) = Self::find_participant(&env, &trading_address)?;
 
-        let mut found = false;
-        let mut updated_participants: Vec<Participant> = vec![&env];
+        // Clear the leaf so the participant can no longer prove membership
+        if participant.is_active {
+            let empty_leaf = BytesN::from_array(&env, &[0u8; 32]);
+            Self::update_whitelist_leaf(&env, participant.tree_index, empty_leaf.clone())?;
+            Self::update_category_leaf(&env, &participant, empty_leaf)?;
+        }
 
-        for p in participants.iter() {
-            if p.trading_address == trading_address {
-                let mut updated = p.clone();
-                updated.is_active = false;
-                updated_participants.push_back(updated);
-                found = true;
-            } else {
-                updated_participants.push_back(p);
-            }
+        let mut updated = participant;
+        updated.is_active = false;
+        Self::save_participant(&env, index, &updated);
+
+        ParticipantDeactivated { trading_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Renew a participant's KYC, updating their whitelist leaf
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `trading_address` - Address of the participant to renew
+    /// * `kyc_expiry` - New KYC expiry timestamp (must be in the future)
+    pub fn renew_kyc(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+        kyc_expiry: u64,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if kyc_expiry <= env.ledger().timestamp() {
+            return Err(RegistryError::InvalidKYCExpiry);
         }
 
-        if !found {
-            return Err(RegistryError::ParticipantNotFound);
+        let (index, mut participant) = Self::find_participant(&env, &trading_address)?;
+        participant.kyc_expiry = kyc_expiry;
+        if !participant.is_active {
+            return Err(RegistryError::ParticipantNotActive);
         }
 
-        env.storage().instance().set(&PARTICIPANTS_KEY, &updated_participants);
+        let leaf = Self::whitelist_leaf(&env, &participant)?;
+        Self::update_whitelist_leaf(&env, participant.tree_index, leaf.clone())?;
+        Self::update_category_leaf(&env, &participant, leaf)?;
+
+        Self::save_participant(&env, index, &participant);
+
+        KycRenewed {
+            trading_address,
+            kyc_expiry,
+        }
+        .publish(&env);
         Ok(())
     }
 
@@ -228,167 +597,1090 @@ impl DarkPoolRegistry {
     ) -> Result<(), RegistryError> {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
-
-        let mut assets: Vec<RWAAsset> = env
-            .storage()
-            .instance()
-            .get(&ASSETS_KEY)
-            .unwrap_or(vec![&env]);
-
-        // Check asset doesn't already exist
-        for a in assets.iter() {
-            if a.token_address == asset.token_address {
-                return Err(RegistryError::AssetAlreadyExists);
-            }
-        }
-
-        assets.push_back(asset);
-        env.storage().instance().set(&ASSETS_KEY, &assets);
-        Ok(())
+        Self::add_asset(&env, asset, &admin)
     }
 
-    /// Deactivate an RWA asset
-    pub fn deactivate_asset(
+    /// Approve an issuer to propose asset listings
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `issuer` - Issuer's address
+    /// * `name` - Human-readable issuer name
+    pub fn add_issuer(
         env: Env,
         admin: Address,
-        token_address: Address,
+        issuer: Address,
+        name: String,
     ) -> Result<(), RegistryError> {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        let assets: Vec<RWAAsset> = env
+        let mut issuers: Map<Address, Issuer> = env
             .storage()
             .instance()
-            .get(&ASSETS_KEY)
-            .unwrap_or(vec![&env]);
-
-        let mut found = false;
-        let mut updated_assets: Vec<RWAAsset> = vec![&env];
+            .get(&ISSUERS_KEY)
+            .unwrap_or(Map::new(&env));
 
-        for a in assets.iter() {
-            if a.token_address == token_address {
-                let mut updated = a.clone();
-                updated.is_active = false;
-                updated_assets.push_back(updated);
-                found = true;
-            } else {
-                updated_assets.push_back(a);
-            }
+        if issuers.contains_key(issuer.clone()) {
+            return Err(RegistryError::IssuerAlreadyExists);
         }
 
-        if !found {
-            return Err(RegistryError::AssetNotFound);
-        }
+        let record = Issuer {
+            address: issuer.clone(),
+            name,
+            is_active: true,
+            added_at: env.ledger().timestamp(),
+        };
+        issuers.set(issuer.clone(), record.clone());
+        env.storage().instance().set(&ISSUERS_KEY, &issuers);
 
-        env.storage().instance().set(&ASSETS_KEY, &updated_assets);
+        IssuerAdded {
+            issuer,
+            name: record.name,
+        }
+        .publish(&env);
         Ok(())
     }
 
-    /// Get the current whitelist Merkle root
-    pub fn get_whitelist_root(env: Env) -> BytesN<32> {
-        env.storage()
-            .instance()
-            .get(&TREE_ROOT_KEY)
-            .unwrap_or(BytesN::from_array(&env, &[0u8; 32]))
-    }
+    /// Revoke an issuer; its listed assets stay listed
+    pub fn revoke_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
 
-    /// Get all registered participants
-    pub fn get_participants(env: Env) -> Vec<Participant> {
-        env.storage()
+        let mut issuers: Map<Address, Issuer> = env
+            .storage()
             .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env])
+            .get(&ISSUERS_KEY)
+            .unwrap_or(Map::new(&env));
+
+        let mut record = issuers
+            .get(issuer.clone())
+            .ok_or(RegistryError::IssuerNotFound)?;
+        record.is_active = false;
+        issuers.set(issuer.clone(), record);
+        env.storage().instance().set(&ISSUERS_KEY, &issuers);
+
+        IssuerRevoked { issuer }.publish(&env);
+        Ok(())
     }
 
-    /// Get active participants only
-    pub fn get_active_participants(env: Env) -> Vec<Participant> {
-        let participants: Vec<Participant> = env
-            .storage()
-            .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env]);
+    /// Propose listing an asset, pending approval with `approve_listing`
+    ///
+    /// If the token has an `admin()` function, as Stellar asset contracts do,
+    /// that admin must also authenticate. Other tokens rely on the compliance
+    /// review in `approve_listing`.
+    ///
+    /// # Arguments
+    /// * `issuer` - Active issuer proposing the listing (must authenticate)
+    /// * `asset` - Asset details to register once approved
+    pub fn propose_listing(env: Env, issuer: Address, asset: RWAAsset) -> Result<(), RegistryError> {
+        issuer.require_auth();
+        Self::require_issuer(&env, &issuer)?;
 
-        let mut active: Vec<Participant> = vec![&env];
-        for p in participants.iter() {
-            if p.is_active {
-                active.push_back(p);
-            }
+        let token_client = token::StellarAssetClient::new(&env, &asset.token_address);
+        if let Ok(Ok(token_admin)) = token_client.try_admin()
+            && token_admin != issuer
+        {
+            token_admin.require_auth();
         }
-        active
-    }
 
-    /// Get a specific participant by trading address
-    pub fn get_participant(env: Env, trading_address: Address) -> Option<Participant> {
-        let participants: Vec<Participant> = env
+        let mut listings: Map<Address, ListingProposal> = env
             .storage()
             .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env]);
+            .get(&LISTINGS_KEY)
+            .unwrap_or(Map::new(&env));
 
-        for p in participants.iter() {
-            if p.trading_address == trading_address {
-                return Some(p);
-            }
+        if listings.contains_key(asset.token_address.clone())
+            || Self::get_asset(env.clone(), asset.token_address.clone()).is_some()
+        {
+            return Err(RegistryError::AssetAlreadyExists);
         }
-        None
-    }
+        Self::validate_metadata(&env, &asset.metadata)?;
 
-    /// Check if a participant is eligible (active and KYC not expired)
-    pub fn is_participant_eligible(env: Env, trading_address: Address) -> bool {
-        if let Some(participant) = Self::get_participant(env.clone(), trading_address) {
-            let current_time = env.ledger().timestamp();
-            return participant.is_active && participant.kyc_expiry > current_time;
+        let token_address = asset.token_address.clone();
+        let proposal = ListingProposal {
+            issuer: issuer.clone(),
+            asset,
+            proposed_at: env.ledger().timestamp(),
+        };
+        listings.set(token_address.clone(), proposal);
+        env.storage().instance().set(&LISTINGS_KEY, &listings);
+
+        ListingProposed {
+            token_address,
+            issuer,
         }
-        false
+        .publish(&env);
+        Ok(())
     }
 
-    /// Get all registered assets
-    pub fn get_assets(env: Env) -> Vec<RWAAsset> {
-        env.storage()
+    /// Approve a pending listing, registering the asset for its issuer
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `token_address` - Token of the pending listing
+    pub fn approve_listing(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let proposal = Self::take_listing(&env, &token_address)?;
+        Self::add_asset(&env, proposal.asset, &proposal.issuer)?;
+
+        let mut asset_issuers: Map<Address, Address> = env
+            .storage()
             .instance()
-            .get(&ASSETS_KEY)
-            .unwrap_or(vec![&env])
-    }
+            .get(&ASSET_ISSUERS_KEY)
+            .unwrap_or(Map::new(&env));
+        asset_issuers.set(token_address.clone(), proposal.issuer.clone());
+        env.storage().instance().set(&ASSET_ISSUERS_KEY, &asset_issuers);
 
-    /// Get active assets only
+        ListingApproved {
+            token_address,
+            issuer: proposal.issuer,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Reject a pending listing
+    pub fn reject_listing(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        Self::take_listing(&env, &token_address)?;
+
+        ListingRejected { token_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Suspend or resume trading in an asset listed for the issuer
+    ///
+    /// A suspended asset is not eligible for trading but keeps its listing.
+    pub fn set_asset_suspended(
+        env: Env,
+        issuer: Address,
+        token_address: Address,
+        suspended: bool,
+    ) -> Result<(), RegistryError> {
+        issuer.require_auth();
+        Self::require_issuer(&env, &issuer)?;
+
+        if Self::get_asset_issuer(env.clone(), token_address.clone()) != Some(issuer) {
+            return Err(RegistryError::NotAssetIssuer);
+        }
+
+        let mut suspended_assets: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&SUSPENDED_KEY)
+            .unwrap_or(Map::new(&env));
+        if suspended {
+            suspended_assets.set(token_address.clone(), true);
+        } else {
+            suspended_assets.remove(token_address.clone());
+        }
+        env.storage().instance().set(&SUSPENDED_KEY, &suspended_assets);
+
+        // Suspended assets leave the asset tree until they are resumed
+        let (_, asset) = Self::find_asset(&env, &token_address)?;
+        Self::sync_asset_leaf(&env, &asset)?;
+
+        AssetSuspensionChanged {
+            token_address,
+            suspended,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Update the metadata of a registered asset
+    ///
+    /// The new version is appended to the asset's metadata history.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `token_address` - Asset to update
+    /// * `metadata` - Replacement metadata
+    pub fn update_asset_metadata(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        metadata: AssetMetadata,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let (index, mut asset) = Self::find_asset(&env, &token_address)?;
+
+        Self::validate_metadata(&env, &metadata)?;
+        let version = Self::record_metadata(&env, &token_address, &metadata, &admin);
+
+        asset.metadata = metadata;
+        Self::save_asset(&env, index, &asset);
+        Self::sync_asset_leaf(&env, &asset)?;
+
+        AssetMetadataUpdated {
+            token_address,
+            version,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Deactivate an RWA asset
+    pub fn deactivate_asset(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let (index, mut asset) = Self::find_asset(&env, &token_address)?;
+
+        // Clear the asset's leaf so it can no longer be proven eligible
+        asset.is_active = false;
+        Self::save_asset(&env, index, &asset);
+        Self::sync_asset_leaf(&env, &asset)?;
+
+        AssetDeactivated { token_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the eligibility policy for a registered asset
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `token_address` - Asset the policy applies to
+    /// * `policy` - Eligibility rules (replaces any existing policy)
+    pub fn set_asset_policy(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        policy: AssetEligibilityPolicy,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if Self::get_asset(env.clone(), token_address.clone()).is_none() {
+            return Err(RegistryError::AssetNotFound);
+        }
+
+        let mut policies: Map<Address, AssetEligibilityPolicy> = env
+            .storage()
+            .instance()
+            .get(&POLICIES_KEY)
+            .unwrap_or(Map::new(&env));
+        policies.set(token_address.clone(), policy);
+        env.storage().instance().set(&POLICIES_KEY, &policies);
+
+        AssetPolicySet { token_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove the eligibility policy for an asset (any eligible participant may trade it)
+    pub fn remove_asset_policy(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut policies: Map<Address, AssetEligibilityPolicy> = env
+            .storage()
+            .instance()
+            .get(&POLICIES_KEY)
+            .unwrap_or(Map::new(&env));
+
+        if !policies.contains_key(token_address.clone()) {
+            return Err(RegistryError::PolicyNotFound);
+        }
+
+        policies.remove(token_address.clone());
+        env.storage().instance().set(&POLICIES_KEY, &policies);
+
+        AssetPolicyRemoved { token_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the trading limits for a registered participant
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `trading_address` - Participant the limits apply to
+    /// * `limits` - Notional limits (replaces any existing limits)
+    pub fn set_trading_limits(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+        limits: TradingLimits,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if Self::get_participant(env.clone(), trading_address.clone()).is_none() {
+            return Err(RegistryError::ParticipantNotFound);
+        }
+
+        let negative = |limit: Option<i128>| limit.is_some_and(|l| l < 0);
+        if negative(limits.max_trade_notional)
+            || negative(limits.max_daily_notional)
+            || limits.max_daily_asset_notional.values().iter().any(|l| l < 0)
+        {
+            return Err(RegistryError::InvalidTradingLimits);
+        }
+
+        let mut all_limits: Map<Address, TradingLimits> = env
+            .storage()
+            .instance()
+            .get(&LIMITS_KEY)
+            .unwrap_or(Map::new(&env));
+        all_limits.set(trading_address.clone(), limits);
+        env.storage().instance().set(&LIMITS_KEY, &all_limits);
+
+        TradingLimitsSet { trading_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove the trading limits for a participant (trading becomes unlimited)
+    pub fn remove_trading_limits(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut all_limits: Map<Address, TradingLimits> = env
+            .storage()
+            .instance()
+            .get(&LIMITS_KEY)
+            .unwrap_or(Map::new(&env));
+
+        if !all_limits.contains_key(trading_address.clone()) {
+            return Err(RegistryError::TradingLimitsNotFound);
+        }
+
+        all_limits.remove(trading_address.clone());
+        env.storage().instance().set(&LIMITS_KEY, &all_limits);
+
+        TradingLimitsRemoved { trading_address }.publish(&env);
+        Ok(())
+    }
+
+    /// Authorize an agent (broker, desk trader) to act for a participant
+    ///
+    /// # Arguments
+    /// * `principal` - Active participant granting the authorization (must authenticate)
+    /// * `agent` - Address allowed to act on the principal's behalf
+    /// * `scope` - Assets, size cap and expiry (replaces any existing scope)
+    pub fn authorize_agent(
+        env: Env,
+        principal: Address,
+        agent: Address,
+        scope: AgentScope,
+    ) -> Result<(), RegistryError> {
+        principal.require_auth();
+
+        let participant = Self::get_participant(env.clone(), principal.clone())
+            .ok_or(RegistryError::ParticipantNotFound)?;
+        if !participant.is_active {
+            return Err(RegistryError::ParticipantNotActive);
+        }
+
+        if agent == principal
+            || scope.expires_at <= env.ledger().timestamp()
+            || scope.max_order_size.is_some_and(|max| max <= 0)
+        {
+            return Err(RegistryError::InvalidAgentScope);
+        }
+
+        let mut agents: Map<AgentKey, AgentScope> = env
+            .storage()
+            .instance()
+            .get(&AGENTS_KEY)
+            .unwrap_or(Map::new(&env));
+        let key = AgentKey {
+            principal: principal.clone(),
+            agent: agent.clone(),
+        };
+        agents.set(key, scope.clone());
+        env.storage().instance().set(&AGENTS_KEY, &agents);
+
+        AgentAuthorized {
+            principal,
+            agent,
+            expires_at: scope.expires_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Revoke an agent's authorization
+    ///
+    /// # Arguments
+    /// * `principal` - Participant that granted the authorization (must authenticate)
+    /// * `agent` - Agent to revoke
+    pub fn revoke_agent(env: Env, principal: Address, agent: Address) -> Result<(), RegistryError> {
+        principal.require_auth();
+
+        let mut agents: Map<AgentKey, AgentScope> = env
+            .storage()
+            .instance()
+            .get(&AGENTS_KEY)
+            .unwrap_or(Map::new(&env));
+        let key = AgentKey {
+            principal: principal.clone(),
+            agent: agent.clone(),
+        };
+        if !agents.contains_key(key.clone()) {
+            return Err(RegistryError::AgentNotFound);
+        }
+
+        agents.remove(key);
+        env.storage().instance().set(&AGENTS_KEY, &agents);
+
+        AgentRevoked { principal, agent }.publish(&env);
+        Ok(())
+    }
+
+    /// Propose moving a participant's record to a new trading address
+    ///
+    /// Both keys must authenticate. The move takes effect once approved with
+    /// `approve_key_rotation`; proposing again replaces the pending address.
+    ///
+    /// # Arguments
+    /// * `trading_address` - Participant's current address
+    /// * `new_address` - Address to move the participant to
+    pub fn propose_key_rotation(
+        env: Env,
+        trading_address: Address,
+        new_address: Address,
+    ) -> Result<(), RegistryError> {
+        trading_address.require_auth();
+        new_address.require_auth();
+
+        if Self::get_participant(env.clone(), trading_address.clone()).is_none() {
+            return Err(RegistryError::ParticipantNotFound);
+        }
+        Self::require_unused_address(&env, &new_address)?;
+
+        let mut pending: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&PENDING_ROTATIONS_KEY)
+            .unwrap_or(Map::new(&env));
+        pending.set(trading_address.clone(), new_address.clone());
+        env.storage().instance().set(&PENDING_ROTATIONS_KEY, &pending);
+
+        KeyRotationProposed {
+            trading_address,
+            new_address,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Approve a pending key rotation, moving the participant to the new address
+    ///
+    /// The participant keeps its `id_hash`, `tree_index`, whitelist leaves and
+    /// trading limits. Agents authorized by the old key are not carried over.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `trading_address` - Participant's current address
+    pub fn approve_key_rotation(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut pending: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&PENDING_ROTATIONS_KEY)
+            .unwrap_or(Map::new(&env));
+        let new_address = pending
+            .get(trading_address.clone())
+            .ok_or(RegistryError::KeyRotationNotFound)?;
+        Self::require_unused_address(&env, &new_address)?;
+
+        let (index, mut participant) = Self::find_participant(&env, &trading_address)?;
+        participant.trading_address = new_address.clone();
+        Self::save_participant(&env, index, &participant);
+
+        Self::move_entry::<u32>(&env, &CATEGORY_INDEX_KEY, &trading_address, &new_address);
+        Self::move_entry::<TradingLimits>(&env, &LIMITS_KEY, &trading_address, &new_address);
+
+        pending.remove(trading_address.clone());
+        env.storage().instance().set(&PENDING_ROTATIONS_KEY, &pending);
+
+        let mut rotated: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&ROTATED_KEY)
+            .unwrap_or(Map::new(&env));
+        rotated.set(trading_address.clone(), new_address.clone());
+        env.storage().instance().set(&ROTATED_KEY, &rotated);
+
+        KeyRotated {
+            old_address: trading_address,
+            new_address,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// List a tradable pair for a registered asset
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `pair` - Pair definition with tick/lot sizes and notional bounds
+    pub fn register_pair(env: Env, admin: Address, pair: TradingPair) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if Self::get_asset(env.clone(), pair.asset.clone()).is_none() {
+            return Err(RegistryError::AssetNotFound);
+        }
+
+        if pair.tick_size <= 0
+            || pair.lot_size <= 0
+            || pair.min_notional < 0
+            || pair.max_notional < pair.min_notional
+        {
+            return Err(RegistryError::InvalidPairParameters);
+        }
+
+        let mut pairs: Vec<TradingPair> = env
+            .storage()
+            .instance()
+            .get(&PAIRS_KEY)
+            .unwrap_or(vec![&env]);
+
+        for p in pairs.iter() {
+            if p.asset == pair.asset && p.payment_asset == pair.payment_asset {
+                return Err(RegistryError::PairAlreadyExists);
+            }
+        }
+
+        pairs.push_back(pair.clone());
+        env.storage().instance().set(&PAIRS_KEY, &pairs);
+
+        PairRegistered {
+            asset: pair.asset,
+            payment_asset: pair.payment_asset,
+            tick_size: pair.tick_size,
+            lot_size: pair.lot_size,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Change the status of a tradable pair
+    pub fn set_pair_status(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        payment_asset: Address,
+        status: PairStatus,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut pairs: Vec<TradingPair> = env
+            .storage()
+            .instance()
+            .get(&PAIRS_KEY)
+            .unwrap_or(vec![&env]);
+        let index = pairs
+            .iter()
+            .position(|p| p.asset == asset && p.payment_asset == payment_asset)
+            .ok_or(RegistryError::PairNotFound)? as u32;
+        let mut pair = pairs.get_unchecked(index);
+        pair.status = status;
+        pairs.set(index, pair);
+        env.storage().instance().set(&PAIRS_KEY, &pairs);
+
+        PairStatusChanged {
+            asset,
+            payment_asset,
+            status,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get the current whitelist Merkle root
+    pub fn get_whitelist_root(env: Env) -> BytesN<32> {
+        Self::trees(&env).get_root(&env.current_contract_address(), &WHITELIST_TREE)
+    }
+
+    /// Compute a whitelist leaf: `Poseidon(id_hash, kyc_expiry, category)`
+    ///
+    /// Uses the circomlib-compatible Poseidon over BN254, so provers can build
+    /// the same leaf in-circuit and compare `kyc_expiry` against a timestamp.
+    pub fn compute_whitelist_leaf(
+        env: Env,
+        id_hash: BytesN<32>,
+        kyc_expiry: u64,
+        category: ParticipantCategory,
+    ) -> Result<BytesN<32>, RegistryError> {
+        let id = U256::from_be_bytes(&env, &Bytes::from(id_hash));
+        if id >= BnScalar::modulus(&env) {
+            return Err(RegistryError::InvalidIdHash);
+        }
+
+        let hash = poseidon_bn254::poseidon3(
+            &env,
+            id,
+            U256::from_u128(&env, kyc_expiry as u128),
+            U256::from_u32(&env, category as u32),
+        );
+
+        let mut leaf = [0u8; 32];
+        hash.to_be_bytes().copy_into_slice(&mut leaf);
+        Ok(BytesN::from_array(&env, &leaf))
+    }
+
+    /// Compute an asset's `assetHash`: its strkey as a field element
+    ///
+    /// `strkey` is the token address's strkey bytes read as a big-endian
+    /// integer reduced into the BN254 scalar field. Order commitments and
+    /// settlement proofs carry this value.
+    ///
+    /// Only account and contract addresses, whose strkeys are 56 characters,
+    /// can be listed; other address kinds fail with `UnsupportedAddress`.
+    pub fn compute_asset_hash(
+        env: Env,
+        token_address: Address,
+    ) -> Result<BytesN<32>, RegistryError> {
+        let mut hash = [0u8; 32];
+        Self::asset_hash(&env, &token_address)?
+            .to_be_bytes()
+            .copy_into_slice(&mut hash);
+        Ok(BytesN::from_array(&env, &hash))
+    }
+
+    /// Compute an asset tree leaf: `Poseidon(assetHash)`
+    ///
+    /// See `compute_asset_hash` for `assetHash`.
+    pub fn compute_asset_leaf(
+        env: Env,
+        token_address: Address,
+    ) -> Result<BytesN<32>, RegistryError> {
+        let hash = poseidon_bn254::poseidon1(&env, Self::asset_hash(&env, &token_address)?);
+        let mut leaf = [0u8; 32];
+        hash.to_be_bytes().copy_into_slice(&mut leaf);
+        Ok(BytesN::from_array(&env, &leaf))
+    }
+
+    /// Get the current Merkle root of the tradable asset list
+    pub fn get_asset_root(env: Env) -> BytesN<32> {
+        Self::trees(&env).get_root(&env.current_contract_address(), &ASSET_TREE)
+    }
+
+    /// Check if an asset root is current, or was replaced no more than `max_age` seconds ago
+    pub fn is_asset_root_fresh(env: Env, root: BytesN<32>, max_age: u64) -> bool {
+        Self::trees(&env).is_root_fresh(
+            &env.current_contract_address(),
+            &ASSET_TREE,
+            &root,
+            &max_age,
+        )
+    }
+
+    /// Bring an asset's tree leaf in line with whether it can trade
+    ///
+    /// Maturity takes effect without a transaction, so anyone may call this
+    /// once an asset matures to clear its leaf from the asset tree.
+    pub fn refresh_asset_leaf(env: Env, token_address: Address) -> Result<(), RegistryError> {
+        let (_, asset) = Self::find_asset(&env, &token_address)?;
+        Self::sync_asset_leaf(&env, &asset)
+    }
+
+    /// Get an asset's leaf index in the asset tree
+    pub fn get_asset_tree_index(env: Env, token_address: Address) -> Option<u32> {
+        let asset_index: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&ASSET_INDEX_KEY)
+            .unwrap_or(Map::new(&env));
+        asset_index.get(token_address)
+    }
+
+    /// Check if a root is the current whitelist root or still in the root history
+    pub fn is_known_root(env: Env, root: BytesN<32>) -> bool {
+        Self::trees(&env).is_known_root(&env.current_contract_address(), &WHITELIST_TREE, &root)
+    }
+
+    /// Get the whitelist root recorded for an epoch, if still in the root history
+    pub fn get_root_at(env: Env, epoch: u32) -> Option<RootRecord> {
+        let record = Self::trees(&env).get_root_at(
+            &env.current_contract_address(),
+            &WHITELIST_TREE,
+            &epoch,
+        )?;
+        Some(RootRecord {
+            root: record.root,
+            epoch: record.epoch,
+            timestamp: record.timestamp,
+        })
+    }
+
+    /// Check if a whitelist root is current, or was replaced no more than `max_age` seconds ago
+    pub fn is_root_fresh(env: Env, root: BytesN<32>, max_age: u64) -> bool {
+        Self::trees(&env).is_root_fresh(
+            &env.current_contract_address(),
+            &WHITELIST_TREE,
+            &root,
+            &max_age,
+        )
+    }
+
+    /// Get a participant's leaf index in their category's whitelist tree
+    ///
+    /// The category tree holds the same leaves as the global whitelist,
+    /// restricted to participants of that category, so a proof can show group
+    /// membership. It is tree `CATEGORY_TREE_BASE + category` in the trees contract.
+    pub fn get_category_tree_index(env: Env, trading_address: Address) -> Option<u32> {
+        let category_index: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&CATEGORY_INDEX_KEY)
+            .unwrap_or(Map::new(&env));
+        category_index.get(trading_address)
+    }
+
+    /// Get the trees contract holding the registry's Merkle trees
+    ///
+    /// The registry owns tree `WHITELIST_TREE` (0), `ASSET_TREE` (1) and one
+    /// tree per participant category from `CATEGORY_TREE_BASE` (2). Leaves,
+    /// epochs and root history are read from there.
+    pub fn get_trees(env: Env) -> Address {
+        env.storage().instance().get(&TREES_KEY).unwrap()
+    }
+
+    /// Get all registered participants
+    pub fn get_participants(env: Env) -> Vec<Participant> {
+        env.storage()
+            .instance()
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Get active participants only
+    pub fn get_active_participants(env: Env) -> Vec<Participant> {
+        let participants: Vec<Participant> = env
+            .storage()
+            .instance()
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![&env]);
+
+        let mut active: Vec<Participant> = vec![&env];
+        for p in participants.iter() {
+            if p.is_active {
+                active.push_back(p);
+            }
+        }
+        active
+    }
+
+    /// Get a specific participant by trading address
+    pub fn get_participant(env: Env, trading_address: Address) -> Option<Participant> {
+        let participants: Vec<Participant> = env
+            .storage()
+            .instance()
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![&env]);
+
+        participants
+            .iter()
+            .find(|p| p.trading_address == trading_address)
+    }
+
+    /// Check if a participant is eligible (active and KYC not expired)
+    pub fn is_participant_eligible(env: Env, trading_address: Address) -> bool {
+        if let Some(participant) = Self::get_participant(env.clone(), trading_address) {
+            let current_time = env.ledger().timestamp();
+            return participant.is_active && participant.kyc_expiry > current_time;
+        }
+        false
+    }
+
+    /// Get all registered assets
+    pub fn get_assets(env: Env) -> Vec<RWAAsset> {
+        env.storage()
+            .instance()
+            .get(&ASSETS_KEY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Get active assets only
     pub fn get_active_assets(env: Env) -> Vec<RWAAsset> {
         let assets: Vec<RWAAsset> = env
             .storage()
             .instance()
-            .get(&ASSETS_KEY)
-            .unwrap_or(vec![&env]);
-
-        let mut active: Vec<RWAAsset> = vec![&env];
-        for a in assets.iter() {
-            if a.is_active {
-                active.push_back(a);
-            }
-        }
-        active
+            .get(&ASSETS_KEY)
+            .unwrap_or(vec![&env]);
+
+        let mut active: Vec<RWAAsset> = vec![&env];
+        for a in assets.iter() {
+            if Self::is_tradable(&env, &a) {
+                active.push_back(a);
+            }
+        }
+        active
+    }
+
+    /// Get a specific asset by token address
+    pub fn get_asset(env: Env, token_address: Address) -> Option<RWAAsset> {
+        let assets: Vec<RWAAsset> = env
+            .storage()
+            .instance()
+            .get(&ASSETS_KEY)
+            .unwrap_or(vec![&env]);
+
+        assets.iter().find(|a| a.token_address == token_address)
+    }
+
+    /// Check if an asset is eligible for trading (active, not suspended and not past maturity)
+    pub fn is_asset_eligible(env: Env, token_address: Address) -> bool {
+        if let Some(asset) = Self::get_asset(env.clone(), token_address) {
+            return Self::is_tradable(&env, &asset);
+        }
+        false
+    }
+
+    /// Get the issuer an asset was listed for, if it was listed by proposal
+    pub fn get_asset_issuer(env: Env, token_address: Address) -> Option<Address> {
+        let asset_issuers: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&ASSET_ISSUERS_KEY)
+            .unwrap_or(Map::new(&env));
+        asset_issuers.get(token_address)
+    }
+
+    /// Get an issuer by address
+    pub fn get_issuer(env: Env, issuer: Address) -> Option<Issuer> {
+        let issuers: Map<Address, Issuer> = env
+            .storage()
+            .instance()
+            .get(&ISSUERS_KEY)
+            .unwrap_or(Map::new(&env));
+        issuers.get(issuer)
+    }
+
+    /// Get all listings awaiting approval
+    pub fn get_listing_proposals(env: Env) -> Vec<ListingProposal> {
+        let listings: Map<Address, ListingProposal> = env
+            .storage()
+            .instance()
+            .get(&LISTINGS_KEY)
+            .unwrap_or(Map::new(&env));
+        listings.values()
+    }
+
+    /// Get every recorded version of an asset's metadata, oldest first
+    pub fn get_asset_metadata_history(
+        env: Env,
+        token_address: Address,
+    ) -> Vec<AssetMetadataRecord> {
+        let history: Map<Address, Vec<AssetMetadataRecord>> = env
+            .storage()
+            .instance()
+            .get(&METADATA_HISTORY_KEY)
+            .unwrap_or(Map::new(&env));
+        history.get(token_address).unwrap_or(vec![&env])
+    }
+
+    /// Get a KYC provider by public key
+    pub fn get_kyc_provider(env: Env, public_key: BytesN<32>) -> Option<KycProvider> {
+        let providers: Map<BytesN<32>, KycProvider> = env
+            .storage()
+            .instance()
+            .get(&KYC_PROVIDERS_KEY)
+            .unwrap_or(Map::new(&env));
+        providers.get(public_key)
+    }
+
+    /// Get all KYC providers, including revoked ones
+    pub fn get_kyc_providers(env: Env) -> Vec<KycProvider> {
+        let providers: Map<BytesN<32>, KycProvider> = env
+            .storage()
+            .instance()
+            .get(&KYC_PROVIDERS_KEY)
+            .unwrap_or(Map::new(&env));
+        providers.values()
+    }
+
+    /// Get all tradable pairs
+    pub fn get_pairs(env: Env) -> Vec<TradingPair> {
+        env.storage()
+            .instance()
+            .get(&PAIRS_KEY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Get a specific tradable pair
+    pub fn get_pair(env: Env, asset: Address, payment_asset: Address) -> Option<TradingPair> {
+        let pairs: Vec<TradingPair> = env
+            .storage()
+            .instance()
+            .get(&PAIRS_KEY)
+            .unwrap_or(vec![&env]);
+
+        pairs
+            .iter()
+            .find(|p| p.asset == asset && p.payment_asset == payment_asset)
+    }
+
+    /// Check a trade against its pair's constraints and the asset's size limits
+    ///
+    /// # Arguments
+    /// * `asset` - The RWA token being traded
+    /// * `payment_asset` - The payment token
+    /// * `quantity` - Amount of RWA tokens
+    /// * `price` - Total price in payment tokens
+    pub fn validate_trade(
+        env: Env,
+        asset: Address,
+        payment_asset: Address,
+        quantity: i128,
+        price: i128,
+    ) -> Result<(), RegistryError> {
+        let pair = Self::get_pair(env.clone(), asset.clone(), payment_asset)
+            .ok_or(RegistryError::PairNotFound)?;
+        if pair.status != PairStatus::Active {
+            return Err(RegistryError::PairNotActive);
+        }
+
+        let rwa_asset = Self::get_asset(env.clone(), asset).ok_or(RegistryError::AssetNotFound)?;
+        if quantity < rwa_asset.min_trade_size || quantity > rwa_asset.max_order_size {
+            return Err(RegistryError::TradeSizeOutOfRange);
+        }
+        if !Self::is_multiple(&env, quantity, pair.lot_size) {
+            return Err(RegistryError::InvalidLotSize);
+        }
+
+        if !Self::is_multiple(&env, price, pair.tick_size) {
+            return Err(RegistryError::InvalidTickSize);
+        }
+        if price < pair.min_notional || price > pair.max_notional {
+            return Err(RegistryError::NotionalOutOfRange);
+        }
+
+        Ok(())
+    }
+
+    /// Get the eligibility policy for an asset, if any
+    pub fn get_asset_policy(env: Env, token_address: Address) -> Option<AssetEligibilityPolicy> {
+        let policies: Map<Address, AssetEligibilityPolicy> = env
+            .storage()
+            .instance()
+            .get(&POLICIES_KEY)
+            .unwrap_or(Map::new(&env));
+        policies.get(token_address)
+    }
+
+    /// Get the trading limits for a participant, if any
+    pub fn get_trading_limits(env: Env, trading_address: Address) -> Option<TradingLimits> {
+        let all_limits: Map<Address, TradingLimits> = env
+            .storage()
+            .instance()
+            .get(&LIMITS_KEY)
+            .unwrap_or(Map::new(&env));
+        all_limits.get(trading_address)
+    }
+
+    /// Get the scope an agent was granted by a participant
+    pub fn get_agent_scope(env: Env, principal: Address, agent: Address) -> Option<AgentScope> {
+        let agents: Map<AgentKey, AgentScope> = env
+            .storage()
+            .instance()
+            .get(&AGENTS_KEY)
+            .unwrap_or(Map::new(&env));
+        agents.get(AgentKey { principal, agent })
     }
 
-    /// Get a specific asset by token address
-    pub fn get_asset(env: Env, token_address: Address) -> Option<RWAAsset> {
-        let assets: Vec<RWAAsset> = env
+    /// Get the address a retired trading address was rotated to, if any
+    pub fn get_rotated_address(env: Env, old_address: Address) -> Option<Address> {
+        let rotated: Map<Address, Address> = env
             .storage()
             .instance()
-            .get(&ASSETS_KEY)
-            .unwrap_or(vec![&env]);
+            .get(&ROTATED_KEY)
+            .unwrap_or(Map::new(&env));
+        rotated.get(old_address)
+    }
 
-        for a in assets.iter() {
-            if a.token_address == token_address {
-                return Some(a);
-            }
-        }
-        None
+    /// Check if an agent may act for a participant on an asset
+    ///
+    /// The authorization must be unexpired, cover `asset` and allow `amount`
+    /// (pass 0 when no amount is involved, e.g. hidden order sizes).
+    pub fn is_agent_authorized(
+        env: Env,
+        principal: Address,
+        agent: Address,
+        asset: Address,
+        amount: i128,
+    ) -> bool {
+        let scope = match Self::get_agent_scope(env.clone(), principal, agent) {
+            Some(scope) => scope,
+            None => return false,
+        };
+
+        scope.expires_at > env.ledger().timestamp()
+            && (scope.assets.is_empty() || scope.assets.contains(&asset))
+            && scope.max_order_size.is_none_or(|max| amount <= max)
     }
 
-    /// Check if an asset is eligible for trading
-    pub fn is_asset_eligible(env: Env, token_address: Address) -> bool {
-        if let Some(asset) = Self::get_asset(env, token_address) {
-            return asset.is_active;
+    /// Check if a participant may trade a specific asset
+    ///
+    /// Requires the participant and the asset to be eligible on their own,
+    /// and the participant to satisfy the asset's policy if one is set.
+    pub fn is_eligible_for(env: Env, trading_address: Address, asset: Address) -> bool {
+        if !Self::is_asset_eligible(env.clone(), asset.clone()) {
+            return false;
         }
-        false
+        if !Self::is_participant_eligible(env.clone(), trading_address.clone()) {
+            return false;
+        }
+
+        let policy = match Self::get_asset_policy(env.clone(), asset) {
+            Some(policy) => policy,
+            None => return true,
+        };
+        let participant = Self::get_participant(env, trading_address).unwrap();
+
+        if !policy.allowed_categories.is_empty()
+            && !policy.allowed_categories.contains(participant.category)
+        {
+            return false;
+        }
+        if !policy.allowed_jurisdictions.is_empty()
+            && !policy.allowed_jurisdictions.contains(&participant.jurisdiction)
+        {
+            return false;
+        }
+        if policy.blocked_jurisdictions.contains(&participant.jurisdiction) {
+            return false;
+        }
+        if policy.requires_qualified_investor && !participant.is_qualified_investor {
+            return false;
+        }
+        true
     }
 
     /// Get the admin address
@@ -403,20 +1695,91 @@ impl DarkPoolRegistry {
 
     /// Get the whitelist tree depth
     pub fn get_whitelist_depth(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&TREE_DEPTH_KEY)
-            .unwrap_or(WHITELIST_TREE_DEPTH)
+        Self::trees(&env).get_depth(&env.current_contract_address(), &WHITELIST_TREE)
     }
 
     /// Get the number of participants in the whitelist tree
     pub fn get_whitelist_count(env: Env) -> u32 {
-        let leaves: Vec<BytesN<32>> = env
-            .storage()
-            .instance()
-            .get(&TREE_LEAVES_KEY)
-            .unwrap_or(vec![&env]);
-        leaves.len() as u32
+        Self::trees(&env).get_leaf_count(&env.current_contract_address(), &WHITELIST_TREE)
+    }
+
+    /// Upgrade the contract code, keeping its storage
+    ///
+    /// Call `migrate` afterwards when the new code expects a newer storage schema.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm to switch to
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded {
+            wasm_hash: new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Point a registry deployed before schema 3 at its trees contract
+    ///
+    /// Schema 3 keeps the Merkle trees in a separate trees contract. Registries
+    /// upgraded from an earlier schema must be given one before calling
+    /// `migrate`; the address cannot be changed once set.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `trees_address` - Address of the trees contract
+    pub fn set_trees(env: Env, admin: Address, trees_address: Address) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if env.storage().instance().has(&TREES_KEY) {
+            return Err(RegistryError::TreesAlreadySet);
+        }
+        env.storage().instance().set(&TREES_KEY, &trees_address);
+        Ok(())
+    }
+
+    /// Convert storage from the stored schema version to `SCHEMA_VERSION`
+    ///
+    /// Runs one migration step per version, recording progress after each.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    ///
+    /// # Returns
+    /// * The schema version after migrating
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut version = Self::get_schema_version(env.clone());
+        if version > SCHEMA_VERSION {
+            return Err(RegistryError::UnsupportedSchemaVersion);
+        }
+
+        while version < SCHEMA_VERSION {
+            Self::migrate_step(&env, version)?;
+            version += 1;
+            env.storage().instance().set(&SCHEMA_VERSION_KEY, &version);
+            SchemaMigrated { version }.publish(&env);
+        }
+        Ok(version)
+    }
+
+    /// Get the schema version of the stored data
+    ///
+    /// Registries deployed before schema versioning have no stored version and
+    /// hold the schema 1 layout.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1)
     }
 
     // Internal helper functions
@@ -430,38 +1793,529 @@ impl DarkPoolRegistry {
         Ok(())
     }
 
-    /// Add an ID hash to the whitelist Merkle tree
-    fn add_to_whitelist_tree(env: &Env, id_hash: BytesN<32>) -> Result<u32, RegistryError> {
-        // Load current tree state
-        let leaves: Vec<BytesN<32>> = env
+    /// Convert storage from schema `from_version` to `from_version + 1`
+    ///
+    /// Version 1 is the original layout; each bump of `SCHEMA_VERSION` adds the
+    /// step converting from the previous version here.
+    fn migrate_step(env: &Env, from_version: u32) -> Result<(), RegistryError> {
+        match from_version {
+            // 1 -> 2: add the fields introduced since schema 1 and rebuild the
+            // trees with leaves committing to KYC expiry and category
+            1 => {
+                Self::migrate_participants(env);
+                Self::migrate_assets(env);
+                Ok(())
+            }
+            // 2 -> 3: move the trees into the trees contract
+            2 => Self::migrate_trees(env),
+            _ => Err(RegistryError::UnsupportedSchemaVersion),
+        }
+    }
+
+    /// Give schema 1 participants a jurisdiction and investor status and rebuild
+    /// the whitelist and category trees
+    ///
+    /// Records are decoded as field maps so the added fields can be filled in.
+    /// The jurisdiction is left empty and investor status false, so policies that
+    /// restrict either exclude migrated participants until they re-register.
+    /// Schema 1 leaves were the raw `id_hash`; one outside the field cannot be
+    /// committed to and its participant is deactivated.
+    fn migrate_participants(env: &Env) {
+        let legacy: Vec<Map<Symbol, Val>> = env
             .storage()
             .instance()
-            .get(&TREE_LEAVES_KEY)
-            .unwrap_or(vec![&env]);
-        let depth: u32 = env
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![env]);
+        let empty_leaf = BytesN::from_array(env, &[0u8; 32]);
+
+        let mut participants: Vec<Participant> = vec![env];
+        let mut leaves: Vec<BytesN<32>> = vec![env];
+        let mut category_leaves: Map<ParticipantCategory, Vec<BytesN<32>>> = Map::new(env);
+        let mut category_index: Map<Address, u32> = Map::new(env);
+        for mut fields in legacy.iter() {
+            fields.set(Symbol::new(env, "jurisdiction"), symbol_short!("").to_val());
+            fields.set(Symbol::new(env, "is_qualified_investor"), false.into());
+            let mut participant = Participant::try_from_val(env, &fields.to_val()).unwrap();
+            participant.tree_index = leaves.len();
+
+            let leaf = match Self::whitelist_leaf(env, &participant) {
+                Ok(leaf) if participant.is_active => leaf,
+                Ok(_) => empty_leaf.clone(),
+                Err(_) => {
+                    participant.is_active = false;
+                    empty_leaf.clone()
+                }
+            };
+            leaves.push_back(leaf.clone());
+
+            let mut category = category_leaves.get(participant.category).unwrap_or(vec![env]);
+            category_index.set(participant.trading_address.clone(), category.len());
+            category.push_back(leaf);
+            category_leaves.set(participant.category, category);
+
+            participants.push_back(participant);
+        }
+
+        // Only the schema 2 leaves are written: the 2 -> 3 step, which always
+        // runs in the same call, builds the trees from them
+        let [leaves_key, ..] = LEGACY_WHITELIST_TREE_KEYS;
+        env.storage().instance().set(&leaves_key, &leaves);
+        let [category_leaves_key, ..] = LEGACY_CATEGORY_TREE_KEYS;
+        for (category, leaves) in category_leaves.iter() {
+            env.storage()
+                .instance()
+                .set(&(category_leaves_key.clone(), category), &leaves);
+        }
+        env.storage().instance().set(&CATEGORY_INDEX_KEY, &category_index);
+        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+    }
+
+    /// Give schema 1 assets metadata and build the asset tree
+    ///
+    /// Schema 1 kept no metadata, so it starts empty for the admin to fill in
+    /// with `update_asset_metadata`.
+    fn migrate_assets(env: &Env) {
+        let legacy: Vec<Map<Symbol, Val>> = env
+            .storage()
+            .instance()
+            .get(&ASSETS_KEY)
+            .unwrap_or(vec![env]);
+        let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
+        let metadata = AssetMetadata {
+            isin: None,
+            cusip: None,
+            issuer: String::from_str(env, ""),
+            decimals: 0,
+            maturity_date: None,
+            coupon_rate_bps: 0,
+            face_value: 0,
+        };
+
+        let empty_leaf = BytesN::from_array(env, &[0u8; 32]);
+        let mut assets: Vec<RWAAsset> = vec![env];
+        let mut leaves: Vec<BytesN<32>> = vec![env];
+        let mut asset_index: Map<Address, u32> = Map::new(env);
+        for mut fields in legacy.iter() {
+            fields.set(symbol_short!("metadata"), metadata.into_val(env));
+            let asset = RWAAsset::try_from_val(env, &fields.to_val()).unwrap();
+            Self::record_metadata(env, &asset.token_address, &metadata, &admin);
+
+            asset_index.set(asset.token_address.clone(), leaves.len());
+            if asset.is_active {
+                let leaf = Self::compute_asset_leaf(env.clone(), asset.token_address.clone());
+                leaves.push_back(leaf.unwrap_or(empty_leaf.clone()));
+            } else {
+                leaves.push_back(empty_leaf.clone());
+            }
+            assets.push_back(asset);
+        }
+
+        // Assets were never listed in a tree before; the first schema 2 tree holds them all
+        let [leaves_key, ..] = LEGACY_ASSET_TREE_KEYS;
+        env.storage().instance().set(&leaves_key, &leaves);
+        env.storage().instance().set(&ASSET_INDEX_KEY, &asset_index);
+        env.storage().instance().set(&ASSETS_KEY, &assets);
+    }
+
+    /// Move the schema 2 trees into the trees contract
+    ///
+    /// Each tree is rebuilt there from its leaves, so its root is unchanged.
+    /// The root history restarts with the current root.
+    fn migrate_trees(env: &Env) -> Result<(), RegistryError> {
+        if !env.storage().instance().has(&TREES_KEY) {
+            return Err(RegistryError::TreesNotSet);
+        }
+        Self::create_trees(env);
+
+        Self::move_legacy_tree(env, WHITELIST_TREE, &LEGACY_WHITELIST_TREE_KEYS.map(|k| k.to_val()));
+        Self::move_legacy_tree(env, ASSET_TREE, &LEGACY_ASSET_TREE_KEYS.map(|k| k.to_val()));
+        for category in 0..=ParticipantCategory::Other as u32 {
+            // Enum keys are stored as their discriminant
+            let keys = LEGACY_CATEGORY_TREE_KEYS.map(|k| (k, category).into_val(env));
+            Self::move_legacy_tree(env, CATEGORY_TREE_BASE + category, &keys);
+        }
+        Ok(())
+    }
+
+    /// Append the leaves stored under `keys[0]` to `tree`, then remove all of `keys`
+    fn move_legacy_tree(env: &Env, tree: u32, keys: &[Val]) {
+        let leaves: Vec<BytesN<32>> = env.storage().instance().get(&keys[0]).unwrap_or(vec![env]);
+        if !leaves.is_empty() {
+            Self::trees(env).append(&env.current_contract_address(), &tree, &leaves);
+        }
+        for key in keys {
+            env.storage().instance().remove(key);
+        }
+    }
+
+    /// Find a participant's position and record in the participant list
+    fn find_participant(
+        env: &Env,
+        trading_address: &Address,
+    ) -> Result<(u32, Participant), RegistryError> {
+        let participants = Self::get_participants(env.clone());
+        let index = participants
+            .iter()
+            .position(|p| p.trading_address == *trading_address)
+            .ok_or(RegistryError::ParticipantNotFound)? as u32;
+        Ok((index, participants.get_unchecked(index)))
+    }
+
+    /// Replace the participant record at `index` in the participant list
+    fn save_participant(env: &Env, index: u32, participant: &Participant) {
+        let mut participants = Self::get_participants(env.clone());
+        participants.set(index, participant.clone());
+        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+    }
+
+    /// Find an asset's position and record in the asset list
+    fn find_asset(env: &Env, token_address: &Address) -> Result<(u32, RWAAsset), RegistryError> {
+        let assets = Self::get_assets(env.clone());
+        let index = assets
+            .iter()
+            .position(|a| a.token_address == *token_address)
+            .ok_or(RegistryError::AssetNotFound)? as u32;
+        Ok((index, assets.get_unchecked(index)))
+    }
+
+    /// Replace the asset record at `index` in the asset list
+    fn save_asset(env: &Env, index: u32, asset: &RWAAsset) {
+        let mut assets = Self::get_assets(env.clone());
+        assets.set(index, asset.clone());
+        env.storage().instance().set(&ASSETS_KEY, &assets);
+    }
+
+    /// Add a participant to the whitelist tree and participant list
+    fn add_participant(env: &Env, participant: Participant) -> Result<u32, RegistryError> {
+        // Check participant doesn't already exist
+        let mut participants: Vec<Participant> = env
+            .storage()
+            .instance()
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![env]);
+
+        for p in participants.iter() {
+            if p.trading_address == participant.trading_address {
+                return Err(RegistryError::ParticipantAlreadyExists);
+            }
+        }
+        if Self::get_rotated_address(env.clone(), participant.trading_address.clone()).is_some() {
+            return Err(RegistryError::ParticipantAlreadyExists);
+        }
+
+        // Validate KYC expiry is in the future
+        let current_time = env.ledger().timestamp();
+        if participant.kyc_expiry <= current_time {
+            return Err(RegistryError::InvalidKYCExpiry);
+        }
+
+        // Add participant's leaf to the Merkle tree
+        let leaf = Self::whitelist_leaf(env, &participant)?;
+        let tree_index = Self::append_leaf(env, WHITELIST_TREE, leaf.clone())?;
+
+        // Add the same leaf to the participant's category tree
+        let category_tree_index =
+            Self::append_leaf(env, Self::category_tree(participant.category), leaf)?;
+        let mut category_index: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&CATEGORY_INDEX_KEY)
+            .unwrap_or(Map::new(env));
+        category_index.set(participant.trading_address.clone(), category_tree_index);
+        env.storage().instance().set(&CATEGORY_INDEX_KEY, &category_index);
+
+        // Store participant with tree index
+        let mut new_participant = participant.clone();
+        new_participant.tree_index = tree_index;
+        participants.push_back(new_participant);
+        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+
+        ParticipantRegistered {
+            trading_address: participant.trading_address,
+            tree_index,
+            category: participant.category,
+            kyc_expiry: participant.kyc_expiry,
+        }
+        .publish(env);
+
+        Ok(tree_index)
+    }
+
+    /// Reject addresses held by a participant or retired by a key rotation
+    ///
+    /// Retired addresses stay unusable so that settlement and the orderbook can
+    /// keep forwarding anything left under them to the rotated address.
+    fn require_unused_address(env: &Env, address: &Address) -> Result<(), RegistryError> {
+        if Self::get_participant(env.clone(), address.clone()).is_some()
+            || Self::get_rotated_address(env.clone(), address.clone()).is_some()
+        {
+            return Err(RegistryError::ParticipantAlreadyExists);
+        }
+        Ok(())
+    }
+
+    /// Move a value in an address-keyed map from one address to another
+    fn move_entry<V>(env: &Env, map_key: &Symbol, from: &Address, to: &Address)
+    where
+        V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let mut map: Map<Address, V> = env
+            .storage()
+            .instance()
+            .get(map_key)
+            .unwrap_or(Map::new(env));
+        if let Some(value) = map.get(from.clone()) {
+            map.remove(from.clone());
+            map.set(to.clone(), value);
+            env.storage().instance().set(map_key, &map);
+        }
+    }
+
+    /// Verify the caller is an active issuer
+    fn require_issuer(env: &Env, issuer: &Address) -> Result<(), RegistryError> {
+        let record = Self::get_issuer(env.clone(), issuer.clone())
+            .ok_or(RegistryError::IssuerNotFound)?;
+        if !record.is_active {
+            return Err(RegistryError::IssuerRevoked);
+        }
+        Ok(())
+    }
+
+    /// Remove and return a pending listing
+    fn take_listing(env: &Env, token_address: &Address) -> Result<ListingProposal, RegistryError> {
+        let mut listings: Map<Address, ListingProposal> = env
+            .storage()
+            .instance()
+            .get(&LISTINGS_KEY)
+            .unwrap_or(Map::new(env));
+        let proposal = listings
+            .get(token_address.clone())
+            .ok_or(RegistryError::ListingNotFound)?;
+        listings.remove(token_address.clone());
+        env.storage().instance().set(&LISTINGS_KEY, &listings);
+        Ok(proposal)
+    }
+
+    /// Add an asset to the asset list and asset tree
+    fn add_asset(env: &Env, asset: RWAAsset, listed_by: &Address) -> Result<(), RegistryError> {
+        let mut assets: Vec<RWAAsset> = env
+            .storage()
+            .instance()
+            .get(&ASSETS_KEY)
+            .unwrap_or(vec![env]);
+
+        // Check asset doesn't already exist
+        for a in assets.iter() {
+            if a.token_address == asset.token_address {
+                return Err(RegistryError::AssetAlreadyExists);
+            }
+        }
+
+        Self::validate_metadata(env, &asset.metadata)?;
+        Self::record_metadata(env, &asset.token_address, &asset.metadata, listed_by);
+
+        // Assets that cannot trade get an empty slot in the asset tree
+        let leaf = if Self::is_tradable(env, &asset) {
+            Self::compute_asset_leaf(env.clone(), asset.token_address.clone())?
+        } else {
+            BytesN::from_array(env, &[0u8; 32])
+        };
+        let tree_index = Self::append_leaf(env, ASSET_TREE, leaf)?;
+        let mut asset_index: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&ASSET_INDEX_KEY)
+            .unwrap_or(Map::new(env));
+        asset_index.set(asset.token_address.clone(), tree_index);
+        env.storage().instance().set(&ASSET_INDEX_KEY, &asset_index);
+
+        assets.push_back(asset.clone());
+        env.storage().instance().set(&ASSETS_KEY, &assets);
+
+        AssetListed {
+            token_address: asset.token_address,
+            symbol: asset.symbol,
+            asset_type: asset.asset_type,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Check whether `value` is a multiple of a positive `step`
+    ///
+    /// The remainder is taken by the host, which keeps the 128-bit division
+    /// routine out of the contract's wasm.
+    fn is_multiple(env: &Env, value: i128, step: i128) -> bool {
+        let zero = I256::from_i32(env, 0);
+        I256::from_i128(env, value).rem_euclid(&I256::from_i128(env, step)) == zero
+    }
+
+    /// Bytes a KYC provider signs for an attestation, bound to this registry
+    fn attestation_message(env: &Env, attestation: &KycAttestation) -> Bytes {
+        (env.current_contract_address(), attestation.clone()).to_xdr(env)
+    }
+
+    /// Check whether an asset can trade: active, not matured and not suspended
+    fn is_tradable(env: &Env, asset: &RWAAsset) -> bool {
+        asset.is_active
+            && !Self::is_matured(env, asset)
+            && !Self::is_suspended(env, &asset.token_address)
+    }
+
+    fn is_suspended(env: &Env, token_address: &Address) -> bool {
+        let suspended_assets: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&TREE_DEPTH_KEY)
-            .unwrap_or(WHITELIST_TREE_DEPTH);
-        let root: BytesN<32> = env
+            .get(&SUSPENDED_KEY)
+            .unwrap_or(Map::new(env));
+        suspended_assets.contains_key(token_address.clone())
+    }
+
+    /// Check whether an asset has reached its maturity date
+    fn is_matured(env: &Env, asset: &RWAAsset) -> bool {
+        match asset.metadata.maturity_date {
+            Some(maturity) => maturity <= env.ledger().timestamp(),
+            None => false,
+        }
+    }
+
+    /// Reject metadata whose maturity date has already passed
+    fn validate_metadata(env: &Env, metadata: &AssetMetadata) -> Result<(), RegistryError> {
+        if let Some(maturity) = metadata.maturity_date
+            && maturity <= env.ledger().timestamp()
+        {
+            return Err(RegistryError::InvalidMaturityDate);
+        }
+        Ok(())
+    }
+
+    /// Append a metadata version to an asset's history
+    fn record_metadata(
+        env: &Env,
+        token_address: &Address,
+        metadata: &AssetMetadata,
+        updated_by: &Address,
+    ) -> u32 {
+        let mut history: Map<Address, Vec<AssetMetadataRecord>> = env
             .storage()
             .instance()
-            .get(&TREE_ROOT_KEY)
-            .unwrap_or(BytesN::from_array(&env, &[0u8; 32]));
+            .get(&METADATA_HISTORY_KEY)
+            .unwrap_or(Map::new(env));
+
+        let mut records = history.get(token_address.clone()).unwrap_or(vec![env]);
+        records.push_back(AssetMetadataRecord {
+            metadata: metadata.clone(),
+            updated_at: env.ledger().timestamp(),
+            updated_by: updated_by.clone(),
+        });
+        let version = records.len() - 1;
+        history.set(token_address.clone(), records);
+        env.storage().instance().set(&METADATA_HISTORY_KEY, &history);
+        version
+    }
+
+    /// Whitelist leaf committing to a participant's identity, KYC expiry and category
+    fn whitelist_leaf(env: &Env, participant: &Participant) -> Result<BytesN<32>, RegistryError> {
+        Self::compute_whitelist_leaf(
+            env.clone(),
+            participant.id_hash.clone(),
+            participant.kyc_expiry,
+            participant.category,
+        )
+    }
+
+    /// The token address's strkey reduced into the BN254 scalar field
+    fn asset_hash(env: &Env, token_address: &Address) -> Result<U256, RegistryError> {
+        let strkey = token_address.to_string();
+        if strkey.len() != 56 {
+            return Err(RegistryError::UnsupportedAddress);
+        }
+        let mut buf = [0u8; 56];
+        strkey.copy_into_slice(&mut buf);
+
+        // Horner's rule two bits at a time keeps the accumulator below 2^256
+        let modulus = BnScalar::modulus(env);
+        let four = U256::from_u32(env, 4);
+        let mut value = U256::from_u32(env, 0);
+        for byte in buf {
+            for shift in [6, 4, 2, 0] {
+                let bits = U256::from_u32(env, ((byte >> shift) & 3) as u32);
+                value = value.mul(&four).add(&bits).rem_euclid(&modulus);
+            }
+        }
+        Ok(value)
+    }
+
+    /// Client for the trees contract holding the registry's Merkle trees
+    fn trees(env: &Env) -> trees_wasm::Client<'_> {
+        let trees_address: Address = env.storage().instance().get(&TREES_KEY).unwrap();
+        trees_wasm::Client::new(env, &trees_address)
+    }
+
+    /// Create the empty whitelist, asset and category trees
+    fn create_trees(env: &Env) {
+        let trees = Self::trees(env);
+        let owner = env.current_contract_address();
+        trees.create(&owner, &WHITELIST_TREE, &WHITELIST_TREE_DEPTH);
+        trees.create(&owner, &ASSET_TREE, &ASSET_TREE_DEPTH);
+        for category in 0..=ParticipantCategory::Other as u32 {
+            trees.create(&owner, &(CATEGORY_TREE_BASE + category), &WHITELIST_TREE_DEPTH);
+        }
+    }
+
+    /// Number of a participant category's tree
+    fn category_tree(category: ParticipantCategory) -> u32 {
+        CATEGORY_TREE_BASE + category as u32
+    }
 
-        // Create tree and insert
-        let mut tree = LeanIMTBN254::from_storage(env, leaves, depth, root);
-        tree.insert(id_hash).map_err(|_| RegistryError::TreeAtCapacity)?;
+    /// Append a leaf to one of the registry's trees, returning its index
+    fn append_leaf(env: &Env, tree: u32, leaf: BytesN<32>) -> Result<u32, RegistryError> {
+        match Self::trees(env).try_append(&env.current_contract_address(), &tree, &vec![env, leaf]) {
+            Ok(Ok(index)) => Ok(index),
+            _ => Err(RegistryError::TreeAtCapacity),
+        }
+    }
+
+    /// Set an asset's leaf to `Poseidon(strkey)` while it can trade, or clear it
+    fn sync_asset_leaf(env: &Env, asset: &RWAAsset) -> Result<(), RegistryError> {
+        let index = Self::get_asset_tree_index(env.clone(), asset.token_address.clone())
+            .ok_or(RegistryError::AssetNotFound)?;
+        let leaf = if Self::is_tradable(env, asset) {
+            Self::compute_asset_leaf(env.clone(), asset.token_address.clone())?
+        } else {
+            BytesN::from_array(env, &[0u8; 32])
+        };
+        if !Self::update_leaf(env, ASSET_TREE, index, leaf) {
+            return Err(RegistryError::AssetNotFound);
+        }
+        Ok(())
+    }
 
-        // Get the leaf index
-        let leaf_index = tree.get_leaf_count() - 1;
+    /// Replace a leaf in one of the registry's trees, returning whether the index exists
+    fn update_leaf(env: &Env, tree: u32, index: u32, leaf: BytesN<32>) -> bool {
+        let owner = env.current_contract_address();
+        matches!(Self::trees(env).try_update(&owner, &tree, &index, &leaf), Ok(Ok(())))
+    }
 
-        // Store updated tree state
-        let (new_leaves, new_depth, new_root) = tree.to_storage();
-        env.storage().instance().set(&TREE_LEAVES_KEY, &new_leaves);
-        env.storage().instance().set(&TREE_DEPTH_KEY, &new_depth);
-        env.storage().instance().set(&TREE_ROOT_KEY, &new_root);
+    /// Replace the leaf at `index` in the whitelist Merkle tree
+    fn update_whitelist_leaf(env: &Env, index: u32, leaf: BytesN<32>) -> Result<(), RegistryError> {
+        if !Self::update_leaf(env, WHITELIST_TREE, index, leaf) {
+            return Err(RegistryError::ParticipantNotFound);
+        }
+        Ok(())
+    }
 
-        Ok(leaf_index)
+    /// Replace a participant's leaf in their category's Merkle tree
+    fn update_category_leaf(
+        env: &Env,
+        participant: &Participant,
+        leaf: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        let index = Self::get_category_tree_index(env.clone(), participant.trading_address.clone())
+            .ok_or(RegistryError::ParticipantNotFound)?;
+        if !Self::update_leaf(env, Self::category_tree(participant.category), index, leaf) {
+            return Err(RegistryError::ParticipantNotFound);
+        }
+        Ok(())
     }
 }
@@ -1,7 +1,35 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env, Symbol};
+use ed25519_dalek::{Signer, SigningKey};
+use lean_imt_bn254::LeanIMTBN254;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    Bytes, BytesN, Env, Event, String, Symbol,
+};
+
+extern crate std;
+
+/// Token without an `admin()` function, unlike Stellar asset contracts
+#[contract]
+pub struct PlainToken;
+
+#[contractimpl]
+impl PlainToken {
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+}
+
+fn create_trees(env: &Env) -> Address {
+    env.register(trees_wasm::WASM, ())
+}
+
+/// Client for the trees contract holding the registry's trees
+fn trees_client<'a>(env: &'a Env, client: &DarkPoolRegistryClient) -> trees_wasm::Client<'a> {
+    trees_wasm::Client::new(env, &client.get_trees())
+}
 
 fn create_test_participant(env: &Env) -> Participant {
     Participant {
@@ -11,6 +39,45 @@ fn create_test_participant(env: &Env) -> Participant {
         kyc_expiry: env.ledger().timestamp() + 31536000, // 1 year from now
         is_active: true,
         tree_index: 0,
+        jurisdiction: Symbol::new(env, "US"),
+        is_qualified_investor: true,
+    }
+}
+
+fn create_test_attestation(env: &Env, id: u8, nonce: u64) -> KycAttestation {
+    KycAttestation {
+        id_hash: BytesN::from_array(env, &[id; 32]),
+        trading_address: Address::generate(env),
+        category: ParticipantCategory::AssetManager,
+        kyc_expiry: env.ledger().timestamp() + 31536000,
+        jurisdiction: Symbol::new(env, "DE"),
+        is_qualified_investor: true,
+        provider_nonce: nonce,
+    }
+}
+
+fn sign_attestation(
+    env: &Env,
+    contract_id: &Address,
+    signing_key: &SigningKey,
+    attestation: &KycAttestation,
+) -> BytesN<64> {
+    let message = env.as_contract(contract_id, || {
+        DarkPoolRegistry::attestation_message(env, attestation)
+    });
+    let message: std::vec::Vec<u8> = message.iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn create_test_metadata(env: &Env) -> AssetMetadata {
+    AssetMetadata {
+        isin: Some(String::from_str(env, "US912828XG55")),
+        cusip: Some(String::from_str(env, "912828XG5")),
+        issuer: String::from_str(env, "US Treasury"),
+        decimals: 7,
+        maturity_date: Some(env.ledger().timestamp() + 2 * 31536000),
+        coupon_rate_bps: 425,
+        face_value: 10_000_000_000,
     }
 }
 
@@ -22,6 +89,7 @@ fn create_test_asset(env: &Env) -> RWAAsset {
         min_trade_size: 1_000_000,
         max_order_size: 100_000_000_000,
         is_active: true,
+        metadata: create_test_metadata(env),
     }
 }
 
@@ -34,7 +102,7 @@ fn test_constructor() {
     let verifier = Address::generate(&env);
     let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
 
-    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
     let client = DarkPoolRegistryClient::new(&env, &contract_id);
 
     assert_eq!(client.get_admin(), admin);
@@ -51,7 +119,7 @@ fn test_register_participant() {
     let verifier = Address::generate(&env);
     let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
 
-    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
     let client = DarkPoolRegistryClient::new(&env, &contract_id);
 
     let participant = create_test_participant(&env);
@@ -74,7 +142,7 @@ fn test_register_asset() {
     let verifier = Address::generate(&env);
     let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
 
-    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
     let client = DarkPoolRegistryClient::new(&env, &contract_id);
 
     let asset = create_test_asset(&env);
@@ -94,7 +162,7 @@ fn test_deactivate_participant() {
     let verifier = Address::generate(&env);
     let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
 
-    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
     let client = DarkPoolRegistryClient::new(&env, &contract_id);
 
     let participant = create_test_participant(&env);
@@ -116,7 +184,7 @@ fn test_whitelist_root_changes() {
     let verifier = Address::generate(&env);
     let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
 
-    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
     let client = DarkPoolRegistryClient::new(&env, &contract_id);
 
     let initial_root = client.get_whitelist_root();
@@ -127,3 +195,1246 @@ fn test_whitelist_root_changes() {
     let new_root = client.get_whitelist_root();
     assert_ne!(initial_root, new_root);
 }
+
+#[test]
+fn test_asset_policy_restricts_eligibility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+
+    // No policy: any eligible participant may trade
+    assert!(client.is_eligible_for(&participant.trading_address, &asset.token_address));
+
+    // Restrict to banks only
+    let policy = AssetEligibilityPolicy {
+        allowed_categories: vec![&env, ParticipantCategory::Bank],
+        allowed_jurisdictions: vec![&env],
+        blocked_jurisdictions: vec![&env],
+        requires_qualified_investor: false,
+    };
+    client.set_asset_policy(&admin, &asset.token_address, &policy);
+    assert!(!client.is_eligible_for(&participant.trading_address, &asset.token_address));
+
+    // Allow broker-dealers but block the participant's jurisdiction
+    let policy = AssetEligibilityPolicy {
+        allowed_categories: vec![&env, ParticipantCategory::Bank, ParticipantCategory::BrokerDealer],
+        allowed_jurisdictions: vec![&env],
+        blocked_jurisdictions: vec![&env, Symbol::new(&env, "US")],
+        requires_qualified_investor: false,
+    };
+    client.set_asset_policy(&admin, &asset.token_address, &policy);
+    assert!(!client.is_eligible_for(&participant.trading_address, &asset.token_address));
+
+    // Restrict to another jurisdiction
+    let policy = AssetEligibilityPolicy {
+        allowed_categories: vec![&env],
+        allowed_jurisdictions: vec![&env, Symbol::new(&env, "GB")],
+        blocked_jurisdictions: vec![&env],
+        requires_qualified_investor: false,
+    };
+    client.set_asset_policy(&admin, &asset.token_address, &policy);
+    assert!(!client.is_eligible_for(&participant.trading_address, &asset.token_address));
+
+    client.remove_asset_policy(&admin, &asset.token_address);
+    assert!(client.get_asset_policy(&asset.token_address).is_none());
+    assert!(client.is_eligible_for(&participant.trading_address, &asset.token_address));
+}
+
+#[test]
+fn test_asset_policy_requires_qualified_investor() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+
+    let mut participant = create_test_participant(&env);
+    participant.is_qualified_investor = false;
+    client.register_participant(&admin, &participant);
+
+    let policy = AssetEligibilityPolicy {
+        allowed_categories: vec![&env],
+        allowed_jurisdictions: vec![&env, Symbol::new(&env, "US")],
+        blocked_jurisdictions: vec![&env],
+        requires_qualified_investor: true,
+    };
+    client.set_asset_policy(&admin, &asset.token_address, &policy);
+
+    assert!(client.is_participant_eligible(&participant.trading_address));
+    assert!(!client.is_eligible_for(&participant.trading_address, &asset.token_address));
+
+    // Unregistered assets cannot carry a policy
+    let unknown = Address::generate(&env);
+    let result = client.try_set_asset_policy(&admin, &unknown, &policy);
+    assert_eq!(result, Err(Ok(RegistryError::AssetNotFound)));
+}
+
+#[test]
+fn test_asset_ineligible_after_maturity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+
+    assert!(client.is_asset_eligible(&asset.token_address));
+    assert_eq!(client.get_active_assets().len(), 1);
+
+    let maturity = asset.metadata.maturity_date.unwrap();
+    env.ledger().set_timestamp(maturity);
+
+    assert!(!client.is_asset_eligible(&asset.token_address));
+    assert_eq!(client.get_active_assets().len(), 0);
+    // Matured assets remain listed
+    assert_eq!(client.get_assets().len(), 1);
+
+    // Cannot register an asset that has already matured
+    let mut matured = create_test_asset(&env);
+    matured.metadata.maturity_date = Some(maturity - 1);
+    let result = client.try_register_asset(&admin, &matured);
+    assert_eq!(result, Err(Ok(RegistryError::InvalidMaturityDate)));
+}
+
+#[test]
+fn test_update_asset_metadata_records_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+
+    env.ledger().set_timestamp(1000);
+    let mut metadata = asset.metadata.clone();
+    metadata.coupon_rate_bps = 450;
+    metadata.maturity_date = None;
+    client.update_asset_metadata(&admin, &asset.token_address, &metadata);
+
+    let stored = client.get_asset(&asset.token_address).unwrap();
+    assert_eq!(stored.metadata.coupon_rate_bps, 450);
+    assert_eq!(stored.metadata.maturity_date, None);
+
+    let history = client.get_asset_metadata_history(&asset.token_address);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().metadata.coupon_rate_bps, 425);
+    assert_eq!(history.get(1).unwrap().updated_at, 1000);
+    assert_eq!(history.get(1).unwrap().updated_by, admin);
+}
+
+#[test]
+fn test_trading_pair_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+
+    let payment_asset = Address::generate(&env);
+    let pair = TradingPair {
+        asset: asset.token_address.clone(),
+        payment_asset: payment_asset.clone(),
+        tick_size: 100,
+        lot_size: 1_000_000,
+        min_notional: 10_000,
+        max_notional: 1_000_000_000,
+        status: PairStatus::Active,
+    };
+    client.register_pair(&admin, &pair);
+    assert_eq!(
+        client.try_register_pair(&admin, &pair),
+        Err(Ok(RegistryError::PairAlreadyExists))
+    );
+
+    let token = asset.token_address.clone();
+    client.validate_trade(&token, &payment_asset, &2_000_000, &50_000);
+
+    let other_payment = Address::generate(&env);
+    assert_eq!(
+        client.try_validate_trade(&token, &other_payment, &2_000_000, &50_000),
+        Err(Ok(RegistryError::PairNotFound))
+    );
+    assert_eq!(
+        client.try_validate_trade(&token, &payment_asset, &2_500_000, &50_000),
+        Err(Ok(RegistryError::InvalidLotSize))
+    );
+    assert_eq!(
+        client.try_validate_trade(&token, &payment_asset, &2_000_000, &50_050),
+        Err(Ok(RegistryError::InvalidTickSize))
+    );
+    assert_eq!(
+        client.try_validate_trade(&token, &payment_asset, &2_000_000, &2_000_000_000),
+        Err(Ok(RegistryError::NotionalOutOfRange))
+    );
+    assert_eq!(
+        client.try_validate_trade(&token, &payment_asset, &200_000_000_000, &50_000),
+        Err(Ok(RegistryError::TradeSizeOutOfRange))
+    );
+
+    client.set_pair_status(&admin, &token, &payment_asset, &PairStatus::Suspended);
+    assert_eq!(
+        client.try_validate_trade(&token, &payment_asset, &2_000_000, &50_000),
+        Err(Ok(RegistryError::PairNotActive))
+    );
+}
+
+#[test]
+fn test_register_with_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let provider_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+    client.add_kyc_provider(&admin, &provider_key, &String::from_str(&env, "Acme KYC"));
+
+    let attestation = create_test_attestation(&env, 1, 1);
+    let signature = sign_attestation(&env, &contract_id, &signing_key, &attestation);
+    let tree_index = client.register_with_attestation(&attestation, &provider_key, &signature);
+
+    assert_eq!(tree_index, 0);
+    assert_eq!(client.get_whitelist_count(), 1);
+    let participant = client.get_participant(&attestation.trading_address).unwrap();
+    assert_eq!(participant.category, ParticipantCategory::AssetManager);
+    assert_eq!(participant.jurisdiction, Symbol::new(&env, "DE"));
+    assert!(client.is_participant_eligible(&attestation.trading_address));
+
+    // The same provider nonce cannot be used twice
+    let replay = create_test_attestation(&env, 2, 1);
+    let signature = sign_attestation(&env, &contract_id, &signing_key, &replay);
+    let result = client.try_register_with_attestation(&replay, &provider_key, &signature);
+    assert_eq!(result, Err(Ok(RegistryError::AttestationNonceUsed)));
+}
+
+#[test]
+fn test_revoked_kyc_provider_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let provider_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+
+    // Unknown provider
+    let attestation = create_test_attestation(&env, 1, 1);
+    let signature = sign_attestation(&env, &contract_id, &signing_key, &attestation);
+    let result = client.try_register_with_attestation(&attestation, &provider_key, &signature);
+    assert_eq!(result, Err(Ok(RegistryError::KycProviderNotFound)));
+
+    client.add_kyc_provider(&admin, &provider_key, &String::from_str(&env, "Acme KYC"));
+    client.revoke_kyc_provider(&admin, &provider_key);
+    assert!(!client.get_kyc_provider(&provider_key).unwrap().is_active);
+
+    let result = client.try_register_with_attestation(&attestation, &provider_key, &signature);
+    assert_eq!(result, Err(Ok(RegistryError::KycProviderRevoked)));
+    assert_eq!(client.get_whitelist_count(), 0);
+}
+
+#[test]
+fn test_attestation_with_invalid_signature_aborts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let provider_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+    client.add_kyc_provider(&admin, &provider_key, &String::from_str(&env, "Acme KYC"));
+
+    // Signed by a key that is not the provider's
+    let attestation = create_test_attestation(&env, 1, 1);
+    let other_key = SigningKey::from_bytes(&[8u8; 32]);
+    let signature = sign_attestation(&env, &contract_id, &other_key, &attestation);
+
+    let result = client.try_register_with_attestation(&attestation, &provider_key, &signature);
+    assert!(result.is_err());
+    assert_eq!(client.get_whitelist_count(), 0);
+}
+
+#[test]
+fn test_whitelist_leaf_commits_to_expiry_and_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let trees = trees_client(&env, &client);
+    let leaf_at = |index| trees.get_leaves(&contract_id, &WHITELIST_TREE).get(index).unwrap();
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+
+    let expected = client.compute_whitelist_leaf(
+        &participant.id_hash,
+        &participant.kyc_expiry,
+        &participant.category,
+    );
+    assert_eq!(leaf_at(0), expected);
+    assert_ne!(expected, participant.id_hash);
+
+    // Renewal replaces the leaf and changes the root
+    let root_before = client.get_whitelist_root();
+    let new_expiry = participant.kyc_expiry + 31536000;
+    client.renew_kyc(&admin, &participant.trading_address, &new_expiry);
+
+    let renewed = client.compute_whitelist_leaf(&participant.id_hash, &new_expiry, &participant.category);
+    assert_eq!(leaf_at(0), renewed);
+    assert_eq!(client.get_whitelist_count(), 1);
+    assert_ne!(client.get_whitelist_root(), root_before);
+    assert_eq!(client.get_participant(&participant.trading_address).unwrap().kyc_expiry, new_expiry);
+
+    // Deactivation clears the leaf
+    client.deactivate_participant(&admin, &participant.trading_address);
+    assert_eq!(leaf_at(0), BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(
+        client.try_renew_kyc(&admin, &participant.trading_address, &new_expiry),
+        Err(Ok(RegistryError::ParticipantNotActive))
+    );
+}
+
+#[test]
+fn test_id_hash_outside_field_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let mut participant = create_test_participant(&env);
+    participant.id_hash = BytesN::from_array(&env, &[0xffu8; 32]);
+
+    let result = client.try_register_participant(&admin, &participant);
+    assert_eq!(result, Err(Ok(RegistryError::InvalidIdHash)));
+    assert_eq!(client.get_whitelist_count(), 0);
+}
+
+#[test]
+fn test_root_history_tracks_epochs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let trees = trees_client(&env, &client);
+
+    let initial_root = client.get_whitelist_root();
+    assert_eq!(trees.get_epoch(&contract_id, &WHITELIST_TREE), 0);
+    assert_eq!(trees.get_root_at(&contract_id, &WHITELIST_TREE, &0).unwrap().root, initial_root);
+
+    env.ledger().set_timestamp(1_000);
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+
+    let new_root = client.get_whitelist_root();
+    assert_eq!(trees.get_epoch(&contract_id, &WHITELIST_TREE), 1);
+    let record = trees.get_root_at(&contract_id, &WHITELIST_TREE, &1).unwrap();
+    assert_eq!(record.root, new_root);
+    assert_eq!(record.timestamp, 1_000);
+    assert!(trees.get_root_at(&contract_id, &WHITELIST_TREE, &2).is_none());
+    assert_eq!(client.get_root_at(&0).unwrap().root, initial_root);
+    let forwarded = client.get_root_at(&1).unwrap();
+    assert_eq!(forwarded.root, new_root);
+    assert_eq!(forwarded.epoch, 1);
+    assert_eq!(forwarded.timestamp, 1_000);
+    assert!(client.get_root_at(&2).is_none());
+
+    // Both roots are known; the replaced one is only fresh within the window
+    assert!(client.is_known_root(&initial_root));
+    assert!(client.is_known_root(&new_root));
+    assert!(!client.is_known_root(&BytesN::from_array(&env, &[7u8; 32])));
+
+    env.ledger().set_timestamp(1_500);
+    assert!(client.is_root_fresh(&initial_root, &600));
+    assert!(!client.is_root_fresh(&initial_root, &300));
+    assert!(client.is_root_fresh(&new_root, &0));
+}
+
+#[test]
+fn test_trading_limits_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    let asset = Address::generate(&env);
+    let mut asset_limits = Map::new(&env);
+    asset_limits.set(asset.clone(), 5_000_000_000);
+    let limits = TradingLimits {
+        max_trade_notional: Some(1_000_000_000),
+        max_daily_notional: Some(10_000_000_000),
+        max_daily_asset_notional: asset_limits,
+    };
+
+    // Limits can only be set for registered participants
+    let result =
+        client.try_set_trading_limits(&admin, &participant.trading_address, &limits);
+    assert_eq!(result, Err(Ok(RegistryError::ParticipantNotFound)));
+
+    client.register_participant(&admin, &participant);
+    client.set_trading_limits(&admin, &participant.trading_address, &limits);
+
+    let stored = client.get_trading_limits(&participant.trading_address).unwrap();
+    assert_eq!(stored.max_trade_notional, Some(1_000_000_000));
+    assert_eq!(stored.max_daily_notional, Some(10_000_000_000));
+    assert_eq!(stored.max_daily_asset_notional.get(asset), Some(5_000_000_000));
+
+    // Negative limits are rejected
+    let invalid = TradingLimits {
+        max_trade_notional: Some(-1),
+        max_daily_notional: None,
+        max_daily_asset_notional: Map::new(&env),
+    };
+    let result =
+        client.try_set_trading_limits(&admin, &participant.trading_address, &invalid);
+    assert_eq!(result, Err(Ok(RegistryError::InvalidTradingLimits)));
+
+    client.remove_trading_limits(&admin, &participant.trading_address);
+    assert!(client.get_trading_limits(&participant.trading_address).is_none());
+    let result = client.try_remove_trading_limits(&admin, &participant.trading_address);
+    assert_eq!(result, Err(Ok(RegistryError::TradingLimitsNotFound)));
+}
+
+#[test]
+fn test_state_changes_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let trees = client.get_trees();
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+    let events = env.events().all();
+
+    // Root changes are published by the trees contract
+    let category_tree = CATEGORY_TREE_BASE + participant.category as u32;
+    let trees_client = trees_wasm::Client::new(&env, &trees);
+    assert_eq!(
+        events.filter_by_contract(&trees),
+        [
+            trees_wasm::RootChanged {
+                owner: contract_id.clone(),
+                tree: WHITELIST_TREE,
+                epoch: 1,
+                root: client.get_whitelist_root(),
+            }
+            .to_xdr(&env, &trees),
+            trees_wasm::RootChanged {
+                owner: contract_id.clone(),
+                tree: category_tree,
+                epoch: 1,
+                root: trees_client.get_root(&contract_id, &category_tree),
+            }
+            .to_xdr(&env, &trees),
+        ]
+    );
+    assert_eq!(
+        events.filter_by_contract(&contract_id),
+        [
+            ParticipantRegistered {
+                trading_address: participant.trading_address.clone(),
+                tree_index: 0,
+                category: participant.category,
+                kyc_expiry: participant.kyc_expiry,
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+
+    client.deactivate_participant(&admin, &participant.trading_address);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            ParticipantDeactivated {
+                trading_address: participant.trading_address,
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            AssetListed {
+                token_address: asset.token_address.clone(),
+                symbol: asset.symbol.clone(),
+                asset_type: asset.asset_type,
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+
+    client.deactivate_asset(&admin, &asset.token_address);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            AssetDeactivated {
+                token_address: asset.token_address,
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+}
+
+#[test]
+fn test_asset_tree_tracks_active_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+    let trees = trees_client(&env, &client);
+    let empty_root = client.get_asset_root();
+
+    let bond = create_test_asset(&env);
+    client.register_asset(&admin, &bond);
+    let mut inactive = create_test_asset(&env);
+    inactive.is_active = false;
+    client.register_asset(&admin, &inactive);
+
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    assert_eq!(client.get_asset_tree_index(&bond.token_address), Some(0));
+    assert_eq!(client.get_asset_tree_index(&inactive.token_address), Some(1));
+    assert_eq!(
+        trees.get_leaves(&contract_id, &ASSET_TREE),
+        vec![&env, client.compute_asset_leaf(&bond.token_address), zero.clone()]
+    );
+    let listed_root = client.get_asset_root();
+    assert_ne!(listed_root, empty_root);
+
+    // Deactivation clears the leaf and moves the root
+    client.deactivate_asset(&admin, &bond.token_address);
+    assert_eq!(
+        trees.get_leaves(&contract_id, &ASSET_TREE),
+        vec![&env, zero.clone(), zero.clone()]
+    );
+    assert_ne!(client.get_asset_root(), listed_root);
+
+    // A matured asset's leaf is cleared once anyone refreshes it
+    let note = create_test_asset(&env);
+    client.register_asset(&admin, &note);
+    let note_leaf = client.compute_asset_leaf(&note.token_address);
+    assert_eq!(trees.get_leaves(&contract_id, &ASSET_TREE).get(2), Some(note_leaf));
+    env.ledger().set_timestamp(note.metadata.maturity_date.unwrap());
+    client.refresh_asset_leaf(&note.token_address);
+    assert_eq!(trees.get_leaves(&contract_id, &ASSET_TREE).get(2), Some(zero));
+}
+
+#[test]
+fn test_asset_root_freshness() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    client.register_asset(&admin, &create_test_asset(&env));
+    let old_root = client.get_asset_root();
+    env.ledger().set_timestamp(1_000);
+    client.register_asset(&admin, &create_test_asset(&env));
+
+    // A replaced root stays usable for in-flight proofs within the window
+    assert!(client.is_asset_root_fresh(&client.get_asset_root(), &0));
+    assert!(client.is_asset_root_fresh(&old_root, &600));
+    env.ledger().set_timestamp(1_601);
+    assert!(!client.is_asset_root_fresh(&old_root, &600));
+    assert!(!client.is_asset_root_fresh(&BytesN::from_array(&env, &[7u8; 32]), &600));
+}
+
+#[test]
+fn test_asset_leaf_matches_circomlib() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    // int.from_bytes(strkey, "big") mod p
+    let token = Address::from_str(&env, "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC");
+    let reduced = U256::from_be_bytes(
+        &env,
+        &Bytes::from_array(
+            &env,
+            &[
+                0x07, 0xd1, 0x14, 0x5f, 0x71, 0xcf, 0x06, 0xc5, 0x66, 0xaf, 0xa7, 0xb3, 0xf6, 0xa7,
+                0x19, 0x4c, 0x55, 0x58, 0x06, 0xf3, 0x95, 0x00, 0xf6, 0x87, 0x6c, 0x44, 0xcb, 0x37,
+                0x99, 0x85, 0xe3, 0x43,
+            ],
+        ),
+    );
+    let asset_hash = client.compute_asset_hash(&token);
+    assert_eq!(U256::from_be_bytes(&env, &asset_hash.into()), reduced);
+    let expected = soroban_poseidon::poseidon_hash::<2, BnScalar>(&env, &vec![&env, reduced]);
+
+    let leaf = client.compute_asset_leaf(&token);
+    assert_eq!(U256::from_be_bytes(&env, &leaf.into()), expected);
+}
+
+#[test]
+fn test_category_trees_track_participants() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+    let trees = trees_client(&env, &client);
+    let category_root =
+        |category| trees.get_root(&contract_id, &(CATEGORY_TREE_BASE + category as u32));
+    let category_leaves =
+        |category| trees.get_leaves(&contract_id, &(CATEGORY_TREE_BASE + category as u32));
+    let empty_root = category_root(ParticipantCategory::Bank);
+    assert_eq!(empty_root, client.get_whitelist_root());
+
+    let mut broker = create_test_participant(&env);
+    broker.id_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let mut bank = create_test_participant(&env);
+    bank.id_hash = BytesN::from_array(&env, &[2u8; 32]);
+    bank.category = ParticipantCategory::Bank;
+    let mut other_bank = create_test_participant(&env);
+    other_bank.id_hash = BytesN::from_array(&env, &[3u8; 32]);
+    other_bank.category = ParticipantCategory::Bank;
+
+    client.register_participant(&admin, &broker);
+    client.register_participant(&admin, &bank);
+    client.register_participant(&admin, &other_bank);
+
+    // Global indices keep growing while category indices restart per tree
+    assert_eq!(client.get_category_tree_index(&broker.trading_address), Some(0));
+    assert_eq!(client.get_category_tree_index(&bank.trading_address), Some(0));
+    assert_eq!(client.get_category_tree_index(&other_bank.trading_address), Some(1));
+
+    let bank_leaf = client.compute_whitelist_leaf(&bank.id_hash, &bank.kyc_expiry, &bank.category);
+    let other_bank_leaf =
+        client.compute_whitelist_leaf(&other_bank.id_hash, &other_bank.kyc_expiry, &other_bank.category);
+    assert_eq!(
+        category_leaves(ParticipantCategory::Bank),
+        vec![&env, bank_leaf, other_bank_leaf.clone()]
+    );
+    assert_eq!(category_leaves(ParticipantCategory::BrokerDealer).len(), 1);
+    assert_eq!(category_leaves(ParticipantCategory::PensionFund).len(), 0);
+    assert_eq!(category_root(ParticipantCategory::PensionFund), empty_root);
+
+    let bank_root = category_root(ParticipantCategory::Bank);
+    let broker_root = category_root(ParticipantCategory::BrokerDealer);
+    assert_ne!(bank_root, broker_root);
+    assert_ne!(bank_root, client.get_whitelist_root());
+
+    // Renewal rewrites the leaf in the category tree only for that category
+    let new_expiry = bank.kyc_expiry + 1000;
+    client.renew_kyc(&admin, &bank.trading_address, &new_expiry);
+    let renewed_leaf = client.compute_whitelist_leaf(&bank.id_hash, &new_expiry, &bank.category);
+    assert_eq!(
+        category_leaves(ParticipantCategory::Bank),
+        vec![&env, renewed_leaf, other_bank_leaf.clone()]
+    );
+    assert_eq!(category_root(ParticipantCategory::BrokerDealer), broker_root);
+
+    // Deactivation clears the category leaf
+    client.deactivate_participant(&admin, &bank.trading_address);
+    assert_eq!(
+        category_leaves(ParticipantCategory::Bank),
+        vec![&env, BytesN::from_array(&env, &[0u8; 32]), other_bank_leaf]
+    );
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_upgrade(&stranger, &BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(RegistryError::OnlyAdmin))
+    );
+    assert_eq!(client.try_migrate(&stranger), Err(Ok(RegistryError::OnlyAdmin)));
+
+    // Already on the current schema: nothing to run
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(env.events().all().filter_by_contract(&contract_id), []);
+
+    // Data written by newer code cannot be migrated down
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(client.try_migrate(&admin), Err(Ok(RegistryError::UnsupportedSchemaVersion)));
+}
+
+#[test]
+fn test_migrate_upgrades_schema_1_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    // Schema 1 records lacked the fields added since
+    let strip = |record: Val, fields: &[&str]| {
+        let mut map = Map::<Symbol, Val>::try_from_val(&env, &record).unwrap();
+        for field in fields {
+            map.remove(Symbol::new(&env, field));
+        }
+        map
+    };
+    let participant = |id: u8, category, is_active| {
+        let mut participant = create_test_participant(&env);
+        participant.id_hash = BytesN::from_array(&env, &[id; 32]);
+        participant.category = category;
+        participant.is_active = is_active;
+        participant
+    };
+    let broker = participant(1, ParticipantCategory::BrokerDealer, true);
+    let bank = participant(2, ParticipantCategory::Bank, true);
+    let retired = participant(3, ParticipantCategory::Bank, false);
+    let bond = create_test_asset(&env);
+    let mut delisted = create_test_asset(&env);
+    delisted.is_active = false;
+
+    // Lay out storage the way schema 1 stored it
+    env.as_contract(&contract_id, || {
+        let participant_fields = ["jurisdiction", "is_qualified_investor"];
+        let participants = vec![
+            &env,
+            strip(broker.clone().into_val(&env), &participant_fields),
+            strip(bank.clone().into_val(&env), &participant_fields),
+            strip(retired.clone().into_val(&env), &participant_fields),
+        ];
+        let assets = vec![
+            &env,
+            strip(bond.clone().into_val(&env), &["metadata"]),
+            strip(delisted.clone().into_val(&env), &["metadata"]),
+        ];
+        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+        env.storage().instance().set(&ASSETS_KEY, &assets);
+        env.storage().instance().remove(&SCHEMA_VERSION_KEY);
+        env.storage().instance().remove(&TREES_KEY);
+    });
+
+    // The trees move to a trees contract, which must be set first
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.try_migrate(&admin), Err(Ok(RegistryError::TreesNotSet)));
+    let trees_address = create_trees(&env);
+    client.set_trees(&admin, &trees_address);
+    assert_eq!(
+        client.try_set_trees(&admin, &create_trees(&env)),
+        Err(Ok(RegistryError::TreesAlreadySet))
+    );
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    let trees = trees_wasm::Client::new(&env, &trees_address);
+    let bank_tree = CATEGORY_TREE_BASE + ParticipantCategory::Bank as u32;
+    let broker_tree = CATEGORY_TREE_BASE + ParticipantCategory::BrokerDealer as u32;
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // Participants gain the new fields with conservative defaults
+    let migrated = client.get_participant(&bank.trading_address).unwrap();
+    assert_eq!(migrated.tree_index, 1);
+    assert_eq!(migrated.jurisdiction, symbol_short!(""));
+    assert!(!migrated.is_qualified_investor);
+    assert!(client.is_participant_eligible(&bank.trading_address));
+    assert!(!client.is_participant_eligible(&retired.trading_address));
+
+    // Leaves are rebuilt to commit to KYC expiry and category
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let broker_leaf = client.compute_whitelist_leaf(
+        &broker.id_hash,
+        &broker.kyc_expiry,
+        &ParticipantCategory::BrokerDealer,
+    );
+    let bank_leaf =
+        client.compute_whitelist_leaf(&bank.id_hash, &bank.kyc_expiry, &ParticipantCategory::Bank);
+    let leaves = vec![&env, broker_leaf.clone(), bank_leaf.clone(), zero.clone()];
+    assert_eq!(trees.get_leaves(&contract_id, &WHITELIST_TREE), leaves);
+    let mut tree = LeanIMTBN254::new(&env, WHITELIST_TREE_DEPTH);
+    for leaf in leaves.iter() {
+        tree.insert(leaf).unwrap();
+    }
+    assert_eq!(client.get_whitelist_root(), tree.get_root());
+    assert!(client.is_known_root(&tree.get_root()));
+    assert_eq!(
+        trees.get_leaves(&contract_id, &bank_tree),
+        vec![&env, bank_leaf, zero.clone()]
+    );
+    assert_eq!(
+        trees.get_leaves(&contract_id, &broker_tree),
+        vec![&env, broker_leaf]
+    );
+    assert_eq!(client.get_category_tree_index(&retired.trading_address), Some(1));
+
+    // Assets gain empty metadata and an asset tree of the active ones
+    let asset = client.get_asset(&bond.token_address).unwrap();
+    assert_eq!(asset.metadata.issuer, String::from_str(&env, ""));
+    assert_eq!(client.get_asset_metadata_history(&bond.token_address).len(), 1);
+    assert_eq!(
+        trees.get_leaves(&contract_id, &ASSET_TREE),
+        vec![&env, client.compute_asset_leaf(&bond.token_address), zero]
+    );
+    assert_eq!(client.get_asset_tree_index(&delisted.token_address), Some(1));
+
+    // The migrated registry keeps working under the new schema
+    let newcomer = participant(4, ParticipantCategory::AssetManager, true);
+    assert_eq!(client.register_participant(&admin, &newcomer), 3);
+    client.update_asset_metadata(&admin, &bond.token_address, &create_test_metadata(&env));
+    assert_eq!(client.get_asset_metadata_history(&bond.token_address).len(), 2);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_migrate_moves_schema_2_trees() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+    let asset = create_test_asset(&env);
+    client.register_asset(&admin, &asset);
+    let whitelist_root = client.get_whitelist_root();
+    let asset_root = client.get_asset_root();
+    let category_tree = CATEGORY_TREE_BASE + participant.category as u32;
+    let category_leaves = trees_client(&env, &client).get_leaves(&contract_id, &category_tree);
+
+    // Lay the trees out the way schema 2 stored them, inside the registry
+    let whitelist_leaves = trees_client(&env, &client).get_leaves(&contract_id, &WHITELIST_TREE);
+    let asset_leaves = trees_client(&env, &client).get_leaves(&contract_id, &ASSET_TREE);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().instance();
+        storage.set(&LEGACY_WHITELIST_TREE_KEYS[0], &whitelist_leaves);
+        storage.set(&LEGACY_WHITELIST_TREE_KEYS[1], &WHITELIST_TREE_DEPTH);
+        storage.set(&LEGACY_ASSET_TREE_KEYS[0], &asset_leaves);
+        storage.set(
+            &(LEGACY_CATEGORY_TREE_KEYS[0].clone(), participant.category),
+            &category_leaves,
+        );
+        storage.set(&SCHEMA_VERSION_KEY, &2u32);
+        storage.remove(&TREES_KEY);
+    });
+
+    let trees_address = create_trees(&env);
+    client.set_trees(&admin, &trees_address);
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+
+    // Rebuilt from the same leaves, the roots are unchanged
+    let trees = trees_wasm::Client::new(&env, &trees_address);
+    assert_eq!(client.get_whitelist_root(), whitelist_root);
+    assert_eq!(client.get_asset_root(), asset_root);
+    assert_eq!(trees.get_leaves(&contract_id, &category_tree), category_leaves);
+    assert_eq!(client.get_whitelist_count(), 1);
+    assert!(client.is_root_fresh(&whitelist_root, &0));
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&LEGACY_WHITELIST_TREE_KEYS[0]));
+        assert!(!env.storage().instance().has(&LEGACY_WHITELIST_TREE_KEYS[1]));
+        assert!(!env.storage().instance().has(&LEGACY_ASSET_TREE_KEYS[0]));
+    });
+
+    let mut other = create_test_participant(&env);
+    other.id_hash = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(client.register_participant(&admin, &other), 1);
+}
+
+#[test]
+fn test_agent_authorization() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+    let principal = participant.trading_address;
+    let agent = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let other_asset = Address::generate(&env);
+
+    let scope = AgentScope {
+        assets: vec![&env, asset.clone()],
+        max_order_size: Some(1_000),
+        expires_at: env.ledger().timestamp() + 3600,
+    };
+
+    // Only active participants can delegate, and scopes must be usable
+    assert_eq!(
+        client.try_authorize_agent(&Address::generate(&env), &agent, &scope),
+        Err(Ok(RegistryError::ParticipantNotFound))
+    );
+    assert_eq!(
+        client.try_authorize_agent(&principal, &principal, &scope),
+        Err(Ok(RegistryError::InvalidAgentScope))
+    );
+    let mut expired = scope.clone();
+    expired.expires_at = env.ledger().timestamp();
+    assert_eq!(
+        client.try_authorize_agent(&principal, &agent, &expired),
+        Err(Ok(RegistryError::InvalidAgentScope))
+    );
+
+    client.authorize_agent(&principal, &agent, &scope);
+    assert_eq!(client.get_agent_scope(&principal, &agent).unwrap().max_order_size, Some(1_000));
+    assert!(client.is_agent_authorized(&principal, &agent, &asset, &1_000));
+    assert!(!client.is_agent_authorized(&principal, &agent, &asset, &1_001));
+    assert!(!client.is_agent_authorized(&principal, &agent, &other_asset, &0));
+    assert!(!client.is_agent_authorized(&principal, &Address::generate(&env), &asset, &0));
+
+    // The authorization lapses at its expiry
+    env.ledger().set_timestamp(scope.expires_at);
+    assert!(!client.is_agent_authorized(&principal, &agent, &asset, &0));
+
+    // An empty asset list covers every asset
+    let unrestricted = AgentScope {
+        assets: vec![&env],
+        max_order_size: None,
+        expires_at: env.ledger().timestamp() + 3600,
+    };
+    client.authorize_agent(&principal, &agent, &unrestricted);
+    assert!(client.is_agent_authorized(&principal, &agent, &other_asset, &i128::MAX));
+
+    client.revoke_agent(&principal, &agent);
+    assert!(client.get_agent_scope(&principal, &agent).is_none());
+    assert!(!client.is_agent_authorized(&principal, &agent, &asset, &0));
+    assert_eq!(
+        client.try_revoke_agent(&principal, &agent),
+        Err(Ok(RegistryError::AgentNotFound))
+    );
+}
+
+#[test]
+fn test_key_rotation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+    let old_address = participant.trading_address.clone();
+    let new_address = Address::generate(&env);
+    let agent = Address::generate(&env);
+
+    let limits = TradingLimits {
+        max_trade_notional: Some(1_000),
+        max_daily_notional: None,
+        max_daily_asset_notional: Map::new(&env),
+    };
+    client.set_trading_limits(&admin, &old_address, &limits);
+    let scope = AgentScope {
+        assets: vec![&env],
+        max_order_size: None,
+        expires_at: env.ledger().timestamp() + 3600,
+    };
+    client.authorize_agent(&old_address, &agent, &scope);
+
+    let mut other = create_test_participant(&env);
+    other.id_hash = BytesN::from_array(&env, &[2u8; 32]);
+    client.register_participant(&admin, &other);
+
+    let trees = trees_client(&env, &client);
+    let category_tree = CATEGORY_TREE_BASE + participant.category as u32;
+    let root = client.get_whitelist_root();
+    let category_root = trees.get_root(&contract_id, &category_tree);
+    let category_index = client.get_category_tree_index(&old_address);
+
+    // The new address must be unused, and approval needs a pending proposal
+    assert_eq!(
+        client.try_propose_key_rotation(&old_address, &other.trading_address),
+        Err(Ok(RegistryError::ParticipantAlreadyExists))
+    );
+    assert_eq!(
+        client.try_approve_key_rotation(&admin, &old_address),
+        Err(Ok(RegistryError::KeyRotationNotFound))
+    );
+
+    client.propose_key_rotation(&old_address, &new_address);
+    assert!(client.get_participant(&new_address).is_none());
+
+    assert_eq!(
+        client.try_approve_key_rotation(&Address::generate(&env), &old_address),
+        Err(Ok(RegistryError::OnlyAdmin))
+    );
+    client.approve_key_rotation(&admin, &old_address);
+
+    // The record moves with its identity and tree positions intact
+    assert!(client.get_participant(&old_address).is_none());
+    let moved = client.get_participant(&new_address).unwrap();
+    assert_eq!(moved.id_hash, participant.id_hash);
+    assert_eq!(moved.tree_index, 0);
+    assert_eq!(client.get_whitelist_root(), root);
+    assert_eq!(trees.get_root(&contract_id, &category_tree), category_root);
+    assert_eq!(client.get_category_tree_index(&new_address), category_index);
+    assert!(client.get_category_tree_index(&old_address).is_none());
+    assert_eq!(client.get_trading_limits(&new_address).unwrap().max_trade_notional, Some(1_000));
+    assert!(client.get_trading_limits(&old_address).is_none());
+
+    // Delegations made with the old key have to be granted again
+    assert!(client.get_agent_scope(&new_address, &agent).is_none());
+
+    assert_eq!(client.get_rotated_address(&old_address), Some(new_address.clone()));
+    assert_eq!(
+        client.try_approve_key_rotation(&admin, &old_address),
+        Err(Ok(RegistryError::KeyRotationNotFound))
+    );
+
+    // The retired address cannot be registered or rotated to again
+    let mut reused = create_test_participant(&env);
+    reused.trading_address = old_address.clone();
+    assert_eq!(
+        client.try_register_participant(&admin, &reused),
+        Err(Ok(RegistryError::ParticipantAlreadyExists))
+    );
+    assert_eq!(
+        client.try_propose_key_rotation(&new_address, &old_address),
+        Err(Ok(RegistryError::ParticipantAlreadyExists))
+    );
+}
+
+#[test]
+fn test_issuer_listing_flow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &create_trees(&env), &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let mut asset = create_test_asset(&env);
+    asset.token_address = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let token = asset.token_address.clone();
+
+    // Only approved issuers can propose listings
+    assert_eq!(
+        client.try_propose_listing(&issuer, &asset),
+        Err(Ok(RegistryError::IssuerNotFound))
+    );
+    client.add_issuer(&admin, &issuer, &String::from_str(&env, "Treasury Desk"));
+    assert_eq!(
+        client.try_add_issuer(&admin, &issuer, &String::from_str(&env, "Treasury Desk")),
+        Err(Ok(RegistryError::IssuerAlreadyExists))
+    );
+
+    // The proposal stays pending until compliance approves it
+    client.propose_listing(&issuer, &asset);
+    assert_eq!(client.get_listing_proposals().len(), 1);
+    assert!(client.get_asset(&token).is_none());
+    assert_eq!(
+        client.try_propose_listing(&issuer, &asset),
+        Err(Ok(RegistryError::AssetAlreadyExists))
+    );
+
+    client.approve_listing(&admin, &token);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [
+            AssetListed {
+                token_address: token.clone(),
+                symbol: asset.symbol.clone(),
+                asset_type: asset.asset_type,
+            }
+            .to_xdr(&env, &contract_id),
+            ListingApproved {
+                token_address: token.clone(),
+                issuer: issuer.clone(),
+            }
+            .to_xdr(&env, &contract_id),
+        ]
+    );
+    assert!(client.is_asset_eligible(&token));
+    assert_eq!(client.get_asset_issuer(&token), Some(issuer.clone()));
+    assert_eq!(client.get_asset_metadata_history(&token).get(0).unwrap().updated_by, issuer);
+    assert_eq!(client.get_listing_proposals().len(), 0);
+    assert_eq!(
+        client.try_approve_listing(&admin, &token),
+        Err(Ok(RegistryError::ListingNotFound))
+    );
+
+    // Issuers can suspend and resume only their own assets
+    let other_issuer = Address::generate(&env);
+    client.add_issuer(&admin, &other_issuer, &String::from_str(&env, "Other"));
+    assert_eq!(
+        client.try_set_asset_suspended(&other_issuer, &token, &true),
+        Err(Ok(RegistryError::NotAssetIssuer))
+    );
+    let listed_root = client.get_asset_root();
+    client.set_asset_suspended(&issuer, &token, &true);
+    assert!(!client.is_asset_eligible(&token));
+    assert_eq!(client.get_active_assets().len(), 0);
+    assert!(client.get_asset(&token).unwrap().is_active);
+    assert_ne!(client.get_asset_root(), listed_root);
+    client.set_asset_suspended(&issuer, &token, &false);
+    assert!(client.is_asset_eligible(&token));
+    assert_eq!(client.get_asset_root(), listed_root);
+
+    // Rejected proposals are dropped; the token's admin must sign proposals
+    let token_admin = Address::generate(&env);
+    let mut second = create_test_asset(&env);
+    second.token_address = env.register_stellar_asset_contract_v2(token_admin.clone()).address();
+    client.propose_listing(&issuer, &second);
+    assert!(env.auths().iter().any(|(address, _)| *address == token_admin));
+    client.reject_listing(&admin, &second.token_address);
+    assert_eq!(client.get_listing_proposals().len(), 0);
+    assert!(client.get_asset(&second.token_address).is_none());
+
+    // Tokens without an `admin()` function can be proposed and await review
+    let mut plain = create_test_asset(&env);
+    plain.token_address = env.register(PlainToken, ());
+    client.propose_listing(&issuer, &plain);
+    let pending = client.get_listing_proposals();
+    assert_eq!(pending.get(0).unwrap().asset.token_address, plain.token_address);
+
+    // Revoked issuers lose their issuer rights
+    client.revoke_issuer(&admin, &issuer);
+    assert!(!client.get_issuer(&issuer).unwrap().is_active);
+    assert_eq!(
+        client.try_propose_listing(&issuer, &second),
+        Err(Ok(RegistryError::IssuerRevoked))
+    );
+    assert_eq!(
+        client.try_set_asset_suspended(&issuer, &token, &true),
+        Err(Ok(RegistryError::IssuerRevoked))
+    );
+}
+
+#[test]
+fn test_wasm_within_contract_size_limit() {
+    // Checks the release build when one exists; see the size budget note in contracts/README.md
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../target/wasm32v1-none/release/darkpool_registry.wasm"
+    );
+    let Ok(wasm) = std::fs::read(path) else {
+        std::println!("skipping size check: {path} has not been built");
+        return;
+    };
+    const MAX_CONTRACT_SIZE: usize = 128 * 1024;
+    assert!(
+        wasm.len() <= MAX_CONTRACT_SIZE,
+        "registry wasm is {} bytes, limit is {}",
+        wasm.len(),
+        MAX_CONTRACT_SIZE
+    );
+}
+
@@ -0,0 +1,187 @@
+//! Settlement events
+//!
+//! Each event's first topic is its name in snake_case (e.g. `trade_settled`),
+//! followed by the fields marked `#[topic]`. The remaining fields form the data map.
+
+use soroban_sdk::{contractevent, Address, BytesN, Symbol};
+
+use crate::TradingStatus;
+
+/// Tokens were deposited into escrow
+#[contractevent]
+#[derive(Clone)]
+pub struct Deposited {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub escrow_balance: i128,
+}
+
+/// Tokens were withdrawn from escrow
+#[contractevent]
+#[derive(Clone)]
+pub struct Withdrawn {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub escrow_balance: i128,
+}
+
+/// Tokens were deposited into the note of an order commitment
+#[contractevent]
+#[derive(Clone)]
+pub struct NoteDeposited {
+    #[topic]
+    pub commitment: BytesN<32>,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// A note's unlocked balance was withdrawn with a proof of the commitment secret
+#[contractevent]
+#[derive(Clone)]
+pub struct NoteWithdrawn {
+    #[topic]
+    pub commitment: BytesN<32>,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+}
+
+/// Escrowed funds were locked for a pending order
+#[contractevent]
+#[derive(Clone)]
+pub struct EscrowLocked {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub locked_balance: i128,
+}
+
+/// Locked funds were released back to the available escrow balance
+#[contractevent]
+#[derive(Clone)]
+pub struct EscrowUnlocked {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub locked_balance: i128,
+}
+
+/// A matched trade was settled and both legs transferred
+#[contractevent]
+#[derive(Clone)]
+pub struct TradeSettled {
+    #[topic]
+    pub match_id: BytesN<32>,
+    #[topic]
+    pub asset_address: Address,
+    pub buyer: Address,
+    pub seller: Address,
+    pub payment_asset: Address,
+    pub quantity: i128,
+    pub price: i128,
+    pub nullifier: BytesN<32>,
+}
+
+/// The whitelist root freshness window was changed
+#[contractevent]
+#[derive(Clone)]
+pub struct RootFreshnessWindowSet {
+    pub window: u64,
+}
+
+/// An asset was opened, halted or closed for trading
+#[contractevent]
+#[derive(Clone)]
+pub struct TradingStatusChanged {
+    #[topic]
+    pub asset: Address,
+    pub status: TradingStatus,
+    pub reason: Symbol,
+}
+
+/// An asset's scheduled trading window was set or replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct TradingWindowSet {
+    #[topic]
+    pub asset: Address,
+    pub days: u32,
+    pub open_time: u64,
+    pub close_time: u64,
+}
+
+/// An asset's trading window was removed
+#[contractevent]
+#[derive(Clone)]
+pub struct TradingWindowRemoved {
+    #[topic]
+    pub asset: Address,
+}
+
+/// An asset's circuit breaker threshold was changed
+#[contractevent]
+#[derive(Clone)]
+pub struct CircuitBreakerSet {
+    #[topic]
+    pub asset: Address,
+    pub max_move_bps: u32,
+}
+
+/// The orderbook allowed to lock order escrow was set
+#[contractevent]
+#[derive(Clone)]
+pub struct OrderbookSet {
+    #[topic]
+    pub orderbook: Address,
+}
+
+/// The whole-order settlement circuit's verification key was replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct SettlementVkSet {
+    pub vk_hash: BytesN<32>,
+}
+
+/// The partial fill settlement circuit's verification key was set
+#[contractevent]
+#[derive(Clone)]
+pub struct PartialFillVkSet {
+    pub vk_hash: BytesN<32>,
+}
+
+/// The contract code was replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgraded {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Stored data was migrated to a new schema version
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigrated {
+    #[topic]
+    pub version: u32,
+}
+
+/// Escrow, locked balances and daily usage moved to a rotated trading address
+#[contractevent]
+#[derive(Clone)]
+pub struct KeyRotationApplied {
+    #[topic]
+    pub old_address: Address,
+    #[topic]
+    pub new_address: Address,
+}
@@ -1,10 +1,15 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec,
-    Address, Bytes, BytesN, Env, Map, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token,
+    vec, Address, Bytes, BytesN, Env, Map, Symbol, Vec,
 };
 
+mod events;
+
+pub use events::*;
+
 #[cfg(test)]
 mod test;
 
@@ -22,15 +27,59 @@ mod registry_wasm {
     );
 }
 
+/// The orderbook entry point settlement reports settled matches to
+#[contractclient(name = "OrderbookClient")]
+pub trait OrderbookInterface {
+    fn mark_settled(
+        env: Env,
+        match_id: BytesN<32>,
+        buy_commitment: BytesN<32>,
+        sell_commitment: BytesN<32>,
+        quantity: i128,
+        price: i128,
+        buy_complete: bool,
+        sell_complete: bool,
+    );
+}
+
 // Storage keys
 const ADMIN_KEY: Symbol = symbol_short!("admin");
 const REGISTRY_KEY: Symbol = symbol_short!("registry");
 const VERIFIER_KEY: Symbol = symbol_short!("verifier");
 const SETTLEMENT_VK_KEY: Symbol = symbol_short!("settl_vk");
+const PARTIAL_FILL_VK_KEY: Symbol = symbol_short!("fill_vk");
 const NULLIFIERS_KEY: Symbol = symbol_short!("nulls");
 const ESCROW_KEY: Symbol = symbol_short!("escrow");
 const LOCKED_KEY: Symbol = symbol_short!("locked");
+const ORDER_LOCKS_KEY: Symbol = symbol_short!("ord_locks");
+const ORDER_LOCKED_KEY: Symbol = symbol_short!("ord_lockd");
+const ORDERBOOK_KEY: Symbol = symbol_short!("orderbook");
+const FILLS_KEY: Symbol = symbol_short!("fills");
+const NOTES_KEY: Symbol = symbol_short!("notes");
 const SETTLEMENTS_KEY: Symbol = symbol_short!("settls");
+const ROOT_WINDOW_KEY: Symbol = symbol_short!("root_win");
+const DAILY_USAGE_KEY: Symbol = symbol_short!("day_use");
+const ASSET_USAGE_KEY: Symbol = symbol_short!("asset_use");
+const MARKET_STATUS_KEY: Symbol = symbol_short!("mkt_stat");
+const TRADING_WINDOWS_KEY: Symbol = symbol_short!("mkt_hours");
+const BREAKERS_KEY: Symbol = symbol_short!("breakers");
+const REFERENCE_PRICES_KEY: Symbol = symbol_short!("ref_px");
+const SCHEMA_VERSION_KEY: Symbol = symbol_short!("schema");
+
+// Storage layout version written by this code; bump it with a new migration step
+const SCHEMA_VERSION: u32 = 2;
+
+// Length of a trading limit day in seconds (UTC days)
+const SECONDS_PER_DAY: u64 = 86400;
+
+// Basis point denominator for circuit breaker price moves
+const BPS_DENOMINATOR: i128 = 10_000;
+
+// Default time (seconds) a replaced whitelist root is still accepted in proofs
+const DEFAULT_ROOT_FRESHNESS_WINDOW: u64 = 3600;
+
+// Largest number of assets plus order commitments one apply_key_rotation call moves
+const MAX_ROTATION_BATCH: u32 = 50;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -48,6 +97,26 @@ pub enum SettlementError {
     AlreadySettled = 10,
     InsufficientLockedFunds = 11,
     TransferFailed = 12,
+    PairNotListed = 13,
+    TradeConstraintViolation = 14,
+    TradeLimitExceeded = 15,
+    DailyLimitExceeded = 16,
+    AssetDailyLimitExceeded = 17,
+    UnsupportedSchemaVersion = 18,
+    AgentNotAuthorized = 19,
+    KeyNotRotated = 20,
+    MarketHalted = 21,
+    MarketClosed = 22,
+    InvalidTradingWindow = 23,
+    OrderbookNotSet = 24,
+    OrderLockExists = 25,
+    OrderLockMismatch = 26,
+    FillMismatch = 27,
+    ProofExpired = 28,
+    PartialFillVkNotSet = 29,
+    OrderLockNotFound = 30,
+    MatchMismatch = 31,
+    RotationBatchTooLarge = 32,
 }
 
 /// Settlement record for completed trades
@@ -72,6 +141,73 @@ pub struct EscrowKey {
     pub asset: Address,
 }
 
+/// Note balance held for an order commitment in one asset
+#[derive(Clone)]
+#[contracttype]
+pub struct NoteKey {
+    pub commitment: BytesN<32>,
+    pub asset: Address,
+}
+
+/// Escrow locked for one resting order, keyed by its commitment
+///
+/// Locks funded from a note are owned by the settlement contract itself.
+#[derive(Clone)]
+#[contracttype]
+pub struct OrderLock {
+    pub owner: Address,
+    pub asset: Address,
+    pub amount: i128,
+}
+
+/// Notional settled by a participant during one UTC day
+#[derive(Clone)]
+#[contracttype]
+pub struct DailyUsage {
+    pub day: u64,
+    pub notional: i128,
+}
+
+/// Remaining notional a participant may settle; `None` means no limit
+#[derive(Clone)]
+#[contracttype]
+pub struct TradingHeadroom {
+    pub per_trade: Option<i128>,
+    pub daily: Option<i128>,
+    pub daily_asset: Option<i128>,
+}
+
+/// Trading status of an asset
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum TradingStatus {
+    Open = 0,
+    Halted = 1,
+    Closed = 2,
+}
+
+/// Current trading status of an asset and the reason it was last changed
+#[derive(Clone)]
+#[contracttype]
+pub struct MarketStatus {
+    pub status: TradingStatus,
+    pub reason: Symbol,
+    pub updated_at: u64,
+}
+
+/// Scheduled trading hours of an asset in UTC
+///
+/// `days` is a weekday bitmask (bit 0 = Monday ... bit 6 = Sunday). `open_time` and
+/// `close_time` are seconds after UTC midnight; the market is open in `[open_time, close_time)`.
+#[derive(Clone)]
+#[contracttype]
+pub struct TradingWindow {
+    pub days: u32,
+    pub open_time: u64,
+    pub close_time: u64,
+}
+
 #[contract]
 pub struct DarkPoolSettlement;
 
@@ -83,7 +219,7 @@ impl DarkPoolSettlement {
     /// * `admin` - Admin address
     /// * `registry_address` - Address of the registry contract
     /// * `verifier_address` - Address of the Groth16 verifier contract
-    /// * `settlement_vk_bytes` - Serialized verification key for settlement proofs
+    /// * `settlement_vk_bytes` - Serialized verification key of the whole-order settlement circuit
     pub fn __constructor(
         env: Env,
         admin: Address,
@@ -92,9 +228,13 @@ impl DarkPoolSettlement {
         settlement_vk_bytes: Bytes,
     ) {
         env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &SCHEMA_VERSION);
         env.storage().instance().set(&REGISTRY_KEY, &registry_address);
         env.storage().instance().set(&VERIFIER_KEY, &verifier_address);
         env.storage().instance().set(&SETTLEMENT_VK_KEY, &settlement_vk_bytes);
+        env.storage()
+            .instance()
+            .set(&ROOT_WINDOW_KEY, &DEFAULT_ROOT_FRESHNESS_WINDOW);
 
         // Initialize empty nullifiers list
         let nullifiers: Vec<BytesN<32>> = vec![&env];
@@ -121,11 +261,19 @@ impl DarkPoolSettlement {
 
         // Transfer tokens from depositor to contract
         let token_client = token::Client::new(&env, &asset_address);
-        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+        token_client.transfer(&depositor, env.current_contract_address(), &amount);
 
         // Update escrow balance
         let new_balance = Self::add_escrow_balance(&env, &depositor, &asset_address, amount);
 
+        Deposited {
+            participant: depositor,
+            asset: asset_address,
+            amount,
+            escrow_balance: new_balance,
+        }
+        .publish(&env);
+
         Ok(new_balance)
     }
 
@@ -159,9 +307,97 @@ impl DarkPoolSettlement {
         let token_client = token::Client::new(&env, &asset_address);
         token_client.transfer(&env.current_contract_address(), &withdrawer, &amount);
 
+        Withdrawn {
+            participant: withdrawer,
+            asset: asset_address,
+            amount,
+            escrow_balance: new_balance,
+        }
+        .publish(&env);
+
         Ok(new_balance)
     }
 
+    /// Deposit tokens into the note of an order commitment
+    ///
+    /// A note is escrow keyed by a commitment rather than by a participant, and
+    /// funds orders submitted through a relayer. Anyone may deposit; only the
+    /// holder of the commitment secret can withdraw, through the orderbook's
+    /// `withdraw_relayed_funds`.
+    ///
+    /// # Arguments
+    /// * `depositor` - Account paying the tokens (must authenticate)
+    /// * `commitment` - Order commitment the note belongs to
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to deposit
+    ///
+    /// # Returns
+    /// * The note's new balance
+    pub fn deposit_note(
+        env: Env,
+        depositor: Address,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        depositor.require_auth();
+
+        let token_client = token::Client::new(&env, &asset_address);
+        token_client.transfer(&depositor, env.current_contract_address(), &amount);
+        let balance = Self::adjust_note(&env, &commitment, &asset_address, amount);
+
+        NoteDeposited {
+            commitment,
+            asset: asset_address,
+            amount,
+            balance,
+        }
+        .publish(&env);
+        Ok(balance)
+    }
+
+    /// Get a note's balance that is not locked for its order
+    pub fn get_note_balance(env: Env, commitment: BytesN<32>, asset: Address) -> i128 {
+        Self::notes(&env).get(NoteKey { commitment, asset }).unwrap_or(0)
+    }
+
+    /// Pay out a note's whole unlocked balance in one asset
+    ///
+    /// Only the orderbook set with `set_orderbook` may call this, once it has
+    /// checked a proof of the commitment secret.
+    ///
+    /// # Arguments
+    /// * `commitment` - Order commitment the note belongs to
+    /// * `asset_address` - Token to withdraw
+    /// * `recipient` - Account receiving the tokens
+    ///
+    /// # Returns
+    /// * The amount withdrawn
+    pub fn withdraw_note(
+        env: Env,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        recipient: Address,
+    ) -> Result<i128, SettlementError> {
+        Self::require_orderbook(&env)?;
+        let amount = Self::get_note_balance(env.clone(), commitment.clone(), asset_address.clone());
+        if amount == 0 {
+            return Ok(0);
+        }
+        Self::adjust_note(&env, &commitment, &asset_address, -amount);
+
+        let token_client = token::Client::new(&env, &asset_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        NoteWithdrawn {
+            commitment,
+            asset: asset_address,
+            amount,
+        }
+        .publish(&env);
+        Ok(amount)
+    }
+
     /// Lock escrow for a pending order
     ///
     /// # Arguments
@@ -175,20 +411,32 @@ impl DarkPoolSettlement {
         amount: i128,
     ) -> Result<(), SettlementError> {
         trader.require_auth();
+        Self::lock(&env, trader, asset_address, amount)
+    }
 
-        let escrow_balance = Self::get_escrow_balance(env.clone(), trader.clone(), asset_address.clone());
-        let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
-        let available = escrow_balance - locked_balance;
-
-        if available < amount {
-            return Err(SettlementError::InsufficientEscrow);
-        }
-
-        Self::add_locked_balance(&env, &trader, &asset_address, amount);
-        Ok(())
+    /// Lock a participant's escrow as their authorized agent
+    ///
+    /// # Arguments
+    /// * `agent` - Agent address (must authenticate)
+    /// * `principal` - Participant whose escrow is locked
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to lock (must be within the agent's size cap)
+    pub fn lock_escrow_for(
+        env: Env,
+        agent: Address,
+        principal: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        agent.require_auth();
+        Self::require_agent(&env, &principal, &agent, &asset_address, amount)?;
+        Self::lock(&env, principal, asset_address, amount)
     }
 
-    /// Unlock escrow when an order is cancelled
+    /// Unlock escrow locked with `lock_escrow`
+    ///
+    /// Escrow held by order locks (`get_order_locked_balance`) is only released
+    /// through the orderbook, so at most the locked balance minus that can be unlocked.
     ///
     /// # Arguments
     /// * `trader` - Address of the trader
@@ -201,13 +449,197 @@ impl DarkPoolSettlement {
         amount: i128,
     ) -> Result<(), SettlementError> {
         trader.require_auth();
+        Self::unlock_unreserved(&env, trader, asset_address, amount)
+    }
 
-        let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
-        if locked_balance < amount {
-            return Err(SettlementError::InsufficientLockedFunds);
+    /// Unlock a participant's escrow as their authorized agent
+    ///
+    /// Like `unlock_escrow`, this cannot release escrow held by order locks.
+    ///
+    /// # Arguments
+    /// * `agent` - Agent address (must authenticate)
+    /// * `principal` - Participant whose escrow is unlocked
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to unlock (must be within the agent's size cap)
+    pub fn unlock_escrow_for(
+        env: Env,
+        agent: Address,
+        principal: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        agent.require_auth();
+        Self::require_agent(&env, &principal, &agent, &asset_address, amount)?;
+        Self::unlock_unreserved(&env, principal, asset_address, amount)
+    }
+
+    /// Lock escrow for one order placed in the orderbook
+    ///
+    /// Only the orderbook set with `set_orderbook` may call this. The lock is
+    /// released by `release_order_escrow` or consumed when the order settles.
+    ///
+    /// # Arguments
+    /// * `commitment` - Commitment of the order the lock belongs to
+    /// * `owner` - Trader whose escrow is locked
+    /// * `asset_address` - Collateral token (payment asset for buys, RWA asset for sells)
+    /// * `amount` - Amount to lock
+    pub fn lock_order_escrow(
+        env: Env,
+        commitment: BytesN<32>,
+        owner: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        Self::require_orderbook(&env)?;
+
+        let mut locks = Self::order_locks(&env);
+        if locks.contains_key(commitment.clone()) {
+            return Err(SettlementError::OrderLockExists);
+        }
+
+        Self::lock(&env, owner.clone(), asset_address.clone(), amount)?;
+        Self::adjust_order_locked(&env, &owner, &asset_address, amount);
+        locks.set(
+            commitment,
+            OrderLock {
+                owner,
+                asset: asset_address,
+                amount,
+            },
+        );
+        env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+        Ok(())
+    }
+
+    /// Lock part of an order's note for the order
+    ///
+    /// Works like `lock_order_escrow`, but the lock is funded from the note of
+    /// `commitment` and owned by this contract. Whatever the order releases,
+    /// and what its trades pay it, goes back to the note.
+    ///
+    /// # Arguments
+    /// * `commitment` - Commitment of the order and its note
+    /// * `asset_address` - Collateral token (payment asset for buys, RWA asset for sells)
+    /// * `amount` - Amount to lock
+    pub fn lock_note_escrow(
+        env: Env,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        Self::require_orderbook(&env)?;
+
+        let mut locks = Self::order_locks(&env);
+        if locks.contains_key(commitment.clone()) {
+            return Err(SettlementError::OrderLockExists);
+        }
+        let balance =
+            Self::get_note_balance(env.clone(), commitment.clone(), asset_address.clone());
+        if amount <= 0 || balance < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        Self::adjust_note(&env, &commitment, &asset_address, -amount);
+        locks.set(
+            commitment,
+            OrderLock {
+                owner: env.current_contract_address(),
+                asset: asset_address,
+                amount,
+            },
+        );
+        env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+        Ok(())
+    }
+
+    /// Release the escrow locked for an order that was cancelled, expired or filled
+    ///
+    /// Only the orderbook set with `set_orderbook` may release a lock. Orders
+    /// without a lock, or whose lock is within `retain`, are left unchanged.
+    ///
+    /// # Arguments
+    /// * `commitment` - Commitment of the order
+    /// * `retain` - Amount to keep locked for fills still awaiting settlement
+    ///
+    /// # Returns
+    /// * The amount released
+    pub fn release_order_escrow(
+        env: Env,
+        commitment: BytesN<32>,
+        retain: i128,
+    ) -> Result<i128, SettlementError> {
+        Self::require_orderbook(&env)?;
+        let mut locks = Self::order_locks(&env);
+        let mut order_lock = match locks.get(commitment.clone()) {
+            Some(order_lock) if order_lock.amount > retain => order_lock,
+            _ => return Ok(0),
+        };
+
+        let released = order_lock.amount - retain.max(0);
+        order_lock.amount -= released;
+        if order_lock.amount == 0 {
+            locks.remove(commitment.clone());
+        } else {
+            locks.set(commitment.clone(), order_lock.clone());
+        }
+        env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+        Self::return_unused(&env, &commitment, &order_lock, released)?;
+        Ok(released)
+    }
+
+    /// Get the escrow locked for an order, if any
+    pub fn get_order_lock(env: Env, commitment: BytesN<32>) -> Option<OrderLock> {
+        Self::order_locks(&env).get(commitment)
+    }
+
+    /// Get how much of a participant's locked balance is held by order locks
+    pub fn get_order_locked_balance(env: Env, participant: Address, asset: Address) -> i128 {
+        Self::order_locked(&env).get(EscrowKey { participant, asset }).unwrap_or(0)
+    }
+
+    /// Get an order's running fill commitment; none until its first partial fill settles
+    pub fn get_fill_commitment(env: Env, commitment: BytesN<32>) -> Option<BytesN<32>> {
+        Self::fill_commitments(&env).get(commitment)
+    }
+
+    /// Move a rotated participant's escrow to their new trading address
+    ///
+    /// Anyone may call this once the registry has approved the rotation. The
+    /// escrow, locked balances and today's usage for each listed asset, the
+    /// listed order locks the old address owns, and today's total usage are
+    /// added to the new address. Each call moves at most 50 assets and
+    /// commitments together; call it again with the rest.
+    ///
+    /// # Arguments
+    /// * `old_address` - Participant's retired trading address
+    /// * `assets` - Assets whose balances and usage to move
+    /// * `commitments` - Orders whose collateral locks to reassign
+    pub fn apply_key_rotation(
+        env: Env,
+        old_address: Address,
+        assets: Vec<Address>,
+        commitments: Vec<BytesN<32>>,
+    ) -> Result<(), SettlementError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(&env, &registry_address);
+        let new_address = registry_client
+            .get_rotated_address(&old_address)
+            .ok_or(SettlementError::KeyNotRotated)?;
+        if assets.len() + commitments.len() > MAX_ROTATION_BATCH {
+            return Err(SettlementError::RotationBatchTooLarge);
+        }
+
+        for map_key in [ESCROW_KEY, LOCKED_KEY, ORDER_LOCKED_KEY] {
+            Self::move_amounts(&env, &map_key, &assets, &old_address, &new_address);
         }
+        Self::move_order_locks(&env, &commitments, &old_address, &new_address);
+        Self::move_usage(&env, &assets, &old_address, &new_address);
 
-        Self::subtract_locked_balance(&env, &trader, &asset_address, amount)?;
+        KeyRotationApplied {
+            old_address,
+            new_address,
+        }
+        .publish(&env);
         Ok(())
     }
 
@@ -220,6 +652,18 @@ impl DarkPoolSettlement {
      * 3. Checks and marks nullifier to prevent double-settlement
      * 4. Executes atomic swap of assets
      *
+     * Each leg is paid from the order lock of the commitment in the proof, and
+     * the rest of that lock is released. Orders without a lock cannot settle.
+     *
+     * Partial fill proofs (partial_fill_settlement.circom) add four signals after
+     * the others: each order's fill commitment before and after this fill. The
+     * before values must equal the stored ones (zero for an unfilled order), the
+     * after values are stored, and the order locks keep what the fill did not use.
+     *
+     * The proof's assetHash, matchedQuantity and executionPrice must equal the
+     * registry's `compute_asset_hash(asset_address)`, `quantity` and `price`,
+     * or the trade is rejected as `InvalidProof`.
+     *
      * Circuit public signals format (7 signals):
      * [0] buyCommitment - Poseidon hash of buy order
      * [1] sellCommitment - Poseidon hash of sell order
@@ -252,6 +696,9 @@ impl DarkPoolSettlement {
         proof_bytes: Bytes,
         pub_signals_bytes: Bytes,
     ) -> Result<SettlementRecord, SettlementError> {
+        // The asset's market must be open
+        Self::check_market_open(env.clone(), asset_address.clone())?;
+
         // NOTE: require_auth removed for both parties because:
         // 1. ZK proof cryptographically proves both parties agreed to the trade
         // 2. Funds are already in escrow (deposited with proper auth)
@@ -271,23 +718,105 @@ impl DarkPoolSettlement {
         // [4] matchedQuantity
         // [5] executionPrice
         // [6] whitelistRoot
+        // [7] assetRoot - root of the registry's tradable asset tree
+        // [8] validAt - time up to which both parties' KYC was proven unexpired
+        // Partial fill proofs end with six more signals:
+        // [9] buyFillBefore, [10] buyFillAfter, [11] sellFillBefore, [12] sellFillAfter,
+        // [13] buyComplete, [14] sellComplete - whether the fill completes each order
         let pub_signals = Self::parse_public_signals(&env, &pub_signals_bytes)?;
 
-        if pub_signals.len() != 7 {
+        let signal_count = pub_signals.len();
+        if signal_count != 9 && signal_count != 15 {
             return Err(SettlementError::InvalidProof);
         }
+        let fill_signals = if signal_count == 15 {
+            Some(pub_signals.slice(9..13))
+        } else {
+            None
+        };
 
-        // TODO: Re-enable whitelist check for production
-        // For testnet testing, whitelist check is temporarily disabled
-        // because on-chain registry uses different Poseidon computation
-        //
-        // let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
-        // let registry_client = registry_wasm::Client::new(&env, &registry_address);
-        // let whitelist_root = registry_client.get_whitelist_root();
-        // let proof_whitelist_root = pub_signals.get(6).unwrap();
-        // if proof_whitelist_root != whitelist_root {
-        //     return Err(SettlementError::WhitelistRootMismatch);
-        // }
+        // A whole-order proof fills both orders completely
+        let (buy_complete, sell_complete) = match &fill_signals {
+            Some(_) => (
+                Self::signal_flag(&pub_signals.get(13).unwrap())?,
+                Self::signal_flag(&pub_signals.get(14).unwrap())?,
+            ),
+            None => (true, true),
+        };
+        let buy_commitment = pub_signals.get(1).unwrap();
+        let sell_commitment = pub_signals.get(2).unwrap();
+
+        // Both parties must be allowed to trade this asset under the registry's policy.
+        // A note's owner is unknown; its order's relay proof covered the whitelist.
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(&env, &registry_address);
+        for party in [&buyer, &seller] {
+            let eligible = if Self::is_note(&env, party) {
+                registry_client.is_asset_eligible(&asset_address)
+            } else {
+                registry_client.is_eligible_for(party, &asset_address)
+            };
+            if !eligible {
+                return Err(SettlementError::ParticipantNotEligible);
+            }
+        }
+
+        // The pair must be listed and the trade must respect its tick/lot sizes
+        match registry_client.try_validate_trade(&asset_address, &payment_asset, &quantity, &price) {
+            Ok(Ok(())) => {}
+            Err(Ok(registry_wasm::RegistryError::PairNotFound))
+            | Err(Ok(registry_wasm::RegistryError::PairNotActive)) => {
+                return Err(SettlementError::PairNotListed);
+            }
+            _ => return Err(SettlementError::TradeConstraintViolation),
+        }
+
+        // Neither party may exceed its trading limits in the registry
+        for party in [&buyer, &seller] {
+            if !Self::is_note(&env, party) {
+                Self::check_trading_limits(&env, &registry_client, party, &asset_address, price)?;
+            }
+        }
+
+        // The proof's whitelist root must be current or replaced within the freshness window
+        let proof_whitelist_root = pub_signals.get(6).unwrap();
+        let root_window = Self::get_root_freshness_window(env.clone());
+        if !registry_client.is_root_fresh(&proof_whitelist_root, &root_window) {
+            return Err(SettlementError::WhitelistRootMismatch);
+        }
+
+        // The asset must be proven listed against a fresh asset root, like the whitelist
+        if !registry_client.is_asset_root_fresh(&pub_signals.get(7).unwrap(), &root_window) {
+            return Err(SettlementError::AssetNotEligible);
+        }
+
+        // The circuit checked both parties' KYC expiry against validAt, which must not have passed
+        let valid_at = Self::signal_u64(&pub_signals.get(8).unwrap())
+            .ok_or(SettlementError::InvalidProof)?;
+        if valid_at < env.ledger().timestamp() {
+            return Err(SettlementError::ProofExpired);
+        }
+
+        // Fill proofs must continue each order's running fill commitment. Whole-order
+        // proofs cannot settle an order that already has fills.
+        let mut fills = Self::fill_commitments(&env);
+        match &fill_signals {
+            Some(signals) => {
+                let zero = BytesN::from_array(&env, &[0u8; 32]);
+                let buy_before = fills.get(buy_commitment.clone()).unwrap_or(zero.clone());
+                let sell_before = fills.get(sell_commitment.clone()).unwrap_or(zero);
+                if signals.get(0).unwrap() != buy_before || signals.get(2).unwrap() != sell_before {
+                    return Err(SettlementError::FillMismatch);
+                }
+            }
+            None => {
+                if fills.contains_key(buy_commitment.clone())
+                    || fills.contains_key(sell_commitment.clone())
+                {
+                    return Err(SettlementError::FillMismatch);
+                }
+            }
+        }
 
         // Check nullifier not used (signal index 0 - it's the output)
         let nullifier = pub_signals.get(0).unwrap();
@@ -295,26 +824,92 @@ impl DarkPoolSettlement {
             return Err(SettlementError::NullifierUsed);
         }
 
-        // Verify ZK proof
+        // The proof must be about this trade: the asset, quantity and price settled here
+        if pub_signals.get(3).unwrap() != registry_client.compute_asset_hash(&asset_address)
+            || Self::signal_i128(&pub_signals.get(4).unwrap()) != Some(quantity)
+            || Self::signal_i128(&pub_signals.get(5).unwrap()) != Some(price)
+        {
+            return Err(SettlementError::InvalidProof);
+        }
+
+        // Verify ZK proof with the key of the circuit that produced it
         let verifier_address: Address = env.storage().instance().get(&VERIFIER_KEY).unwrap();
-        let vk_bytes: Bytes = env.storage().instance().get(&SETTLEMENT_VK_KEY).unwrap();
+        let vk_bytes: Bytes = match &fill_signals {
+            None => env.storage().instance().get(&SETTLEMENT_VK_KEY).unwrap(),
+            Some(_) => env
+                .storage()
+                .instance()
+                .get(&PARTIAL_FILL_VK_KEY)
+                .ok_or(SettlementError::PartialFillVkNotSet)?,
+        };
 
         let verifier_client = verifier_wasm::Client::new(&env, &verifier_address);
 
-        let is_valid = verifier_client.verify_proof_bytes(&vk_bytes, &proof_bytes, &pub_signals_bytes);
-        if !is_valid {
+        // A key whose IC length does not fit the signals is reported as an invalid proof
+        let verified =
+            verifier_client.try_verify_proof_bytes(&vk_bytes, &proof_bytes, &pub_signals_bytes);
+        if verified != Ok(Ok(true)) {
             return Err(SettlementError::InvalidProof);
         }
 
+        // Consume the escrow locked for each order; incomplete orders keep the rest
+        Self::consume_order_lock(
+            &env,
+            &buy_commitment,
+            &buyer,
+            &payment_asset,
+            price,
+            buy_complete,
+        )?;
+        Self::consume_order_lock(
+            &env,
+            &sell_commitment,
+            &seller,
+            &asset_address,
+            quantity,
+            sell_complete,
+        )?;
+
+        // Advance both orders' running fill commitments
+        if let Some(signals) = &fill_signals {
+            fills.set(buy_commitment.clone(), signals.get(1).unwrap());
+            fills.set(sell_commitment.clone(), signals.get(3).unwrap());
+            env.storage().instance().set(&FILLS_KEY, &fills);
+        }
+
         // Execute atomic swap - seller sends asset to buyer
-        Self::transfer_from_escrow(&env, &seller, &buyer, &asset_address, quantity)?;
+        Self::transfer_from_escrow(
+            &env,
+            &seller,
+            &buyer,
+            &buy_commitment,
+            &asset_address,
+            quantity,
+        )?;
 
         // Buyer sends payment to seller
-        Self::transfer_from_escrow(&env, &buyer, &seller, &payment_asset, price)?;
+        Self::transfer_from_escrow(
+            &env,
+            &buyer,
+            &seller,
+            &sell_commitment,
+            &payment_asset,
+            price,
+        )?;
 
         // Mark nullifier as used
         Self::mark_nullifier_used(&env, &nullifier);
 
+        // Count the trade notional against both parties' daily usage
+        for party in [&buyer, &seller] {
+            if !Self::is_note(&env, party) {
+                Self::record_usage(&env, party, &asset_address, price);
+            }
+        }
+
+        // Halt the asset if this trade moved its price past the circuit breaker
+        Self::check_circuit_breaker(&env, &asset_address, quantity, price);
+
         // Create settlement record
         let record = SettlementRecord {
             match_id: match_id.clone(),
@@ -336,6 +931,38 @@ impl DarkPoolSettlement {
         settlements.push_back(record.clone());
         env.storage().instance().set(&SETTLEMENTS_KEY, &settlements);
 
+        // The orderbook releases the orders' collateral only for a match it recorded
+        // with these orders and amounts, and settles the orders this trade completed
+        let orderbook: Address = env
+            .storage()
+            .instance()
+            .get(&ORDERBOOK_KEY)
+            .ok_or(SettlementError::OrderbookNotSet)?;
+        let marked = OrderbookClient::new(&env, &orderbook).try_mark_settled(
+            &match_id,
+            &buy_commitment,
+            &sell_commitment,
+            &quantity,
+            &price,
+            &buy_complete,
+            &sell_complete,
+        );
+        if marked != Ok(Ok(())) {
+            return Err(SettlementError::MatchMismatch);
+        }
+
+        TradeSettled {
+            match_id,
+            asset_address,
+            buyer,
+            seller,
+            payment_asset,
+            quantity,
+            price,
+            nullifier,
+        }
+        .publish(&env);
+
         Ok(record)
     }
 
@@ -400,55 +1027,647 @@ impl DarkPoolSettlement {
             .get(&SETTLEMENTS_KEY)
             .unwrap_or(vec![&env]);
 
-        for s in settlements.iter() {
-            if s.match_id == match_id {
-                return Some(s);
-            }
-        }
-        None
-    }
-
-    /// Get admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage().instance().get(&ADMIN_KEY).unwrap()
+        settlements.iter().find(|s| s.match_id == match_id)
     }
 
-    /// Get registry address
-    pub fn get_registry(env: Env) -> Address {
-        env.storage().instance().get(&REGISTRY_KEY).unwrap()
+    /// Get the notional a participant may still settle in an asset today
+    ///
+    /// # Arguments
+    /// * `participant` - Trading address
+    /// * `asset` - RWA token address
+    pub fn get_trading_headroom(env: Env, participant: Address, asset: Address) -> TradingHeadroom {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(&env, &registry_address);
+        Self::trading_headroom(&env, &registry_client, &participant, &asset)
     }
 
-    /// Get verifier address
-    pub fn get_verifier(env: Env) -> Address {
-        env.storage().instance().get(&VERIFIER_KEY).unwrap()
+    /// Get the notional a participant has settled today across all assets
+    pub fn get_daily_usage(env: Env, participant: Address) -> i128 {
+        let usage: Map<Address, DailyUsage> = env
+            .storage()
+            .instance()
+            .get(&DAILY_USAGE_KEY)
+            .unwrap_or(Map::new(&env));
+        Self::usage_today(&env, usage.get(participant))
     }
 
-    // Internal helper functions
-
-    fn add_escrow_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) -> i128 {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
-        };
-        let mut escrow: Map<EscrowKey, i128> = env
+    /// Get the notional a participant has settled today in one asset
+    pub fn get_daily_asset_usage(env: Env, participant: Address, asset: Address) -> i128 {
+        let usage: Map<EscrowKey, DailyUsage> = env
             .storage()
             .instance()
-            .get(&ESCROW_KEY)
+            .get(&ASSET_USAGE_KEY)
             .unwrap_or(Map::new(&env));
-
-        let current = escrow.get(key.clone()).unwrap_or(0);
-        let new_balance = current + amount;
-        escrow.set(key, new_balance);
-        env.storage().instance().set(&ESCROW_KEY, &escrow);
-        new_balance
+        Self::usage_today(&env, usage.get(EscrowKey { participant, asset }))
     }
 
-    fn subtract_escrow_balance(
-        env: &Env,
-        participant: &Address,
-        asset: &Address,
-        amount: i128,
-    ) -> Result<i128, SettlementError> {
+    /// Set how long (seconds) a replaced whitelist or asset root is still accepted in proofs
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `window` - Freshness window in seconds
+    pub fn set_root_freshness_window(
+        env: Env,
+        admin: Address,
+        window: u64,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&ROOT_WINDOW_KEY, &window);
+
+        RootFreshnessWindowSet { window }.publish(&env);
+        Ok(())
+    }
+
+    /// Get the root freshness window in seconds
+    pub fn get_root_freshness_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ROOT_WINDOW_KEY)
+            .unwrap_or(DEFAULT_ROOT_FRESHNESS_WINDOW)
+    }
+
+    /// Open, halt or close trading in an asset
+    ///
+    /// The asset's active issuer in the registry may halt it, e.g. ahead of a
+    /// corporate action; reopening or closing an asset is left to the admin.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin, or the asset's issuer when halting (must authenticate)
+    /// * `asset` - RWA asset to change
+    /// * `status` - New trading status
+    /// * `reason` - Short reason code, e.g. `news` or `corp_act`
+    pub fn set_trading_status(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        status: TradingStatus,
+        reason: Symbol,
+    ) -> Result<(), SettlementError> {
+        caller.require_auth();
+        if Self::require_admin(&env, &caller).is_err()
+            && (status != TradingStatus::Halted || !Self::is_active_issuer(&env, &caller, &asset))
+        {
+            return Err(SettlementError::OnlyAdmin);
+        }
+        Self::store_market_status(&env, asset, status, reason);
+        Ok(())
+    }
+
+    /// Restrict trading in an asset to scheduled UTC hours
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `asset` - RWA asset to schedule
+    /// * `window` - Trading days and hours (replaces any existing window)
+    pub fn set_trading_window(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        window: TradingWindow,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if window.days == 0
+            || window.days > 0x7f
+            || window.open_time >= window.close_time
+            || window.close_time > SECONDS_PER_DAY
+        {
+            return Err(SettlementError::InvalidTradingWindow);
+        }
+
+        let mut windows: Map<Address, TradingWindow> = env
+            .storage()
+            .instance()
+            .get(&TRADING_WINDOWS_KEY)
+            .unwrap_or(Map::new(&env));
+        windows.set(asset.clone(), window.clone());
+        env.storage().instance().set(&TRADING_WINDOWS_KEY, &windows);
+
+        TradingWindowSet {
+            asset,
+            days: window.days,
+            open_time: window.open_time,
+            close_time: window.close_time,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Remove an asset's trading window so it trades around the clock
+    pub fn remove_trading_window(
+        env: Env,
+        admin: Address,
+        asset: Address,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut windows: Map<Address, TradingWindow> = env
+            .storage()
+            .instance()
+            .get(&TRADING_WINDOWS_KEY)
+            .unwrap_or(Map::new(&env));
+        windows.remove(asset.clone());
+        env.storage().instance().set(&TRADING_WINDOWS_KEY, &windows);
+
+        TradingWindowRemoved { asset }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the largest unit price move (basis points) allowed between consecutive trades
+    ///
+    /// A settled trade that moves the price further halts the asset with reason
+    /// `breaker`. Zero disables the circuit breaker.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `asset` - RWA asset to protect
+    /// * `max_move_bps` - Maximum price move in basis points
+    pub fn set_circuit_breaker(
+        env: Env,
+        admin: Address,
+        asset: Address,
+        max_move_bps: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut breakers: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&BREAKERS_KEY)
+            .unwrap_or(Map::new(&env));
+        if max_move_bps == 0 {
+            breakers.remove(asset.clone());
+        } else {
+            breakers.set(asset.clone(), max_move_bps);
+        }
+        env.storage().instance().set(&BREAKERS_KEY, &breakers);
+
+        CircuitBreakerSet {
+            asset,
+            max_move_bps,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get an asset's trading status; `None` means it was never changed and is open
+    pub fn get_market_status(env: Env, asset: Address) -> Option<MarketStatus> {
+        let statuses: Map<Address, MarketStatus> = env
+            .storage()
+            .instance()
+            .get(&MARKET_STATUS_KEY)
+            .unwrap_or(Map::new(&env));
+        statuses.get(asset)
+    }
+
+    /// Get an asset's scheduled trading window, if any
+    pub fn get_trading_window(env: Env, asset: Address) -> Option<TradingWindow> {
+        let windows: Map<Address, TradingWindow> = env
+            .storage()
+            .instance()
+            .get(&TRADING_WINDOWS_KEY)
+            .unwrap_or(Map::new(&env));
+        windows.get(asset)
+    }
+
+    /// Get an asset's circuit breaker threshold in basis points (0 when disabled)
+    pub fn get_circuit_breaker(env: Env, asset: Address) -> u32 {
+        let breakers: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&BREAKERS_KEY)
+            .unwrap_or(Map::new(&env));
+        breakers.get(asset).unwrap_or(0)
+    }
+
+    /// Check that an asset can trade now
+    ///
+    /// Fails with `MarketHalted` while the asset is halted, and with `MarketClosed`
+    /// when it is closed or outside its trading window. Called by the orderbook on
+    /// order submission and matching.
+    pub fn check_market_open(env: Env, asset: Address) -> Result<(), SettlementError> {
+        match Self::get_market_status(env.clone(), asset.clone()).map(|m| m.status) {
+            Some(TradingStatus::Halted) => return Err(SettlementError::MarketHalted),
+            Some(TradingStatus::Closed) => return Err(SettlementError::MarketClosed),
+            _ => {}
+        }
+
+        if let Some(window) = Self::get_trading_window(env.clone(), asset) {
+            let now = env.ledger().timestamp();
+            // 1970-01-01 was a Thursday, weekday 3 counting from Monday
+            let weekday = (now / SECONDS_PER_DAY + 3) % 7;
+            let time = now % SECONDS_PER_DAY;
+            if window.days & (1 << weekday) == 0
+                || time < window.open_time
+                || time >= window.close_time
+            {
+                return Err(SettlementError::MarketClosed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the orderbook contract allowed to lock and release order escrow
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `orderbook` - Address of the orderbook contract
+    pub fn set_orderbook(
+        env: Env,
+        admin: Address,
+        orderbook: Address,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&ORDERBOOK_KEY, &orderbook);
+
+        OrderbookSet { orderbook }.publish(&env);
+        Ok(())
+    }
+
+    /// Get the orderbook address, if set
+    pub fn get_orderbook(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ORDERBOOK_KEY)
+    }
+
+    /// Replace the verification key of the whole-order settlement circuit
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `vk_bytes` - Serialized verification key of `settlement_proof.circom`
+    pub fn set_settlement_vk(
+        env: Env,
+        admin: Address,
+        vk_bytes: Bytes,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&SETTLEMENT_VK_KEY, &vk_bytes);
+
+        let vk_hash = env.crypto().sha256(&vk_bytes).into();
+        SettlementVkSet { vk_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the verification key of the partial fill settlement circuit
+    ///
+    /// Partial fill proofs fail with `PartialFillVkNotSet` until this is called.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authenticate)
+    /// * `vk_bytes` - Serialized verification key of `partial_fill_settlement.circom`
+    pub fn set_partial_fill_vk(
+        env: Env,
+        admin: Address,
+        vk_bytes: Bytes,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&PARTIAL_FILL_VK_KEY, &vk_bytes);
+
+        let vk_hash = env.crypto().sha256(&vk_bytes).into();
+        PartialFillVkSet { vk_hash }.publish(&env);
+        Ok(())
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN_KEY).unwrap()
+    }
+
+    /// Get registry address
+    pub fn get_registry(env: Env) -> Address {
+        env.storage().instance().get(&REGISTRY_KEY).unwrap()
+    }
+
+    /// Get verifier address
+    pub fn get_verifier(env: Env) -> Address {
+        env.storage().instance().get(&VERIFIER_KEY).unwrap()
+    }
+
+    /// Upgrade the contract code, keeping its storage
+    ///
+    /// Call `migrate` afterwards when the new code expects a newer storage schema.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm to switch to
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded {
+            wasm_hash: new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Convert storage from the stored schema version to `SCHEMA_VERSION`
+    ///
+    /// Runs one migration step per version, recording progress after each.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    ///
+    /// # Returns
+    /// * The schema version after migrating
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut version = Self::get_schema_version(env.clone());
+        if version > SCHEMA_VERSION {
+            return Err(SettlementError::UnsupportedSchemaVersion);
+        }
+
+        while version < SCHEMA_VERSION {
+            Self::migrate_step(&env, version)?;
+            version += 1;
+            env.storage().instance().set(&SCHEMA_VERSION_KEY, &version);
+            SchemaMigrated { version }.publish(&env);
+        }
+        Ok(version)
+    }
+
+    /// Get the schema version of the stored data
+    ///
+    /// Settlement contracts deployed before schema versioning have no stored
+    /// version and hold the schema 1 layout.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1)
+    }
+
+    // Internal helper functions
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), SettlementError> {
+        let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
+        if *caller != admin {
+            return Err(SettlementError::OnlyAdmin);
+        }
+        Ok(())
+    }
+
+    /// Convert storage from schema `from_version` to `from_version + 1`
+    ///
+    /// Version 1 is the original layout; each bump of `SCHEMA_VERSION` adds the
+    /// step converting from the previous version here.
+    fn migrate_step(env: &Env, from_version: u32) -> Result<(), SettlementError> {
+        match from_version {
+            // 1 -> 2: total the order locks per participant and asset, which
+            // `unlock_escrow` now keeps out of reach, and drop the relayed order
+            // marks; relayed locks stay with the relayer that funded them
+            1 => {
+                let mut order_locked: Map<EscrowKey, i128> = Map::new(env);
+                for order_lock in Self::order_locks(env).values().iter() {
+                    let key = EscrowKey {
+                        participant: order_lock.owner,
+                        asset: order_lock.asset,
+                    };
+                    let total = order_locked.get(key.clone()).unwrap_or(0) + order_lock.amount;
+                    order_locked.set(key, total);
+                }
+                env.storage().instance().set(&ORDER_LOCKED_KEY, &order_locked);
+                env.storage().instance().remove(&symbol_short!("relayed"));
+                Ok(())
+            }
+            _ => Err(SettlementError::UnsupportedSchemaVersion),
+        }
+    }
+
+    /// Require the call to come from the configured orderbook contract
+    fn require_orderbook(env: &Env) -> Result<(), SettlementError> {
+        let orderbook: Address = env
+            .storage()
+            .instance()
+            .get(&ORDERBOOK_KEY)
+            .ok_or(SettlementError::OrderbookNotSet)?;
+        orderbook.require_auth();
+        Ok(())
+    }
+
+    fn order_locks(env: &Env) -> Map<BytesN<32>, OrderLock> {
+        env.storage()
+            .instance()
+            .get(&ORDER_LOCKS_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Escrow held by order locks, per participant and asset
+    fn order_locked(env: &Env) -> Map<EscrowKey, i128> {
+        env.storage()
+            .instance()
+            .get(&ORDER_LOCKED_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Add `amount` (negative to subtract) to a participant's escrow held by order locks
+    fn adjust_order_locked(env: &Env, participant: &Address, asset: &Address, amount: i128) {
+        let key = EscrowKey {
+            participant: participant.clone(),
+            asset: asset.clone(),
+        };
+        let mut order_locked = Self::order_locked(env);
+        let total = order_locked.get(key.clone()).unwrap_or(0) + amount;
+        if total == 0 {
+            order_locked.remove(key);
+        } else {
+            order_locked.set(key, total);
+        }
+        env.storage().instance().set(&ORDER_LOCKED_KEY, &order_locked);
+    }
+
+    fn fill_commitments(env: &Env) -> Map<BytesN<32>, BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&FILLS_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Take a settling order's lock, releasing whatever the trade does not use
+    ///
+    /// The traded `amount` stays in the aggregate locked balance for
+    /// `transfer_from_escrow` to spend. A fill that does not `complete` the
+    /// order only takes `amount` and leaves the rest locked for later fills.
+    fn consume_order_lock(
+        env: &Env,
+        commitment: &BytesN<32>,
+        owner: &Address,
+        asset: &Address,
+        amount: i128,
+        complete: bool,
+    ) -> Result<(), SettlementError> {
+        let mut locks = Self::order_locks(env);
+        let order_lock = locks
+            .get(commitment.clone())
+            .ok_or(SettlementError::OrderLockNotFound)?;
+        if order_lock.owner != *owner || order_lock.asset != *asset {
+            return Err(SettlementError::OrderLockMismatch);
+        }
+        if order_lock.amount < amount {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+
+        // The traded amount leaves the order lock; only the payment spends it
+        if !Self::is_note(env, owner) {
+            Self::adjust_order_locked(env, owner, asset, -amount);
+        }
+        if !complete && order_lock.amount > amount {
+            let mut remaining = order_lock.clone();
+            remaining.amount -= amount;
+            locks.set(commitment.clone(), remaining);
+            env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+        } else {
+            locks.remove(commitment.clone());
+            env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+            if order_lock.amount > amount {
+                Self::return_unused(env, commitment, &order_lock, order_lock.amount - amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Give back collateral an order lock no longer holds
+    ///
+    /// Participant locks unlock it in the owner's escrow; note-funded locks
+    /// return it to the note.
+    fn return_unused(
+        env: &Env,
+        commitment: &BytesN<32>,
+        order_lock: &OrderLock,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        if Self::is_note(env, &order_lock.owner) {
+            Self::adjust_note(env, commitment, &order_lock.asset, amount);
+            return Ok(());
+        }
+        Self::adjust_order_locked(env, &order_lock.owner, &order_lock.asset, -amount);
+        Self::unlock(env, order_lock.owner.clone(), order_lock.asset.clone(), amount)
+    }
+
+    /// Whether a trade party stands for the note of its order rather than a participant
+    fn is_note(env: &Env, party: &Address) -> bool {
+        *party == env.current_contract_address()
+    }
+
+    fn notes(env: &Env) -> Map<NoteKey, i128> {
+        env.storage()
+            .instance()
+            .get(&NOTES_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Add `delta` to a note's balance, returning the new balance
+    fn adjust_note(env: &Env, commitment: &BytesN<32>, asset: &Address, delta: i128) -> i128 {
+        let mut notes = Self::notes(env);
+        let key = NoteKey {
+            commitment: commitment.clone(),
+            asset: asset.clone(),
+        };
+        let balance = notes.get(key.clone()).unwrap_or(0) + delta;
+        if balance == 0 {
+            notes.remove(key);
+        } else {
+            notes.set(key, balance);
+        }
+        env.storage().instance().set(&NOTES_KEY, &notes);
+        balance
+    }
+
+    /// Verify `agent` may act for `principal` on `asset` for `amount` under the registry
+    fn require_agent(
+        env: &Env,
+        principal: &Address,
+        agent: &Address,
+        asset: &Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if !registry_client.is_agent_authorized(principal, agent, asset, &amount) {
+            return Err(SettlementError::AgentNotAuthorized);
+        }
+        Ok(())
+    }
+
+    /// Lock part of a participant's available escrow
+    fn lock(
+        env: &Env,
+        trader: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        let escrow_balance = Self::get_escrow_balance(env.clone(), trader.clone(), asset_address.clone());
+        let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
+        let available = escrow_balance - locked_balance;
+
+        if available < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        Self::add_locked_balance(env, &trader, &asset_address, amount);
+
+        EscrowLocked {
+            locked_balance: locked_balance + amount,
+            participant: trader,
+            asset: asset_address,
+            amount,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// Release locked escrow that no order lock holds
+    fn unlock_unreserved(
+        env: &Env,
+        trader: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
+        let reserved =
+            Self::get_order_locked_balance(env.clone(), trader.clone(), asset_address.clone());
+        if locked_balance - reserved < amount {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+        Self::unlock(env, trader, asset_address, amount)
+    }
+
+    /// Release part of a participant's locked escrow
+    fn unlock(
+        env: &Env,
+        trader: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
+        if locked_balance < amount {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+
+        Self::subtract_locked_balance(env, &trader, &asset_address, amount)?;
+
+        EscrowUnlocked {
+            locked_balance: locked_balance - amount,
+            participant: trader,
+            asset: asset_address,
+            amount,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    fn add_escrow_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) -> i128 {
         let key = EscrowKey {
             participant: participant.clone(),
             asset: asset.clone(),
@@ -457,7 +1676,30 @@ impl DarkPoolSettlement {
             .storage()
             .instance()
             .get(&ESCROW_KEY)
-            .unwrap_or(Map::new(&env));
+            .unwrap_or(Map::new(env));
+
+        let current = escrow.get(key.clone()).unwrap_or(0);
+        let new_balance = current + amount;
+        escrow.set(key, new_balance);
+        env.storage().instance().set(&ESCROW_KEY, &escrow);
+        new_balance
+    }
+
+    fn subtract_escrow_balance(
+        env: &Env,
+        participant: &Address,
+        asset: &Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        let key = EscrowKey {
+            participant: participant.clone(),
+            asset: asset.clone(),
+        };
+        let mut escrow: Map<EscrowKey, i128> = env
+            .storage()
+            .instance()
+            .get(&ESCROW_KEY)
+            .unwrap_or(Map::new(env));
 
         let current = escrow.get(key.clone()).unwrap_or(0);
         if current < amount {
@@ -479,7 +1721,7 @@ impl DarkPoolSettlement {
             .storage()
             .instance()
             .get(&LOCKED_KEY)
-            .unwrap_or(Map::new(&env));
+            .unwrap_or(Map::new(env));
 
         let current = locked.get(key.clone()).unwrap_or(0);
         locked.set(key, current + amount);
@@ -500,7 +1742,7 @@ impl DarkPoolSettlement {
             .storage()
             .instance()
             .get(&LOCKED_KEY)
-            .unwrap_or(Map::new(&env));
+            .unwrap_or(Map::new(env));
 
         let current = locked.get(key.clone()).unwrap_or(0);
         if current < amount {
@@ -512,23 +1754,292 @@ impl DarkPoolSettlement {
         Ok(())
     }
 
+    /// Pay one leg of a trade
+    ///
+    /// A note's payment was already taken out of its order lock, and a note
+    /// receives into the note of `to_commitment`.
     fn transfer_from_escrow(
         env: &Env,
         from: &Address,
         to: &Address,
+        to_commitment: &BytesN<32>,
         asset: &Address,
         amount: i128,
     ) -> Result<(), SettlementError> {
         // Subtract from sender's escrow and locked
-        Self::subtract_locked_balance(env, from, asset, amount)?;
-        Self::subtract_escrow_balance(env, from, asset, amount)?;
+        if !Self::is_note(env, from) {
+            Self::subtract_locked_balance(env, from, asset, amount)?;
+            Self::subtract_escrow_balance(env, from, asset, amount)?;
+        }
 
         // Add to receiver's escrow
-        Self::add_escrow_balance(env, to, asset, amount);
+        if Self::is_note(env, to) {
+            Self::adjust_note(env, to_commitment, asset, amount);
+        } else {
+            Self::add_escrow_balance(env, to, asset, amount);
+        }
+
+        Ok(())
+    }
+
+    fn trading_headroom(
+        env: &Env,
+        registry_client: &registry_wasm::Client,
+        participant: &Address,
+        asset: &Address,
+    ) -> TradingHeadroom {
+        let Some(limits) = registry_client.get_trading_limits(participant) else {
+            return TradingHeadroom {
+                per_trade: None,
+                daily: None,
+                daily_asset: None,
+            };
+        };
+
+        let daily_used = Self::get_daily_usage(env.clone(), participant.clone());
+        let asset_used =
+            Self::get_daily_asset_usage(env.clone(), participant.clone(), asset.clone());
+
+        TradingHeadroom {
+            per_trade: limits.max_trade_notional,
+            daily: limits.max_daily_notional.map(|l| (l - daily_used).max(0)),
+            daily_asset: limits
+                .max_daily_asset_notional
+                .get(asset.clone())
+                .map(|l| (l - asset_used).max(0)),
+        }
+    }
 
+    fn check_trading_limits(
+        env: &Env,
+        registry_client: &registry_wasm::Client,
+        participant: &Address,
+        asset: &Address,
+        notional: i128,
+    ) -> Result<(), SettlementError> {
+        let headroom = Self::trading_headroom(env, registry_client, participant, asset);
+        if headroom.per_trade.is_some_and(|h| notional > h) {
+            return Err(SettlementError::TradeLimitExceeded);
+        }
+        if headroom.daily.is_some_and(|h| notional > h) {
+            return Err(SettlementError::DailyLimitExceeded);
+        }
+        if headroom.daily_asset.is_some_and(|h| notional > h) {
+            return Err(SettlementError::AssetDailyLimitExceeded);
+        }
         Ok(())
     }
 
+    /// Whether `issuer` is an active issuer and the asset was listed for it in the registry
+    fn is_active_issuer(env: &Env, issuer: &Address, asset: &Address) -> bool {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+        if registry_client.try_get_asset_issuer(asset) != Ok(Ok(Some(issuer.clone()))) {
+            return false;
+        }
+        matches!(registry_client.try_get_issuer(issuer), Ok(Ok(Some(record))) if record.is_active)
+    }
+
+    fn store_market_status(env: &Env, asset: Address, status: TradingStatus, reason: Symbol) {
+        let mut statuses: Map<Address, MarketStatus> = env
+            .storage()
+            .instance()
+            .get(&MARKET_STATUS_KEY)
+            .unwrap_or(Map::new(env));
+        statuses.set(
+            asset.clone(),
+            MarketStatus {
+                status,
+                reason: reason.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&MARKET_STATUS_KEY, &statuses);
+
+        TradingStatusChanged {
+            asset,
+            status,
+            reason,
+        }
+        .publish(env);
+    }
+
+    /// Record a trade as the asset's reference price, halting the asset when
+    /// its unit price moved past the circuit breaker threshold
+    fn check_circuit_breaker(env: &Env, asset: &Address, quantity: i128, price: i128) {
+        let mut references: Map<Address, (i128, i128)> = env
+            .storage()
+            .instance()
+            .get(&REFERENCE_PRICES_KEY)
+            .unwrap_or(Map::new(env));
+        let previous = references.get(asset.clone());
+        references.set(asset.clone(), (quantity, price));
+        env.storage().instance().set(&REFERENCE_PRICES_KEY, &references);
+
+        let max_move_bps = Self::get_circuit_breaker(env.clone(), asset.clone());
+        let Some((ref_quantity, ref_price)) = previous else {
+            return;
+        };
+        if max_move_bps == 0 {
+            return;
+        }
+
+        // Compare price / quantity with ref_price / ref_quantity without dividing;
+        // an overflow is treated as a tripped breaker
+        let tripped = match (price.checked_mul(ref_quantity), ref_price.checked_mul(quantity)) {
+            (Some(current), Some(reference)) => {
+                let moved = (current - reference).abs().checked_mul(BPS_DENOMINATOR);
+                let allowed = reference.checked_mul(i128::from(max_move_bps));
+                match (moved, allowed) {
+                    (Some(moved), Some(allowed)) => moved > allowed,
+                    _ => true,
+                }
+            }
+            _ => true,
+        };
+        if tripped {
+            Self::store_market_status(
+                env,
+                asset.clone(),
+                TradingStatus::Halted,
+                symbol_short!("breaker"),
+            );
+        }
+    }
+
+    /// Add the amounts `old` holds for `assets` in a balance map to `new`
+    fn move_amounts(
+        env: &Env,
+        map_key: &Symbol,
+        assets: &Vec<Address>,
+        old: &Address,
+        new: &Address,
+    ) {
+        let mut amounts: Map<EscrowKey, i128> = env
+            .storage()
+            .instance()
+            .get(map_key)
+            .unwrap_or(Map::new(env));
+
+        for asset in assets.iter() {
+            let key = EscrowKey {
+                participant: old.clone(),
+                asset: asset.clone(),
+            };
+            if let Some(amount) = amounts.get(key.clone()) {
+                let moved = EscrowKey {
+                    participant: new.clone(),
+                    asset,
+                };
+                let current = amounts.get(moved.clone()).unwrap_or(0);
+                amounts.remove(key);
+                amounts.set(moved, current + amount);
+            }
+        }
+        env.storage().instance().set(map_key, &amounts);
+    }
+
+    /// Reassign the locks of `commitments` owned by `old` to `new`
+    fn move_order_locks(env: &Env, commitments: &Vec<BytesN<32>>, old: &Address, new: &Address) {
+        let mut locks = Self::order_locks(env);
+        for commitment in commitments.iter() {
+            if let Some(mut order_lock) = locks.get(commitment.clone())
+                && order_lock.owner == *old
+            {
+                order_lock.owner = new.clone();
+                locks.set(commitment, order_lock);
+            }
+        }
+        env.storage().instance().set(&ORDER_LOCKS_KEY, &locks);
+    }
+
+    /// Add today's daily usage of `old`, and its usage of `assets`, to `new`
+    fn move_usage(env: &Env, assets: &Vec<Address>, old: &Address, new: &Address) {
+        let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+
+        let mut daily: Map<Address, DailyUsage> = env
+            .storage()
+            .instance()
+            .get(&DAILY_USAGE_KEY)
+            .unwrap_or(Map::new(env));
+        if let Some(usage) = daily.get(old.clone()) {
+            let notional = Self::usage_today(env, Some(usage))
+                + Self::usage_today(env, daily.get(new.clone()));
+            daily.remove(old.clone());
+            daily.set(new.clone(), DailyUsage { day: today, notional });
+            env.storage().instance().set(&DAILY_USAGE_KEY, &daily);
+        }
+
+        let mut per_asset: Map<EscrowKey, DailyUsage> = env
+            .storage()
+            .instance()
+            .get(&ASSET_USAGE_KEY)
+            .unwrap_or(Map::new(env));
+        for asset in assets.iter() {
+            let key = EscrowKey {
+                participant: old.clone(),
+                asset: asset.clone(),
+            };
+            if let Some(usage) = per_asset.get(key.clone()) {
+                let moved = EscrowKey {
+                    participant: new.clone(),
+                    asset,
+                };
+                let notional = Self::usage_today(env, Some(usage))
+                    + Self::usage_today(env, per_asset.get(moved.clone()));
+                per_asset.remove(key);
+                per_asset.set(moved, DailyUsage { day: today, notional });
+            }
+        }
+        env.storage().instance().set(&ASSET_USAGE_KEY, &per_asset);
+    }
+
+    fn usage_today(env: &Env, usage: Option<DailyUsage>) -> i128 {
+        let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+        match usage {
+            Some(u) if u.day == today => u.notional,
+            _ => 0,
+        }
+    }
+
+    fn record_usage(env: &Env, participant: &Address, asset: &Address, notional: i128) {
+        let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+
+        let mut daily: Map<Address, DailyUsage> = env
+            .storage()
+            .instance()
+            .get(&DAILY_USAGE_KEY)
+            .unwrap_or(Map::new(env));
+        let used = Self::usage_today(env, daily.get(participant.clone()));
+        daily.set(
+            participant.clone(),
+            DailyUsage {
+                day: today,
+                notional: used + notional,
+            },
+        );
+        env.storage().instance().set(&DAILY_USAGE_KEY, &daily);
+
+        let key = EscrowKey {
+            participant: participant.clone(),
+            asset: asset.clone(),
+        };
+        let mut per_asset: Map<EscrowKey, DailyUsage> = env
+            .storage()
+            .instance()
+            .get(&ASSET_USAGE_KEY)
+            .unwrap_or(Map::new(env));
+        let used = Self::usage_today(env, per_asset.get(key.clone()));
+        per_asset.set(
+            key,
+            DailyUsage {
+                day: today,
+                notional: used + notional,
+            },
+        );
+        env.storage().instance().set(&ASSET_USAGE_KEY, &per_asset);
+    }
+
     fn mark_nullifier_used(env: &Env, nullifier: &BytesN<32>) {
         let mut nullifiers: Vec<BytesN<32>> = env
             .storage()
@@ -539,6 +2050,37 @@ impl DarkPoolSettlement {
         env.storage().instance().set(&NULLIFIERS_KEY, &nullifiers);
     }
 
+    /// Read a public signal the circuit range-checked to 64 bits
+    fn signal_u64(signal: &BytesN<32>) -> Option<u64> {
+        let bytes = signal.to_array();
+        if bytes[..24].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&bytes[24..]);
+        Some(u64::from_be_bytes(value))
+    }
+
+    /// Read a public signal as a non-negative i128, if it fits
+    fn signal_i128(signal: &BytesN<32>) -> Option<i128> {
+        let bytes = signal.to_array();
+        if bytes[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut value = [0u8; 16];
+        value.copy_from_slice(&bytes[16..]);
+        i128::try_from(u128::from_be_bytes(value)).ok()
+    }
+
+    /// Read a public signal that must be 0 or 1
+    fn signal_flag(signal: &BytesN<32>) -> Result<bool, SettlementError> {
+        match Self::signal_u64(signal) {
+            Some(0) => Ok(false),
+            Some(1) => Ok(true),
+            _ => Err(SettlementError::InvalidProof),
+        }
+    }
+
     fn parse_public_signals(env: &Env, bytes: &Bytes) -> Result<Vec<BytesN<32>>, SettlementError> {
         let mut pos = 0usize;
 
@@ -1,97 +1,1612 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    crypto::bn254::{Bn254G1Affine, Fr},
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Bytes, BytesN, Env, Event, String, Symbol,
+};
 
 // Note: Full integration tests require deploying the verifier and registry contracts first.
 // These are basic unit tests for escrow functionality.
 
+// The registry keeps its Merkle trees in a trees contract
+mod trees_wasm {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/darkpool_trees.wasm"
+    );
+}
+
+/// Stands in for the Groth16 verifier: accepts proofs whose first byte is 1
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn verify_proof_bytes(
+        _env: Env,
+        _vk_bytes: Bytes,
+        proof_bytes: Bytes,
+        _pub_signals_bytes: Bytes,
+    ) -> bool {
+        proof_bytes.get(0) == Some(1)
+    }
+}
+
+/// Stands in for the orderbook: accepts settlements of the matches recorded with it
+/// and keeps the completion each settlement reported
+#[contract]
+pub struct MockOrderbook;
+
+#[contractimpl]
+impl MockOrderbook {
+    pub fn record_match(
+        env: Env,
+        match_id: BytesN<32>,
+        buy_commitment: BytesN<32>,
+        sell_commitment: BytesN<32>,
+        quantity: i128,
+        price: i128,
+    ) {
+        let terms = (buy_commitment, sell_commitment, quantity, price);
+        env.storage().persistent().set(&match_id, &terms);
+    }
+
+    pub fn mark_settled(
+        env: Env,
+        match_id: BytesN<32>,
+        buy_commitment: BytesN<32>,
+        sell_commitment: BytesN<32>,
+        quantity: i128,
+        price: i128,
+        buy_complete: bool,
+        sell_complete: bool,
+    ) {
+        let terms: (BytesN<32>, BytesN<32>, i128, i128) =
+            env.storage().persistent().get(&match_id).unwrap();
+        assert!(terms == (buy_commitment, sell_commitment, quantity, price));
+        env.storage().persistent().set(&match_id, &(buy_complete, sell_complete));
+    }
+
+    pub fn get_completion(env: Env, match_id: BytesN<32>) -> (bool, bool) {
+        env.storage().persistent().get(&match_id).unwrap()
+    }
+}
+
+fn register_settlement(env: &Env) -> Address {
+    let admin = Address::generate(env);
+    let registry = Address::generate(env);
+    let verifier = Address::generate(env);
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes))
+}
+
+fn create_registry(env: &Env, admin: &Address) -> Address {
+    let verifier = Address::generate(env);
+    let trees = env.register(trees_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    env.register(registry_wasm::WASM, (admin, &verifier, &trees, &vk_bytes))
+}
+
+fn register_trader(env: &Env, registry: &Address, admin: &Address, id: u8) -> Address {
+    let registry_client = registry_wasm::Client::new(env, registry);
+    let trader = Address::generate(env);
+    registry_client.register_participant(
+        admin,
+        &registry_wasm::Participant {
+            id_hash: BytesN::from_array(env, &[id; 32]),
+            trading_address: trader.clone(),
+            category: registry_wasm::ParticipantCategory::Bank,
+            kyc_expiry: env.ledger().timestamp() + 31536000,
+            is_active: true,
+            tree_index: 0,
+            jurisdiction: Symbol::new(env, "US"),
+            is_qualified_investor: true,
+        },
+    );
+    trader
+}
+
+fn register_asset(env: &Env, registry: &Address, admin: &Address) -> Address {
+    let registry_client = registry_wasm::Client::new(env, registry);
+    let asset = Address::generate(env);
+    registry_client.register_asset(
+        admin,
+        &registry_wasm::RWAAsset {
+            token_address: asset.clone(),
+            symbol: Symbol::new(env, "TBOND25"),
+            asset_type: registry_wasm::AssetType::TreasuryBond,
+            min_trade_size: 1_000_000,
+            max_order_size: 100_000_000_000,
+            is_active: true,
+            metadata: registry_wasm::AssetMetadata {
+                isin: None,
+                cusip: None,
+                issuer: String::from_str(env, "US Treasury"),
+                decimals: 7,
+                maturity_date: None,
+                coupon_rate_bps: 0,
+                face_value: 10_000_000_000,
+            },
+        },
+    );
+    asset
+}
+
+fn dummy_pub_signals(env: &Env) -> Bytes {
+    pub_signals_with_root(env, &BytesN::from_array(env, &[0u8; 32]))
+}
+
+fn pub_signals_with_root(env: &Env, whitelist_root: &BytesN<32>) -> Bytes {
+    pub_signals_with_asset_root(env, whitelist_root, &BytesN::from_array(env, &[0u8; 32]))
+}
+
+fn pub_signals_with_asset_root(
+    env: &Env,
+    whitelist_root: &BytesN<32>,
+    asset_root: &BytesN<32>,
+) -> Bytes {
+    pub_signals_valid_at(env, whitelist_root, asset_root, u64::MAX)
+}
+
+/// Whole-order signals with zero commitments, proving KYC valid until `valid_at`
+fn pub_signals_valid_at(
+    env: &Env,
+    whitelist_root: &BytesN<32>,
+    asset_root: &BytesN<32>,
+    valid_at: u64,
+) -> Bytes {
+    let mut signals = Bytes::from_slice(env, &9u32.to_be_bytes());
+    signals.append(&Bytes::from_slice(env, &[0u8; 32 * 6]));
+    signals.append(&Bytes::from_array(env, &whitelist_root.to_array()));
+    signals.append(&Bytes::from_array(env, &asset_root.to_array()));
+    signals.append(&u64_signal(env, valid_at));
+    signals
+}
+
+fn u64_signal(env: &Env, value: u64) -> Bytes {
+    let mut signal = [0u8; 32];
+    signal[24..].copy_from_slice(&value.to_be_bytes());
+    Bytes::from_array(env, &signal)
+}
+
+/// BN254 G2 generator, `x.c1 || x.c0 || y.c1 || y.c0`
+const G2_GENERATOR: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+/// BN254 G1 generator `(1, 2)`
+fn g1_generator(env: &Env) -> Bn254G1Affine {
+    let mut point = [0u8; 64];
+    point[31] = 1;
+    point[63] = 2;
+    Bn254G1Affine::from_array(env, &point)
+}
+
+/// A verification key in the verifier's layout for a circuit with `signal_count` public signals
+///
+/// Every point is a generator, so `groth16_proof` can build proofs it accepts.
+fn groth16_vk(env: &Env, signal_count: u32) -> Bytes {
+    let g1 = Bytes::from_array(env, &g1_generator(env).to_array());
+    let g2 = Bytes::from_array(env, &G2_GENERATOR);
+    let mut vk = g1.clone();
+    for _ in 0..3 {
+        vk.append(&g2);
+    }
+    vk.append(&Bytes::from_array(env, &(signal_count + 1).to_be_bytes()));
+    for _ in 0..=signal_count {
+        vk.append(&g1);
+    }
+    vk
+}
+
+/// A proof that `groth16_vk` accepts for `signals`
+///
+/// With generator points the pairing check reduces to `a = alpha + vk_x + c`.
+fn groth16_proof(env: &Env, signals: &Bytes) -> Bytes {
+    let bn254 = env.crypto().bn254();
+    let g1 = g1_generator(env);
+    let mut vk_x = g1.clone();
+    let mut pos = 4;
+    while pos < signals.len() {
+        let signal: BytesN<32> = signals.slice(pos..pos + 32).try_into().unwrap();
+        vk_x = bn254.g1_add(&vk_x, &bn254.g1_mul(&g1, &Fr::from_bytes(signal)));
+        pos += 32;
+    }
+    let a = bn254.g1_add(&bn254.g1_add(&g1, &vk_x), &g1);
+
+    let mut proof = Bytes::from_array(env, &a.to_array());
+    proof.append(&Bytes::from_array(env, &G2_GENERATOR));
+    proof.append(&Bytes::from_array(env, &g1.to_array()));
+    proof
+}
+
+fn i128_signal(env: &Env, value: i128) -> Bytes {
+    let mut signal = [0u8; 32];
+    signal[16..].copy_from_slice(&(value as u128).to_be_bytes());
+    Bytes::from_array(env, &signal)
+}
+
+/// Signals for a trade of `(asset_hash, quantity, price)` between two orders, under
+/// `[whitelist_root, asset_root]`, with fill commitments and completion flags for
+/// partial fill proofs
+fn trade_pub_signals(
+    env: &Env,
+    nullifier: u8,
+    roots: [&BytesN<32>; 2],
+    orders: [&BytesN<32>; 2],
+    trade: (&BytesN<32>, i128, i128),
+    fills: Option<([&BytesN<32>; 4], [bool; 2])>,
+) -> Bytes {
+    let count: u32 = if fills.is_some() { 15 } else { 9 };
+    let mut signals = Bytes::from_slice(env, &count.to_be_bytes());
+    signals.append(&Bytes::from_slice(env, &[nullifier; 32]));
+    for commitment in orders {
+        signals.append(&Bytes::from_array(env, &commitment.to_array()));
+    }
+    let (asset_hash, quantity, price) = trade;
+    signals.append(&Bytes::from_array(env, &asset_hash.to_array()));
+    signals.append(&i128_signal(env, quantity));
+    signals.append(&i128_signal(env, price));
+    for root in roots {
+        signals.append(&Bytes::from_array(env, &root.to_array()));
+    }
+    signals.append(&u64_signal(env, u64::MAX));
+    if let Some((commitments, complete)) = fills {
+        for fill in commitments {
+            signals.append(&Bytes::from_array(env, &fill.to_array()));
+        }
+        for flag in complete {
+            signals.append(&u64_signal(env, flag as u64));
+        }
+    }
+    signals
+}
+
 #[test]
 fn test_escrow_balance_tracking() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
 
-    let participant = Address::generate(&env);
-    let asset = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let participant = Address::generate(&env);
+        let asset = Address::generate(&env);
 
-    // Initially zero
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 0);
+        // Initially zero
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 0);
 
-    // Add balance
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 1000);
+        // Add balance
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 1000);
 
-    // Add more
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 500);
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 1500);
+        // Add more
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 500);
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 1500);
+    });
 }
 
 #[test]
 fn test_locked_balance_tracking() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
 
-    let participant = Address::generate(&env);
-    let asset = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let participant = Address::generate(&env);
+        let asset = Address::generate(&env);
 
-    // Add escrow first
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+        // Add escrow first
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
 
-    // Lock some
-    DarkPoolSettlement::add_locked_balance(&env, &participant, &asset, 400);
-    let locked = DarkPoolSettlement::get_locked_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(locked, 400);
+        // Lock some
+        DarkPoolSettlement::add_locked_balance(&env, &participant, &asset, 400);
+        let locked = DarkPoolSettlement::get_locked_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(locked, 400);
 
-    // Available should be escrow - locked
-    let available = DarkPoolSettlement::get_available_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(available, 600);
+        // Available should be escrow - locked
+        let available = DarkPoolSettlement::get_available_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(available, 600);
+    });
 }
 
 #[test]
 fn test_nullifier_tracking() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
 
-    let nullifier = BytesN::from_array(&env, &[1u8; 32]);
+    env.as_contract(&contract_id, || {
+        let nullifier = BytesN::from_array(&env, &[1u8; 32]);
 
-    // Initialize nullifiers storage
-    let nullifiers: Vec<BytesN<32>> = vec![&env];
-    env.storage().instance().set(&symbol_short!("nulls"), &nullifiers);
+        // Initialize nullifiers storage
+        let nullifiers: Vec<BytesN<32>> = vec![&env];
+        env.storage().instance().set(&symbol_short!("nulls"), &nullifiers);
 
-    // Should not be used initially
-    assert!(!DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+        // Should not be used initially
+        assert!(!DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
 
-    // Mark as used
-    DarkPoolSettlement::mark_nullifier_used(&env, &nullifier);
+        // Mark as used
+        DarkPoolSettlement::mark_nullifier_used(&env, &nullifier);
 
-    // Should be used now
-    assert!(DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+        // Should be used now
+        assert!(DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+    });
 }
 
 #[test]
 fn test_escrow_transfer() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let asset = Address::generate(&env);
+
+        // Give Alice some balance and lock it
+        DarkPoolSettlement::add_escrow_balance(&env, &alice, &asset, 1000);
+        DarkPoolSettlement::add_locked_balance(&env, &alice, &asset, 1000);
+
+        // Transfer from Alice to Bob
+        let commitment = BytesN::from_array(&env, &[0u8; 32]);
+        let result =
+            DarkPoolSettlement::transfer_from_escrow(&env, &alice, &bob, &commitment, &asset, 500);
+        assert!(result.is_ok());
+
+        // Check balances
+        let alice_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), alice.clone(), asset.clone());
+        let bob_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), bob.clone(), asset.clone());
+
+        assert_eq!(alice_balance, 500);
+        assert_eq!(bob_balance, 500);
+
+        // Alice's locked balance should also decrease
+        let alice_locked = DarkPoolSettlement::get_locked_balance(env.clone(), alice.clone(), asset.clone());
+        assert_eq!(alice_locked, 500);
+    });
+}
+
+#[test]
+fn test_settle_trade_rejects_ineligible_participant() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+
+    let asset = register_asset(&env, &registry, &admin);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    // Seller was never registered
+    let seller = Address::generate(&env);
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &Address::generate(&env),
+        &1_000_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &dummy_pub_signals(&env),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::ParticipantNotEligible)));
+}
+
+#[test]
+fn test_settle_trade_rejects_unlisted_pair() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+
+    let asset = register_asset(&env, &registry, &admin);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let payment_asset = Address::generate(&env);
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &payment_asset,
+        &1_000_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &dummy_pub_signals(&env),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::PairNotListed)));
+
+    // Listed pair, but below the asset's minimum trade size
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &payment_asset,
+        &1_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &dummy_pub_signals(&env),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::TradeConstraintViolation)));
+}
+
+#[test]
+fn test_settle_trade_rejects_stale_whitelist_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let stale_root = registry_client.get_whitelist_root();
+
+    let asset = register_asset(&env, &registry, &admin);
+    env.ledger().set_timestamp(1_000);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+
+    let payment_asset = Address::generate(&env);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    client.set_root_freshness_window(&admin, &600);
+    assert_eq!(client.get_root_freshness_window(), 600);
+
+    // The pre-registration root was replaced at t=1000 and is stale past the window
+    env.ledger().set_timestamp(2_000);
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &payment_asset,
+        &1_000_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &pub_signals_with_root(&env, &stale_root),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::WhitelistRootMismatch)));
+
+    // A root the registry never produced is rejected as well
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &payment_asset,
+        &1_000_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &pub_signals_with_root(&env, &BytesN::from_array(&env, &[7u8; 32])),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::WhitelistRootMismatch)));
+
+    // Proofs of KYC validity up to a time already passed have expired
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[9u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &payment_asset,
+        &1_000_000,
+        &50_000_000,
+        &Bytes::from_slice(&env, &[0u8; 256]),
+        &pub_signals_valid_at(
+            &env,
+            &registry_client.get_whitelist_root(),
+            &registry_client.get_asset_root(),
+            1_999,
+        ),
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::ProofExpired)));
+
+    // Only the admin can change the window
+    let result = client.try_set_root_freshness_window(&Address::generate(&env), &0);
+    assert_eq!(result, Err(Ok(SettlementError::OnlyAdmin)));
+}
+
+#[test]
+fn test_settle_trade_enforces_trading_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+
+    let asset = register_asset(&env, &registry, &admin);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+
+    let payment_asset = Address::generate(&env);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let settle = |price: i128| {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &payment_asset,
+            &1_000_000,
+            &price,
+            &Bytes::from_slice(&env, &[0u8; 256]),
+            &dummy_pub_signals(&env),
+        )
+    };
+
+    // No limits set: unlimited headroom
+    let headroom = client.get_trading_headroom(&buyer, &asset);
+    assert_eq!(headroom.per_trade, None);
+    assert_eq!(headroom.daily, None);
+
+    let mut asset_limits = Map::new(&env);
+    asset_limits.set(asset.clone(), 80_000_000);
+    registry_client.set_trading_limits(
+        &admin,
+        &buyer,
+        &registry_wasm::TradingLimits {
+            max_trade_notional: Some(40_000_000),
+            max_daily_notional: Some(100_000_000),
+            max_daily_asset_notional: asset_limits,
+        },
+    );
+    assert_eq!(settle(50_000_000).err(), Some(Ok(SettlementError::TradeLimitExceeded)));
+
+    // Earlier settlements today count against the daily limits
+    env.ledger().set_timestamp(SECONDS_PER_DAY * 10);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::record_usage(&env, &buyer, &asset, 30_000_000);
+        DarkPoolSettlement::record_usage(&env, &buyer, &Address::generate(&env), 40_000_000);
+    });
+    let headroom = client.get_trading_headroom(&buyer, &asset);
+    assert_eq!(headroom.per_trade, Some(40_000_000));
+    assert_eq!(headroom.daily, Some(30_000_000));
+    assert_eq!(headroom.daily_asset, Some(50_000_000));
+    assert_eq!(settle(35_000_000).err(), Some(Ok(SettlementError::DailyLimitExceeded)));
+
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::record_usage(&env, &buyer, &asset, 25_000_000);
+    });
+    assert_eq!(client.get_daily_asset_usage(&buyer, &asset), 55_000_000);
+    registry_client.set_trading_limits(
+        &admin,
+        &buyer,
+        &registry_wasm::TradingLimits {
+            max_trade_notional: None,
+            max_daily_notional: None,
+            max_daily_asset_notional: {
+                let mut limits = Map::new(&env);
+                limits.set(asset.clone(), 80_000_000);
+                limits
+            },
+        },
+    );
+    assert_eq!(settle(30_000_000).err(), Some(Ok(SettlementError::AssetDailyLimitExceeded)));
+
+    // Usage resets at the start of the next UTC day
+    env.ledger().set_timestamp(SECONDS_PER_DAY * 11);
+    assert_eq!(client.get_daily_usage(&buyer), 0);
+    assert_eq!(client.get_trading_headroom(&buyer, &asset).daily_asset, Some(80_000_000));
+}
+
+#[test]
+fn test_escrow_changes_emit_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let trader = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    StellarAssetClient::new(&env, &token).mint(&trader, &1_000);
+
+    client.deposit(&trader, &token, &1_000);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [Deposited {
+            participant: trader.clone(),
+            asset: token.clone(),
+            amount: 1_000,
+            escrow_balance: 1_000,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+
+    client.lock_escrow(&trader, &token, &600);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [EscrowLocked {
+            participant: trader.clone(),
+            asset: token.clone(),
+            amount: 600,
+            locked_balance: 600,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+
+    client.unlock_escrow(&trader, &token, &200);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [EscrowUnlocked {
+            participant: trader.clone(),
+            asset: token.clone(),
+            amount: 200,
+            locked_balance: 400,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+
+    client.withdraw(&trader, &token, &600);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [Withdrawn {
+            participant: trader,
+            asset: token,
+            amount: 600,
+            escrow_balance: 400,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+}
+
+#[test]
+fn test_settle_trade_rejects_stale_asset_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = env.register(MockVerifier, ());
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+
+    let asset = register_asset(&env, &registry, &admin);
+    let stale_asset_root = registry_client.get_asset_root();
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+
+    let payment_asset = Address::generate(&env);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+
+    // Listing another asset at t=1000 replaces the asset root
+    env.ledger().set_timestamp(1_000);
+    register_asset(&env, &registry, &admin);
+    assert_ne!(registry_client.get_asset_root(), stale_asset_root);
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    client.set_root_freshness_window(&admin, &600);
+    let settle = |asset_root: &BytesN<32>| {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &payment_asset,
+            &1_000_000,
+            &50_000_000,
+            &Bytes::from_slice(&env, &[0u8; 256]),
+            &pub_signals_with_asset_root(&env, &registry_client.get_whitelist_root(), asset_root),
+        )
+    };
+
+    // In-flight proofs against the replaced root still reach the verifier within the window
+    env.ledger().set_timestamp(1_500);
+    assert_eq!(settle(&stale_asset_root).err(), Some(Ok(SettlementError::InvalidProof)));
+
+    // Past the window, or with a root the registry never had, the asset is not proven listed
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(settle(&stale_asset_root).err(), Some(Ok(SettlementError::AssetNotEligible)));
+    let unknown_root = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(settle(&unknown_root).err(), Some(Ok(SettlementError::AssetNotEligible)));
+}
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_upgrade(&stranger, &BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(SettlementError::OnlyAdmin))
+    );
+    assert_eq!(client.try_migrate(&stranger), Err(Ok(SettlementError::OnlyAdmin)));
+
+    // Already on the current schema: nothing to run
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(env.events().all().filter_by_contract(&contract_id), []);
+
+    // Data written by newer code cannot be migrated down
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(client.try_migrate(&admin), Err(Ok(SettlementError::UnsupportedSchemaVersion)));
+}
+
+#[test]
+fn test_migrate_totals_order_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let admin = client.get_admin();
+    client.set_orderbook(&admin, &Address::generate(&env));
+
+    let trader = Address::generate(&env);
+    let token = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &trader, &token, 1_000);
+    });
+    client.lock_order_escrow(&BytesN::from_array(&env, &[1u8; 32]), &trader, &token, &600);
+    client.lock_order_escrow(&BytesN::from_array(&env, &[2u8; 32]), &trader, &token, &300);
+    client.lock_escrow(&trader, &token, &100);
+
+    // Schema 1 kept order locks without a per-participant total
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&ORDER_LOCKED_KEY);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &1u32);
+    });
+    assert_eq!(client.get_order_locked_balance(&trader, &token), 0);
+
+    assert_eq!(client.migrate(&admin), 2);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [SchemaMigrated { version: 2 }.to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(client.get_order_locked_balance(&trader, &token), 900);
+    assert_eq!(
+        client.try_unlock_escrow(&trader, &token, &200),
+        Err(Ok(SettlementError::InsufficientLockedFunds))
+    );
+    client.unlock_escrow(&trader, &token, &100);
+}
+
+#[test]
+fn test_upgrade_populated_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+
+    let asset = register_asset(&env, &registry, &admin);
+    let trader = register_trader(&env, &registry, &admin, 1);
+    let whitelist_root = registry_client.get_whitelist_root();
+    let asset_root = registry_client.get_asset_root();
+
+    // Switch to freshly uploaded code; storage carries over unchanged
+    let wasm_hash = env.deployer().upload_contract_wasm(registry_wasm::WASM);
+    registry_client.upgrade(&admin, &wasm_hash);
+    assert_eq!(
+        env.events().all().filter_by_contract(&registry),
+        [registry_wasm::ContractUpgraded { wasm_hash }.to_xdr(&env, &registry)]
+    );
+
+    assert_eq!(registry_client.migrate(&admin), 3);
+    assert_eq!(registry_client.get_schema_version(), 3);
+    assert_eq!(registry_client.get_whitelist_root(), whitelist_root);
+    assert_eq!(registry_client.get_asset_root(), asset_root);
+    assert!(registry_client.get_participant(&trader).is_some());
+    assert!(registry_client.get_asset(&asset).is_some());
+
+    // The upgraded registry keeps working
+    register_trader(&env, &registry, &admin, 2);
+    assert_eq!(registry_client.get_whitelist_count(), 2);
+}
+
+#[test]
+fn test_agent_locks_escrow_for_principal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let principal = register_trader(&env, &registry, &admin, 1);
+    let agent = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&principal, &1_000);
+    client.deposit(&principal, &token, &1_000);
+
+    assert_eq!(
+        client.try_lock_escrow_for(&agent, &principal, &token, &100),
+        Err(Ok(SettlementError::AgentNotAuthorized))
+    );
+
+    registry_client.authorize_agent(
+        &principal,
+        &agent,
+        &registry_wasm::AgentScope {
+            assets: vec![&env, token.clone()],
+            max_order_size: Some(500),
+            expires_at: env.ledger().timestamp() + 3600,
+        },
+    );
+
+    client.lock_escrow_for(&agent, &principal, &token, &500);
+    assert_eq!(client.get_locked_balance(&principal, &token), 500);
+
+    // Amounts above the agent's cap are refused even with funds available
+    assert_eq!(
+        client.try_lock_escrow_for(&agent, &principal, &token, &501),
+        Err(Ok(SettlementError::AgentNotAuthorized))
+    );
+
+    client.unlock_escrow_for(&agent, &principal, &token, &200);
+    assert_eq!(client.get_locked_balance(&principal, &token), 300);
+    assert_eq!(client.get_available_balance(&principal, &token), 700);
+}
+
+#[test]
+fn test_apply_key_rotation_moves_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let old_address = register_trader(&env, &registry, &admin, 1);
+    let new_address = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&old_address, &1_000);
+    client.deposit(&old_address, &token, &1_000);
+    client.lock_escrow(&old_address, &token, &300);
+    client.set_orderbook(&admin, &Address::generate(&env));
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    client.lock_order_escrow(&commitment, &old_address, &token, &200);
+
+    // Nothing moves until the registry approves the rotation
+    registry_client.propose_key_rotation(&old_address, &new_address);
+    let assets = vec![&env, token.clone()];
+    let commitments = vec![&env, commitment.clone()];
+    assert_eq!(
+        client.try_apply_key_rotation(&old_address, &assets, &commitments),
+        Err(Ok(SettlementError::KeyNotRotated))
+    );
+
+    registry_client.approve_key_rotation(&admin, &old_address);
+    let mut too_many = vec![&env];
+    for i in 0..51u8 {
+        too_many.push_back(BytesN::from_array(&env, &[i; 32]));
+    }
+    assert_eq!(
+        client.try_apply_key_rotation(&old_address, &assets, &too_many),
+        Err(Ok(SettlementError::RotationBatchTooLarge))
+    );
+    client.apply_key_rotation(&old_address, &assets, &commitments);
 
-    let alice = Address::generate(&env);
-    let bob = Address::generate(&env);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [KeyRotationApplied {
+            old_address: old_address.clone(),
+            new_address: new_address.clone(),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(client.get_escrow_balance(&new_address, &token), 1_000);
+    assert_eq!(client.get_locked_balance(&new_address, &token), 500);
+    assert_eq!(client.get_order_locked_balance(&new_address, &token), 200);
+    assert_eq!(client.get_escrow_balance(&old_address, &token), 0);
+    assert_eq!(client.get_locked_balance(&old_address, &token), 0);
+    assert_eq!(client.get_order_locked_balance(&old_address, &token), 0);
+
+    // The new key controls the funds
+    client.unlock_escrow(&new_address, &token, &300);
+    client.release_order_escrow(&commitment, &0);
+    client.withdraw(&new_address, &token, &1_000);
+    assert_eq!(token::TokenClient::new(&env, &token).balance(&new_address), 1_000);
+}
+
+#[test]
+fn test_trading_status_and_window_gate_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let admin = client.get_admin();
     let asset = Address::generate(&env);
+    let settle = || {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[9u8; 32]),
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &asset,
+            &Address::generate(&env),
+            &1_000_000,
+            &50_000_000,
+            &Bytes::from_slice(&env, &[0u8; 256]),
+            &dummy_pub_signals(&env),
+        )
+    };
+
+    assert!(client.get_market_status(&asset).is_none());
+    assert_eq!(client.try_check_market_open(&asset), Ok(Ok(())));
+
+    client.set_trading_status(&admin, &asset, &TradingStatus::Halted, &symbol_short!("corp_act"));
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [TradingStatusChanged {
+            asset: asset.clone(),
+            status: TradingStatus::Halted,
+            reason: symbol_short!("corp_act"),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(settle().err(), Some(Ok(SettlementError::MarketHalted)));
+
+    client.set_trading_status(&admin, &asset, &TradingStatus::Closed, &symbol_short!("eod"));
+    assert_eq!(settle().err(), Some(Ok(SettlementError::MarketClosed)));
+    client.set_trading_status(&admin, &asset, &TradingStatus::Open, &symbol_short!("resumed"));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_trading_status(
+            &stranger,
+            &asset,
+            &TradingStatus::Halted,
+            &symbol_short!("x")
+        ),
+        Err(Ok(SettlementError::OnlyAdmin))
+    );
+
+    let window = |days: u32, open_time: u64, close_time: u64| TradingWindow {
+        days,
+        open_time,
+        close_time,
+    };
+    for invalid in [window(0, 0, 3600), window(0b1, 3600, 3600), window(0b1, 0, 90_000)] {
+        assert_eq!(
+            client.try_set_trading_window(&admin, &asset, &invalid),
+            Err(Ok(SettlementError::InvalidTradingWindow))
+        );
+    }
+
+    // Weekdays, 09:00 to 17:00 UTC
+    client.set_trading_window(&admin, &asset, &window(0b001_1111, 9 * 3600, 17 * 3600));
+
+    // Sunday 1970-01-04, noon
+    env.ledger().set_timestamp(3 * SECONDS_PER_DAY + 12 * 3600);
+    assert_eq!(client.try_check_market_open(&asset), Err(Ok(SettlementError::MarketClosed)));
+    assert_eq!(settle().err(), Some(Ok(SettlementError::MarketClosed)));
+
+    // Monday, noon and just before the open
+    env.ledger().set_timestamp(4 * SECONDS_PER_DAY + 12 * 3600);
+    assert_eq!(client.try_check_market_open(&asset), Ok(Ok(())));
+    env.ledger().set_timestamp(4 * SECONDS_PER_DAY + 9 * 3600 - 1);
+    assert_eq!(client.try_check_market_open(&asset), Err(Ok(SettlementError::MarketClosed)));
+
+    client.remove_trading_window(&admin, &asset);
+    assert!(client.get_trading_window(&asset).is_none());
+    assert_eq!(client.try_check_market_open(&asset), Ok(Ok(())));
+}
+
+#[test]
+fn test_issuers_halt_their_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    // List an asset for an approved issuer
+    let issuer = Address::generate(&env);
+    let other_issuer = Address::generate(&env);
+    registry_client.add_issuer(&admin, &issuer, &String::from_str(&env, "Issuer"));
+    registry_client.add_issuer(&admin, &other_issuer, &String::from_str(&env, "Other"));
+    let asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let mut listing = registry_client.get_asset(&register_asset(&env, &registry, &admin)).unwrap();
+    listing.token_address = asset.clone();
+    registry_client.propose_listing(&issuer, &listing);
+    registry_client.approve_listing(&admin, &asset);
+
+    let halt = |caller: &Address, status: TradingStatus| {
+        client.try_set_trading_status(caller, &asset, &status, &symbol_short!("corp_act"))
+    };
+
+    // Only the asset's own issuer may halt it, and only the admin reopens it
+    assert_eq!(halt(&other_issuer, TradingStatus::Halted), Err(Ok(SettlementError::OnlyAdmin)));
+    assert_eq!(halt(&issuer, TradingStatus::Halted), Ok(Ok(())));
+    assert_eq!(client.try_check_market_open(&asset), Err(Ok(SettlementError::MarketHalted)));
+    assert_eq!(halt(&issuer, TradingStatus::Open), Err(Ok(SettlementError::OnlyAdmin)));
+    assert_eq!(halt(&issuer, TradingStatus::Closed), Err(Ok(SettlementError::OnlyAdmin)));
+    assert_eq!(halt(&admin, TradingStatus::Open), Ok(Ok(())));
+
+    // Revoked issuers lose the right to halt
+    registry_client.revoke_issuer(&admin, &issuer);
+    assert_eq!(halt(&issuer, TradingStatus::Halted), Err(Ok(SettlementError::OnlyAdmin)));
+}
+
+#[test]
+fn test_circuit_breaker_halts_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let admin = client.get_admin();
+    let asset = Address::generate(&env);
+
+    // 5% maximum move between trades
+    client.set_circuit_breaker(&admin, &asset, &500);
+    assert_eq!(client.get_circuit_breaker(&asset), 500);
+
+    env.as_contract(&contract_id, || {
+        // First trade sets the reference unit price of 50
+        DarkPoolSettlement::check_circuit_breaker(&env, &asset, 1_000_000, 50_000_000);
+        // A 4% move at a different size stays within the band
+        DarkPoolSettlement::check_circuit_breaker(&env, &asset, 2_000_000, 104_000_000);
+    });
+    assert!(client.get_market_status(&asset).is_none());
+
+    env.as_contract(&contract_id, || {
+        // Unit price drops from 52 to 45
+        DarkPoolSettlement::check_circuit_breaker(&env, &asset, 1_000_000, 45_000_000);
+    });
+    let status = client.get_market_status(&asset).unwrap();
+    assert_eq!(status.status, TradingStatus::Halted);
+    assert_eq!(status.reason, symbol_short!("breaker"));
+    assert_eq!(client.try_check_market_open(&asset), Err(Ok(SettlementError::MarketHalted)));
+
+    client.set_circuit_breaker(&admin, &asset, &0);
+    assert_eq!(client.get_circuit_breaker(&asset), 0);
+}
+
+#[test]
+fn test_order_escrow_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let admin = client.get_admin();
+
+    let trader = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&trader, &1_000);
+    client.deposit(&trader, &token, &1_000);
+
+    let commitment = BytesN::from_array(&env, &[1u8; 32]);
+    let other_commitment = BytesN::from_array(&env, &[2u8; 32]);
+
+    // Only a configured orderbook may lock or release order escrow
+    assert_eq!(
+        client.try_lock_order_escrow(&commitment, &trader, &token, &600),
+        Err(Ok(SettlementError::OrderbookNotSet))
+    );
+    assert_eq!(
+        client.try_release_order_escrow(&commitment, &0),
+        Err(Ok(SettlementError::OrderbookNotSet))
+    );
+    let orderbook = Address::generate(&env);
+    client.set_orderbook(&admin, &orderbook);
+    assert_eq!(client.get_orderbook(), Some(orderbook));
+
+    client.lock_order_escrow(&commitment, &trader, &token, &600);
+    assert_eq!(
+        client.try_lock_order_escrow(&commitment, &trader, &token, &100),
+        Err(Ok(SettlementError::OrderLockExists))
+    );
+    assert_eq!(
+        client.try_lock_order_escrow(&other_commitment, &trader, &token, &500),
+        Err(Ok(SettlementError::InsufficientEscrow))
+    );
+    client.lock_order_escrow(&other_commitment, &trader, &token, &400);
+    assert_eq!(client.get_locked_balance(&trader, &token), 1_000);
+    assert_eq!(client.get_order_locked_balance(&trader, &token), 1_000);
+    assert_eq!(client.get_order_lock(&commitment).unwrap().amount, 600);
+
+    // Escrow held by order locks cannot be unlocked directly
+    assert_eq!(
+        client.try_unlock_escrow(&trader, &token, &100),
+        Err(Ok(SettlementError::InsufficientLockedFunds))
+    );
+
+    // Releasing frees exactly that order's lock, once
+    assert_eq!(client.release_order_escrow(&commitment, &0), 600);
+    assert_eq!(client.release_order_escrow(&commitment, &0), 0);
+    assert!(client.get_order_lock(&commitment).is_none());
+    assert_eq!(client.get_available_balance(&trader, &token), 600);
+    assert_eq!(client.get_order_locked_balance(&trader, &token), 400);
+
+    // Only escrow locked with `lock_escrow` can be unlocked with `unlock_escrow`
+    client.lock_escrow(&trader, &token, &100);
+    assert_eq!(
+        client.try_unlock_escrow(&trader, &token, &200),
+        Err(Ok(SettlementError::InsufficientLockedFunds))
+    );
+    client.unlock_escrow(&trader, &token, &100);
+
+    // Settling consumes the matched order's lock and releases the unused rest
+    env.as_contract(&contract_id, || {
+        let seller = Address::generate(&env);
+        assert_eq!(
+            DarkPoolSettlement::consume_order_lock(
+                &env, &other_commitment, &seller, &token, 300, true,
+            ),
+            Err(SettlementError::OrderLockMismatch)
+        );
+        assert_eq!(
+            DarkPoolSettlement::consume_order_lock(
+                &env, &other_commitment, &trader, &token, 500, true,
+            ),
+            Err(SettlementError::InsufficientLockedFunds)
+        );
+        DarkPoolSettlement::consume_order_lock(
+            &env, &other_commitment, &trader, &token, 300, true,
+        )
+        .unwrap();
+
+        // Orders settle only from their own lock
+        assert_eq!(
+            DarkPoolSettlement::consume_order_lock(
+                &env, &commitment, &trader, &token, 300, true,
+            ),
+            Err(SettlementError::OrderLockNotFound)
+        );
+    });
+    assert!(client.get_order_lock(&other_commitment).is_none());
+    assert_eq!(client.get_locked_balance(&trader, &token), 300);
+    assert_eq!(client.get_order_locked_balance(&trader, &token), 0);
+}
+
+#[test]
+fn test_partial_fills_continue_fill_commitments() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let asset = register_asset(&env, &registry, &admin);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let payment_asset = Address::generate(&env);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+    let root = registry_client.get_whitelist_root();
+    let asset_root = registry_client.get_asset_root();
+
+    let verifier = env.register(MockVerifier, ());
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id =
+        env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let orderbook = env.register(MockOrderbook, ());
+    let orderbook_client = MockOrderbookClient::new(&env, &orderbook);
+    client.set_orderbook(&admin, &orderbook);
+    client.set_partial_fill_vk(&admin, &vk_bytes);
+
+    // A 10M sell order and a buy order locking 100M of payment
+    let buy = BytesN::from_array(&env, &[1u8; 32]);
+    let sell = BytesN::from_array(&env, &[2u8; 32]);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &buyer, &payment_asset, 100_000_000);
+        DarkPoolSettlement::add_escrow_balance(&env, &seller, &asset, 10_000_000);
+    });
+    client.lock_order_escrow(&buy, &buyer, &payment_asset, &100_000_000);
+    client.lock_order_escrow(&sell, &seller, &asset, &10_000_000);
+
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let buy_fill_1 = BytesN::from_array(&env, &[3u8; 32]);
+    let sell_fill_1 = BytesN::from_array(&env, &[4u8; 32]);
+    let buy_fill_2 = BytesN::from_array(&env, &[5u8; 32]);
+    let sell_fill_2 = BytesN::from_array(&env, &[6u8; 32]);
+    let proof = Bytes::from_slice(&env, &[1u8; 256]);
+    let asset_hash = registry_client.compute_asset_hash(&asset);
+    let settle = |nullifier: u8, quantity: i128, price: i128, fills: Option<[&BytesN<32>; 4]>| {
+        // Only the second fill completes the sell order
+        let complete = [false, nullifier == 8];
+        let fills = fills.map(|fills| (fills, complete));
+        let trade = (&asset_hash, quantity, price);
+        let signals =
+            trade_pub_signals(&env, nullifier, [&root, &asset_root], [&buy, &sell], trade, fills);
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[nullifier; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &payment_asset,
+            &quantity,
+            &price,
+            &proof,
+            &signals,
+        )
+    };
+
+    // The first fill must start from an empty fill commitment
+    let fills = [&buy_fill_1, &buy_fill_1, &zero, &sell_fill_1];
+    let result = settle(7, 4_000_000, 20_000_000, Some(fills));
+    assert_eq!(result.err(), Some(Ok(SettlementError::FillMismatch)));
+
+    // The orderbook must have recorded the match with the settled orders and amounts
+    let fills = [&zero, &buy_fill_1, &zero, &sell_fill_1];
+    let result = settle(7, 4_000_000, 20_000_000, Some(fills));
+    assert_eq!(result.err(), Some(Ok(SettlementError::MatchMismatch)));
+    let match_id = BytesN::from_array(&env, &[7u8; 32]);
+    orderbook_client.record_match(&match_id, &buy, &sell, &4_000_000, &25_000_000);
+    let result = settle(7, 4_000_000, 20_000_000, Some(fills));
+    assert_eq!(result.err(), Some(Ok(SettlementError::MatchMismatch)));
+    orderbook_client.record_match(&match_id, &buy, &sell, &4_000_000, &20_000_000);
+
+    // The proof must carry the settled asset, quantity and price
+    let settle_as = |trade: (&BytesN<32>, i128, i128)| {
+        let fills = Some(([&zero, &buy_fill_1, &zero, &sell_fill_1], [false, false]));
+        let signals = trade_pub_signals(&env, 7, [&root, &asset_root], [&buy, &sell], trade, fills);
+        client.try_settle_trade(
+            &match_id,
+            &buyer,
+            &seller,
+            &asset,
+            &payment_asset,
+            &4_000_000,
+            &20_000_000,
+            &proof,
+            &signals,
+        )
+    };
+    let other_asset_hash = registry_client.compute_asset_hash(&payment_asset);
+    for trade in [
+        (&other_asset_hash, 4_000_000, 20_000_000),
+        (&asset_hash, 3_000_000, 20_000_000),
+        (&asset_hash, 4_000_000, 25_000_000),
+    ] {
+        assert_eq!(settle_as(trade).err(), Some(Ok(SettlementError::InvalidProof)));
+    }
+    assert!(settle(7, 4_000_000, 20_000_000, Some(fills)).is_ok());
+
+    assert_eq!(orderbook_client.get_completion(&match_id), (false, false));
+
+    // Both orders keep the rest of their locks for later fills
+    assert_eq!(client.get_order_lock(&buy).unwrap().amount, 80_000_000);
+    assert_eq!(client.get_order_lock(&sell).unwrap().amount, 6_000_000);
+    assert_eq!(client.get_fill_commitment(&buy), Some(buy_fill_1.clone()));
+    assert_eq!(client.get_fill_commitment(&sell), Some(sell_fill_1.clone()));
+
+    // Later fills continue from the stored commitments; whole-order proofs are refused
+    let match_id = BytesN::from_array(&env, &[8u8; 32]);
+    orderbook_client.record_match(&match_id, &buy, &sell, &6_000_000, &30_000_000);
+    let result = settle(8, 6_000_000, 30_000_000, None);
+    assert_eq!(result.err(), Some(Ok(SettlementError::FillMismatch)));
+    let fills = [&zero, &buy_fill_2, &zero, &sell_fill_2];
+    let result = settle(8, 6_000_000, 30_000_000, Some(fills));
+    assert_eq!(result.err(), Some(Ok(SettlementError::FillMismatch)));
+    let fills = [&buy_fill_1, &buy_fill_2, &sell_fill_1, &sell_fill_2];
+    assert!(settle(8, 6_000_000, 30_000_000, Some(fills)).is_ok());
+    assert_eq!(orderbook_client.get_completion(&match_id), (false, true));
+
+    // The sell order is used up; the buy order's unused payment is released at the end
+    assert!(client.get_order_lock(&sell).is_none());
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 10_000_000);
+    assert_eq!(client.get_order_lock(&buy).unwrap().amount, 50_000_000);
+    assert_eq!(client.release_order_escrow(&buy, &0), 50_000_000);
+    assert_eq!(client.get_locked_balance(&buyer, &payment_asset), 0);
+}
+
+#[test]
+fn test_note_funded_orders_settle_into_their_notes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let asset = register_asset(&env, &registry, &admin);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let payment_asset = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+    let root = registry_client.get_whitelist_root();
+    let asset_root = registry_client.get_asset_root();
+
+    let verifier = env.register(MockVerifier, ());
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let contract_id =
+        env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let orderbook = env.register(MockOrderbook, ());
+    let orderbook_client = MockOrderbookClient::new(&env, &orderbook);
+
+    // Anyone funds a note; it is keyed by the commitment alone
+    let buy = BytesN::from_array(&env, &[1u8; 32]);
+    let sell = BytesN::from_array(&env, &[2u8; 32]);
+    let relayer = Address::generate(&env);
+    StellarAssetClient::new(&env, &payment_asset).mint(&relayer, &100_000_000);
+    assert_eq!(client.deposit_note(&relayer, &buy, &payment_asset, &100_000_000), 100_000_000);
+    assert_eq!(client.get_escrow_balance(&relayer, &payment_asset), 0);
+
+    // Only the orderbook locks and withdraws notes
+    assert_eq!(
+        client.try_lock_note_escrow(&buy, &payment_asset, &60_000_000),
+        Err(Ok(SettlementError::OrderbookNotSet))
+    );
+    client.set_orderbook(&admin, &orderbook);
+    assert_eq!(
+        client.try_lock_note_escrow(&buy, &payment_asset, &200_000_000),
+        Err(Ok(SettlementError::InsufficientEscrow))
+    );
+    client.lock_note_escrow(&buy, &payment_asset, &60_000_000);
+    assert_eq!(client.get_order_lock(&buy).unwrap().owner, contract_id);
+    assert_eq!(client.get_note_balance(&buy, &payment_asset), 40_000_000);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &seller, &asset, 10_000_000);
+    });
+    client.lock_order_escrow(&sell, &seller, &asset, &10_000_000);
+
+    // The note-funded side settles as the settlement contract itself
+    let match_id = BytesN::from_array(&env, &[7u8; 32]);
+    orderbook_client.record_match(&match_id, &buy, &sell, &10_000_000, &5_000_000);
+    let asset_hash = registry_client.compute_asset_hash(&asset);
+    let trade = (&asset_hash, 10_000_000, 5_000_000);
+    let signals = trade_pub_signals(&env, 7, [&root, &asset_root], [&buy, &sell], trade, None);
+    client.settle_trade(
+        &match_id,
+        &contract_id,
+        &seller,
+        &asset,
+        &payment_asset,
+        &10_000_000,
+        &5_000_000,
+        &Bytes::from_slice(&env, &[1u8; 256]),
+        &signals,
+    );
+
+    // The bought asset and the unused payment land in the note, the payment with the seller
+    assert!(client.get_order_lock(&buy).is_none());
+    assert_eq!(client.get_note_balance(&buy, &asset), 10_000_000);
+    assert_eq!(client.get_note_balance(&buy, &payment_asset), 95_000_000);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), 5_000_000);
+    assert_eq!(client.get_escrow_balance(&contract_id, &payment_asset), 0);
+
+    let recipient = Address::generate(&env);
+    assert_eq!(client.withdraw_note(&buy, &payment_asset, &recipient), 95_000_000);
+    assert_eq!(client.withdraw_note(&buy, &payment_asset, &recipient), 0);
+    let payment = TokenClient::new(&env, &payment_asset);
+    assert_eq!(payment.balance(&recipient), 95_000_000);
+}
+
+#[test]
+fn test_settle_trade_selects_vk_by_circuit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = create_registry(&env, &admin);
+    let registry_client = registry_wasm::Client::new(&env, &registry);
+    let asset = register_asset(&env, &registry, &admin);
+    let buyer = register_trader(&env, &registry, &admin, 1);
+    let seller = register_trader(&env, &registry, &admin, 2);
+    let payment_asset = Address::generate(&env);
+    registry_client.register_pair(
+        &admin,
+        &registry_wasm::TradingPair {
+            asset: asset.clone(),
+            payment_asset: payment_asset.clone(),
+            tick_size: 1,
+            lot_size: 1,
+            min_notional: 0,
+            max_notional: 1_000_000_000_000,
+            status: registry_wasm::PairStatus::Active,
+        },
+    );
+    let root = registry_client.get_whitelist_root();
+    let asset_root = registry_client.get_asset_root();
+
+    // Keys sized like the real circuits': 9 signals for whole orders, 15 for partial fills
+    let verifier = env.register(verifier_wasm::WASM, (&admin,));
+    let whole_vk = groth16_vk(&env, 9);
+    let partial_vk = groth16_vk(&env, 15);
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &whole_vk));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let orderbook = env.register(MockOrderbook, ());
+    let orderbook_client = MockOrderbookClient::new(&env, &orderbook);
+    client.set_orderbook(&admin, &orderbook);
+
+    let orders: [BytesN<32>; 4] = [1, 2, 3, 4].map(|id| BytesN::from_array(&env, &[id; 32]));
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &buyer, &payment_asset, 100_000_000);
+        DarkPoolSettlement::add_escrow_balance(&env, &seller, &asset, 20_000_000);
+    });
+    client.lock_order_escrow(&orders[0], &buyer, &payment_asset, &50_000_000);
+    client.lock_order_escrow(&orders[1], &seller, &asset, &10_000_000);
+    client.lock_order_escrow(&orders[2], &buyer, &payment_asset, &50_000_000);
+    client.lock_order_escrow(&orders[3], &seller, &asset, &10_000_000);
 
-    // Give Alice some balance and lock it
-    DarkPoolSettlement::add_escrow_balance(&env, &alice, &asset, 1000);
-    DarkPoolSettlement::add_locked_balance(&env, &alice, &asset, 1000);
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let buy_fill = BytesN::from_array(&env, &[5u8; 32]);
+    let sell_fill = BytesN::from_array(&env, &[6u8; 32]);
+    let asset_hash = registry_client.compute_asset_hash(&asset);
+    let settle = |nullifier: u8, buy: &BytesN<32>, sell: &BytesN<32>, partial: bool| {
+        let match_id = BytesN::from_array(&env, &[nullifier; 32]);
+        orderbook_client.record_match(&match_id, buy, sell, &4_000_000, &20_000_000);
+        let fills = ([&zero, &buy_fill, &zero, &sell_fill], [false, false]);
+        let trade = (&asset_hash, 4_000_000, 20_000_000);
+        let signals = trade_pub_signals(
+            &env,
+            nullifier,
+            [&root, &asset_root],
+            [buy, sell],
+            trade,
+            partial.then_some(fills),
+        );
+        client.try_settle_trade(
+            &match_id,
+            &buyer,
+            &seller,
+            &asset,
+            &payment_asset,
+            &4_000_000,
+            &20_000_000,
+            &groth16_proof(&env, &signals),
+            &signals,
+        )
+    };
 
-    // Transfer from Alice to Bob
-    let result = DarkPoolSettlement::transfer_from_escrow(&env, &alice, &bob, &asset, 500);
-    assert!(result.is_ok());
+    // Partial fill proofs need their own key
+    let result = settle(7, &orders[0], &orders[1], true);
+    assert_eq!(result.err(), Some(Ok(SettlementError::PartialFillVkNotSet)));
 
-    // Check balances
-    let alice_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), alice.clone(), asset.clone());
-    let bob_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), bob.clone(), asset.clone());
+    // A key for another circuit does not fit the signals and cannot verify them
+    client.set_partial_fill_vk(&admin, &whole_vk);
+    let result = settle(7, &orders[0], &orders[1], true);
+    assert_eq!(result.err(), Some(Ok(SettlementError::InvalidProof)));
 
-    assert_eq!(alice_balance, 500);
-    assert_eq!(bob_balance, 500);
+    client.set_partial_fill_vk(&admin, &partial_vk);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [PartialFillVkSet {
+            vk_hash: env.crypto().sha256(&partial_vk).into(),
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert!(settle(7, &orders[0], &orders[1], true).is_ok());
+    assert!(settle(8, &orders[2], &orders[3], false).is_ok());
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 8_000_000);
 
-    // Alice's locked balance should also decrease
-    let alice_locked = DarkPoolSettlement::get_locked_balance(env.clone(), alice.clone(), asset.clone());
-    assert_eq!(alice_locked, 500);
+    // Only the admin can replace either key
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_settlement_vk(&stranger, &partial_vk),
+        Err(Ok(SettlementError::OnlyAdmin))
+    );
+    assert_eq!(
+        client.try_set_partial_fill_vk(&stranger, &whole_vk),
+        Err(Ok(SettlementError::OnlyAdmin))
+    );
 }
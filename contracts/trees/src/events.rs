@@ -0,0 +1,18 @@
+//! Trees events
+//!
+//! Each event's first topic is its name in snake_case (e.g. `root_changed`),
+//! followed by the fields marked `#[topic]`. The remaining fields form the data map.
+
+use soroban_sdk::{contractevent, Address, BytesN};
+
+/// A tree's root changed and a new epoch started
+#[contractevent]
+#[derive(Clone)]
+pub struct RootChanged {
+    #[topic]
+    pub owner: Address,
+    #[topic]
+    pub tree: u32,
+    pub epoch: u32,
+    pub root: BytesN<32>,
+}
@@ -0,0 +1,412 @@
+#![no_std]
+
+use lean_imt_bn254::{zero_hash, NodeHasher};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, vec, Address, BytesN, Env, IntoVal, Val,
+    Vec,
+};
+
+mod events;
+
+pub use events::*;
+
+#[cfg(test)]
+mod test;
+
+// Number of roots kept in each tree's root history
+const ROOT_HISTORY_SIZE: u32 = 64;
+
+// Levels of nodes kept in one storage entry. A path from a leaf to the root
+// touches one entry per four levels, so appends and updates stay within the
+// per-transaction ledger entry limits whatever the size of the tree.
+const TILE_HEIGHT: u32 = 4;
+
+// Persistent entries are extended to ~90 days whenever fewer than ~30 days remain
+// (ledgers close about every 5 seconds)
+const DAY_IN_LEDGERS: u32 = 17280;
+const ENTRY_TTL_THRESHOLD: u32 = 30 * DAY_IN_LEDGERS;
+const ENTRY_TTL_EXTEND: u32 = 90 * DAY_IN_LEDGERS;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreesError {
+    TreeNotFound = 1,
+    TreeAlreadyExists = 2,
+    TreeAtCapacity = 3,
+    LeafNotFound = 4,
+    InvalidDepth = 5,
+}
+
+/// A tree root together with the epoch and time it became current
+#[derive(Clone)]
+#[contracttype]
+pub struct RootRecord {
+    pub root: BytesN<32>,
+    pub epoch: u32,
+    pub timestamp: u64,
+}
+
+/// Persistent storage keys, one set per `(owner, tree)`
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Depth and number of leaves appended so far
+    Tree(Address, u32),
+    /// Nodes of up to four levels starting at `level`, below node `index` of
+    /// the first level above them; bottom level first, left to right
+    Tile(Address, u32, u32, u32),
+    /// Recent roots, oldest first; the last entry is the current root
+    History(Address, u32),
+}
+
+/// Merkle trees kept on behalf of other contracts
+///
+/// Each tree is identified by the contract (or account) that owns it and a
+/// number chosen by the owner. Only the owner can change its trees; anyone can
+/// read them. Trees are LeanIMTs with Poseidon2 hashing and keep their last 64
+/// roots, so proofs built against a recent root can still be checked.
+#[contract]
+pub struct DarkPoolTrees;
+
+#[contractimpl]
+impl DarkPoolTrees {
+    /// Create an empty tree
+    ///
+    /// # Arguments
+    /// * `owner` - Owner of the tree, must authorize
+    /// * `tree` - Owner-chosen tree number
+    /// * `depth` - Tree depth, from 1 to 32; the tree holds up to `2^depth` leaves
+    pub fn create(env: Env, owner: Address, tree: u32, depth: u32) -> Result<(), TreesError> {
+        owner.require_auth();
+
+        if env.storage().persistent().has(&DataKey::Tree(owner.clone(), tree)) {
+            return Err(TreesError::TreeAlreadyExists);
+        }
+
+        if depth == 0 {
+            return Err(TreesError::InvalidDepth);
+        }
+        let empty_root = zero_hash(&env, depth).ok_or(TreesError::InvalidDepth)?;
+        Self::persist(&env, &DataKey::Tree(owner.clone(), tree), &(depth, 0u32));
+        Self::record_root(&env, &owner, tree, empty_root, vec![&env]);
+        Ok(())
+    }
+
+    /// Append leaves to a tree
+    ///
+    /// Only the nodes above the new leaves are rehashed, and the root history
+    /// records one new root for the whole batch.
+    ///
+    /// # Returns
+    /// * The index of the first appended leaf
+    pub fn append(
+        env: Env,
+        owner: Address,
+        tree: u32,
+        leaves: Vec<BytesN<32>>,
+    ) -> Result<u32, TreesError> {
+        owner.require_auth();
+
+        let (depth, first_index) = Self::info(&env, &owner, tree)?;
+        if first_index as u64 + leaves.len() as u64 > 1u64 << depth {
+            return Err(TreesError::TreeAtCapacity);
+        }
+        if leaves.is_empty() {
+            return Ok(first_index);
+        }
+
+        Self::write(&env, &owner, tree, depth, first_index, leaves.clone());
+        let info = (depth, first_index + leaves.len());
+        Self::persist(&env, &DataKey::Tree(owner.clone(), tree), &info);
+        Ok(first_index)
+    }
+
+    /// Replace the leaf at `index`, rehashing only its path to the root
+    pub fn update(
+        env: Env,
+        owner: Address,
+        tree: u32,
+        index: u32,
+        leaf: BytesN<32>,
+    ) -> Result<(), TreesError> {
+        owner.require_auth();
+
+        let (depth, count) = Self::info(&env, &owner, tree)?;
+        if index >= count {
+            return Err(TreesError::LeafNotFound);
+        }
+        Self::write(&env, &owner, tree, depth, index, vec![&env, leaf]);
+        Ok(())
+    }
+
+    /// Get a tree's current root
+    pub fn get_root(env: Env, owner: Address, tree: u32) -> Result<BytesN<32>, TreesError> {
+        let history = Self::history(&env, &owner, tree)?;
+        Ok(history.last_unchecked().root)
+    }
+
+    /// Get a tree's depth
+    pub fn get_depth(env: Env, owner: Address, tree: u32) -> Result<u32, TreesError> {
+        Ok(Self::info(&env, &owner, tree)?.0)
+    }
+
+    /// Get the number of leaves in a tree, including cleared ones
+    pub fn get_leaf_count(env: Env, owner: Address, tree: u32) -> Result<u32, TreesError> {
+        Ok(Self::info(&env, &owner, tree)?.1)
+    }
+
+    /// Get the leaf at `index` (cleared leaves are zero)
+    pub fn get_leaf(
+        env: Env,
+        owner: Address,
+        tree: u32,
+        index: u32,
+    ) -> Result<BytesN<32>, TreesError> {
+        let (depth, count) = Self::info(&env, &owner, tree)?;
+        if index >= count {
+            return Err(TreesError::LeafNotFound);
+        }
+        let height = TILE_HEIGHT.min(depth);
+        let tile = Self::tile(&env, &owner, tree, 0, index >> height, height);
+        Ok(tile.get_unchecked(index & ((1 << height) - 1)))
+    }
+
+    /// Get all leaves of a tree in index order (cleared leaves are zero)
+    ///
+    /// Reads one entry per 16 leaves; meant for off-chain queries and
+    /// migrations of small trees.
+    pub fn get_leaves(
+        env: Env,
+        owner: Address,
+        tree: u32,
+    ) -> Result<Vec<BytesN<32>>, TreesError> {
+        let (depth, count) = Self::info(&env, &owner, tree)?;
+        let height = TILE_HEIGHT.min(depth);
+        let mut leaves = vec![&env];
+        let mut tile_index = 0;
+        while leaves.len() < count {
+            let tile = Self::tile(&env, &owner, tree, 0, tile_index, height);
+            for leaf in tile.iter().take((count - leaves.len()).min(1 << height) as usize) {
+                leaves.push_back(leaf);
+            }
+            tile_index += 1;
+        }
+        Ok(leaves)
+    }
+
+    /// Get the epoch of a tree's current root
+    ///
+    /// The empty tree is epoch 0 and every root change starts a new epoch.
+    pub fn get_epoch(env: Env, owner: Address, tree: u32) -> Result<u32, TreesError> {
+        let history = Self::history(&env, &owner, tree)?;
+        Ok(history.last_unchecked().epoch)
+    }
+
+    /// Get a tree's recent roots, oldest first
+    pub fn get_root_history(
+        env: Env,
+        owner: Address,
+        tree: u32,
+    ) -> Result<Vec<RootRecord>, TreesError> {
+        Self::history(&env, &owner, tree)
+    }
+
+    /// Get the root recorded for an epoch, if still in the history
+    pub fn get_root_at(
+        env: Env,
+        owner: Address,
+        tree: u32,
+        epoch: u32,
+    ) -> Result<Option<RootRecord>, TreesError> {
+        let history = Self::history(&env, &owner, tree)?;
+        Ok(history.iter().find(|r| r.epoch == epoch))
+    }
+
+    /// Check if a root is the current root of a tree or still in its history
+    pub fn is_known_root(env: Env, owner: Address, tree: u32, root: BytesN<32>) -> bool {
+        match Self::history(&env, &owner, tree) {
+            Ok(history) => history.iter().any(|r| r.root == root),
+            Err(_) => false,
+        }
+    }
+
+    /// Check if a root is current, or was replaced no more than `max_age` seconds ago
+    pub fn is_root_fresh(
+        env: Env,
+        owner: Address,
+        tree: u32,
+        root: BytesN<32>,
+        max_age: u64,
+    ) -> bool {
+        let Ok(history) = Self::history(&env, &owner, tree) else {
+            return false;
+        };
+        let current_time = env.ledger().timestamp();
+
+        // Search newest first so a repeated root uses its latest occurrence
+        let mut index = history.len();
+        while index > 0 {
+            index -= 1;
+            if history.get_unchecked(index).root != root {
+                continue;
+            }
+            return match history.get(index + 1) {
+                None => true,
+                Some(next) => current_time <= next.timestamp.saturating_add(max_age),
+            };
+        }
+        false
+    }
+
+    // Internal helper functions
+
+    /// Write a persistent entry and extend its TTL
+    fn persist<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, ENTRY_TTL_THRESHOLD, ENTRY_TTL_EXTEND);
+    }
+
+    /// Get a tree's depth and leaf count
+    fn info(env: &Env, owner: &Address, tree: u32) -> Result<(u32, u32), TreesError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Tree(owner.clone(), tree))
+            .ok_or(TreesError::TreeNotFound)
+    }
+
+    fn history(env: &Env, owner: &Address, tree: u32) -> Result<Vec<RootRecord>, TreesError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::History(owner.clone(), tree))
+            .ok_or(TreesError::TreeNotFound)
+    }
+
+    /// Load a tile, or the empty tile if nothing was written below it yet
+    fn tile(
+        env: &Env,
+        owner: &Address,
+        tree: u32,
+        level: u32,
+        index: u32,
+        height: u32,
+    ) -> Vec<BytesN<32>> {
+        let key = DataKey::Tile(owner.clone(), tree, level, index);
+        if let Some(tile) = env.storage().persistent().get(&key) {
+            return tile;
+        }
+        let mut tile = vec![env];
+        for row in 0..height {
+            let zero = zero_hash(env, level + row).unwrap();
+            for _ in 0..1u32 << (height - row) {
+                tile.push_back(zero.clone());
+            }
+        }
+        tile
+    }
+
+    /// Write consecutive leaves from `first_index` and record the new root
+    ///
+    /// Works up the tree one band of tiles at a time: the tiles holding the
+    /// written nodes are rehashed above them, and their tops become the written
+    /// nodes of the next band. A single leaf touches one tile per band.
+    fn write(
+        env: &Env,
+        owner: &Address,
+        tree: u32,
+        depth: u32,
+        first_index: u32,
+        leaves: Vec<BytesN<32>>,
+    ) {
+        let mut hasher = NodeHasher::new(env);
+        let mut first = first_index;
+        let mut nodes = leaves;
+        let mut level = 0;
+
+        while level < depth {
+            let height = TILE_HEIGHT.min(depth - level);
+            let last = first + nodes.len() - 1;
+            let mut tops = vec![env];
+
+            for tile_index in (first >> height)..=(last >> height) {
+                let mut tile = Self::tile(env, owner, tree, level, tile_index, height);
+                let base = tile_index << height;
+                let mut low = first.max(base) - base;
+                let mut high = last.min(base + (1 << height) - 1) - base;
+                for i in low..=high {
+                    tile.set(i, nodes.get_unchecked(base + i - first));
+                }
+
+                // Rehash the parents of the written range, row by row
+                let mut row_start = 0;
+                let mut width = 1u32 << height;
+                while width > 2 {
+                    let parents = row_start + width;
+                    for parent in low / 2..=high / 2 {
+                        let left = tile.get_unchecked(row_start + 2 * parent);
+                        let right = tile.get_unchecked(row_start + 2 * parent + 1);
+                        tile.set(parents + parent, hasher.hash(&left, &right));
+                    }
+                    (low, high) = (low / 2, high / 2);
+                    row_start = parents;
+                    width /= 2;
+                }
+                let top = hasher.hash(
+                    &tile.get_unchecked(row_start),
+                    &tile.get_unchecked(row_start + 1),
+                );
+
+                let key = DataKey::Tile(owner.clone(), tree, level, tile_index);
+                Self::persist(env, &key, &tile);
+                tops.push_back(top);
+            }
+
+            first >>= height;
+            nodes = tops;
+            level += height;
+        }
+
+        let history = Self::history(env, owner, tree).unwrap_or(vec![env]);
+        Self::record_root(env, owner, tree, nodes.get_unchecked(0), history);
+    }
+
+    /// Append a root to a tree's bounded history unless it is already current
+    fn record_root(
+        env: &Env,
+        owner: &Address,
+        tree: u32,
+        root: BytesN<32>,
+        mut history: Vec<RootRecord>,
+    ) {
+        let key = DataKey::History(owner.clone(), tree);
+        let epoch = match history.last() {
+            Some(latest) if latest.root == root => {
+                // Keep the entry alive even when nothing changed
+                Self::persist(env, &key, &history);
+                return;
+            }
+            Some(latest) => latest.epoch + 1,
+            None => 0,
+        };
+
+        history.push_back(RootRecord {
+            root: root.clone(),
+            epoch,
+            timestamp: env.ledger().timestamp(),
+        });
+        while history.len() > ROOT_HISTORY_SIZE {
+            history.pop_front();
+        }
+        Self::persist(env, &key, &history);
+
+        RootChanged {
+            owner: owner.clone(),
+            tree,
+            epoch,
+            root,
+        }
+        .publish(env);
+    }
+}
@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+use super::*;
+use lean_imt_bn254::LeanIMTBN254;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    Env, Event, IntoVal,
+};
+
+fn leaf(env: &Env, value: u8) -> BytesN<32> {
+    let mut bytes = [0u8; 32];
+    bytes[31] = value;
+    BytesN::from_array(env, &bytes)
+}
+
+fn setup(env: &Env) -> (DarkPoolTreesClient<'_>, Address) {
+    let contract_id = env.register(DarkPoolTrees, ());
+    let client = DarkPoolTreesClient::new(env, &contract_id);
+    (client, Address::generate(env))
+}
+
+#[test]
+fn test_tree_matches_lean_imt() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, owner) = setup(&env);
+
+    client.create(&owner, &0, &10);
+    assert_eq!(client.get_root(&owner, &0), LeanIMTBN254::new(&env, 10).get_root());
+    assert_eq!(client.get_depth(&owner, &0), 10);
+    assert_eq!(client.get_epoch(&owner, &0), 0);
+
+    assert_eq!(client.append(&owner, &0, &vec![&env, leaf(&env, 1), leaf(&env, 2)]), 0);
+    assert_eq!(client.append(&owner, &0, &vec![&env, leaf(&env, 3)]), 2);
+    client.update(&owner, &0, &1, &leaf(&env, 0));
+
+    let mut expected = LeanIMTBN254::new(&env, 10);
+    for value in [1, 2, 3] {
+        expected.insert(leaf(&env, value)).unwrap();
+    }
+    expected.update(1, leaf(&env, 0)).unwrap();
+
+    assert_eq!(client.get_root(&owner, &0), expected.get_root());
+    assert_eq!(client.get_leaves(&owner, &0), expected.get_leaves().clone());
+    assert_eq!(client.get_leaf_count(&owner, &0), 3);
+    assert_eq!(client.get_leaf(&owner, &0, &2), leaf(&env, 3));
+    assert_eq!(client.try_get_leaf(&owner, &0, &3), Err(Ok(TreesError::LeafNotFound)));
+    // One epoch per call, not per leaf
+    assert_eq!(client.get_epoch(&owner, &0), 3);
+}
+
+#[test]
+fn test_trees_are_namespaced_by_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, owner) = setup(&env);
+    let other = Address::generate(&env);
+
+    client.create(&owner, &0, &10);
+    client.create(&other, &0, &10);
+    client.create(&owner, &1, &10);
+    assert_eq!(client.try_create(&owner, &0, &10), Err(Ok(TreesError::TreeAlreadyExists)));
+
+    client.append(&owner, &0, &vec![&env, leaf(&env, 1)]);
+    assert_eq!(client.get_leaf_count(&owner, &0), 1);
+    assert_eq!(client.get_leaf_count(&owner, &1), 0);
+    assert_eq!(client.get_leaf_count(&other, &0), 0);
+
+    assert_eq!(client.try_get_root(&owner, &2), Err(Ok(TreesError::TreeNotFound)));
+    assert_eq!(
+        client.try_append(&owner, &2, &vec![&env, leaf(&env, 1)]),
+        Err(Ok(TreesError::TreeNotFound))
+    );
+    assert!(!client.is_known_root(&owner, &2, &leaf(&env, 0)));
+}
+
+#[test]
+fn test_only_owner_can_change_its_trees() {
+    let env = Env::default();
+    let (client, owner) = setup(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "create",
+            args: (&owner, 0u32, 10u32).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.create(&owner, &0, &10);
+
+    // The stranger signs, but the tree belongs to `owner`
+    let leaves = vec![&env, leaf(&env, 1)];
+    env.mock_auths(&[MockAuth {
+        address: &stranger,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "append",
+            args: (&owner, 0u32, leaves.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_append(&owner, &0, &leaves).is_err());
+    assert_eq!(client.get_leaf_count(&owner, &0), 0);
+}
+
+#[test]
+fn test_update_and_capacity_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, owner) = setup(&env);
+
+    client.create(&owner, &0, &1);
+    client.append(&owner, &0, &vec![&env, leaf(&env, 1), leaf(&env, 2)]);
+    assert_eq!(
+        client.try_append(&owner, &0, &vec![&env, leaf(&env, 3)]),
+        Err(Ok(TreesError::TreeAtCapacity))
+    );
+    assert_eq!(
+        client.try_update(&owner, &0, &2, &leaf(&env, 3)),
+        Err(Ok(TreesError::LeafNotFound))
+    );
+    assert_eq!(client.try_create(&owner, &1, &0), Err(Ok(TreesError::InvalidDepth)));
+    assert_eq!(client.try_create(&owner, &1, &33), Err(Ok(TreesError::InvalidDepth)));
+}
+
+#[test]
+fn test_append_and_update_cost_does_not_grow_with_the_tree() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, owner) = setup(&env);
+    client.create(&owner, &0, &20);
+
+    let cpu_cost = |f: &dyn Fn()| {
+        env.cost_estimate().budget().reset_default();
+        f();
+        env.cost_estimate().budget().cpu_instruction_cost()
+    };
+    client.append(&owner, &0, &vec![&env, leaf(&env, 1)]);
+    let small_append = cpu_cost(&|| {
+        client.append(&owner, &0, &vec![&env, leaf(&env, 2)]);
+    });
+    let small_update = cpu_cost(&|| client.update(&owner, &0, &0, &leaf(&env, 3)));
+
+    env.cost_estimate().budget().reset_unlimited();
+    let mut batch = vec![&env];
+    for value in 0..300u32 {
+        batch.push_back(leaf(&env, value as u8));
+    }
+    client.append(&owner, &0, &batch);
+    client.update(&owner, &0, &150, &leaf(&env, 6));
+    let leaves = client.get_leaves(&owner, &0);
+    assert_eq!(leaves.len(), 302);
+    let expected = LeanIMTBN254::from_storage(&env, leaves, 20, client.get_root(&owner, &0));
+    assert_eq!(expected.get_root(), client.get_root(&owner, &0));
+
+    let large_append = cpu_cost(&|| {
+        client.append(&owner, &0, &vec![&env, leaf(&env, 4)]);
+    });
+    let large_update = cpu_cost(&|| client.update(&owner, &0, &0, &leaf(&env, 5)));
+    assert!(large_append < small_append * 11 / 10);
+    assert!(large_update < small_update * 11 / 10);
+}
+
+#[test]
+fn test_root_history_and_freshness() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, owner) = setup(&env);
+
+    env.ledger().set_timestamp(1_000);
+    client.create(&owner, &0, &10);
+    let empty_root = client.get_root(&owner, &0);
+
+    env.ledger().set_timestamp(2_000);
+    client.append(&owner, &0, &vec![&env, leaf(&env, 1)]);
+    let events = env.events().all().filter_by_contract(&client.address);
+    let root = client.get_root(&owner, &0);
+
+    assert_eq!(
+        events,
+        [RootChanged {
+            owner: owner.clone(),
+            tree: 0,
+            epoch: 1,
+            root: root.clone(),
+        }
+        .to_xdr(&env, &client.address)]
+    );
+
+    let history = client.get_root_history(&owner, &0);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().root, empty_root);
+    assert_eq!(client.get_root_at(&owner, &0, &1).unwrap().timestamp, 2_000);
+    assert!(client.get_root_at(&owner, &0, &2).is_none());
+
+    // The replaced root stays fresh for `max_age` seconds after its replacement
+    env.ledger().set_timestamp(2_100);
+    assert!(client.is_root_fresh(&owner, &0, &root, &0));
+    assert!(client.is_root_fresh(&owner, &0, &empty_root, &100));
+    assert!(!client.is_root_fresh(&owner, &0, &empty_root, &99));
+    assert!(client.is_known_root(&owner, &0, &empty_root));
+    assert!(!client.is_root_fresh(&owner, &0, &leaf(&env, 9), &u64::MAX));
+
+    // Only the last ROOT_HISTORY_SIZE roots are kept
+    for value in 2..(ROOT_HISTORY_SIZE as u8 + 1) {
+        client.append(&owner, &0, &vec![&env, leaf(&env, value)]);
+    }
+    let history = client.get_root_history(&owner, &0);
+    assert_eq!(history.len(), ROOT_HISTORY_SIZE);
+    assert!(!client.is_known_root(&owner, &0, &empty_root));
+    assert!(client.is_known_root(&owner, &0, &root));
+}
@@ -0,0 +1,21 @@
+//! Verifier events
+//!
+//! Each event's first topic is its name in snake_case (e.g. `contract_upgraded`),
+//! followed by the fields marked `#[topic]`. The remaining fields form the data map.
+
+use soroban_sdk::{contractevent, BytesN};
+
+/// The contract code was replaced
+#[contractevent]
+#[derive(Clone)]
+pub struct ContractUpgraded {
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Stored data was migrated to a new schema version
+#[contractevent]
+#[derive(Clone)]
+pub struct SchemaMigrated {
+    #[topic]
+    pub version: u32,
+}
@@ -1,11 +1,22 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec,
     crypto::bn254::{Fr, Bn254G1Affine, Bn254G2Affine},
-    Bytes, BytesN, Env, Vec,
+    Address, Bytes, BytesN, Env, Symbol, Vec,
 };
 
+mod events;
+
+pub use events::*;
+
+// Storage keys
+const ADMIN_KEY: Symbol = symbol_short!("admin");
+const SCHEMA_VERSION_KEY: Symbol = symbol_short!("schema");
+
+// Storage layout version written by this code; bump it with a new migration step
+const SCHEMA_VERSION: u32 = 1;
+
 // Type aliases for cleaner code
 type G1Affine = Bn254G1Affine;
 type G2Affine = Bn254G2Affine;
@@ -18,6 +29,8 @@ pub enum VerifierError {
     MalformedProof = 2,
     InvalidPublicSignals = 3,
     PairingCheckFailed = 4,
+    OnlyAdmin = 5,
+    UnsupportedSchemaVersion = 6,
 }
 
 /// BN254 G1 Affine point size (64 bytes: 32 for x, 32 for y)
@@ -52,6 +65,15 @@ pub struct Groth16VerifierBN254;
 
 #[contractimpl]
 impl Groth16VerifierBN254 {
+    /// Initialize the verifier contract
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address allowed to upgrade the contract
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &SCHEMA_VERSION);
+    }
+
     /// Verifies a Groth16 proof using BN254 curve
     ///
     /// # Arguments
@@ -108,6 +130,84 @@ impl Groth16VerifierBN254 {
         Self::verify_proof(env, vk, proof, pub_signals)
     }
 
+    /// Upgrade the contract code, keeping its storage
+    ///
+    /// Call `migrate` afterwards when the new code expects a newer storage schema.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `new_wasm_hash` - Hash of the already uploaded wasm to switch to
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), VerifierError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded {
+            wasm_hash: new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Convert storage from the stored schema version to `SCHEMA_VERSION`
+    ///
+    /// Runs one migration step per version, recording progress after each.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    ///
+    /// # Returns
+    /// * The schema version after migrating
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, VerifierError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut version = Self::get_schema_version(env.clone());
+        if version > SCHEMA_VERSION {
+            return Err(VerifierError::UnsupportedSchemaVersion);
+        }
+
+        while version < SCHEMA_VERSION {
+            Self::migrate_step(&env, version)?;
+            version += 1;
+            env.storage().instance().set(&SCHEMA_VERSION_KEY, &version);
+            SchemaMigrated { version }.publish(&env);
+        }
+        Ok(version)
+    }
+
+    /// Get the schema version of the stored data
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEMA_VERSION_KEY).unwrap_or(1)
+    }
+
+    /// Get admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN_KEY).unwrap()
+    }
+
+    /// Verify caller is admin
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), VerifierError> {
+        let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
+        if *caller != admin {
+            return Err(VerifierError::OnlyAdmin);
+        }
+        Ok(())
+    }
+
+    /// Convert storage from schema `from_version` to `from_version + 1`
+    ///
+    /// Version 1 is the original layout; each bump of `SCHEMA_VERSION` adds the
+    /// step converting from the previous version here.
+    fn migrate_step(_env: &Env, _from_version: u32) -> Result<(), VerifierError> {
+        Err(VerifierError::UnsupportedSchemaVersion)
+    }
+
     /// Parse verification key from bytes
     fn parse_verification_key(env: &Env, bytes: &Bytes) -> Result<VerificationKey, VerifierError> {
         let mut pos = 0usize;
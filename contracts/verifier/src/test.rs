@@ -1,13 +1,45 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::Env;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Env,
+};
 
 #[test]
 fn test_verifier_contract_deploys() {
     let env = Env::default();
-    let contract_id = env.register(Groth16VerifierBN254, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(Groth16VerifierBN254, (&admin,));
 
     // Contract should deploy successfully
     assert!(!contract_id.to_string().is_empty());
 }
+
+#[test]
+fn test_upgrade_and_migrate_require_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(Groth16VerifierBN254, (&admin,));
+    let client = Groth16VerifierBN254Client::new(&env, &contract_id);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+    assert_eq!(
+        client.try_upgrade(&stranger, &BytesN::from_array(&env, &[0u8; 32])),
+        Err(Ok(VerifierError::OnlyAdmin))
+    );
+    assert_eq!(client.try_migrate(&stranger), Err(Ok(VerifierError::OnlyAdmin)));
+
+    // Already on the current schema: nothing to run
+    assert_eq!(client.migrate(&admin), SCHEMA_VERSION);
+    assert_eq!(env.events().all().filter_by_contract(&contract_id), []);
+
+    // Data written by newer code cannot be migrated down
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(&SCHEMA_VERSION_KEY, &(SCHEMA_VERSION + 1));
+    });
+    assert_eq!(client.try_migrate(&admin), Err(Ok(VerifierError::UnsupportedSchemaVersion)));
+}
@@ -1,5 +1,8 @@
 #![no_std]
 
+mod poseidon2_params;
+
+use poseidon2_params::{Bn254, ZERO_HASHES};
 use soroban_poseidon::{poseidon2_hash, Poseidon2Sponge};
 use soroban_sdk::{
     crypto::bn254::Fr as Bn254Scalar, symbol_short, vec, BytesN, Env, Map, Symbol, Vec, U256,
@@ -25,6 +28,84 @@ pub fn bytes_to_bn254_scalar(bytes_n: &BytesN<32>) -> Bn254Scalar {
     Bn254Scalar::from_bytes(bytes_n.clone())
 }
 
+/// Appends a leaf to a tree kept only as its frontier and returns the new root
+///
+/// `frontier[level]` is the root of the last left-hand subtree completed at
+/// `level`, which is all an append-only tree needs: each insert hashes `depth`
+/// pairs and never reads earlier leaves. `leaf_index` is the number of leaves
+/// already appended. Roots match [`LeanIMTBN254`] for the same leaves.
+pub fn frontier_insert(
+    env: &Env,
+    frontier: &mut Vec<BytesN<32>>,
+    leaf_index: u32,
+    leaf: BytesN<32>,
+    depth: u32,
+) -> Result<BytesN<32>, &'static str> {
+    if leaf_index >= 1u32.checked_shl(depth).unwrap_or(u32::MAX) {
+        return Err("Tree is at capacity: cannot insert more leaves");
+    }
+
+    let mut sponge = Poseidon2Sponge::<3, Bn254>::new(env);
+    let mut node = bytes_to_bn254_scalar(&leaf);
+    let mut index = leaf_index;
+
+    for level in 0..depth {
+        let (left, right) = if index.is_multiple_of(2) {
+            // A left node completes a new subtree at this level; its sibling is still empty
+            let zero = ZERO_HASHES.get(level as usize).ok_or("Tree is too deep for a frontier")?;
+            if level < frontier.len() {
+                frontier.set(level, bn254_scalar_to_bytes(&node));
+            } else {
+                frontier.push_back(bn254_scalar_to_bytes(&node));
+            }
+            (node, bytes_to_bn254_scalar(&BytesN::from_array(env, zero)))
+        } else {
+            let filled = frontier.get(level).ok_or("Frontier is missing a filled subtree")?;
+            (bytes_to_bn254_scalar(&filled), node)
+        };
+
+        let inputs = Vec::from_array(env, [left.to_u256(), right.to_u256()]);
+        node = Bn254Scalar::from_u256(sponge.compute_hash(&inputs));
+        index /= 2;
+    }
+
+    Ok(bn254_scalar_to_bytes(&node))
+}
+
+/// Root of an all-zero subtree of height `level`, for levels up to 32
+pub fn zero_hash(env: &Env, level: u32) -> Option<BytesN<32>> {
+    ZERO_HASHES
+        .get(level as usize)
+        .map(|hash| BytesN::from_array(env, hash))
+}
+
+/// Hashes pairs of sibling nodes the way [`LeanIMTBN254`] does
+///
+/// Keeps one Poseidon2 sponge for all pairs, so callers maintaining their own
+/// node storage avoid rebuilding the parameters for every hash.
+pub struct NodeHasher {
+    env: Env,
+    sponge: Poseidon2Sponge<3, Bn254>,
+}
+
+impl NodeHasher {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            env: env.clone(),
+            sponge: Poseidon2Sponge::<3, Bn254>::new(env),
+        }
+    }
+
+    /// Returns the parent of `left` and `right`
+    pub fn hash(&mut self, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let inputs = Vec::from_array(
+            &self.env,
+            [bytes_to_bn254_scalar(left).to_u256(), bytes_to_bn254_scalar(right).to_u256()],
+        );
+        bn254_scalar_to_bytes(&Bn254Scalar::from_u256(self.sponge.compute_hash(&inputs)))
+    }
+}
+
 /// Lean Incremental Merkle Tree implementation for BN254 curve
 /// Uses Poseidon2 hash function optimized for BN254 scalar field
 pub struct LeanIMTBN254 {
@@ -57,7 +138,7 @@ impl LeanIMTBN254 {
 
     /// Inserts a new leaf into the tree
     pub fn insert(&mut self, leaf: BytesN<32>) -> Result<(), &'static str> {
-        let current_count = self.leaves.len() as u32;
+        let current_count = self.leaves.len();
 
         if current_count >= self.capacity {
             return Err("Tree is at capacity: cannot insert more leaves");
@@ -68,6 +149,18 @@ impl LeanIMTBN254 {
         Ok(())
     }
 
+    /// Replaces the leaf at an existing index and recomputes its path to the root
+    pub fn update(&mut self, leaf_index: u32, leaf: BytesN<32>) -> Result<(), &'static str> {
+        if leaf_index >= self.leaves.len() {
+            return Err("Leaf index out of range");
+        }
+
+        self.leaves.set(leaf_index, leaf.clone());
+        self.cache_sparse_node(0, leaf_index, bytes_to_bn254_scalar(&leaf));
+        self.root = self.recompute_path_to_root_with_cache_update(leaf_index);
+        Ok(())
+    }
+
     /// Gets the current root of the tree
     pub fn get_root(&self) -> BytesN<32> {
         self.root.clone()
@@ -85,7 +178,7 @@ impl LeanIMTBN254 {
 
     /// Gets the number of leaves that have been explicitly inserted
     pub fn get_leaf_count(&self) -> u32 {
-        self.leaves.len() as u32
+        self.leaves.len()
     }
 
     /// Gets the maximum capacity of the tree (2^depth)
@@ -100,7 +193,7 @@ impl LeanIMTBN254 {
 
     /// Generates a merkle proof for a given leaf index
     pub fn generate_proof(&self, leaf_index: u32) -> Option<(Vec<Bn254Scalar>, u32)> {
-        if leaf_index >= self.leaves.len() as u32 {
+        if leaf_index >= self.leaves.len() {
             return None;
         }
 
@@ -119,14 +212,14 @@ impl LeanIMTBN254 {
             let mut current_depth = 0;
 
             while current_depth < self.depth {
-                let sibling_index = if current_index % 2 == 0 {
+                let sibling_index = if current_index.is_multiple_of(2) {
                     current_index + 1
                 } else {
                     current_index - 1
                 };
 
                 let sibling_scalar = if current_depth == 0 {
-                    if sibling_index < self.leaves.len() as u32 {
+                    if sibling_index < self.leaves.len() {
                         let sibling_bytes = self.leaves.get(sibling_index).unwrap();
                         bytes_to_bn254_scalar(&sibling_bytes)
                     } else {
@@ -137,7 +230,7 @@ impl LeanIMTBN254 {
                 };
 
                 siblings.push_back(sibling_scalar);
-                current_index = current_index / 2;
+                current_index /= 2;
                 current_depth += 1;
             }
         }
@@ -156,7 +249,7 @@ impl LeanIMTBN254 {
         }
 
         if target_level == 0 {
-            if node_index < self.leaves.len() as u32 {
+            if node_index < self.leaves.len() {
                 let leaf_bytes = self.leaves.get(node_index).unwrap();
                 bytes_to_bn254_scalar(&leaf_bytes)
             } else {
@@ -176,7 +269,7 @@ impl LeanIMTBN254 {
 
     /// Incremental update using path recomputation
     fn incremental_update(&mut self) {
-        let leaf_index = (self.leaves.len() - 1) as u32;
+        let leaf_index = self.leaves.len() - 1;
 
         let leaf_bytes = self.leaves.get(leaf_index).unwrap();
         let leaf_scalar = bytes_to_bn254_scalar(&leaf_bytes);
@@ -190,21 +283,21 @@ impl LeanIMTBN254 {
         let leaf_bytes = self.leaves.get(leaf_index).unwrap();
         let leaf_scalar = bytes_to_bn254_scalar(&leaf_bytes);
 
-        let mut sponge = Poseidon2Sponge::<3, Bn254Scalar>::new(&self.env);
+        let mut sponge = Poseidon2Sponge::<3, Bn254>::new(&self.env);
 
         let mut current_index = leaf_index;
         let mut current_level = 0;
         let mut current_scalar = leaf_scalar;
 
         while current_level < self.depth {
-            let sibling_index = if current_index % 2 == 0 {
+            let sibling_index = if current_index.is_multiple_of(2) {
                 current_index + 1
             } else {
                 current_index - 1
             };
 
             let sibling_scalar = if current_level == 0 {
-                if sibling_index < self.leaves.len() as u32 {
+                if sibling_index < self.leaves.len() {
                     let sibling_bytes = self.leaves.get(sibling_index).unwrap();
                     bytes_to_bn254_scalar(&sibling_bytes)
                 } else {
@@ -218,7 +311,7 @@ impl LeanIMTBN254 {
                 }
             };
 
-            let parent_scalar = if current_index % 2 == 0 {
+            let parent_scalar = if current_index.is_multiple_of(2) {
                 self.hash_pair_with_sponge(&mut sponge, current_scalar, sibling_scalar)
             } else {
                 self.hash_pair_with_sponge(&mut sponge, sibling_scalar, current_scalar)
@@ -228,7 +321,7 @@ impl LeanIMTBN254 {
             let parent_level = current_level + 1;
             self.cache_sparse_node(parent_level, parent_index, parent_scalar.clone());
 
-            current_index = current_index / 2;
+            current_index /= 2;
             current_level = parent_level;
             current_scalar = parent_scalar;
         }
@@ -256,13 +349,37 @@ impl LeanIMTBN254 {
     }
 
     /// Rebuilds the cache from the current leaves
+    ///
+    /// Every non-empty node is cached level by level so that later path
+    /// recomputations never fall back to recursive subtree hashing.
     fn rebuild_cache_from_leaves(&mut self) {
-        if self.leaves.is_empty() {
-            self.recompute_tree();
-            return;
-        }
         self.subtree_cache = Map::new(&self.env);
         self.sparse_cache = Map::new(&self.env);
+        self.recompute_tree();
+
+        if self.leaves.is_empty() || self.depth == 0 {
+            return;
+        }
+
+        for (index, leaf_bytes) in self.leaves.iter().enumerate() {
+            self.cache_sparse_node(0, index as u32, bytes_to_bn254_scalar(&leaf_bytes));
+        }
+
+        let mut sponge = Poseidon2Sponge::<3, Bn254>::new(&self.env);
+        let mut level_count = self.leaves.len();
+
+        for level in 1..=self.depth {
+            let parent_count = level_count.div_ceil(2);
+            for parent_index in 0..parent_count {
+                let left = self.get_cached_node(level - 1, parent_index * 2).unwrap();
+                let right = self.get_cached_node(level - 1, parent_index * 2 + 1).unwrap();
+                let parent = self.hash_pair_with_sponge(&mut sponge, left, right);
+                self.cache_sparse_node(level, parent_index, parent);
+            }
+            level_count = parent_count;
+        }
+
+        self.root = bn254_scalar_to_bytes(&self.get_cached_node(self.depth, 0).unwrap());
     }
 
     /// Recomputes the entire tree using dynamic programming for empty trees
@@ -272,20 +389,17 @@ impl LeanIMTBN254 {
             return;
         }
 
-        let mut sponge = Poseidon2Sponge::<3, Bn254Scalar>::new(&self.env);
-
         let zero_scalar = Bn254Scalar::from_u256(U256::from_u32(&self.env, 0));
         let mut current_level_hash = zero_scalar.clone();
 
+        // Empty subtree roots come from the precomputed table where available
         for level in 0..=self.depth {
             if level == 0 {
                 current_level_hash = zero_scalar.clone();
+            } else if let Some(zero_hash) = ZERO_HASHES.get(level as usize) {
+                current_level_hash = bytes_to_bn254_scalar(&BytesN::from_array(&self.env, zero_hash));
             } else {
-                current_level_hash = self.hash_pair_with_sponge(
-                    &mut sponge,
-                    current_level_hash.clone(),
-                    current_level_hash,
-                );
+                current_level_hash = self.hash_pair(current_level_hash.clone(), current_level_hash);
             }
             self.cache_subtree_level(level, current_level_hash.clone());
         }
@@ -298,14 +412,14 @@ impl LeanIMTBN254 {
         let left_u256 = Bn254Scalar::to_u256(&left);
         let right_u256 = Bn254Scalar::to_u256(&right);
         let inputs = Vec::from_array(&self.env, [left_u256, right_u256]);
-        let result_u256 = poseidon2_hash::<3, Bn254Scalar>(&self.env, &inputs);
+        let result_u256 = poseidon2_hash::<3, Bn254>(&self.env, &inputs);
         Bn254Scalar::from_u256(result_u256)
     }
 
     /// Hashes using a pre-initialized sponge for efficiency
     fn hash_pair_with_sponge(
         &self,
-        sponge: &mut Poseidon2Sponge<3, Bn254Scalar>,
+        sponge: &mut Poseidon2Sponge<3, Bn254>,
         left: Bn254Scalar,
         right: Bn254Scalar,
     ) -> Bn254Scalar {
@@ -322,6 +436,12 @@ impl LeanIMTBN254 {
     }
 
     /// Deserializes the tree state from storage
+    ///
+    /// Only the leaves are stored, so the node cache is rebuilt from them with
+    /// one hash per internal node (O(n)). Without it, the next insert, update or
+    /// proof would recompute each missing sibling subtree recursively, which is
+    /// exponential in the tree depth. Callers appending to large trees on every
+    /// call should persist a frontier and use [`frontier_insert`] instead.
     pub fn from_storage(env: &Env, leaves: Vec<BytesN<32>>, depth: u32, root: BytesN<32>) -> Self {
         let capacity = 1u32.checked_shl(depth).unwrap_or(u32::MAX);
         let env_clone = env.clone();
@@ -350,10 +470,7 @@ impl LeanIMTBN254 {
 
     /// Gets a leaf at a specific index
     pub fn get_leaf(&self, index: usize) -> Option<BytesN<32>> {
-        match self.leaves.get(index.try_into().unwrap()) {
-            Some(leaf) => Some(leaf.clone()),
-            None => None,
-        }
+        self.leaves.get(index.try_into().unwrap())
     }
 
     /// Gets a leaf as Bn254Scalar at a specific index
@@ -401,6 +518,100 @@ mod tests {
         assert_ne!(initial_root, new_root);
     }
 
+    #[test]
+    fn test_from_storage_matches_in_memory_tree() {
+        let env = Env::default();
+        let mut in_memory = LeanIMTBN254::new(&env, 10);
+        let (mut leaves, mut depth, mut root) = in_memory.to_storage();
+
+        for i in 1..=3u8 {
+            let leaf = BytesN::from_array(&env, &[i; 32]);
+            in_memory.insert(leaf.clone()).unwrap();
+
+            let mut reloaded = LeanIMTBN254::from_storage(&env, leaves, depth, root);
+            reloaded.insert(leaf).unwrap();
+            (leaves, depth, root) = reloaded.to_storage();
+
+            assert_eq!(root, in_memory.get_root());
+        }
+    }
+
+    #[test]
+    fn test_frontier_insert_matches_tree() {
+        let env = Env::default();
+        let mut tree = LeanIMTBN254::new(&env, 10);
+        let mut frontier = vec![&env];
+
+        for i in 0..5u32 {
+            let leaf = BytesN::from_array(&env, &[i as u8 + 1; 32]);
+            tree.insert(leaf.clone()).unwrap();
+            let root = frontier_insert(&env, &mut frontier, i, leaf, 10).unwrap();
+            assert_eq!(root, tree.get_root());
+        }
+        assert_eq!(frontier.len(), 10);
+
+        let leaf = BytesN::from_array(&env, &[9u8; 32]);
+        assert!(frontier_insert(&env, &mut frontier, 4, leaf, 2).is_err());
+    }
+
+    #[test]
+    fn test_node_hasher_matches_tree() {
+        let env = Env::default();
+        let mut tree = LeanIMTBN254::new(&env, 1);
+        let left = BytesN::from_array(&env, &[1u8; 32]);
+        let right = BytesN::from_array(&env, &[2u8; 32]);
+        tree.insert(left.clone()).unwrap();
+        tree.insert(right.clone()).unwrap();
+
+        let mut hasher = NodeHasher::new(&env);
+        assert_eq!(hasher.hash(&left, &right), tree.get_root());
+        let zero = zero_hash(&env, 4).unwrap();
+        assert_eq!(hasher.hash(&zero, &zero), zero_hash(&env, 5).unwrap());
+        assert!(zero_hash(&env, 33).is_none());
+    }
+
+    #[test]
+    fn test_update_leaf() {
+        let env = Env::default();
+        let mut tree = LeanIMTBN254::new(&env, 10);
+        let mut expected = LeanIMTBN254::new(&env, 10);
+
+        for i in 1..=3u8 {
+            tree.insert(BytesN::from_array(&env, &[i; 32])).unwrap();
+        }
+        tree.update(1, BytesN::from_array(&env, &[9u8; 32])).unwrap();
+
+        expected.insert(BytesN::from_array(&env, &[1u8; 32])).unwrap();
+        expected.insert(BytesN::from_array(&env, &[9u8; 32])).unwrap();
+        expected.insert(BytesN::from_array(&env, &[3u8; 32])).unwrap();
+
+        assert_eq!(tree.get_root(), expected.get_root());
+        assert!(tree.update(3, BytesN::from_array(&env, &[1u8; 32])).is_err());
+    }
+
+    #[test]
+    fn test_table_params_match_upstream() {
+        let env = Env::default();
+        let inputs = vec![&env, U256::from_u32(&env, 1), U256::from_u32(&env, 2)];
+
+        assert_eq!(
+            poseidon2_hash::<3, Bn254>(&env, &inputs),
+            poseidon2_hash::<3, Bn254Scalar>(&env, &inputs)
+        );
+    }
+
+    #[test]
+    fn test_zero_hashes_match_poseidon2() {
+        let env = Env::default();
+        let mut zero = U256::from_u32(&env, 0);
+
+        for zero_hash in ZERO_HASHES.iter() {
+            let expected = BytesN::from_array(&env, zero_hash);
+            assert_eq!(bn254_scalar_to_bytes(&Bn254Scalar::from_u256(zero.clone())), expected);
+            zero = poseidon2_hash::<3, Bn254Scalar>(&env, &vec![&env, zero.clone(), zero]);
+        }
+    }
+
     #[test]
     fn test_generate_proof() {
         let env = Env::default();
@@ -414,6 +625,6 @@ mod tests {
 
         let (siblings, depth) = proof.unwrap();
         assert_eq!(depth, 3);
-        assert_eq!(siblings.len() as u32, 3);
+        assert_eq!(siblings.len(), 3);
     }
 }
@@ -0,0 +1,293 @@
+//! Poseidon2 (t = 3) parameters for BN254 kept as byte tables
+//!
+//! `soroban_poseidon` builds its constants with one host call sequence per
+//! value, which costs tens of KB of wasm. [`Bn254`] selects the same
+//! parameters through `Poseidon2Config`, reading them from compact tables.
+//! Empty subtree roots are tabulated too, so trees skip rehashing them on load.
+
+use soroban_poseidon::{Field, Poseidon2Config, Poseidon2Sponge};
+use soroban_sdk::{crypto::BnScalar, symbol_short, vec, Bytes, Env, Symbol, Vec, U256};
+
+/// BN254 scalar field marker for table-backed Poseidon2 parameters
+pub struct Bn254;
+
+impl Field for Bn254 {
+    fn symbol() -> Symbol {
+        symbol_short!("BN254")
+    }
+
+    fn modulus(env: &Env) -> U256 {
+        BnScalar::modulus(env)
+    }
+}
+
+impl Poseidon2Config<3, Bn254> for Poseidon2Sponge<3, Bn254> {
+    const ROUNDS_F: u32 = 8;
+    const ROUNDS_P: u32 = 56;
+
+    fn get_m_diag(e: &Env) -> Vec<U256> {
+        let mut diag = vec![e];
+        for value in MAT_DIAG.iter() {
+            diag.push_back(to_u256(e, value));
+        }
+        diag
+    }
+
+    fn get_rc(e: &Env) -> Vec<Vec<U256>> {
+        let mut rc = vec![e];
+        for round in ROUND_CONSTANTS.chunks(3) {
+            let mut row = vec![e];
+            for value in round {
+                row.push_back(to_u256(e, value));
+            }
+            rc.push_back(row);
+        }
+        rc
+    }
+}
+
+fn to_u256(e: &Env, value: &[u8; 32]) -> U256 {
+    U256::from_be_bytes(e, &Bytes::from_array(e, value))
+}
+
+#[rustfmt::skip]
+const MAT_DIAG: [[u8; 32]; 3] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02],
+];
+
+#[rustfmt::skip]
+const ROUND_CONSTANTS: [[u8; 32]; 192] = [
+    [0x1d, 0x06, 0x6a, 0x25, 0x55, 0x17, 0xb7, 0xfd, 0x8b, 0xdd, 0xd3, 0xa9, 0x3f, 0x78, 0x04, 0xef, 0x7f, 0x8f, 0xcd, 0xe4, 0x8b, 0xb4, 0xc3, 0x7a, 0x59, 0xa0, 0x9a, 0x1a, 0x97, 0x05, 0x28, 0x16],
+    [0x29, 0xda, 0xef, 0xb5, 0x5f, 0x6f, 0x2d, 0xc6, 0xac, 0x3f, 0x08, 0x9c, 0xeb, 0xcc, 0x61, 0x20, 0xb7, 0xc6, 0xfe, 0xf3, 0x13, 0x67, 0xb6, 0x8e, 0xb7, 0x23, 0x85, 0x47, 0xd3, 0x2c, 0x16, 0x10],
+    [0x1f, 0x2c, 0xb1, 0x62, 0x4a, 0x78, 0xee, 0x00, 0x1e, 0xcb, 0xd8, 0x8a, 0xd9, 0x59, 0xd7, 0x01, 0x25, 0x72, 0xd7, 0x6f, 0x08, 0xec, 0x5c, 0x4f, 0x9e, 0x8b, 0x7a, 0xd7, 0xb0, 0xb4, 0xe1, 0xd1],
+    [0x0a, 0xad, 0x2e, 0x79, 0xf1, 0x57, 0x35, 0xf2, 0xbd, 0x77, 0xc0, 0xed, 0x3d, 0x14, 0xaa, 0x27, 0xb1, 0x1f, 0x09, 0x2a, 0x53, 0xbb, 0xc6, 0xe1, 0xdb, 0x06, 0x72, 0xde, 0xd8, 0x4f, 0x31, 0xe5],
+    [0x22, 0x52, 0x62, 0x4f, 0x86, 0x17, 0x73, 0x8c, 0xd6, 0xf6, 0x61, 0xdd, 0x40, 0x94, 0x37, 0x5f, 0x37, 0x02, 0x8a, 0x98, 0xf1, 0xde, 0xce, 0x66, 0x09, 0x1c, 0xcf, 0x15, 0x95, 0xb4, 0x3f, 0x28],
+    [0x1a, 0x24, 0x91, 0x3a, 0x92, 0x8b, 0x38, 0x48, 0x5a, 0x65, 0xa8, 0x4a, 0x29, 0x1d, 0xa1, 0xff, 0x91, 0xc2, 0x06, 0x26, 0x52, 0x4b, 0x2b, 0x87, 0xd4, 0x9f, 0x4f, 0x2c, 0x90, 0x18, 0xd7, 0x35],
+    [0x22, 0xfc, 0x46, 0x8f, 0x17, 0x59, 0xb7, 0x4d, 0x7b, 0xfc, 0x42, 0x7b, 0x5f, 0x11, 0xeb, 0xb1, 0x0a, 0x41, 0x51, 0x5d, 0xdf, 0xf4, 0x97, 0xb1, 0x4f, 0xd6, 0xda, 0xe1, 0x50, 0x8f, 0xc4, 0x7a],
+    [0x10, 0x59, 0xca, 0x78, 0x7f, 0x1f, 0x89, 0xed, 0x9c, 0xd0, 0x26, 0xe9, 0xc9, 0xca, 0x10, 0x7a, 0xe6, 0x19, 0x56, 0xff, 0x0b, 0x41, 0x21, 0xd5, 0xef, 0xd6, 0x55, 0x15, 0x61, 0x7f, 0x6e, 0x4d],
+    [0x02, 0xbe, 0x94, 0x73, 0x35, 0x84, 0x61, 0xd8, 0xf6, 0x1f, 0x35, 0x36, 0xd8, 0x77, 0xde, 0x98, 0x21, 0x23, 0x01, 0x1f, 0x0b, 0xf6, 0xf1, 0x55, 0xa4, 0x5c, 0xbb, 0xfa, 0xe8, 0xb9, 0x81, 0xce],
+    [0x0e, 0xc9, 0x6c, 0x8e, 0x32, 0x96, 0x2d, 0x46, 0x27, 0x78, 0xa7, 0x49, 0xc8, 0x2e, 0xd6, 0x23, 0xab, 0xa9, 0xb6, 0x69, 0xac, 0x5b, 0x87, 0x36, 0xa1, 0xff, 0x3a, 0x44, 0x1a, 0x50, 0x84, 0xa4],
+    [0x29, 0x2f, 0x90, 0x6e, 0x07, 0x36, 0x77, 0x40, 0x54, 0x42, 0xd9, 0x55, 0x3c, 0x45, 0xfa, 0x3f, 0x5a, 0x47, 0xa7, 0xcd, 0xb8, 0xc9, 0x9f, 0x96, 0x48, 0xfb, 0x2e, 0x4d, 0x81, 0x4d, 0xf5, 0x7e],
+    [0x27, 0x49, 0x82, 0x44, 0x41, 0x57, 0xb8, 0x67, 0x26, 0xc1, 0x1b, 0x9a, 0x0f, 0x5e, 0x39, 0xa5, 0xcc, 0x61, 0x11, 0x60, 0xa3, 0x94, 0xea, 0x46, 0x0c, 0x63, 0xf0, 0xb2, 0xff, 0xe5, 0x65, 0x7e],
+    [0x1a, 0x1d, 0x06, 0x3e, 0x54, 0xb1, 0xe7, 0x64, 0xb6, 0x3e, 0x18, 0x55, 0xbf, 0xf0, 0x15, 0xb8, 0xce, 0xdd, 0x19, 0x2f, 0x47, 0x30, 0x87, 0x31, 0x49, 0x95, 0x73, 0xf2, 0x35, 0x97, 0xd4, 0xb5],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x26, 0xab, 0xc6, 0x6f, 0x3f, 0xdf, 0x8e, 0x68, 0x83, 0x9d, 0x10, 0x95, 0x62, 0x59, 0x06, 0x37, 0x08, 0x23, 0x5d, 0xcc, 0xc1, 0xaa, 0x37, 0x93, 0xb9, 0x1b, 0x00, 0x2c, 0x5b, 0x25, 0x7c, 0x37],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0c, 0x7c, 0x64, 0xa9, 0xd8, 0x87, 0x38, 0x53, 0x81, 0xa5, 0x78, 0xcf, 0xed, 0x5a, 0xed, 0x37, 0x07, 0x54, 0x42, 0x7a, 0xab, 0xca, 0x92, 0xa7, 0x0b, 0x3c, 0x2b, 0x12, 0xff, 0x4d, 0x7b, 0xe8],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1c, 0xf5, 0x99, 0x87, 0x69, 0xe9, 0xfa, 0xb7, 0x9e, 0x17, 0xf0, 0xb6, 0xd0, 0x8b, 0x2d, 0x1e, 0xba, 0x2e, 0xba, 0xc3, 0x0d, 0xc3, 0x86, 0xb0, 0xed, 0xd3, 0x83, 0x83, 0x13, 0x54, 0xb4, 0x95],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0f, 0x5e, 0x3a, 0x85, 0x66, 0xbe, 0x31, 0xb7, 0x56, 0x4c, 0xa6, 0x04, 0x61, 0xe9, 0xe0, 0x8b, 0x19, 0x82, 0x87, 0x64, 0xa9, 0x66, 0x9b, 0xc1, 0x7a, 0xba, 0x0b, 0x97, 0xe6, 0x6b, 0x01, 0x09],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x18, 0xdf, 0x6a, 0x9d, 0x19, 0xea, 0x90, 0xd8, 0x95, 0xe6, 0x0e, 0x4d, 0xb0, 0x79, 0x4a, 0x01, 0xf3, 0x59, 0xa5, 0x3a, 0x18, 0x0b, 0x7d, 0x4b, 0x42, 0xbf, 0x3d, 0x7a, 0x53, 0x1c, 0x97, 0x6e],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x04, 0xf7, 0xbf, 0x2c, 0x5c, 0x05, 0x38, 0xac, 0x6e, 0x4b, 0x78, 0x2c, 0x3c, 0x6e, 0x60, 0x1a, 0xd0, 0xea, 0x1d, 0x3a, 0x3b, 0x9d, 0x25, 0xef, 0x4e, 0x32, 0x40, 0x55, 0xfa, 0x31, 0x23, 0xdc],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x29, 0xc7, 0x6c, 0xe2, 0x22, 0x55, 0x20, 0x6e, 0x3c, 0x40, 0x05, 0x85, 0x23, 0x74, 0x85, 0x31, 0xe7, 0x70, 0xc0, 0x58, 0x4a, 0xa2, 0x32, 0x8c, 0xe5, 0x5d, 0x54, 0x62, 0x8b, 0x89, 0xeb, 0xe6],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x19, 0x8d, 0x42, 0x5a, 0x45, 0xb7, 0x8e, 0x85, 0xc0, 0x53, 0x65, 0x9a, 0xb4, 0x34, 0x7f, 0x5d, 0x65, 0xb1, 0xb8, 0xe9, 0xc6, 0x10, 0x8d, 0xbe, 0x00, 0xe0, 0xe9, 0x45, 0xdb, 0xc5, 0xff, 0x15],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x25, 0xee, 0x27, 0xab, 0x62, 0x96, 0xcd, 0x5e, 0x6a, 0xf3, 0xcc, 0x79, 0xc5, 0x98, 0xa1, 0xda, 0xa7, 0xff, 0x7f, 0x68, 0x78, 0xb3, 0xc4, 0x9d, 0x49, 0xd3, 0xa9, 0xa9, 0x0c, 0x3f, 0xdf, 0x74],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x13, 0x8e, 0xa8, 0xe0, 0xaf, 0x41, 0xa1, 0xe0, 0x24, 0x56, 0x10, 0x01, 0xc0, 0xb6, 0xeb, 0x15, 0x05, 0x84, 0x5d, 0x7d, 0x0c, 0x55, 0xb1, 0xb2, 0xc0, 0xf8, 0x86, 0x87, 0xa9, 0x6d, 0x13, 0x81],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x30, 0x61, 0x97, 0xfb, 0x3f, 0xab, 0x67, 0x1e, 0xf6, 0xe7, 0xc2, 0xcb, 0xa2, 0xee, 0xfd, 0x0e, 0x42, 0x85, 0x1b, 0x5b, 0x98, 0x11, 0xf2, 0xca, 0x40, 0x13, 0x37, 0x0a, 0x01, 0xd9, 0x56, 0x87],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1a, 0x0c, 0x7d, 0x52, 0xdc, 0x32, 0xa4, 0x43, 0x2b, 0x66, 0xf0, 0xb4, 0x89, 0x4d, 0x4f, 0x1a, 0x21, 0xdb, 0x75, 0x65, 0xe5, 0xb4, 0x25, 0x04, 0x86, 0x41, 0x9e, 0xaf, 0x00, 0xe8, 0xf6, 0x20],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x2b, 0x46, 0xb4, 0x18, 0xde, 0x80, 0x91, 0x5f, 0x3f, 0xf8, 0x6a, 0x8e, 0x5c, 0x8b, 0xdf, 0xcc, 0xeb, 0xfb, 0xe5, 0xf5, 0x51, 0x63, 0xcd, 0x6c, 0xaa, 0x52, 0x99, 0x7d, 0xa2, 0xc5, 0x4a, 0x9f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x12, 0xd3, 0xe0, 0xdc, 0x00, 0x85, 0x87, 0x37, 0x01, 0xf8, 0xb7, 0x77, 0xb9, 0x67, 0x3a, 0xf9, 0x61, 0x3a, 0x1a, 0xf5, 0xdb, 0x48, 0xe0, 0x5b, 0xfb, 0x46, 0xe3, 0x12, 0xb5, 0x82, 0x9f, 0x64],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x26, 0x33, 0x90, 0xcf, 0x74, 0xdc, 0x3a, 0x88, 0x70, 0xf5, 0x00, 0x2e, 0xd2, 0x1d, 0x08, 0x9f, 0xfb, 0x2b, 0xf7, 0x68, 0x23, 0x0f, 0x64, 0x8d, 0xba, 0x33, 0x8a, 0x5c, 0xb1, 0x9b, 0x3a, 0x1f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0a, 0x14, 0xf3, 0x3a, 0x5f, 0xe6, 0x68, 0xa6, 0x0a, 0xc8, 0x84, 0xb4, 0xca, 0x60, 0x7a, 0xd0, 0xf8, 0xab, 0xb5, 0xaf, 0x40, 0xf9, 0x6f, 0x1d, 0x7d, 0x54, 0x3d, 0xb5, 0x2b, 0x00, 0x3d, 0xcd],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x28, 0xea, 0xd9, 0xc5, 0x86, 0x51, 0x3e, 0xab, 0x1a, 0x5e, 0x86, 0x50, 0x9d, 0x68, 0xb2, 0xda, 0x27, 0xbe, 0x3a, 0x4f, 0x01, 0x17, 0x1a, 0x1d, 0xd8, 0x47, 0xdf, 0x82, 0x9b, 0xc6, 0x83, 0xb9],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1c, 0x6a, 0xb1, 0xc3, 0x28, 0xc3, 0xc6, 0x43, 0x09, 0x72, 0x03, 0x1f, 0x1b, 0xdb, 0x2a, 0xc9, 0x88, 0x8f, 0x0e, 0xa1, 0xab, 0xe7, 0x1c, 0xff, 0xea, 0x16, 0xcd, 0xa6, 0xe1, 0xa7, 0x41, 0x6c],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1f, 0xc7, 0xe7, 0x1b, 0xc0, 0xb8, 0x19, 0x79, 0x2b, 0x25, 0x00, 0x23, 0x9f, 0x7f, 0x8d, 0xe0, 0x4f, 0x6d, 0xec, 0xd6, 0x08, 0xcb, 0x98, 0xa9, 0x32, 0x34, 0x60, 0x15, 0xc5, 0xb4, 0x2c, 0x94],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x03, 0xe1, 0x07, 0xeb, 0x3a, 0x42, 0xb2, 0xec, 0xe3, 0x80, 0xe0, 0xd8, 0x60, 0x29, 0x8f, 0x17, 0xc0, 0xc1, 0xe1, 0x97, 0xc9, 0x52, 0x65, 0x0e, 0xe6, 0xdd, 0x85, 0xb9, 0x3a, 0x0d, 0xda, 0xa8],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x2d, 0x35, 0x4a, 0x25, 0x1f, 0x38, 0x1a, 0x46, 0x69, 0xc0, 0xd5, 0x2b, 0xf8, 0x8b, 0x77, 0x2c, 0x46, 0x45, 0x2c, 0xa5, 0x7c, 0x08, 0x69, 0x7f, 0x45, 0x45, 0x05, 0xf6, 0x94, 0x1d, 0x78, 0xcd],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x09, 0x4a, 0xf8, 0x8a, 0xb0, 0x5d, 0x94, 0xba, 0xf6, 0x87, 0xef, 0x14, 0xbc, 0x56, 0x6d, 0x1c, 0x52, 0x25, 0x51, 0xd6, 0x16, 0x06, 0xed, 0xa3, 0xd1, 0x4b, 0x46, 0x06, 0x82, 0x6f, 0x79, 0x4b],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x19, 0x70, 0x5b, 0x78, 0x3b, 0xf3, 0xd2, 0xdc, 0x19, 0xbc, 0xae, 0xab, 0xf0, 0x2f, 0x8c, 0xa5, 0xe1, 0xab, 0x5b, 0x6f, 0x2e, 0x31, 0x95, 0xa9, 0xd5, 0x2b, 0x2d, 0x24, 0x9d, 0x13, 0x96, 0xf7],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x09, 0xbf, 0x4a, 0xcc, 0x3a, 0x8b, 0xce, 0x3f, 0x1f, 0xcc, 0x33, 0xfe, 0xe5, 0x4f, 0xc5, 0xb2, 0x87, 0x23, 0xb1, 0x6b, 0x7d, 0x74, 0x0a, 0x3e, 0x60, 0xce, 0xf6, 0x85, 0x22, 0x71, 0x20, 0x0e],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x18, 0x03, 0xf8, 0x20, 0x0d, 0xb6, 0x01, 0x3c, 0x50, 0xf8, 0x3c, 0x0c, 0x8f, 0xab, 0x62, 0x84, 0x34, 0x13, 0x73, 0x2f, 0x30, 0x1f, 0x70, 0x58, 0x54, 0x3a, 0x07, 0x3f, 0x3f, 0x3b, 0x5e, 0x4e],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0f, 0x80, 0xaf, 0xb5, 0x04, 0x62, 0x44, 0xde, 0x30, 0x59, 0x5b, 0x16, 0x0b, 0x8d, 0x1f, 0x38, 0xbf, 0x6f, 0xb0, 0x2d, 0x44, 0x54, 0xc0, 0xad, 0xd4, 0x1f, 0x7f, 0xef, 0x2f, 0xaf, 0x3e, 0x5c],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x12, 0x6e, 0xe1, 0xf8, 0x50, 0x4f, 0x15, 0xc3, 0xd7, 0x7f, 0x00, 0x88, 0xc1, 0xcf, 0xc9, 0x64, 0xab, 0xcf, 0xcf, 0x64, 0x3f, 0x4a, 0x6f, 0xea, 0x7d, 0xc3, 0xf9, 0x82, 0x19, 0x52, 0x9d, 0x78],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x23, 0xc2, 0x03, 0xd1, 0x0c, 0xfc, 0xc6, 0x0f, 0x69, 0xbf, 0xb3, 0xd9, 0x19, 0x55, 0x2c, 0xa1, 0x0f, 0xfb, 0x4e, 0xe6, 0x31, 0x75, 0xdd, 0xf8, 0xef, 0x86, 0xf9, 0x91, 0xd7, 0xd0, 0xa5, 0x91],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x2a, 0x2a, 0xe1, 0x5d, 0x8b, 0x14, 0x37, 0x09, 0xec, 0x0d, 0x09, 0x70, 0x5f, 0xa3, 0xa6, 0x30, 0x3d, 0xec, 0x1e, 0xe4, 0xee, 0xc2, 0xcf, 0x74, 0x7c, 0x5a, 0x33, 0x9f, 0x77, 0x44, 0xfb, 0x94],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x07, 0xb6, 0x0d, 0xee, 0x58, 0x6e, 0xd6, 0xef, 0x47, 0xe5, 0xc3, 0x81, 0xab, 0x63, 0x43, 0xec, 0xc3, 0xd3, 0xb3, 0x00, 0x6c, 0xb4, 0x61, 0xbb, 0xb6, 0xb5, 0xd8, 0x90, 0x81, 0x97, 0x0b, 0x2b],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x27, 0x31, 0x6b, 0x55, 0x9b, 0xe3, 0xed, 0xfd, 0x88, 0x5d, 0x95, 0xc4, 0x94, 0xc1, 0xae, 0x3d, 0x8a, 0x98, 0xa3, 0x20, 0xba, 0xa7, 0xd1, 0x52, 0x13, 0x2c, 0xfe, 0x58, 0x3c, 0x93, 0x11, 0xbd],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1d, 0x5c, 0x49, 0xba, 0x15, 0x7c, 0x32, 0xb8, 0xd8, 0x93, 0x7c, 0xb2, 0xd3, 0xf8, 0x43, 0x11, 0xef, 0x83, 0x4c, 0xc2, 0xa7, 0x43, 0xed, 0x66, 0x2f, 0x5f, 0x9a, 0xf0, 0xc0, 0x34, 0x2e, 0x76],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x2f, 0x8b, 0x12, 0x4e, 0x78, 0x16, 0x3b, 0x2f, 0x33, 0x27, 0x74, 0xe0, 0xb8, 0x50, 0xb5, 0xec, 0x09, 0xc0, 0x1b, 0xf6, 0x97, 0x99, 0x38, 0xf6, 0x7c, 0x24, 0xbd, 0x59, 0x40, 0x96, 0x84, 0x88],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1e, 0x68, 0x43, 0xa5, 0x45, 0x74, 0x16, 0xb6, 0xdc, 0x5b, 0x7a, 0xa0, 0x9a, 0x9c, 0xe2, 0x1b, 0x1d, 0x4c, 0xba, 0x65, 0x54, 0xe5, 0x1d, 0x84, 0x66, 0x5f, 0x75, 0x26, 0x01, 0x13, 0xb3, 0xd5],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x11, 0xcd, 0xf0, 0x0a, 0x35, 0xf6, 0x50, 0xc5, 0x5f, 0xca, 0x25, 0xc9, 0x92, 0x9c, 0x8a, 0xd9, 0xa6, 0x8d, 0xaf, 0x9a, 0xc6, 0xa1, 0x89, 0xab, 0x1f, 0x5b, 0xc7, 0x9f, 0x21, 0x64, 0x1d, 0x4b],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x21, 0x63, 0x2d, 0xe3, 0xd3, 0xbb, 0xc5, 0xe4, 0x2e, 0xf3, 0x6e, 0x58, 0x81, 0x58, 0xd6, 0xd4, 0x60, 0x8b, 0x28, 0x15, 0xc7, 0x73, 0x55, 0xb7, 0xe8, 0x2b, 0x5b, 0x9b, 0x7e, 0xb5, 0x60, 0xbc],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0d, 0xe6, 0x25, 0x75, 0x84, 0x52, 0xef, 0xbd, 0x97, 0xb2, 0x70, 0x25, 0xfb, 0xd2, 0x45, 0xe0, 0x25, 0x5a, 0xe4, 0x8e, 0xf2, 0xa3, 0x29, 0xe4, 0x49, 0xd7, 0xb5, 0xc5, 0x1c, 0x18, 0x49, 0x8a],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x2a, 0xd2, 0x53, 0xc0, 0x53, 0xe7, 0x52, 0x13, 0xe2, 0xfe, 0xbf, 0xd4, 0xd9, 0x76, 0xcc, 0x01, 0xdd, 0x9e, 0x1e, 0x1c, 0x6f, 0x0f, 0xb6, 0xb0, 0x9b, 0x09, 0x54, 0x6b, 0xa0, 0x83, 0x80, 0x98],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1d, 0x6b, 0x16, 0x9e, 0xd6, 0x38, 0x72, 0xdc, 0x6e, 0xc7, 0x68, 0x1e, 0xc3, 0x9b, 0x3b, 0xe9, 0x3d, 0xd4, 0x9c, 0xdd, 0x13, 0xc8, 0x13, 0xb7, 0xd3, 0x57, 0x02, 0xe3, 0x8d, 0x60, 0xb0, 0x77],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x16, 0x60, 0xb7, 0x40, 0xa1, 0x43, 0x66, 0x4b, 0xb9, 0x12, 0x7c, 0x49, 0x41, 0xb6, 0x7f, 0xed, 0x0b, 0xe3, 0xea, 0x70, 0xa2, 0x4d, 0x55, 0x68, 0xc3, 0xa5, 0x4e, 0x70, 0x6c, 0xfe, 0xf7, 0xfe],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x65, 0xa9, 0x2d, 0x1d, 0xe8, 0x1f, 0x34, 0x11, 0x4f, 0x4c, 0xa2, 0xde, 0xef, 0x76, 0xe0, 0xce, 0xac, 0xdd, 0xdb, 0x12, 0xcf, 0x87, 0x90, 0x96, 0xa2, 0x9f, 0x10, 0x37, 0x6c, 0xcb, 0xfe],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1f, 0x11, 0xf0, 0x65, 0x20, 0x25, 0x35, 0x98, 0x73, 0x67, 0xf8, 0x23, 0xda, 0x7d, 0x67, 0x2c, 0x35, 0x3e, 0xbe, 0x2c, 0xcb, 0xc4, 0x86, 0x9b, 0xcf, 0x30, 0xd5, 0x0a, 0x58, 0x71, 0x04, 0x0d],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x26, 0x59, 0x6f, 0x5c, 0x5d, 0xd5, 0xa5, 0xd1, 0xb4, 0x37, 0xce, 0x7b, 0x14, 0xa2, 0xc3, 0xdd, 0x3b, 0xd1, 0xd1, 0xa3, 0x9b, 0x67, 0x59, 0xba, 0x11, 0x08, 0x52, 0xd1, 0x7d, 0xf0, 0x69, 0x3e],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x16, 0xf4, 0x9b, 0xc7, 0x27, 0xe4, 0x5a, 0x2f, 0x7b, 0xf3, 0x05, 0x6e, 0xfc, 0xf8, 0xb6, 0xd3, 0x85, 0x39, 0xc4, 0x16, 0x3a, 0x5f, 0x1e, 0x70, 0x67, 0x43, 0xdb, 0x15, 0xaf, 0x91, 0x86, 0x0f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1a, 0xbe, 0x1d, 0xeb, 0x45, 0xb3, 0xe3, 0x11, 0x99, 0x54, 0x17, 0x5e, 0xfb, 0x33, 0x1b, 0xf4, 0x56, 0x8f, 0xea, 0xf7, 0xea, 0x8b, 0x3d, 0xc5, 0xe1, 0xa4, 0xe7, 0x43, 0x8d, 0xd3, 0x9e, 0x5f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0e, 0x42, 0x6c, 0xca, 0xb6, 0x69, 0x84, 0xd1, 0xd8, 0x99, 0x3a, 0x74, 0xca, 0x54, 0x8b, 0x77, 0x9f, 0x5d, 0xb9, 0x2a, 0xae, 0xc5, 0xf1, 0x02, 0x02, 0x0d, 0x34, 0xae, 0xa1, 0x5f, 0xba, 0x59],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0e, 0x7c, 0x30, 0xc2, 0xe2, 0xe8, 0x95, 0x7f, 0x49, 0x33, 0xbd, 0x19, 0x42, 0x05, 0x3f, 0x1f, 0x00, 0x71, 0x68, 0x4b, 0x90, 0x2d, 0x53, 0x4f, 0xa8, 0x41, 0x92, 0x43, 0x03, 0xf6, 0xa6, 0xc6],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x08, 0x12, 0xa0, 0x17, 0xca, 0x92, 0xcf, 0x0a, 0x16, 0x22, 0x70, 0x8f, 0xc7, 0xed, 0xff, 0x1d, 0x61, 0x66, 0xde, 0xd6, 0xe3, 0x52, 0x8e, 0xad, 0x4c, 0x76, 0xe1, 0xf3, 0x1d, 0x3f, 0xc6, 0x9d],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x21, 0xa5, 0xad, 0xe3, 0xdf, 0x2b, 0xc1, 0xb5, 0xbb, 0xa9, 0x49, 0xd1, 0xdb, 0x96, 0x04, 0x00, 0x68, 0xaf, 0xe5, 0x02, 0x6e, 0xdd, 0x7a, 0x9c, 0x2e, 0x27, 0x6b, 0x47, 0xcf, 0x01, 0x0d, 0x54],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x01, 0xf3, 0x03, 0x54, 0x63, 0x81, 0x6c, 0x84, 0xad, 0x71, 0x1b, 0xf1, 0xa0, 0x58, 0xc6, 0xc6, 0xbd, 0x10, 0x19, 0x45, 0xf5, 0x0e, 0x5a, 0xfe, 0x72, 0xb1, 0xa5, 0x23, 0x3f, 0x87, 0x49, 0xce],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x0b, 0x11, 0x55, 0x72, 0xf0, 0x38, 0xc0, 0xe2, 0x02, 0x8c, 0x2a, 0xaf, 0xc2, 0xd0, 0x6a, 0x5e, 0x8b, 0xf2, 0xf9, 0x39, 0x8d, 0xbd, 0x0f, 0xdf, 0x4d, 0xca, 0xa8, 0x2b, 0x0f, 0x0c, 0x1c, 0x8b],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1c, 0x38, 0xec, 0x0b, 0x99, 0xb6, 0x2f, 0xd4, 0xf0, 0xef, 0x25, 0x55, 0x43, 0xf5, 0x0d, 0x2e, 0x27, 0xfc, 0x24, 0xdb, 0x42, 0xbc, 0x91, 0x0a, 0x34, 0x60, 0x61, 0x3b, 0x6e, 0xf5, 0x9e, 0x2f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1c, 0x89, 0xc6, 0xd9, 0x66, 0x62, 0x72, 0xe8, 0x42, 0x5c, 0x3f, 0xf1, 0xf4, 0xac, 0x73, 0x7b, 0x2f, 0x5d, 0x31, 0x46, 0x06, 0xa2, 0x97, 0xd4, 0xb1, 0xd0, 0xb2, 0x54, 0xd8, 0x80, 0xc5, 0x3e],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x03, 0x32, 0x6e, 0x64, 0x35, 0x80, 0x35, 0x6b, 0xf6, 0xd4, 0x40, 0x08, 0xae, 0x4c, 0x04, 0x2a, 0x21, 0xad, 0x48, 0x80, 0x09, 0x7a, 0x5e, 0xb3, 0x8b, 0x71, 0xe2, 0x31, 0x1b, 0xb8, 0x8f, 0x8f],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x26, 0x80, 0x76, 0xb0, 0x05, 0x4f, 0xb7, 0x3f, 0x67, 0xce, 0xe9, 0xea, 0x0e, 0x51, 0xe3, 0xad, 0x50, 0xf2, 0x7a, 0x64, 0x34, 0xb5, 0xdc, 0xeb, 0x5b, 0xdd, 0xe2, 0x29, 0x99, 0x10, 0xa4, 0xc9],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1a, 0xcd, 0x63, 0xc6, 0x7f, 0xbc, 0x9a, 0xb1, 0x62, 0x6e, 0xd9, 0x34, 0x91, 0xbd, 0xa3, 0x2e, 0x5d, 0xa1, 0x8e, 0xa9, 0xd8, 0xe4, 0xf1, 0x01, 0x78, 0xd0, 0x4a, 0xa6, 0xf8, 0x74, 0x7a, 0xd0],
+    [0x19, 0xf8, 0xa5, 0xd6, 0x70, 0xe8, 0xab, 0x66, 0xc4, 0xe3, 0x14, 0x4b, 0xe5, 0x8e, 0xf6, 0x90, 0x1b, 0xf9, 0x33, 0x75, 0xe2, 0x32, 0x3e, 0xc3, 0xca, 0x8c, 0x86, 0xcd, 0x2a, 0x28, 0xb5, 0xa5],
+    [0x1c, 0x0d, 0xc4, 0x43, 0x51, 0x9a, 0xd7, 0xa8, 0x6e, 0xfa, 0x40, 0xd2, 0xdf, 0x10, 0xa0, 0x11, 0x06, 0x81, 0x93, 0xea, 0x51, 0xf6, 0xc9, 0x2a, 0xe1, 0xcf, 0xbb, 0x5f, 0x7b, 0x9b, 0x68, 0x93],
+    [0x14, 0xb3, 0x9e, 0x7a, 0xa4, 0x06, 0x8d, 0xbe, 0x50, 0xfe, 0x71, 0x90, 0xe4, 0x21, 0xdc, 0x19, 0xfb, 0xea, 0xb3, 0x3c, 0xb4, 0xf6, 0xa2, 0xc4, 0x18, 0x0e, 0x4c, 0x32, 0x24, 0x98, 0x7d, 0x3d],
+    [0x1d, 0x44, 0x9b, 0x71, 0xbd, 0x82, 0x6e, 0xc5, 0x8f, 0x28, 0xc6, 0x3e, 0xa6, 0xc5, 0x61, 0xb7, 0xb8, 0x20, 0xfc, 0x51, 0x9f, 0x01, 0xf0, 0x21, 0xaf, 0xb1, 0xe3, 0x5e, 0x28, 0xb0, 0x79, 0x5e],
+    [0x1e, 0xa2, 0xc9, 0xa8, 0x9b, 0xaa, 0xdd, 0xbb, 0x60, 0xfa, 0x97, 0xfe, 0x60, 0xfe, 0x9d, 0x8e, 0x89, 0xde, 0x14, 0x16, 0x89, 0xd1, 0x25, 0x22, 0x76, 0x52, 0x4d, 0xc0, 0xa9, 0xe9, 0x87, 0xfc],
+    [0x04, 0x78, 0xd6, 0x6d, 0x43, 0x53, 0x5a, 0x8c, 0xb5, 0x7e, 0x9c, 0x1c, 0x3d, 0x6a, 0x2b, 0xd7, 0x59, 0x1f, 0x9a, 0x46, 0xa0, 0xe9, 0xc0, 0x58, 0x13, 0x4d, 0x5c, 0xef, 0xdb, 0x3c, 0x7f, 0xf1],
+    [0x19, 0x27, 0x2d, 0xb7, 0x1e, 0xec, 0xe6, 0xa6, 0xf6, 0x08, 0xf3, 0xb2, 0x71, 0x7f, 0x9c, 0xd2, 0x66, 0x2e, 0x26, 0xad, 0x86, 0xc4, 0x00, 0xb2, 0x1c, 0xde, 0x5e, 0x4a, 0x7b, 0x00, 0xbe, 0xbe],
+    [0x14, 0x22, 0x65, 0x37, 0x33, 0x5c, 0xab, 0x33, 0xc7, 0x49, 0xc7, 0x46, 0xf0, 0x92, 0x08, 0xab, 0xb2, 0xdd, 0x1b, 0xd6, 0x6a, 0x87, 0xef, 0x75, 0x03, 0x9b, 0xe8, 0x46, 0xaf, 0x13, 0x41, 0x66],
+    [0x01, 0xfd, 0x6a, 0xf1, 0x59, 0x56, 0x29, 0x4f, 0x9d, 0xfe, 0x38, 0xc0, 0xd9, 0x76, 0xa0, 0x88, 0xb2, 0x1c, 0x21, 0xe4, 0xa1, 0xc2, 0xe8, 0x23, 0xf9, 0x12, 0xf4, 0x49, 0x61, 0xf9, 0xa9, 0xce],
+    [0x18, 0xe5, 0xab, 0xed, 0xd6, 0x26, 0xec, 0x30, 0x7b, 0xca, 0x19, 0x0b, 0x8b, 0x2c, 0xab, 0x1a, 0xae, 0xe2, 0xe6, 0x2e, 0xd2, 0x29, 0xba, 0x5a, 0x5a, 0xd8, 0x51, 0x8d, 0x4e, 0x5f, 0x2a, 0x57],
+    [0x0f, 0xc1, 0xbb, 0xce, 0xba, 0x05, 0x90, 0xf5, 0xab, 0xbd, 0xff, 0xa6, 0xd3, 0xb3, 0x5e, 0x32, 0x97, 0xc0, 0x21, 0xa3, 0xa4, 0x09, 0x92, 0x6d, 0x0e, 0x2d, 0x54, 0xdc, 0x1c, 0x84, 0xfd, 0xa6],
+];
+
+/// Roots of empty subtrees: `ZERO_HASHES[level]` is the root of an all-zero
+/// subtree of height `level`, i.e. `H(ZERO_HASHES[level - 1], ZERO_HASHES[level - 1])`
+#[rustfmt::skip]
+pub(crate) const ZERO_HASHES: [[u8; 32]; 33] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x1a, 0xf3, 0xd5, 0x49, 0xef, 0xcf, 0x2e, 0x24, 0xb8, 0x29, 0xe4, 0x5a, 0x68, 0xbb, 0x32, 0xa2, 0xfe, 0x55, 0xe4, 0x7f, 0xb3, 0xf6, 0xc2, 0x50, 0x2b, 0xfd, 0x69, 0xbf, 0x7a, 0x9e, 0x56, 0x9d],
+    [0x20, 0x28, 0x18, 0xda, 0x56, 0xc4, 0x5e, 0x84, 0x4f, 0x95, 0xb4, 0xad, 0x49, 0xc0, 0x75, 0xf1, 0x5c, 0x82, 0x81, 0x18, 0x97, 0x01, 0x7e, 0xed, 0x61, 0xca, 0xd4, 0x41, 0x99, 0x19, 0x53, 0xb7],
+    [0x2d, 0x5e, 0x3b, 0x6c, 0x45, 0x5b, 0xa0, 0x7c, 0x1f, 0x7e, 0xdb, 0x45, 0x81, 0x02, 0xf6, 0x68, 0x24, 0x3d, 0x83, 0x3c, 0xbb, 0x41, 0x32, 0xcd, 0x44, 0xec, 0xd4, 0x7c, 0x76, 0x1e, 0xd1, 0xab],
+    [0x1e, 0x31, 0x20, 0xf7, 0x80, 0xa1, 0x77, 0x3f, 0x80, 0x67, 0x8a, 0x28, 0x0a, 0x87, 0x37, 0x26, 0x0d, 0x10, 0x34, 0xca, 0xcd, 0x7e, 0x16, 0x0b, 0x2b, 0x8b, 0xe8, 0x80, 0x7f, 0xf6, 0x48, 0x56],
+    [0x19, 0x89, 0xff, 0x43, 0x1d, 0x2e, 0xc6, 0x5e, 0x49, 0xf6, 0xd2, 0x4c, 0x9d, 0xe7, 0x0a, 0xd3, 0x56, 0xd0, 0x9e, 0xd2, 0xce, 0xae, 0x13, 0x77, 0x29, 0x5a, 0x8f, 0x6a, 0x3d, 0xcc, 0x79, 0x38],
+    [0x02, 0x6a, 0x35, 0x93, 0xc4, 0x06, 0x82, 0x23, 0x33, 0x26, 0x2a, 0xf5, 0x93, 0xef, 0x75, 0xbd, 0xec, 0xea, 0x27, 0x9b, 0x4d, 0x10, 0x37, 0xba, 0x7b, 0x56, 0x8f, 0x81, 0x2e, 0xdf, 0x59, 0x33],
+    [0x29, 0x8c, 0xda, 0xbd, 0x31, 0x4a, 0x98, 0x74, 0x64, 0x8b, 0xb4, 0xe3, 0xf4, 0x15, 0x18, 0x31, 0x35, 0x3c, 0x25, 0xa4, 0x11, 0x06, 0x2d, 0xc7, 0x5a, 0xa0, 0x34, 0xed, 0xf1, 0x17, 0xe9, 0x80],
+    [0x06, 0xec, 0x88, 0xdd, 0x17, 0x22, 0xa3, 0x54, 0x26, 0xee, 0xb1, 0x56, 0xd9, 0x0a, 0x71, 0x7d, 0xf2, 0xd9, 0x70, 0x17, 0x4e, 0x49, 0x2c, 0x33, 0x1e, 0xb2, 0xc3, 0x2a, 0xe7, 0x9c, 0xe5, 0x4f],
+    [0x0d, 0x5e, 0xf9, 0xee, 0xb9, 0x44, 0xea, 0xe6, 0x24, 0xfe, 0xbd, 0xcf, 0x82, 0xe2, 0x0d, 0x2b, 0xc8, 0xef, 0xe3, 0xdc, 0x35, 0x8c, 0xb2, 0xdd, 0xf2, 0x8b, 0xb0, 0x0d, 0xe5, 0x96, 0x8d, 0xec],
+    [0x1c, 0xec, 0xa3, 0xd4, 0xd8, 0xbf, 0x03, 0x2f, 0x86, 0xa2, 0xae, 0xd6, 0xa5, 0xa6, 0x69, 0x68, 0x46, 0x1e, 0xcc, 0x0c, 0x41, 0x68, 0x92, 0xa9, 0x77, 0x3d, 0xac, 0xe0, 0x2d, 0x40, 0xbc, 0xab],
+    [0x19, 0x83, 0x33, 0x53, 0xb1, 0x75, 0x66, 0x29, 0x54, 0x5e, 0x18, 0xdd, 0xde, 0xac, 0xa8, 0x16, 0xcb, 0xa7, 0xf0, 0xa4, 0x8c, 0x66, 0x0a, 0xe1, 0xa0, 0x51, 0x8c, 0xea, 0x25, 0xed, 0x6e, 0x99],
+    [0x2b, 0x8b, 0x3f, 0xab, 0xc3, 0x44, 0x8a, 0x49, 0x83, 0x1c, 0x2e, 0x07, 0x5e, 0x26, 0x81, 0x07, 0x23, 0x7f, 0x80, 0xd5, 0x7e, 0x7e, 0xe1, 0x08, 0xf9, 0xda, 0x19, 0xf9, 0x9c, 0x0c, 0x95, 0x30],
+    [0x1b, 0x35, 0x85, 0xa4, 0x3a, 0x51, 0x71, 0x3a, 0x05, 0x68, 0xdf, 0x2b, 0x47, 0x19, 0xd2, 0x4b, 0x27, 0xa2, 0x23, 0xaa, 0xf1, 0x21, 0x0e, 0x30, 0xea, 0x4e, 0x60, 0x35, 0xfe, 0xdf, 0x85, 0xfb],
+    [0x2b, 0x95, 0x59, 0x32, 0xa2, 0x3e, 0x37, 0x29, 0x66, 0x65, 0x73, 0x2d, 0x0b, 0xb9, 0xc3, 0xd8, 0xfb, 0x33, 0x48, 0x6a, 0x8d, 0xc3, 0xce, 0xf6, 0x1c, 0xa5, 0x94, 0x06, 0x36, 0x83, 0x45, 0xbe],
+    [0x07, 0x04, 0x01, 0x76, 0xc2, 0x5b, 0xa4, 0xb2, 0x6c, 0x3f, 0x2e, 0x5a, 0xa2, 0x51, 0x25, 0xca, 0xa9, 0x95, 0x4f, 0x58, 0x85, 0xf5, 0x1a, 0xad, 0xf6, 0x13, 0x18, 0x72, 0x9a, 0xdf, 0xab, 0x3e],
+    [0x12, 0xab, 0x8e, 0x1c, 0x63, 0x30, 0x29, 0x68, 0x77, 0x22, 0x79, 0x8d, 0x96, 0x29, 0x52, 0xe8, 0xd9, 0xf2, 0xbe, 0xbb, 0xd1, 0x2e, 0xe1, 0xde, 0x5b, 0x5a, 0xa7, 0xc6, 0x4d, 0x9e, 0xae, 0x6a],
+    [0x1d, 0x20, 0xe0, 0x1c, 0x05, 0x7d, 0xf4, 0x8f, 0x60, 0xe3, 0xaf, 0x48, 0xad, 0xe9, 0x4c, 0x3a, 0xbf, 0xf4, 0x6f, 0x5d, 0x0f, 0xe1, 0x91, 0xb8, 0xd1, 0x49, 0x8f, 0x3d, 0xff, 0x30, 0x62, 0xaa],
+    [0x18, 0xce, 0x8d, 0xe2, 0x10, 0x8e, 0xbf, 0xe8, 0x2f, 0xe5, 0x3f, 0x2e, 0x59, 0x7c, 0x06, 0x36, 0xfe, 0x65, 0x65, 0xb2, 0x8e, 0x06, 0x2d, 0xf8, 0xa6, 0xae, 0x87, 0xb3, 0x7b, 0xc5, 0x65, 0x9a],
+    [0x28, 0x4d, 0x6b, 0xa9, 0x90, 0xfa, 0xae, 0x97, 0xcb, 0xd2, 0xe3, 0x82, 0x5f, 0x54, 0xd0, 0x01, 0xb8, 0x4e, 0xac, 0x50, 0x16, 0x2e, 0x6e, 0x9c, 0x21, 0x46, 0x99, 0xb2, 0xc4, 0xf5, 0x8c, 0x06],
+    [0x25, 0xeb, 0x74, 0x0a, 0x73, 0x00, 0xe6, 0x8d, 0x53, 0xdb, 0x90, 0xce, 0x4f, 0x05, 0x0e, 0x11, 0xa2, 0x3d, 0xfc, 0xf4, 0x70, 0x5b, 0xa3, 0xe7, 0x0d, 0xaa, 0x0e, 0x84, 0x4a, 0x50, 0xf4, 0x6e],
+    [0x12, 0x2b, 0xc4, 0x51, 0xff, 0x2f, 0x58, 0x32, 0xb0, 0x31, 0xc5, 0xb3, 0x52, 0xd5, 0xbd, 0x77, 0xe5, 0x5b, 0xcb, 0x69, 0x59, 0x73, 0xd7, 0xc8, 0x36, 0xb9, 0xe9, 0xaf, 0x9b, 0xef, 0x5e, 0xfb],
+    [0x0d, 0x8f, 0x12, 0xa6, 0x2b, 0xc1, 0x9b, 0x50, 0x7a, 0xbd, 0x66, 0xcd, 0x45, 0x10, 0x4c, 0x77, 0xbd, 0x5a, 0xb5, 0xb4, 0xd1, 0x9a, 0xbe, 0xf8, 0xb3, 0x69, 0xaa, 0x13, 0x08, 0x6c, 0x8a, 0x3d],
+    [0x2c, 0x30, 0xa3, 0x69, 0x04, 0x3f, 0xd8, 0xf1, 0xc9, 0x15, 0x0b, 0xa2, 0x60, 0x62, 0x4e, 0x50, 0xf0, 0xe6, 0x1b, 0x20, 0x72, 0x6d, 0xba, 0x1d, 0xd0, 0x2d, 0xfb, 0x78, 0xff, 0x3a, 0x47, 0xe0],
+    [0x09, 0x5e, 0x11, 0x32, 0x2c, 0x78, 0x28, 0xe4, 0xd0, 0xcf, 0xe8, 0x11, 0x46, 0x31, 0x83, 0xf2, 0xe9, 0xfc, 0x57, 0x42, 0x83, 0x47, 0x4c, 0xfd, 0xfd, 0x17, 0x14, 0x01, 0x20, 0x3b, 0x7d, 0x41],
+    [0x05, 0x17, 0x3f, 0x23, 0xc5, 0x4f, 0x4b, 0xaf, 0x95, 0x13, 0x39, 0x62, 0xa5, 0x6c, 0xf7, 0x21, 0x7b, 0xf8, 0x13, 0xb8, 0x03, 0xaf, 0x51, 0x02, 0x4f, 0x87, 0xe4, 0x1e, 0xf5, 0x52, 0x83, 0x27],
+    [0x14, 0x80, 0x30, 0x5b, 0x2d, 0x1f, 0xf2, 0x4e, 0x0f, 0xd0, 0x9e, 0xdb, 0xd6, 0x72, 0x02, 0x38, 0xfa, 0xba, 0xa3, 0x98, 0xfe, 0x7f, 0xc1, 0xd4, 0xc7, 0xe9, 0xee, 0xf2, 0xaa, 0xdc, 0xfa, 0x38],
+    [0x10, 0xea, 0xb4, 0xe1, 0x0b, 0x90, 0x5c, 0xea, 0xf9, 0x61, 0x36, 0xd9, 0x7a, 0x20, 0xf9, 0x26, 0x50, 0x34, 0x69, 0xd7, 0xc9, 0x77, 0xe4, 0x0e, 0x53, 0x7e, 0xd2, 0xe3, 0x19, 0xfe, 0xc4, 0xcf],
+    [0x12, 0x10, 0x2c, 0xf4, 0x72, 0xae, 0x28, 0xd8, 0x53, 0x1a, 0xd8, 0x95, 0x05, 0xab, 0x60, 0x3e, 0x1c, 0x89, 0x11, 0x61, 0xa0, 0x72, 0x71, 0x01, 0x80, 0xe3, 0xf9, 0x06, 0x40, 0x90, 0xe2, 0x71],
+    [0x09, 0x8f, 0x1e, 0x9a, 0x49, 0x83, 0x03, 0xed, 0x75, 0x35, 0x3d, 0x0a, 0x08, 0xe2, 0x7d, 0x9b, 0xea, 0xa7, 0xe3, 0xbd, 0xd6, 0x38, 0x72, 0x3b, 0x76, 0x15, 0x02, 0xa3, 0x92, 0xb7, 0x5e, 0x5f],
+    [0x17, 0x3a, 0xe7, 0x2e, 0x8b, 0x74, 0x9d, 0x33, 0xc5, 0x30, 0x42, 0x6d, 0x6a, 0x85, 0x2e, 0x6d, 0x76, 0x37, 0xa5, 0x09, 0x57, 0xee, 0x6e, 0x37, 0x0d, 0xb2, 0xe1, 0xf2, 0x18, 0xde, 0xaf, 0x71],
+    [0x09, 0x6e, 0x22, 0x3b, 0x22, 0xbf, 0x1c, 0xee, 0x58, 0x58, 0x57, 0x76, 0x66, 0x26, 0xd1, 0x4b, 0x6a, 0xe1, 0x33, 0x0d, 0xa5, 0x56, 0xa3, 0x6a, 0x19, 0x95, 0xdf, 0xfd, 0x77, 0x03, 0x8a, 0xa5],
+    [0x1b, 0x04, 0x84, 0xeb, 0xa5, 0xbb, 0x9d, 0xc3, 0xc2, 0x92, 0x7b, 0xcb, 0xb1, 0x77, 0x91, 0xf3, 0xe4, 0xa6, 0xff, 0x1f, 0x16, 0xd2, 0xe7, 0xa2, 0x89, 0x44, 0xa6, 0xe2, 0xd8, 0x64, 0xee, 0x39],
+];
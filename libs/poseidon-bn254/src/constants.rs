@@ -0,0 +1,421 @@
+//! circomlib Poseidon constants for state sizes 2 and 4, as big-endian field elements
+
+#[rustfmt::skip]
+pub(crate) const MDS_T2: [[u8; 32]; 4] = [
+    [0x06, 0x6f, 0x6f, 0x85, 0xd6, 0xf6, 0x8a, 0x85, 0xec, 0x10, 0x34, 0x53, 0x51, 0xa2, 0x3a, 0x3a, 0xaf, 0x07, 0xf3, 0x8a, 0xf8, 0xc9, 0x52, 0xa7, 0xbc, 0xec, 0xa7, 0x0b, 0xd2, 0xaf, 0x7a, 0xd5],
+    [0x2b, 0x9d, 0x4b, 0x41, 0x10, 0xc9, 0xae, 0x99, 0x77, 0x82, 0xe1, 0x50, 0x9b, 0x1d, 0x0f, 0xdb, 0x20, 0xa7, 0xc0, 0x2b, 0xbd, 0x8b, 0xea, 0x73, 0x05, 0x46, 0x2b, 0x9f, 0x81, 0x25, 0xb1, 0xe8],
+    [0x0c, 0xc5, 0x7c, 0xdb, 0xb0, 0x85, 0x07, 0xd6, 0x2b, 0xf6, 0x7a, 0x44, 0x93, 0xcc, 0x26, 0x2f, 0xb6, 0xc0, 0x9d, 0x55, 0x70, 0x13, 0xff, 0xf1, 0xf5, 0x73, 0xf4, 0x31, 0x22, 0x1f, 0x8f, 0xf9],
+    [0x12, 0x74, 0xe6, 0x49, 0xa3, 0x2e, 0xd3, 0x55, 0xa3, 0x1a, 0x6e, 0xd6, 0x97, 0x24, 0xe1, 0xad, 0xad, 0xe8, 0x57, 0xe8, 0x6e, 0xb5, 0xc3, 0xa1, 0x21, 0xbc, 0xd1, 0x47, 0x94, 0x32, 0x03, 0xc8],
+];
+
+#[rustfmt::skip]
+pub(crate) const ROUND_CONSTANTS_T2: [[u8; 32]; 128] = [
+    [0x09, 0xc4, 0x6e, 0x9e, 0xc6, 0x8e, 0x9b, 0xd4, 0xfe, 0x1f, 0xaa, 0xba, 0x29, 0x4c, 0xba, 0x38, 0xa7, 0x1a, 0xa1, 0x77, 0x53, 0x4c, 0xdd, 0x1b, 0x6c, 0x7d, 0xc0, 0xdb, 0xd0, 0xab, 0xd7, 0xa7],
+    [0x0c, 0x03, 0x56, 0x53, 0x08, 0x96, 0xee, 0xc4, 0x2a, 0x97, 0xed, 0x93, 0x7f, 0x31, 0x35, 0xcf, 0xc5, 0x14, 0x2b, 0x3a, 0xe4, 0x05, 0xb8, 0x34, 0x3c, 0x1d, 0x83, 0xff, 0xa6, 0x04, 0xcb, 0x81],
+    [0x1e, 0x28, 0xa1, 0xd9, 0x35, 0x69, 0x8a, 0xd1, 0x14, 0x2e, 0x51, 0x18, 0x2b, 0xb5, 0x4c, 0xf4, 0xa0, 0x0e, 0xa5, 0xaa, 0xbd, 0x62, 0x68, 0xbd, 0x31, 0x7e, 0xa9, 0x77, 0xcc, 0x15, 0x4a, 0x30],
+    [0x27, 0xaf, 0x2d, 0x83, 0x1a, 0x9d, 0x27, 0x48, 0x08, 0x09, 0x65, 0xdb, 0x30, 0xe2, 0x98, 0xe4, 0x0e, 0x57, 0x57, 0xc3, 0xe0, 0x08, 0xdb, 0x96, 0x4c, 0xf9, 0xe2, 0xb1, 0x2b, 0x91, 0x25, 0x1f],
+    [0x1e, 0x6f, 0x11, 0xce, 0x60, 0xfc, 0x8f, 0x51, 0x3a, 0x6a, 0x3c, 0xfe, 0x16, 0xae, 0x17, 0x5a, 0x41, 0x29, 0x14, 0x62, 0xf2, 0x14, 0xcd, 0x08, 0x79, 0xaa, 0xf4, 0x35, 0x45, 0xb7, 0x4e, 0x03],
+    [0x2a, 0x67, 0x38, 0x4d, 0x3b, 0xbd, 0x5e, 0x43, 0x85, 0x41, 0x81, 0x9c, 0xb6, 0x81, 0xf0, 0xbe, 0x04, 0x46, 0x2e, 0xd1, 0x4c, 0x36, 0x13, 0xd8, 0xf7, 0x19, 0x20, 0x62, 0x68, 0xd1, 0x42, 0xd3],
+    [0x0b, 0x66, 0xfd, 0xf3, 0x56, 0x09, 0x3a, 0x61, 0x16, 0x09, 0xf8, 0xe1, 0x2f, 0xbf, 0xec, 0xf0, 0xb9, 0x85, 0xe3, 0x81, 0xf0, 0x25, 0x18, 0x89, 0x36, 0x40, 0x8f, 0x5d, 0x5c, 0x9f, 0x45, 0xd0],
+    [0x01, 0x2e, 0xe3, 0xec, 0x1e, 0x78, 0xd4, 0x70, 0x83, 0x0c, 0x61, 0x09, 0x3c, 0x2a, 0xde, 0x37, 0x0b, 0x26, 0xc8, 0x3c, 0xc5, 0xce, 0xbe, 0xed, 0xda, 0xa6, 0x85, 0x2d, 0xbd, 0xb0, 0x9e, 0x21],
+    [0x02, 0x52, 0xba, 0x5f, 0x67, 0x60, 0xbf, 0xbd, 0xfd, 0x88, 0xf6, 0x7f, 0x81, 0x75, 0xe3, 0xfd, 0x6c, 0xd1, 0xc4, 0x31, 0xb0, 0x99, 0xb6, 0xbb, 0x2d, 0x10, 0x8e, 0x7b, 0x44, 0x5b, 0xb1, 0xb9],
+    [0x17, 0x94, 0x74, 0xcc, 0xec, 0xa5, 0xff, 0x67, 0x6c, 0x6b, 0xec, 0x3c, 0xef, 0x54, 0x29, 0x63, 0x54, 0x39, 0x1a, 0x89, 0x35, 0xff, 0x71, 0xd6, 0xef, 0x5a, 0xea, 0xad, 0x7c, 0xa9, 0x32, 0xf1],
+    [0x2c, 0x24, 0x26, 0x13, 0x79, 0xa5, 0x1b, 0xfa, 0x92, 0x28, 0xff, 0x4a, 0x50, 0x3f, 0xd4, 0xed, 0x9c, 0x1f, 0x97, 0x4a, 0x26, 0x49, 0x69, 0xb3, 0x7e, 0x1a, 0x25, 0x89, 0xbb, 0xed, 0x2b, 0x91],
+    [0x1c, 0xc1, 0xd7, 0xb6, 0x26, 0x92, 0xe6, 0x3e, 0xac, 0x2f, 0x28, 0x8b, 0xd0, 0x69, 0x5b, 0x43, 0xc2, 0xf6, 0x3f, 0x50, 0x01, 0xfc, 0x0f, 0xc5, 0x53, 0xe6, 0x6c, 0x05, 0x51, 0x80, 0x1b, 0x05],
+    [0x25, 0x50, 0x59, 0x30, 0x1a, 0xad, 0xa9, 0x8b, 0xb2, 0xed, 0x55, 0xf8, 0x52, 0x97, 0x9e, 0x96, 0x00, 0x78, 0x4d, 0xbf, 0x17, 0xfb, 0xac, 0xd0, 0x5d, 0x9e, 0xff, 0x5f, 0xd9, 0xc9, 0x1b, 0x56],
+    [0x28, 0x43, 0x7b, 0xe3, 0xac, 0x1c, 0xb2, 0xe4, 0x79, 0xe1, 0xf5, 0xc0, 0xec, 0xcd, 0x32, 0xb3, 0xae, 0xa2, 0x42, 0x34, 0x97, 0x0a, 0x81, 0x93, 0xb1, 0x1c, 0x29, 0xce, 0x7e, 0x59, 0xef, 0xd9],
+    [0x28, 0x21, 0x6a, 0x44, 0x2f, 0x2e, 0x1f, 0x71, 0x1c, 0xa4, 0xfa, 0x6b, 0x53, 0x76, 0x6e, 0xb1, 0x18, 0x54, 0x8d, 0xa8, 0xfb, 0x4f, 0x78, 0xd4, 0x33, 0x87, 0x62, 0xc3, 0x7f, 0x5f, 0x20, 0x43],
+    [0x2c, 0x1f, 0x47, 0xcd, 0x17, 0xfa, 0x5a, 0xdf, 0x1f, 0x39, 0xf4, 0xe7, 0x05, 0x6d, 0xd0, 0x3f, 0xee, 0xe1, 0xef, 0xce, 0x03, 0x09, 0x45, 0x81, 0x13, 0x1f, 0x23, 0x77, 0x32, 0x34, 0x82, 0xc9],
+    [0x07, 0xab, 0xad, 0x02, 0xb7, 0xa5, 0xeb, 0xc4, 0x86, 0x32, 0xbc, 0xc9, 0x35, 0x6c, 0xeb, 0x7d, 0xd9, 0xda, 0xfc, 0xa2, 0x76, 0x63, 0x8a, 0x63, 0x64, 0x6b, 0x85, 0x66, 0xa6, 0x21, 0xaf, 0xc9],
+    [0x02, 0x30, 0x26, 0x46, 0x01, 0xff, 0xdf, 0x29, 0x27, 0x5b, 0x33, 0xff, 0xaa, 0xb5, 0x1d, 0xfe, 0x94, 0x29, 0xf9, 0x08, 0x80, 0xa6, 0x9c, 0xd1, 0x37, 0xda, 0x0c, 0x4d, 0x15, 0xf9, 0x6c, 0x3c],
+    [0x1b, 0xc9, 0x73, 0x05, 0x4e, 0x51, 0xd9, 0x05, 0xa0, 0xf1, 0x68, 0x65, 0x64, 0x97, 0xca, 0x40, 0xa8, 0x64, 0x41, 0x45, 0x57, 0xee, 0x28, 0x9e, 0x71, 0x7e, 0x5d, 0x66, 0x89, 0x9a, 0xa0, 0xa9],
+    [0x2e, 0x1c, 0x22, 0xf9, 0x64, 0x43, 0x50, 0x08, 0x20, 0x6c, 0x31, 0x57, 0xe8, 0x63, 0x41, 0xed, 0xd2, 0x49, 0xaf, 0xf5, 0xc2, 0xd8, 0x42, 0x1f, 0x2a, 0x6b, 0x22, 0x28, 0x8f, 0x0a, 0x67, 0xfc],
+    [0x12, 0x24, 0xf3, 0x8d, 0xf6, 0x7c, 0x53, 0x78, 0x12, 0x1c, 0x1d, 0x5f, 0x46, 0x1b, 0xbc, 0x50, 0x9e, 0x8e, 0xa1, 0x59, 0x8e, 0x46, 0xc9, 0xf7, 0xa7, 0x04, 0x52, 0xbc, 0x2b, 0xba, 0x86, 0xb8],
+    [0x02, 0xe4, 0xe6, 0x9d, 0x8b, 0xa5, 0x9e, 0x51, 0x92, 0x80, 0xb4, 0xbd, 0x9e, 0xd0, 0x06, 0x8f, 0xd7, 0xbf, 0xe8, 0xcd, 0x9d, 0xfe, 0xda, 0x19, 0x69, 0xd2, 0x98, 0x91, 0x86, 0xcd, 0xe2, 0x0e],
+    [0x1f, 0x1e, 0xcc, 0xc3, 0x4a, 0xab, 0xa0, 0x13, 0x7f, 0x5d, 0xf8, 0x1f, 0xc0, 0x4f, 0xf3, 0xee, 0x4f, 0x19, 0xee, 0x36, 0x4e, 0x65, 0x3f, 0x07, 0x6d, 0x47, 0xe9, 0x73, 0x5d, 0x98, 0x01, 0x8e],
+    [0x16, 0x72, 0xad, 0x3d, 0x70, 0x9a, 0x35, 0x39, 0x74, 0x26, 0x6c, 0x30, 0x39, 0xa9, 0xa7, 0x31, 0x14, 0x24, 0x44, 0x80, 0x32, 0xcd, 0x18, 0x19, 0xea, 0xcb, 0x8a, 0x4d, 0x42, 0x84, 0xf5, 0x82],
+    [0x28, 0x3e, 0x3f, 0xdc, 0x2c, 0x6e, 0x42, 0x0c, 0x56, 0xf4, 0x4a, 0xf5, 0x19, 0x2b, 0x4a, 0xe9, 0xcd, 0xa6, 0x96, 0x1f, 0x28, 0x4d, 0x24, 0x99, 0x1d, 0x2e, 0xd6, 0x02, 0xdf, 0x8c, 0x8f, 0xc7],
+    [0x1c, 0x2a, 0x3d, 0x12, 0x0c, 0x55, 0x0e, 0xcf, 0xd0, 0xdb, 0x09, 0x57, 0x17, 0x0f, 0xa0, 0x13, 0x68, 0x37, 0x51, 0xf8, 0xfd, 0xff, 0x59, 0xd6, 0x61, 0x4f, 0xbd, 0x69, 0xff, 0x39, 0x4b, 0xcc],
+    [0x21, 0x6f, 0x84, 0x87, 0x7a, 0xac, 0x61, 0x72, 0xf7, 0x89, 0x7a, 0x73, 0x23, 0x45, 0x6e, 0xfe, 0x14, 0x3a, 0x9a, 0x43, 0x77, 0x3e, 0xa6, 0xf2, 0x96, 0xcb, 0x6b, 0x81, 0x77, 0x65, 0x3f, 0xbd],
+    [0x2c, 0x0d, 0x27, 0x2b, 0xec, 0xf2, 0xa7, 0x57, 0x64, 0xba, 0x7e, 0x8e, 0x3e, 0x28, 0xd1, 0x2b, 0xce, 0xaa, 0x47, 0xea, 0x61, 0xca, 0x59, 0xa4, 0x11, 0xa1, 0xf5, 0x15, 0x52, 0xf9, 0x47, 0x88],
+    [0x16, 0xe3, 0x42, 0x99, 0x86, 0x5c, 0x0e, 0x28, 0x48, 0x4e, 0xe7, 0xa7, 0x4c, 0x45, 0x4e, 0x9f, 0x17, 0x0a, 0x54, 0x80, 0xab, 0xe0, 0x50, 0x8f, 0xcb, 0x4a, 0x6c, 0x3d, 0x89, 0x54, 0x6f, 0x43],
+    [0x17, 0x5c, 0xeb, 0xa5, 0x99, 0xe9, 0x6f, 0x5b, 0x37, 0x5a, 0x23, 0x2a, 0x6f, 0xb9, 0xcc, 0x71, 0x77, 0x20, 0x47, 0x76, 0x58, 0x02, 0x29, 0x0f, 0x48, 0xcd, 0x93, 0x97, 0x55, 0x48, 0x8f, 0xc5],
+    [0x0c, 0x75, 0x94, 0x44, 0x0d, 0xc4, 0x8c, 0x16, 0xfe, 0xad, 0x9e, 0x17, 0x58, 0xb0, 0x28, 0x06, 0x6a, 0xa4, 0x10, 0xbf, 0xbc, 0x35, 0x4f, 0x54, 0xd8, 0xc5, 0xff, 0xbb, 0x44, 0xa1, 0xee, 0x32],
+    [0x1a, 0x3c, 0x29, 0xbc, 0x39, 0xf2, 0x1b, 0xb5, 0xc4, 0x66, 0xdb, 0x7d, 0x7e, 0xb6, 0xfd, 0x8f, 0x76, 0x0e, 0x20, 0x01, 0x3c, 0xcf, 0x91, 0x2c, 0x92, 0x47, 0x98, 0x82, 0xd9, 0x19, 0xfd, 0x8d],
+    [0x0c, 0xcf, 0xdd, 0x90, 0x6f, 0x34, 0x26, 0xe5, 0xc0, 0x98, 0x6e, 0xa0, 0x49, 0xb2, 0x53, 0x40, 0x08, 0x55, 0xd3, 0x49, 0x07, 0x4f, 0x5a, 0x66, 0x95, 0xc8, 0xee, 0xab, 0xcd, 0x22, 0xe6, 0x8f],
+    [0x14, 0xf6, 0xbc, 0x81, 0xd9, 0xf1, 0x86, 0xf6, 0x2b, 0xdb, 0x47, 0x5c, 0xe6, 0xc9, 0x41, 0x18, 0x66, 0xa7, 0xa8, 0xa3, 0xfd, 0x06, 0x5b, 0x3c, 0xe0, 0xe6, 0x99, 0xb6, 0x7d, 0xd9, 0xe7, 0x96],
+    [0x09, 0x62, 0xb8, 0x27, 0x89, 0xfb, 0x3d, 0x12, 0x97, 0x02, 0xca, 0x70, 0xb2, 0xf6, 0xc5, 0xaa, 0xcc, 0x09, 0x98, 0x10, 0xc9, 0xc4, 0x95, 0xc8, 0x88, 0xed, 0xeb, 0x73, 0x86, 0xb9, 0x70, 0x52],
+    [0x1a, 0x88, 0x0a, 0xf7, 0x07, 0x4d, 0x18, 0xb3, 0xbf, 0x20, 0xc7, 0x9d, 0xe2, 0x51, 0x27, 0xbc, 0x13, 0x28, 0x4a, 0xb0, 0x1e, 0xf0, 0x25, 0x75, 0xaf, 0xef, 0x0c, 0x8f, 0x6a, 0x31, 0xa8, 0x6d],
+    [0x10, 0xcb, 0xa1, 0x84, 0x19, 0xa6, 0xa3, 0x32, 0xcd, 0x5e, 0x77, 0xf0, 0x21, 0x1c, 0x15, 0x4b, 0x20, 0xaf, 0x29, 0x24, 0xfc, 0x20, 0xff, 0x3f, 0x4c, 0x30, 0x12, 0xbb, 0x7a, 0xe9, 0x31, 0x1b],
+    [0x05, 0x7e, 0x62, 0xa9, 0xa8, 0xf8, 0x9b, 0x3e, 0xbd, 0xc7, 0x6b, 0xa6, 0x3a, 0x9e, 0xac, 0xa8, 0xfa, 0x27, 0xb7, 0x31, 0x9c, 0xae, 0x34, 0x06, 0x75, 0x6a, 0x28, 0x49, 0xf3, 0x02, 0xf1, 0x0d],
+    [0x28, 0x7c, 0x97, 0x1d, 0xe9, 0x1d, 0xc0, 0xab, 0xd4, 0x4a, 0xdf, 0x53, 0x84, 0xb4, 0x98, 0x8c, 0xb9, 0x61, 0x30, 0x3b, 0xbf, 0x65, 0xcf, 0xf5, 0xaf, 0xa0, 0x41, 0x3b, 0x44, 0x28, 0x0c, 0xee],
+    [0x21, 0xdf, 0x33, 0x88, 0xaf, 0x16, 0x87, 0xbb, 0xb3, 0xbc, 0xa9, 0xda, 0x0c, 0xca, 0x90, 0x8f, 0x1e, 0x56, 0x2b, 0xc4, 0x6d, 0x4a, 0xba, 0x4e, 0x6f, 0x7f, 0x79, 0x60, 0xe3, 0x06, 0x89, 0x1d],
+    [0x1b, 0xe5, 0xc8, 0x87, 0xd2, 0x5b, 0xce, 0x70, 0x3e, 0x25, 0xcc, 0x97, 0x4d, 0x09, 0x34, 0xcd, 0x78, 0x9d, 0xf8, 0xf7, 0x0b, 0x49, 0x8f, 0xd8, 0x3e, 0xff, 0x8b, 0x56, 0x0e, 0x16, 0x82, 0xb3],
+    [0x26, 0x8d, 0xa3, 0x6f, 0x76, 0xe5, 0x68, 0xfb, 0x68, 0x11, 0x71, 0x75, 0xce, 0xa2, 0xcd, 0x0d, 0xd2, 0xcb, 0x5d, 0x42, 0xfd, 0xa5, 0xac, 0xea, 0x48, 0xd5, 0x9c, 0x27, 0x06, 0xa0, 0xd5, 0xc1],
+    [0x0e, 0x17, 0xab, 0x09, 0x1f, 0x6e, 0xae, 0x50, 0xc6, 0x09, 0xbe, 0xaf, 0x55, 0x10, 0xec, 0xec, 0xc5, 0xd8, 0xbb, 0x74, 0x13, 0x5e, 0xbd, 0x05, 0xbd, 0x06, 0x46, 0x0c, 0xc2, 0x6a, 0x5e, 0xd6],
+    [0x04, 0xd7, 0x27, 0xe7, 0x28, 0xff, 0xa0, 0xa6, 0x7a, 0xee, 0x53, 0x5a, 0xb0, 0x74, 0xa4, 0x30, 0x91, 0xef, 0x62, 0xd8, 0xcf, 0x83, 0xd2, 0x70, 0x04, 0x0f, 0x5c, 0xaa, 0x1f, 0x62, 0xaf, 0x40],
+    [0x0d, 0xdb, 0xd7, 0xbf, 0x9c, 0x29, 0x34, 0x15, 0x81, 0xb5, 0x49, 0x76, 0x2b, 0xc0, 0x22, 0xed, 0x33, 0x70, 0x2a, 0xc1, 0x0f, 0x1b, 0xfd, 0x86, 0x2b, 0x15, 0x41, 0x7d, 0x7e, 0x39, 0xca, 0x6e],
+    [0x27, 0x90, 0xeb, 0x33, 0x51, 0x62, 0x17, 0x52, 0x76, 0x81, 0x62, 0xe8, 0x29, 0x89, 0xc6, 0xc2, 0x34, 0xf5, 0xb0, 0xd1, 0xd3, 0xaf, 0x9b, 0x58, 0x8a, 0x29, 0xc4, 0x9c, 0x87, 0x89, 0x65, 0x4b],
+    [0x1e, 0x45, 0x7c, 0x60, 0x1a, 0x63, 0xb7, 0x3e, 0x44, 0x71, 0x95, 0x01, 0x93, 0xd8, 0xa5, 0x70, 0x39, 0x5f, 0x3d, 0x9a, 0xb8, 0xb2, 0xfd, 0x09, 0x84, 0xb7, 0x64, 0x20, 0x61, 0x42, 0xf9, 0xe9],
+    [0x21, 0xae, 0x64, 0x30, 0x1d, 0xca, 0x96, 0x25, 0x63, 0x8d, 0x6a, 0xb2, 0xbb, 0xe7, 0x13, 0x5f, 0xfa, 0x90, 0xec, 0xd0, 0xc4, 0x3f, 0xf9, 0x1f, 0xc4, 0xc6, 0x86, 0xfc, 0x46, 0xe0, 0x91, 0xb0],
+    [0x03, 0x79, 0xf6, 0x3c, 0x8c, 0xe3, 0x46, 0x8d, 0x4d, 0xa2, 0x93, 0x16, 0x6f, 0x49, 0x49, 0x28, 0x85, 0x4b, 0xe9, 0xe3, 0x43, 0x2e, 0x09, 0x55, 0x58, 0x58, 0x53, 0x4e, 0xed, 0x8d, 0x35, 0x0b],
+    [0x00, 0x2d, 0x56, 0x42, 0x03, 0x59, 0xd0, 0x26, 0x6a, 0x74, 0x4a, 0x08, 0x08, 0x09, 0xe0, 0x54, 0xca, 0x0e, 0x49, 0x21, 0xa4, 0x66, 0x86, 0xac, 0x8c, 0x9f, 0x58, 0xa3, 0x24, 0xc3, 0x50, 0x49],
+    [0x12, 0x31, 0x58, 0xe5, 0x96, 0x5b, 0x5d, 0x9b, 0x1d, 0x68, 0xb3, 0xcd, 0x32, 0xe1, 0x0b, 0xbe, 0xda, 0x8d, 0x62, 0x45, 0x9e, 0x21, 0xf4, 0x09, 0x0f, 0xc2, 0xc5, 0xaf, 0x96, 0x35, 0x15, 0xa6],
+    [0x0b, 0xe2, 0x9f, 0xc4, 0x08, 0x47, 0xa9, 0x41, 0x66, 0x1d, 0x14, 0xbb, 0xf6, 0xcb, 0xe0, 0x42, 0x0f, 0xbb, 0x2b, 0x6f, 0x52, 0x83, 0x6d, 0x4e, 0x60, 0xc8, 0x0e, 0xb4, 0x9c, 0xad, 0x9e, 0xc1],
+    [0x1a, 0xc9, 0x69, 0x91, 0xde, 0xc2, 0xbb, 0x05, 0x57, 0x71, 0x61, 0x42, 0x01, 0x5a, 0x45, 0x3c, 0x36, 0xdb, 0x9d, 0x85, 0x9c, 0xad, 0x5f, 0x9a, 0x23, 0x38, 0x02, 0xf2, 0x4f, 0xdf, 0x4c, 0x1a],
+    [0x15, 0x96, 0x44, 0x3f, 0x76, 0x3d, 0xbc, 0xc2, 0x5f, 0x49, 0x64, 0xfc, 0x61, 0xd2, 0x3b, 0x3e, 0x5e, 0x12, 0xc9, 0xfa, 0x97, 0xf1, 0x8a, 0x92, 0x51, 0xca, 0x33, 0x55, 0xbc, 0xb0, 0x62, 0x7e],
+    [0x12, 0xe0, 0xbc, 0xd3, 0x65, 0x4b, 0xdf, 0xa7, 0x6b, 0x28, 0x61, 0xd4, 0xec, 0x3a, 0xea, 0xe0, 0xf1, 0x85, 0x7d, 0x9f, 0x17, 0xe7, 0x15, 0xae, 0xd6, 0xd0, 0x49, 0xea, 0xe3, 0xba, 0x32, 0x12],
+    [0x0f, 0xc9, 0x2b, 0x4f, 0x1b, 0xbe, 0xa8, 0x2b, 0x9e, 0xa7, 0x3d, 0x4a, 0xf9, 0xaf, 0x2a, 0x50, 0xce, 0xab, 0xac, 0x7f, 0x37, 0x15, 0x4b, 0x19, 0x04, 0xe6, 0xc7, 0x6c, 0x7c, 0xf9, 0x64, 0xba],
+    [0x1f, 0x9c, 0x0b, 0x16, 0x10, 0x44, 0x64, 0x42, 0xd6, 0xf2, 0xe5, 0x92, 0xa8, 0x01, 0x3f, 0x40, 0xb1, 0x4f, 0x7c, 0x77, 0x22, 0x23, 0x6f, 0x4f, 0x9c, 0x7e, 0x96, 0x52, 0x33, 0x87, 0x27, 0x62],
+    [0x0e, 0xbd, 0x74, 0x24, 0x4a, 0xe7, 0x26, 0x75, 0xf8, 0xcd, 0xe0, 0x61, 0x57, 0xa7, 0x82, 0xf4, 0x05, 0x0d, 0x91, 0x4d, 0xa3, 0x8b, 0x4c, 0x05, 0x8d, 0x15, 0x9f, 0x64, 0x3d, 0xbb, 0xf4, 0xd3],
+    [0x2c, 0xb7, 0xf0, 0xed, 0x39, 0xe1, 0x6e, 0x9f, 0x69, 0xa9, 0xfa, 0xfd, 0x4a, 0xb9, 0x51, 0xc0, 0x3b, 0x06, 0x71, 0xe9, 0x73, 0x46, 0xee, 0x39, 0x7a, 0x83, 0x98, 0x39, 0xdc, 0xcf, 0xc6, 0xd1],
+    [0x1a, 0x9d, 0x6e, 0x2e, 0xcf, 0xf0, 0x22, 0xcc, 0x56, 0x05, 0x44, 0x3e, 0xe4, 0x1b, 0xab, 0x20, 0xce, 0x76, 0x1d, 0x05, 0x14, 0xce, 0x52, 0x66, 0x90, 0xc7, 0x2b, 0xca, 0x73, 0x52, 0xd9, 0xbf],
+    [0x2a, 0x11, 0x54, 0x39, 0x60, 0x7f, 0x33, 0x5a, 0x5e, 0xa8, 0x3c, 0x3b, 0xc4, 0x4a, 0x93, 0x31, 0xd0, 0xc1, 0x33, 0x26, 0xa9, 0xa7, 0xba, 0x30, 0x87, 0xda, 0x18, 0x2d, 0x64, 0x8e, 0xc7, 0x2f],
+    [0x23, 0xf9, 0xb6, 0x52, 0x9b, 0x5d, 0x04, 0x0d, 0x15, 0xb8, 0xfa, 0x7a, 0xee, 0x3e, 0x34, 0x10, 0xe7, 0x38, 0xb5, 0x63, 0x05, 0xcd, 0x44, 0xf2, 0x95, 0x35, 0xc1, 0x15, 0xc5, 0xa4, 0xc0, 0x60],
+    [0x05, 0x87, 0x2c, 0x16, 0xdb, 0x0f, 0x72, 0xa2, 0x24, 0x9a, 0xc6, 0xba, 0x48, 0x4b, 0xb9, 0xc3, 0xa3, 0xce, 0x97, 0xc1, 0x6d, 0x58, 0xb6, 0x8b, 0x26, 0x0e, 0xb9, 0x39, 0xf0, 0xe6, 0xe8, 0xa7],
+    [0x13, 0x00, 0xbd, 0xee, 0x08, 0xbb, 0x78, 0x24, 0xca, 0x20, 0xfb, 0x80, 0x11, 0x80, 0x75, 0xf4, 0x02, 0x19, 0xb6, 0x15, 0x1d, 0x55, 0xb5, 0xc5, 0x2b, 0x62, 0x4a, 0x7c, 0xde, 0xdd, 0xf6, 0xa7],
+    [0x19, 0xb9, 0xb6, 0x3d, 0x2f, 0x10, 0x8e, 0x17, 0xe6, 0x38, 0x17, 0x86, 0x3a, 0x8f, 0x6c, 0x28, 0x8d, 0x7a, 0xd2, 0x99, 0x16, 0xd9, 0x8c, 0xb1, 0x07, 0x2e, 0x4e, 0x7b, 0x7d, 0x52, 0xb3, 0x76],
+    [0x01, 0x5b, 0xee, 0x13, 0x57, 0xe3, 0xc0, 0x15, 0xb5, 0xbd, 0xa2, 0x37, 0x66, 0x85, 0x22, 0xf6, 0x13, 0xd1, 0xc8, 0x87, 0x26, 0xb5, 0xec, 0x42, 0x24, 0xa2, 0x01, 0x28, 0x48, 0x1b, 0x4f, 0x7f],
+    [0x29, 0x53, 0x73, 0x6e, 0x94, 0xbb, 0x6b, 0x9f, 0x1b, 0x97, 0x07, 0xa4, 0xf1, 0x61, 0x5e, 0x4e, 0xfe, 0x1e, 0x1c, 0xe4, 0xba, 0xb2, 0x18, 0xcb, 0xea, 0x92, 0xc7, 0x85, 0xb1, 0x28, 0xff, 0xd1],
+    [0x0b, 0x06, 0x93, 0x53, 0xba, 0x09, 0x16, 0x18, 0x86, 0x2f, 0x80, 0x61, 0x80, 0xc0, 0x38, 0x5f, 0x85, 0x1b, 0x98, 0xd3, 0x72, 0xb4, 0x5f, 0x54, 0x4c, 0xe7, 0x26, 0x6e, 0xd6, 0x60, 0x8d, 0xfc],
+    [0x30, 0x4f, 0x74, 0xd4, 0x61, 0xcc, 0xc1, 0x31, 0x15, 0xe4, 0xe0, 0xbc, 0xfb, 0x93, 0x81, 0x7e, 0x55, 0xae, 0xb7, 0xeb, 0x93, 0x06, 0xb6, 0x4e, 0x4f, 0x58, 0x8a, 0xc9, 0x7d, 0x81, 0xf4, 0x29],
+    [0x15, 0xbb, 0xf1, 0x46, 0xce, 0x9b, 0xca, 0x09, 0xe8, 0xa3, 0x3f, 0x5e, 0x77, 0xdf, 0xe4, 0xf5, 0xaa, 0xd2, 0xa1, 0x64, 0xa4, 0x61, 0x7a, 0x4c, 0xb8, 0xee, 0x54, 0x15, 0xcd, 0xe9, 0x13, 0xfc],
+    [0x0a, 0xb4, 0xdf, 0xe0, 0xc2, 0x74, 0x2c, 0xde, 0x44, 0x90, 0x10, 0x31, 0x48, 0x79, 0x64, 0xed, 0x9b, 0x8f, 0x4b, 0x85, 0x04, 0x05, 0xc1, 0x0c, 0xa9, 0xff, 0x23, 0x85, 0x95, 0x72, 0xc8, 0xc6],
+    [0x0e, 0x32, 0xdb, 0x32, 0x0a, 0x04, 0x4e, 0x31, 0x97, 0xf4, 0x5f, 0x76, 0x49, 0xa1, 0x96, 0x75, 0xef, 0x5e, 0xed, 0xfe, 0xa5, 0x46, 0xde, 0xa9, 0x25, 0x1d, 0xe3, 0x9f, 0x96, 0x39, 0x77, 0x9a],
+    [0x0a, 0x17, 0x56, 0xaa, 0x1f, 0x37, 0x8c, 0xa4, 0xb2, 0x76, 0x35, 0xa7, 0x8b, 0x68, 0x88, 0xe6, 0x67, 0x97, 0x73, 0x3a, 0x82, 0x77, 0x48, 0x96, 0xa3, 0x07, 0x8e, 0xfa, 0x51, 0x6d, 0xa0, 0x16],
+    [0x04, 0x4c, 0x4a, 0x33, 0xb1, 0x0f, 0x69, 0x34, 0x47, 0xfd, 0x17, 0x17, 0x7f, 0x95, 0x2e, 0xf8, 0x95, 0xe6, 0x1d, 0x32, 0x8f, 0x85, 0xef, 0xa9, 0x42, 0x54, 0xd6, 0xa2, 0xa2, 0x5d, 0x93, 0xef],
+    [0x2e, 0xd3, 0x61, 0x1b, 0x72, 0x5b, 0x8a, 0x70, 0xbe, 0x65, 0x5b, 0x53, 0x7f, 0x66, 0xf7, 0x00, 0xfe, 0x08, 0x79, 0xd7, 0x9a, 0x49, 0x68, 0x91, 0xd3, 0x7b, 0x07, 0xb5, 0x46, 0x6c, 0x4b, 0x8b],
+    [0x1f, 0x9b, 0xa4, 0xe8, 0xba, 0xb7, 0xce, 0x42, 0xc8, 0xec, 0xc3, 0xd7, 0x22, 0xaa, 0x2e, 0x0e, 0xad, 0xfd, 0xeb, 0x9c, 0xfd, 0xd3, 0x47, 0xb5, 0xd8, 0x33, 0x9e, 0xa7, 0x12, 0x08, 0x58, 0xaa],
+    [0x1b, 0x23, 0x30, 0x43, 0x05, 0x2e, 0x8c, 0x28, 0x8f, 0x7e, 0xe9, 0x07, 0xa8, 0x4e, 0x51, 0x8a, 0xa3, 0x8e, 0x82, 0xac, 0x45, 0x02, 0x06, 0x6d, 0xb7, 0x40, 0x56, 0xf8, 0x65, 0xc5, 0xd3, 0xda],
+    [0x24, 0x31, 0xe1, 0xcc, 0x16, 0x4b, 0xb8, 0xd0, 0x74, 0x03, 0x1a, 0xb7, 0x2b, 0xd5, 0x5b, 0x4c, 0x90, 0x20, 0x53, 0xbf, 0xc0, 0xf1, 0x4d, 0xb0, 0xca, 0x2f, 0x97, 0xb0, 0x20, 0x87, 0x59, 0x54],
+    [0x08, 0x2f, 0x93, 0x4c, 0x91, 0xf5, 0xaa, 0xc3, 0x30, 0xcd, 0x69, 0x53, 0xa0, 0xa7, 0xdb, 0x45, 0xa1, 0x3e, 0x32, 0x20, 0x97, 0x58, 0x33, 0x19, 0xa7, 0x91, 0xf2, 0x73, 0x96, 0x58, 0x01, 0xfd],
+    [0x2b, 0x9a, 0x0a, 0x22, 0x3e, 0x75, 0x38, 0xb0, 0xa3, 0x4b, 0xe0, 0x74, 0x31, 0x55, 0x42, 0xa3, 0xc7, 0x72, 0x45, 0xe2, 0xae, 0x7c, 0xbe, 0x99, 0x9a, 0xd6, 0xbb, 0x93, 0x0c, 0x48, 0x99, 0x7c],
+    [0x0e, 0x1c, 0xd9, 0x1e, 0xdd, 0x2c, 0xfa, 0x2c, 0xce, 0xb8, 0x54, 0x83, 0xb8, 0x87, 0xa9, 0xbe, 0x81, 0x64, 0x16, 0x3e, 0x75, 0xa8, 0xa0, 0x0e, 0xb0, 0xb5, 0x89, 0xcc, 0x70, 0x21, 0x4e, 0x7d],
+    [0x2e, 0x1e, 0xac, 0x0f, 0x2b, 0xfd, 0xfd, 0x63, 0xc9, 0x51, 0xf6, 0x14, 0x77, 0xe3, 0x69, 0x89, 0x99, 0x77, 0x4f, 0x19, 0x85, 0x4d, 0x00, 0xf5, 0x88, 0xd3, 0x24, 0x60, 0x1c, 0xeb, 0xe2, 0xf9],
+    [0x0c, 0xbf, 0xa9, 0x5f, 0x37, 0xfb, 0x74, 0x06, 0x0c, 0x76, 0x15, 0x8e, 0x76, 0x9d, 0x6d, 0x15, 0x73, 0x45, 0x78, 0x4d, 0x8e, 0xfd, 0xb3, 0x3c, 0x23, 0xd7, 0x48, 0x11, 0x5b, 0x50, 0x0b, 0x83],
+    [0x08, 0xf0, 0x5b, 0x3b, 0xe9, 0x23, 0xed, 0x44, 0xd6, 0x5a, 0xd4, 0x9d, 0x8a, 0x61, 0xe9, 0xa6, 0x76, 0xd9, 0x91, 0xe3, 0xa7, 0x75, 0x13, 0xd9, 0x98, 0x0c, 0x23, 0x2d, 0xfa, 0x4a, 0x4f, 0x84],
+    [0x22, 0x71, 0x9e, 0x2a, 0x07, 0x0b, 0xcd, 0x08, 0x52, 0xbf, 0x8e, 0x21, 0x98, 0x4d, 0x04, 0x43, 0xe7, 0x28, 0x49, 0x25, 0xdc, 0x07, 0x58, 0xa3, 0x25, 0xa2, 0xdd, 0x51, 0x0c, 0x04, 0x7e, 0xf6],
+    [0x04, 0x1f, 0x59, 0x6a, 0x9e, 0xe1, 0xcb, 0x2b, 0xc0, 0x60, 0xf7, 0xfc, 0xc3, 0xa1, 0xab, 0x4c, 0x7b, 0xdb, 0xf0, 0x36, 0x11, 0x99, 0x82, 0xc0, 0xf4, 0x1f, 0x62, 0xb2, 0xf2, 0x68, 0x30, 0xc0],
+    [0x23, 0x3f, 0xd3, 0x5d, 0xe1, 0xbe, 0x52, 0x0a, 0x87, 0x62, 0x8e, 0xb0, 0x6f, 0x6b, 0x1d, 0x4c, 0x02, 0x1b, 0xe1, 0xc2, 0xd0, 0xdc, 0x46, 0x4a, 0x19, 0xfc, 0xdd, 0x09, 0x86, 0xb1, 0x0f, 0x89],
+    [0x05, 0x24, 0xb4, 0x6d, 0x1a, 0xa8, 0x7a, 0x5e, 0x43, 0x25, 0xe0, 0xa4, 0x23, 0xeb, 0xc8, 0x10, 0xd3, 0x1e, 0x07, 0x8a, 0xa1, 0xb4, 0x70, 0x7e, 0xef, 0xcb, 0x45, 0x3c, 0x61, 0xc9, 0xc2, 0x67],
+    [0x2c, 0x34, 0xf4, 0x24, 0xc8, 0x1e, 0x57, 0x16, 0xce, 0x47, 0xfc, 0xac, 0x89, 0x4b, 0x85, 0x82, 0x42, 0x27, 0xbb, 0x95, 0x4b, 0x0f, 0x31, 0x99, 0xcc, 0x44, 0x86, 0x23, 0x7c, 0x51, 0x52, 0x11],
+    [0x0b, 0x5f, 0x2a, 0x4b, 0x63, 0x38, 0x78, 0x19, 0x20, 0x7e, 0xff, 0xc2, 0xb5, 0x54, 0x1f, 0xb7, 0x2d, 0xd2, 0x02, 0x5b, 0x54, 0x57, 0xcc, 0x97, 0xf3, 0x30, 0x10, 0x32, 0x7d, 0xe4, 0x91, 0x5e],
+    [0x22, 0x20, 0x78, 0x56, 0x08, 0x2c, 0xcc, 0x54, 0xc5, 0xb7, 0x2f, 0xe4, 0x39, 0xd2, 0xcf, 0xd6, 0xc1, 0x74, 0x35, 0xd2, 0xf5, 0x7a, 0xf6, 0xce, 0xae, 0xfa, 0xc4, 0x1f, 0xe0, 0x5c, 0x65, 0x9f],
+    [0x24, 0xd5, 0x7a, 0x8b, 0xf5, 0xda, 0x63, 0xfe, 0x4e, 0x24, 0x15, 0x9b, 0x7f, 0x89, 0x50, 0xb5, 0xcd, 0xfb, 0x21, 0x01, 0x94, 0xca, 0xf7, 0x9f, 0x27, 0x85, 0x40, 0x48, 0xce, 0x2c, 0x81, 0x71],
+    [0x0a, 0xfa, 0xb1, 0x81, 0xfd, 0xd5, 0xe0, 0x58, 0x3b, 0x37, 0x1d, 0x75, 0xbd, 0x69, 0x3f, 0x98, 0x37, 0x4a, 0xd7, 0x09, 0x7b, 0xb0, 0x1a, 0x85, 0x73, 0x91, 0x9b, 0xb2, 0x3b, 0x79, 0x39, 0x6e],
+    [0x2d, 0xba, 0x9b, 0x10, 0x8f, 0x20, 0x87, 0x72, 0x99, 0x8a, 0x52, 0xef, 0xac, 0x7c, 0xbd, 0x56, 0x76, 0xc0, 0x05, 0x71, 0x94, 0xc1, 0x6c, 0x0b, 0xf1, 0x62, 0x90, 0xd6, 0x2b, 0x11, 0x28, 0xee],
+    [0x26, 0x34, 0x9b, 0x66, 0xed, 0xb8, 0xb1, 0x6f, 0x56, 0xf8, 0x81, 0xc7, 0x88, 0xf5, 0x3f, 0x83, 0xcb, 0xb8, 0x3d, 0xe0, 0xbd, 0x59, 0x2b, 0x25, 0x5a, 0xff, 0x13, 0xe6, 0xbc, 0xe4, 0x20, 0xb3],
+    [0x25, 0xaf, 0x7c, 0xe0, 0xe5, 0xe1, 0x03, 0x57, 0x68, 0x5e, 0x95, 0xf9, 0x23, 0x39, 0x75, 0x3a, 0xd8, 0x1a, 0x56, 0xd2, 0x8e, 0xcc, 0x19, 0x3b, 0x23, 0x52, 0x88, 0xa3, 0xe6, 0xf1, 0x37, 0xdb],
+    [0x25, 0xb4, 0xce, 0x7b, 0xd2, 0x29, 0x43, 0x90, 0xc0, 0x94, 0xd6, 0xa5, 0x5e, 0xdd, 0x68, 0xb9, 0x70, 0xee, 0xd7, 0xaa, 0xe8, 0x8b, 0x2b, 0xff, 0x1f, 0x7c, 0x01, 0x87, 0xfe, 0x35, 0x01, 0x1f],
+    [0x22, 0xc5, 0x43, 0xf1, 0x0f, 0x6c, 0x89, 0xec, 0x38, 0x7e, 0x53, 0xf1, 0x90, 0x8a, 0x88, 0xe5, 0xde, 0x9c, 0xef, 0x28, 0xeb, 0xdf, 0x30, 0xb1, 0x8c, 0xb9, 0xd5, 0x4c, 0x1e, 0x02, 0xb6, 0x31],
+    [0x02, 0x36, 0xf9, 0x3e, 0x77, 0x89, 0xc4, 0x72, 0x4f, 0xc7, 0x90, 0x8a, 0x9f, 0x19, 0x1e, 0x1e, 0x42, 0x5e, 0x90, 0x6a, 0x91, 0x9d, 0x7a, 0x34, 0xdf, 0x66, 0x8e, 0x74, 0x88, 0x2f, 0x87, 0xa9],
+    [0x29, 0x35, 0x0b, 0x40, 0x11, 0x66, 0xca, 0x01, 0x0e, 0x7d, 0x27, 0xe3, 0x7d, 0x05, 0xda, 0x99, 0x65, 0x2b, 0xda, 0xe1, 0x14, 0xeb, 0x01, 0x65, 0x9c, 0xb4, 0x97, 0xaf, 0x98, 0x0c, 0x4b, 0x52],
+    [0x0e, 0xed, 0x78, 0x7d, 0x65, 0x82, 0x0d, 0x3f, 0x6b, 0xd3, 0x1b, 0xba, 0xb5, 0x47, 0xf7, 0x5a, 0x65, 0xed, 0xb7, 0x5d, 0x84, 0x4e, 0xbb, 0x89, 0xee, 0x12, 0x60, 0x91, 0x66, 0x52, 0x36, 0x3f],
+    [0x07, 0xcc, 0x11, 0x70, 0xf1, 0x3b, 0x46, 0xf2, 0x03, 0x6a, 0x75, 0x3f, 0x52, 0x0b, 0x32, 0x91, 0xfd, 0xcd, 0x0e, 0x99, 0xbd, 0x94, 0x29, 0x7d, 0x19, 0x06, 0xf6, 0x56, 0xf4, 0xde, 0x6f, 0xad],
+    [0x22, 0xb9, 0x39, 0x23, 0x3b, 0x1d, 0x72, 0x05, 0xf4, 0x9b, 0xcf, 0x61, 0x3a, 0x3d, 0x30, 0xb1, 0x90, 0x87, 0x86, 0xd7, 0xf9, 0xf5, 0xd1, 0x0c, 0x20, 0x59, 0x43, 0x56, 0x89, 0xe8, 0xac, 0xea],
+    [0x01, 0x45, 0x17, 0x62, 0xa0, 0xaa, 0xb8, 0x1c, 0x8a, 0xad, 0x1d, 0xc8, 0xbc, 0x33, 0xe8, 0x70, 0x74, 0x0f, 0x08, 0x3a, 0x5a, 0xa8, 0x54, 0x38, 0xad, 0xd6, 0x50, 0xac, 0xe6, 0x0a, 0xe5, 0xa6],
+    [0x23, 0x50, 0x6b, 0xb5, 0xd8, 0x72, 0x7d, 0x44, 0x61, 0xfa, 0xbf, 0x10, 0x25, 0xd4, 0x6d, 0x1f, 0xe3, 0x2e, 0xaa, 0x61, 0xde, 0xc7, 0xda, 0x57, 0xe7, 0x04, 0xfe, 0xc0, 0x89, 0x2f, 0xce, 0x89],
+    [0x2e, 0x48, 0x4c, 0x44, 0xe8, 0x38, 0xae, 0xa0, 0xba, 0xc0, 0x6a, 0xe3, 0xf7, 0x1b, 0xdd, 0x09, 0x2a, 0x37, 0x09, 0x53, 0x1e, 0x1e, 0xfe, 0xa9, 0x7f, 0x8b, 0xd6, 0x89, 0x07, 0x35, 0x55, 0x22],
+    [0x0f, 0x4b, 0xc7, 0xd0, 0x7e, 0xba, 0xfd, 0x64, 0x37, 0x9e, 0x78, 0xc5, 0x0b, 0xd2, 0xe4, 0x2b, 0xaf, 0x4a, 0x59, 0x45, 0x45, 0xce, 0xdc, 0x25, 0x45, 0x41, 0x8d, 0xa2, 0x68, 0x35, 0xb5, 0x4c],
+    [0x1f, 0x4d, 0x3c, 0x8f, 0x65, 0x83, 0xe9, 0xe5, 0xfa, 0x76, 0x63, 0x78, 0x62, 0xfa, 0xae, 0xe8, 0x51, 0x58, 0x23, 0x88, 0x72, 0x5d, 0xf4, 0x60, 0xe6, 0x20, 0x99, 0x6d, 0x50, 0xd8, 0xe7, 0x4e],
+    [0x09, 0x35, 0x14, 0xe0, 0xc7, 0x07, 0x11, 0xf8, 0x26, 0x60, 0xd0, 0x7b, 0xe0, 0xe4, 0xa9, 0x88, 0xfa, 0xe0, 0x2a, 0xbc, 0x7b, 0x68, 0x1d, 0x91, 0x53, 0xeb, 0x9b, 0xcb, 0x48, 0xfe, 0x73, 0x89],
+    [0x1a, 0xda, 0xb0, 0xc8, 0xe2, 0xb3, 0xba, 0xd3, 0x46, 0x69, 0x9a, 0x2b, 0x5f, 0x3b, 0xc0, 0x36, 0x43, 0xee, 0x83, 0xec, 0xe4, 0x72, 0x28, 0xf2, 0x4a, 0x58, 0xe0, 0xa3, 0x47, 0xe1, 0x53, 0xd8],
+    [0x16, 0x72, 0xb1, 0x72, 0x60, 0x57, 0xd9, 0x9d, 0xd1, 0x47, 0x09, 0xeb, 0xb4, 0x74, 0x64, 0x1a, 0x37, 0x8c, 0x1b, 0x94, 0xb8, 0x07, 0x2b, 0xac, 0x1a, 0x22, 0xdb, 0xef, 0x9e, 0x80, 0xda, 0xd2],
+    [0x1d, 0xfd, 0x53, 0xd4, 0x57, 0x6a, 0xf2, 0xe3, 0x8f, 0x44, 0xf5, 0x3f, 0xdc, 0xab, 0x46, 0x8c, 0xc5, 0xd8, 0xe2, 0xfa, 0xe0, 0xac, 0xc4, 0xee, 0x30, 0xd4, 0x7b, 0x23, 0x9b, 0x47, 0x9c, 0x14],
+    [0x0c, 0x68, 0x88, 0xa1, 0x0b, 0x75, 0xb0, 0xf3, 0xa7, 0x0a, 0x36, 0x26, 0x3a, 0x37, 0xe1, 0x7f, 0xe6, 0xd7, 0x7d, 0x64, 0x0f, 0x6f, 0xc3, 0xde, 0xbc, 0x7f, 0x20, 0x77, 0x53, 0x20, 0x5c, 0x60],
+    [0x1a, 0xdd, 0xb9, 0x33, 0xa6, 0x5b, 0xe7, 0x70, 0x92, 0xb3, 0x4a, 0x7e, 0x77, 0xd1, 0x2f, 0xe8, 0x61, 0x1a, 0x61, 0xe0, 0x0e, 0xe6, 0x84, 0x8b, 0x85, 0x09, 0x1e, 0xcc, 0xa9, 0xd1, 0xe5, 0x08],
+    [0x00, 0xd7, 0x54, 0x0d, 0xcd, 0x26, 0x8a, 0x84, 0x5c, 0x10, 0xae, 0x18, 0xd1, 0xde, 0x93, 0x3c, 0xf6, 0x38, 0xff, 0x54, 0x25, 0xf0, 0xaf, 0xff, 0x79, 0x35, 0x62, 0x8e, 0x29, 0x9d, 0x17, 0x91],
+    [0x14, 0x0c, 0x0e, 0x42, 0x68, 0x7e, 0x9e, 0xad, 0x01, 0xb2, 0x82, 0x7a, 0x56, 0x64, 0xca, 0x9c, 0x26, 0xfe, 0xdd, 0xe4, 0xac, 0xd9, 0x9d, 0xb1, 0xd3, 0x16, 0x93, 0x9d, 0x20, 0xb8, 0x2c, 0x0e],
+    [0x2f, 0x0c, 0x3a, 0x11, 0x5d, 0x43, 0x17, 0xd1, 0x91, 0xba, 0x89, 0xb8, 0xd1, 0x3d, 0x18, 0x06, 0xc2, 0x0a, 0x0f, 0x9b, 0x24, 0xf8, 0xc5, 0xed, 0xc0, 0x91, 0xe2, 0xae, 0x56, 0x56, 0x59, 0x84],
+    [0x0c, 0x4e, 0xe7, 0x78, 0xff, 0x7c, 0x14, 0x55, 0x30, 0x06, 0xed, 0x22, 0x0c, 0xf9, 0xc8, 0x10, 0x08, 0xa0, 0xcf, 0xf6, 0x70, 0xb2, 0x2b, 0x82, 0xd8, 0xc5, 0x38, 0xa1, 0xdc, 0x95, 0x8c, 0x61],
+    [0x17, 0x04, 0xf2, 0x76, 0x6d, 0x46, 0xf8, 0x2c, 0x36, 0x93, 0xf0, 0x04, 0x40, 0xcc, 0xc3, 0x60, 0x94, 0x24, 0xed, 0x26, 0xc0, 0xac, 0xc6, 0x62, 0x27, 0xc3, 0xd7, 0x48, 0x5d, 0xe7, 0x4c, 0x69],
+    [0x2f, 0x2d, 0x19, 0xcc, 0x3e, 0xa5, 0xd7, 0x8e, 0xa7, 0xa0, 0x2c, 0x1b, 0x51, 0xd2, 0x44, 0xab, 0xf0, 0x76, 0x9c, 0x9f, 0x85, 0x44, 0xe4, 0x02, 0x39, 0xb6, 0x6f, 0xe9, 0x00, 0x9c, 0x3c, 0xfa],
+    [0x1a, 0xe0, 0x38, 0x53, 0xb7, 0x5f, 0xca, 0xba, 0x50, 0x53, 0xf1, 0x12, 0xe2, 0xa8, 0xe8, 0xdc, 0xdd, 0x7e, 0xe6, 0xcb, 0x9c, 0xfe, 0xd9, 0xc7, 0xd6, 0xc7, 0x66, 0xa8, 0x06, 0xfc, 0x66, 0x29],
+    [0x09, 0x71, 0xaa, 0xbf, 0x79, 0x52, 0x41, 0xdf, 0x51, 0xd1, 0x31, 0xd0, 0xfa, 0x61, 0xaa, 0x5f, 0x35, 0x56, 0x92, 0x1b, 0x2d, 0x6f, 0x01, 0x4e, 0x4e, 0x41, 0xa8, 0x6d, 0xda, 0xf0, 0x56, 0xd5],
+    [0x14, 0x08, 0xc3, 0x16, 0xe6, 0x01, 0x4e, 0x1a, 0x91, 0xd4, 0xcf, 0x6b, 0x6e, 0x0d, 0xe7, 0x3e, 0xda, 0x62, 0x4f, 0x83, 0x80, 0xdf, 0x1c, 0x87, 0x5f, 0x5c, 0x29, 0xf7, 0xbf, 0xe2, 0xf6, 0x46],
+    [0x16, 0x67, 0xf3, 0xfe, 0x2e, 0xdb, 0xe8, 0x50, 0x24, 0x8a, 0xbe, 0x42, 0xb5, 0x43, 0x09, 0x3b, 0x6c, 0x89, 0xf1, 0xf7, 0x73, 0xef, 0x28, 0x53, 0x41, 0x69, 0x1f, 0x39, 0x82, 0x2e, 0xf5, 0xbd],
+    [0x13, 0xbf, 0x7c, 0x5d, 0x0d, 0x2c, 0x43, 0x76, 0xa4, 0x8b, 0x0a, 0x03, 0x55, 0x7c, 0xdf, 0x91, 0x5b, 0x81, 0x71, 0x84, 0x09, 0xe5, 0xc1, 0x33, 0x42, 0x4c, 0x69, 0x57, 0x65, 0x00, 0xfe, 0x37],
+    [0x07, 0x62, 0x0a, 0x6d, 0xfb, 0x0b, 0x6c, 0xec, 0x30, 0x16, 0xad, 0xf3, 0xd3, 0x53, 0x3c, 0x24, 0x02, 0x4b, 0x95, 0x34, 0x78, 0x56, 0xb7, 0x97, 0x19, 0xbc, 0x0b, 0xa7, 0x43, 0xa6, 0x2c, 0x2c],
+    [0x15, 0x74, 0xc7, 0xef, 0x0c, 0x43, 0x54, 0x5f, 0x36, 0xa8, 0xca, 0x08, 0xbd, 0xbd, 0xd8, 0xb0, 0x75, 0xd2, 0x95, 0x9e, 0x2f, 0x32, 0x2b, 0x73, 0x16, 0x75, 0xde, 0x3e, 0x19, 0x82, 0xb4, 0xd0],
+    [0x26, 0x9e, 0x4b, 0x5b, 0x7a, 0x2e, 0xb2, 0x1a, 0xfd, 0x56, 0x79, 0x70, 0xa7, 0x17, 0xce, 0xec, 0x5b, 0xd4, 0x18, 0x45, 0x71, 0xc2, 0x54, 0xfd, 0xc0, 0x6e, 0x03, 0xa7, 0xff, 0x83, 0x78, 0xf0],
+];
+
+#[rustfmt::skip]
+pub(crate) const MDS_T4: [[u8; 32]; 16] = [
+    [0x23, 0x6d, 0x13, 0x39, 0x3e, 0xf8, 0x5c, 0xc4, 0x8a, 0x35, 0x1d, 0xd7, 0x86, 0xdd, 0x7a, 0x1d, 0xe5, 0xe3, 0x99, 0x42, 0x29, 0x61, 0x27, 0xfd, 0x87, 0x94, 0x72, 0x23, 0xae, 0x51, 0x08, 0xad],
+    [0x27, 0x76, 0x86, 0x49, 0x4f, 0x76, 0x44, 0xbb, 0xc4, 0xa9, 0xb1, 0x94, 0xe1, 0x07, 0x24, 0xeb, 0x96, 0x7f, 0x1d, 0xc5, 0x87, 0x18, 0xe5, 0x9e, 0x3c, 0xed, 0xc8, 0x21, 0xb2, 0xa7, 0xae, 0x19],
+    [0x02, 0x3d, 0xb6, 0x87, 0x84, 0xe3, 0xf0, 0xcc, 0x0b, 0x85, 0x61, 0x88, 0x26, 0xa9, 0xb3, 0x50, 0x51, 0x29, 0xc1, 0x64, 0x79, 0x97, 0x3b, 0x0a, 0x84, 0xa4, 0x52, 0x9e, 0x66, 0xb0, 0x9c, 0x62],
+    [0x1d, 0x35, 0x9d, 0x24, 0x5f, 0x28, 0x6c, 0x12, 0xd5, 0x0d, 0x66, 0x3b, 0xae, 0x73, 0x3f, 0x97, 0x8a, 0xf0, 0x8c, 0xdb, 0xd6, 0x30, 0x17, 0xc5, 0x7b, 0x3a, 0x75, 0x64, 0x6f, 0xf3, 0x82, 0xc1],
+    [0x2a, 0x75, 0xa1, 0x71, 0x56, 0x3b, 0x80, 0x7d, 0xb5, 0x25, 0xbe, 0x25, 0x96, 0x99, 0xab, 0x28, 0xfe, 0x9b, 0xc7, 0xfb, 0x1f, 0x70, 0x94, 0x3f, 0xf0, 0x49, 0xbc, 0x97, 0x0e, 0x84, 0x1a, 0x0c],
+    [0x08, 0x3a, 0xbf, 0xf5, 0xe1, 0x00, 0x51, 0xf0, 0x78, 0xe2, 0x82, 0x7d, 0x09, 0x2e, 0x1a, 0xe8, 0x08, 0xb4, 0xdd, 0x3e, 0x15, 0xcc, 0xc3, 0x70, 0x6f, 0x38, 0xce, 0x41, 0x57, 0xb6, 0x77, 0x0e],
+    [0x1a, 0x5a, 0xd7, 0x1b, 0xbb, 0xec, 0xd8, 0xa9, 0x7d, 0xc4, 0x9c, 0xfd, 0xba, 0xe3, 0x03, 0xad, 0x24, 0xd5, 0xc4, 0x74, 0x1e, 0xab, 0x8b, 0x75, 0x68, 0xa9, 0xff, 0x82, 0x53, 0xa1, 0xeb, 0x6f],
+    [0x0d, 0x74, 0x5f, 0xd0, 0x0d, 0xd1, 0x67, 0xfb, 0x86, 0x77, 0x21, 0x33, 0x64, 0x0f, 0x02, 0xce, 0x94, 0x50, 0x04, 0xa7, 0xbc, 0x2c, 0x59, 0xe8, 0x79, 0x0f, 0x72, 0x5c, 0x5d, 0x84, 0xf0, 0xaf],
+    [0x20, 0x70, 0x67, 0x9e, 0x79, 0x87, 0x82, 0xef, 0x59, 0x2a, 0x52, 0xca, 0x9c, 0xef, 0x82, 0x0d, 0x49, 0x7a, 0xd2, 0xee, 0xcb, 0xaa, 0x7e, 0x42, 0xf3, 0x66, 0xb3, 0xe5, 0x21, 0xc4, 0xed, 0x42],
+    [0x2e, 0x18, 0xc8, 0x57, 0x0d, 0x20, 0xbf, 0x5d, 0xf8, 0x00, 0x73, 0x9a, 0x53, 0xda, 0x75, 0xd9, 0x06, 0xec, 0xe3, 0x18, 0xcd, 0x22, 0x4a, 0xb6, 0xb3, 0xa2, 0xbe, 0x97, 0x9e, 0x2d, 0x7e, 0xab],
+    [0x0f, 0xa8, 0x6f, 0x0f, 0x27, 0xe4, 0xd3, 0xdd, 0x7f, 0x33, 0x67, 0xce, 0x86, 0xf6, 0x84, 0xf1, 0xf2, 0xe4, 0x38, 0x6d, 0x3e, 0x5b, 0x9f, 0x38, 0xfa, 0x28, 0x3c, 0x6a, 0xa7, 0x23, 0xb6, 0x08],
+    [0x03, 0xf3, 0xe6, 0xfa, 0xb7, 0x91, 0xf1, 0x66, 0x28, 0x16, 0x8e, 0x4b, 0x14, 0xdb, 0xae, 0xb6, 0x57, 0x03, 0x5e, 0xe3, 0xda, 0x6b, 0x2c, 0xa8, 0x3f, 0x0c, 0x24, 0x91, 0xe0, 0xb4, 0x03, 0xeb],
+    [0x2f, 0x54, 0x5e, 0x57, 0x82, 0x02, 0xc9, 0x73, 0x24, 0x88, 0x54, 0x0e, 0x41, 0xf7, 0x83, 0xb6, 0x8f, 0xf0, 0x61, 0x3f, 0xd7, 0x93, 0x75, 0xf8, 0xba, 0x8b, 0x3d, 0x30, 0x95, 0x8e, 0x76, 0x77],
+    [0x23, 0x81, 0x0b, 0xf8, 0x28, 0x77, 0xfc, 0x19, 0xbf, 0xf7, 0xee, 0xfe, 0xae, 0x3f, 0xaf, 0x4b, 0xb8, 0x10, 0x4c, 0x32, 0xba, 0x4c, 0xd7, 0x01, 0x59, 0x6a, 0x15, 0x62, 0x3d, 0x01, 0x47, 0x6e],
+    [0x01, 0x4f, 0xcd, 0x5e, 0xb0, 0xbe, 0x6d, 0x5b, 0xee, 0xaf, 0xc4, 0x94, 0x40, 0x34, 0xcf, 0x32, 0x1c, 0x06, 0x8e, 0xf9, 0x30, 0xf1, 0x0b, 0xe2, 0x20, 0x7e, 0xd5, 0x8d, 0x2a, 0x34, 0xcd, 0xd6],
+    [0x00, 0xc1, 0x5f, 0xc3, 0xa1, 0xd5, 0x73, 0x3d, 0xd8, 0x35, 0xea, 0xe0, 0x82, 0x3e, 0x37, 0x7f, 0x8b, 0xa4, 0xa8, 0xb6, 0x27, 0x62, 0x7c, 0xc2, 0xbb, 0x66, 0x1c, 0x25, 0xd2, 0x0f, 0xb5, 0x2a],
+];
+
+#[rustfmt::skip]
+pub(crate) const ROUND_CONSTANTS_T4: [[u8; 32]; 256] = [
+    [0x19, 0xb8, 0x49, 0xf6, 0x94, 0x50, 0xb0, 0x68, 0x48, 0xda, 0x1d, 0x39, 0xbd, 0x5e, 0x4a, 0x43, 0x02, 0xbb, 0x86, 0x74, 0x4e, 0xdc, 0x26, 0x23, 0x8b, 0x08, 0x78, 0xe2, 0x69, 0xed, 0x23, 0xe5],
+    [0x26, 0x5d, 0xdf, 0xe1, 0x27, 0xdd, 0x51, 0xbd, 0x72, 0x39, 0x34, 0x7b, 0x75, 0x8f, 0x0a, 0x13, 0x20, 0xeb, 0x2c, 0xc7, 0x45, 0x0a, 0xcc, 0x1d, 0xad, 0x47, 0xf8, 0x0c, 0x8d, 0xcf, 0x34, 0xd6],
+    [0x19, 0x97, 0x50, 0xec, 0x47, 0x2f, 0x18, 0x09, 0xe0, 0xf6, 0x6a, 0x54, 0x5e, 0x1e, 0x51, 0x62, 0x41, 0x08, 0xac, 0x84, 0x50, 0x15, 0xc2, 0xaa, 0x3d, 0xfc, 0x36, 0xba, 0xb4, 0x97, 0xd8, 0xaa],
+    [0x15, 0x7f, 0xf3, 0xfe, 0x65, 0xac, 0x72, 0x08, 0x11, 0x0f, 0x06, 0xa5, 0xf7, 0x43, 0x02, 0xb1, 0x4d, 0x74, 0x3e, 0xa2, 0x50, 0x67, 0xf0, 0xff, 0xd0, 0x32, 0xf7, 0x87, 0xc7, 0xf1, 0xcd, 0xf8],
+    [0x2e, 0x49, 0xc4, 0x3c, 0x45, 0x69, 0xdd, 0x9c, 0x5f, 0xd3, 0x5a, 0xc4, 0x5f, 0xca, 0x33, 0xf1, 0x0b, 0x15, 0xc5, 0x90, 0x69, 0x2f, 0x8b, 0xee, 0xfe, 0x18, 0xf4, 0x89, 0x6a, 0xc9, 0x49, 0x02],
+    [0x0e, 0x35, 0xfb, 0x89, 0x98, 0x18, 0x90, 0x52, 0x0d, 0x4a, 0xef, 0x2b, 0x6d, 0x65, 0x06, 0xc3, 0xcb, 0x2f, 0x0b, 0x69, 0x73, 0xc2, 0x4f, 0xa8, 0x27, 0x31, 0x34, 0x5f, 0xfa, 0x2d, 0x1f, 0x1e],
+    [0x25, 0x1a, 0xd4, 0x7c, 0xb1, 0x5c, 0x4f, 0x11, 0x05, 0xf1, 0x09, 0xae, 0x5e, 0x94, 0x4f, 0x1b, 0xa9, 0xd9, 0xe7, 0x80, 0x6d, 0x66, 0x7f, 0xfe, 0xc6, 0xfe, 0x72, 0x30, 0x02, 0xe0, 0xb9, 0x96],
+    [0x13, 0xda, 0x07, 0xdc, 0x64, 0xd4, 0x28, 0x36, 0x98, 0x73, 0xe9, 0x71, 0x60, 0x23, 0x46, 0x41, 0xf8, 0xbe, 0xb5, 0x6f, 0xdd, 0x05, 0xe5, 0xf3, 0x56, 0x3f, 0xa3, 0x9d, 0x9c, 0x22, 0xdf, 0x4e],
+    [0x0c, 0x00, 0x9b, 0x84, 0xe6, 0x50, 0xe6, 0xd2, 0x3d, 0xc0, 0x0c, 0x7d, 0xcc, 0xef, 0x74, 0x83, 0xa5, 0x53, 0x93, 0x96, 0x89, 0xd3, 0x50, 0xcd, 0x46, 0xe7, 0xb8, 0x90, 0x55, 0xfd, 0x47, 0x38],
+    [0x01, 0x1f, 0x16, 0xb1, 0xc6, 0x3a, 0x85, 0x4f, 0x01, 0x99, 0x2e, 0x39, 0x56, 0xf4, 0x2d, 0x8b, 0x04, 0xeb, 0x65, 0x0c, 0x6d, 0x53, 0x5e, 0xb0, 0x20, 0x3d, 0xec, 0x74, 0xbe, 0xfd, 0xca, 0x06],
+    [0x0e, 0xd6, 0x9e, 0x5e, 0x38, 0x3a, 0x68, 0x8f, 0x20, 0x9d, 0x9a, 0x56, 0x1d, 0xaa, 0x79, 0x61, 0x2f, 0x3f, 0x78, 0xd0, 0x46, 0x7a, 0xd4, 0x54, 0x85, 0xdf, 0x07, 0x09, 0x3f, 0x36, 0x75, 0x49],
+    [0x04, 0xdb, 0xa9, 0x4a, 0x7b, 0x0c, 0xe9, 0xe2, 0x21, 0xac, 0xad, 0x41, 0x47, 0x2b, 0x6b, 0xbe, 0x3a, 0xec, 0x50, 0x7f, 0x5e, 0xb3, 0xd3, 0x3f, 0x46, 0x36, 0x72, 0x26, 0x4c, 0x9f, 0x78, 0x9b],
+    [0x0a, 0x3f, 0x26, 0x37, 0xd8, 0x40, 0xf3, 0xa1, 0x6e, 0xb0, 0x94, 0x27, 0x1c, 0x9d, 0x23, 0x7b, 0x60, 0x36, 0x75, 0x7d, 0x4b, 0xb5, 0x0b, 0xf7, 0xce, 0x73, 0x2f, 0xf1, 0xd4, 0xfa, 0x28, 0xe8],
+    [0x25, 0x9a, 0x66, 0x6f, 0x12, 0x9e, 0xea, 0x19, 0x8f, 0x8a, 0x1c, 0x50, 0x2f, 0xdb, 0x38, 0xfa, 0x39, 0xb1, 0xf0, 0x75, 0x56, 0x95, 0x64, 0xb6, 0xe5, 0x4a, 0x48, 0x5d, 0x11, 0x82, 0x32, 0x3f],
+    [0x28, 0xbf, 0x74, 0x59, 0xc9, 0xb2, 0xf4, 0xc6, 0xd8, 0xe7, 0xd0, 0x6a, 0x4e, 0xe3, 0xa4, 0x7f, 0x77, 0x45, 0xd4, 0x27, 0x10, 0x38, 0xe5, 0x15, 0x7a, 0x32, 0xfd, 0xf7, 0xed, 0xe0, 0xd6, 0xa1],
+    [0x0a, 0x1c, 0xa9, 0x41, 0xf0, 0x57, 0x03, 0x75, 0x26, 0xea, 0x20, 0x0f, 0x48, 0x9b, 0xe8, 0xd4, 0xc3, 0x7c, 0x85, 0xbb, 0xcc, 0xe6, 0xa2, 0xae, 0xec, 0x91, 0xbd, 0x69, 0x41, 0x43, 0x24, 0x47],
+    [0x0c, 0x6f, 0x8f, 0x95, 0x8b, 0xe0, 0xe9, 0x30, 0x53, 0xd7, 0xfd, 0x4f, 0xc5, 0x45, 0x12, 0x85, 0x55, 0x35, 0xed, 0x15, 0x39, 0xf0, 0x51, 0xdc, 0xb4, 0x3a, 0x26, 0xfd, 0x92, 0x63, 0x61, 0xcf],
+    [0x12, 0x31, 0x06, 0xa9, 0x3c, 0xd1, 0x75, 0x78, 0xd4, 0x26, 0xe8, 0x12, 0x8a, 0xc9, 0xd9, 0x0a, 0xa9, 0xe8, 0xa0, 0x07, 0x08, 0xe2, 0x96, 0xe0, 0x84, 0xdd, 0x57, 0xe6, 0x9c, 0xaa, 0xf8, 0x11],
+    [0x26, 0xe1, 0xba, 0x52, 0xad, 0x92, 0x85, 0xd9, 0x7d, 0xd3, 0xab, 0x52, 0xf8, 0xe8, 0x40, 0x08, 0x5e, 0x8f, 0xa8, 0x3f, 0xf1, 0xe8, 0xf1, 0x87, 0x7b, 0x07, 0x48, 0x67, 0xcd, 0x2d, 0xee, 0x75],
+    [0x1c, 0xb5, 0x5c, 0xad, 0x7b, 0xd1, 0x33, 0xde, 0x18, 0xa6, 0x4c, 0x5c, 0x47, 0xb9, 0xc9, 0x7c, 0xbe, 0x4d, 0x8b, 0x7b, 0xf9, 0xe0, 0x95, 0x86, 0x44, 0x71, 0x53, 0x7e, 0x6a, 0x4a, 0xe2, 0xc5],
+    [0x1d, 0xcd, 0x73, 0xe4, 0x6a, 0xcd, 0x8f, 0x8e, 0x0e, 0x2c, 0x7c, 0xe0, 0x4b, 0xde, 0x7f, 0x6d, 0x2a, 0x53, 0x04, 0x3d, 0x50, 0x60, 0xa4, 0x1c, 0x71, 0x43, 0xf0, 0x8e, 0x6e, 0x90, 0x55, 0xd0],
+    [0x01, 0x10, 0x03, 0xe3, 0x2f, 0x6d, 0x9c, 0x66, 0xf5, 0x85, 0x2f, 0x05, 0x47, 0x4a, 0x4d, 0xef, 0x0c, 0xda, 0x29, 0x4a, 0x0e, 0xb4, 0xe9, 0xb9, 0xb1, 0x2b, 0x9b, 0xb4, 0x51, 0x2e, 0x55, 0x74],
+    [0x2b, 0x1e, 0x80, 0x9a, 0xc1, 0xd1, 0x0a, 0xb2, 0x9a, 0xd5, 0xf2, 0x0d, 0x03, 0xa5, 0x7d, 0xfe, 0xba, 0xdf, 0xe5, 0x90, 0x3f, 0x58, 0xba, 0xfe, 0xd7, 0xc5, 0x08, 0xdd, 0x22, 0x87, 0xae, 0x8c],
+    [0x25, 0x39, 0xde, 0x17, 0x85, 0xb7, 0x35, 0x99, 0x9f, 0xb4, 0xda, 0xc3, 0x5e, 0xe1, 0x7e, 0xd0, 0xef, 0x99, 0x5d, 0x05, 0xab, 0x2f, 0xc5, 0xfa, 0xea, 0xa6, 0x9a, 0xe8, 0x7b, 0xce, 0xc0, 0xa5],
+    [0x0c, 0x24, 0x6c, 0x5a, 0x2e, 0xf8, 0xee, 0x01, 0x26, 0x49, 0x7f, 0x22, 0x2b, 0x3e, 0x0a, 0x0e, 0xf4, 0xe1, 0xc3, 0xd4, 0x1c, 0x86, 0xd4, 0x6e, 0x43, 0x98, 0x2c, 0xb1, 0x1d, 0x77, 0x95, 0x1d],
+    [0x19, 0x20, 0x89, 0xc4, 0x97, 0x4f, 0x68, 0xe9, 0x54, 0x08, 0x14, 0x8f, 0x7c, 0x06, 0x32, 0xed, 0xbb, 0x09, 0xe6, 0xa6, 0xad, 0x1a, 0x1c, 0x2f, 0x3f, 0x03, 0x05, 0xf5, 0xd0, 0x3b, 0x52, 0x7b],
+    [0x1e, 0xae, 0x0a, 0xd8, 0xab, 0x68, 0xb2, 0xf0, 0x6a, 0x0e, 0xe3, 0x6e, 0xeb, 0x0d, 0x0c, 0x05, 0x85, 0x29, 0x09, 0x7d, 0x91, 0x09, 0x6b, 0x75, 0x6d, 0x8f, 0xdc, 0x2f, 0xb5, 0xa6, 0x0d, 0x85],
+    [0x17, 0x91, 0x90, 0xe5, 0xd0, 0xe2, 0x21, 0x79, 0xe4, 0x6f, 0x82, 0x82, 0x87, 0x2a, 0xbc, 0x88, 0xdb, 0x6e, 0x2f, 0xdc, 0x0d, 0xee, 0x99, 0xe6, 0x97, 0x68, 0xbd, 0x98, 0xc5, 0xd0, 0x6b, 0xfb],
+    [0x29, 0xbb, 0x9e, 0x2c, 0x90, 0x76, 0x73, 0x25, 0x76, 0xe9, 0xa8, 0x1c, 0x7a, 0xc4, 0xb8, 0x32, 0x14, 0x52, 0x8f, 0x7d, 0xb0, 0x0f, 0x31, 0xbf, 0x6c, 0xaf, 0xe7, 0x94, 0xa9, 0xb3, 0xcd, 0x1c],
+    [0x22, 0x5d, 0x39, 0x4e, 0x42, 0x20, 0x75, 0x99, 0x40, 0x3e, 0xfd, 0x0c, 0x24, 0x64, 0xa9, 0x0d, 0x52, 0x65, 0x26, 0x45, 0x88, 0x2a, 0xac, 0x35, 0xb1, 0x0e, 0x59, 0x0e, 0x6e, 0x69, 0x1e, 0x08],
+    [0x06, 0x47, 0x60, 0x62, 0x3c, 0x25, 0xc8, 0xcf, 0x75, 0x3d, 0x23, 0x80, 0x55, 0xb4, 0x44, 0x53, 0x2b, 0xe1, 0x35, 0x57, 0x45, 0x1c, 0x08, 0x7d, 0xe0, 0x9e, 0xfd, 0x45, 0x4b, 0x23, 0xfd, 0x59],
+    [0x10, 0xba, 0x3a, 0x0e, 0x01, 0xdf, 0x92, 0xe8, 0x7f, 0x30, 0x1c, 0x4b, 0x71, 0x6d, 0x8a, 0x39, 0x4d, 0x67, 0xf4, 0xbf, 0x42, 0xa7, 0x5c, 0x10, 0x92, 0x29, 0x10, 0xa7, 0x8f, 0x6b, 0x5b, 0x87],
+    [0x0e, 0x07, 0x0b, 0xf5, 0x3f, 0x84, 0x51, 0xb2, 0x4f, 0x9c, 0x6e, 0x96, 0xb0, 0xc2, 0xa8, 0x01, 0xcb, 0x51, 0x1b, 0xc0, 0xc2, 0x42, 0xeb, 0x9d, 0x36, 0x1b, 0x77, 0x69, 0x3f, 0x21, 0x47, 0x1c],
+    [0x1b, 0x94, 0xcd, 0x61, 0xb0, 0x51, 0xb0, 0x4d, 0xd3, 0x97, 0x55, 0xff, 0x93, 0x82, 0x1a, 0x73, 0xcc, 0xd6, 0xcb, 0x11, 0xd2, 0x49, 0x1d, 0x8a, 0xa7, 0xf9, 0x21, 0x01, 0x4d, 0xe2, 0x52, 0xfb],
+    [0x1d, 0x7c, 0xb3, 0x9b, 0xaf, 0xb8, 0xc7, 0x44, 0xe1, 0x48, 0x78, 0x7a, 0x2e, 0x70, 0x23, 0x0f, 0x9d, 0x4e, 0x91, 0x7d, 0x57, 0x13, 0xbb, 0x05, 0x04, 0x87, 0xb5, 0xaa, 0x7d, 0x74, 0x07, 0x0b],
+    [0x2e, 0xc9, 0x31, 0x89, 0xbd, 0x1a, 0xb4, 0xf6, 0x91, 0x17, 0xd0, 0xfe, 0x98, 0x0c, 0x80, 0xff, 0x87, 0x85, 0xc2, 0x96, 0x18, 0x29, 0xf7, 0x01, 0xbb, 0x74, 0xac, 0x1f, 0x30, 0x3b, 0x17, 0xdb],
+    [0x2d, 0xb3, 0x66, 0xbf, 0xdd, 0x36, 0xd2, 0x77, 0xa6, 0x92, 0xbb, 0x82, 0x5b, 0x86, 0x27, 0x5b, 0xea, 0xc4, 0x04, 0xa1, 0x9a, 0xe0, 0x7a, 0x90, 0x82, 0xea, 0x46, 0xbd, 0x83, 0x51, 0x79, 0x26],
+    [0x06, 0x21, 0x00, 0xeb, 0x48, 0x5d, 0xb0, 0x62, 0x69, 0x65, 0x5c, 0xf1, 0x86, 0xa6, 0x85, 0x32, 0x98, 0x52, 0x75, 0x42, 0x84, 0x50, 0x35, 0x9a, 0xdc, 0x99, 0xce, 0xc6, 0x96, 0x07, 0x11, 0xb8],
+    [0x07, 0x61, 0xd3, 0x3c, 0x66, 0x61, 0x4a, 0xaa, 0x57, 0x0e, 0x7f, 0x1e, 0x82, 0x44, 0xca, 0x11, 0x20, 0x24, 0x3f, 0x92, 0xfa, 0x59, 0xe4, 0xf9, 0x00, 0xc5, 0x67, 0xbf, 0x41, 0xf5, 0xa5, 0x9b],
+    [0x20, 0xfc, 0x41, 0x1a, 0x11, 0x4d, 0x13, 0x99, 0x2c, 0x27, 0x05, 0xaa, 0x03, 0x4e, 0x3f, 0x31, 0x5d, 0x78, 0x60, 0x8a, 0x0f, 0x7d, 0xe4, 0xcc, 0xf7, 0xa7, 0x2e, 0x49, 0x48, 0x55, 0xad, 0x0d],
+    [0x25, 0xb5, 0xc0, 0x04, 0xa4, 0xbd, 0xfc, 0xb5, 0xad, 0xd9, 0xec, 0x4e, 0x9a, 0xb2, 0x19, 0xba, 0x10, 0x2c, 0x67, 0xe8, 0xb3, 0xef, 0xfb, 0x5f, 0xc3, 0xa3, 0x0f, 0x31, 0x72, 0x50, 0xbc, 0x5a],
+    [0x23, 0xb1, 0x82, 0x2d, 0x27, 0x8e, 0xd6, 0x32, 0xa4, 0x94, 0xe5, 0x8f, 0x6d, 0xf6, 0xf5, 0xed, 0x03, 0x8b, 0x18, 0x6d, 0x84, 0x74, 0x15, 0x5a, 0xd8, 0x7e, 0x7d, 0xff, 0x62, 0xb3, 0x7f, 0x4b],
+    [0x22, 0x73, 0x4b, 0x4c, 0x5c, 0x3f, 0x94, 0x93, 0x60, 0x6c, 0x4b, 0xa9, 0x01, 0x24, 0x99, 0xbf, 0x0f, 0x14, 0xd1, 0x3b, 0xfc, 0xfc, 0xcc, 0xaa, 0x16, 0x10, 0x2a, 0x29, 0xcc, 0x2f, 0x69, 0xe0],
+    [0x26, 0xc0, 0xc8, 0xfe, 0x09, 0xeb, 0x30, 0xb7, 0xe2, 0x7a, 0x74, 0xdc, 0x33, 0x49, 0x23, 0x47, 0xe5, 0xbd, 0xff, 0x40, 0x9a, 0xa3, 0x61, 0x02, 0x54, 0x41, 0x3d, 0x3f, 0xad, 0x79, 0x5c, 0xe5],
+    [0x07, 0x0d, 0xd0, 0xcc, 0xb6, 0xbd, 0x7b, 0xba, 0xe8, 0x8e, 0xac, 0x03, 0xfa, 0x1f, 0xbb, 0x26, 0x19, 0x6b, 0xe3, 0x08, 0x3a, 0x80, 0x98, 0x29, 0xbb, 0xd6, 0x26, 0xdf, 0x34, 0x8c, 0xca, 0xd9],
+    [0x12, 0xb6, 0x59, 0x5b, 0xdb, 0x32, 0x9b, 0x6f, 0xb0, 0x43, 0xba, 0x78, 0xbb, 0x28, 0xc3, 0xbe, 0xc2, 0xc0, 0xa6, 0xde, 0x46, 0xd8, 0xc5, 0xad, 0x60, 0x67, 0xc4, 0xeb, 0xfd, 0x42, 0x50, 0xda],
+    [0x24, 0x8d, 0x97, 0xd7, 0xf7, 0x62, 0x83, 0xd6, 0x3b, 0xec, 0x30, 0xe7, 0xa5, 0x87, 0x6c, 0x11, 0xc0, 0x6f, 0xca, 0x9b, 0x27, 0x5c, 0x67, 0x1c, 0x5e, 0x33, 0xd9, 0x5b, 0xb7, 0xe8, 0xd7, 0x29],
+    [0x1a, 0x30, 0x6d, 0x43, 0x9d, 0x46, 0x3b, 0x08, 0x16, 0xfc, 0x6f, 0xd6, 0x4c, 0xc9, 0x39, 0x31, 0x8b, 0x45, 0xeb, 0x75, 0x9d, 0xdd, 0xe4, 0xaa, 0x10, 0x6d, 0x15, 0xd9, 0xbd, 0x9b, 0xaa, 0xaa],
+    [0x28, 0xa8, 0xf8, 0x37, 0x2e, 0x3c, 0x38, 0xda, 0xce, 0xd7, 0xc0, 0x04, 0x21, 0xcb, 0x46, 0x21, 0xf4, 0xf1, 0xb5, 0x4d, 0xdc, 0x27, 0x82, 0x1b, 0x0d, 0x62, 0xd3, 0xd6, 0xec, 0x7c, 0x56, 0xcf],
+    [0x00, 0x94, 0x97, 0x57, 0x17, 0xf9, 0xa8, 0xa8, 0xbb, 0x35, 0x15, 0x2f, 0x24, 0xd4, 0x32, 0x94, 0x07, 0x1c, 0xe3, 0x20, 0xc8, 0x29, 0xf3, 0x88, 0xbc, 0x85, 0x21, 0x83, 0xe1, 0xe2, 0xce, 0x7e],
+    [0x04, 0xd5, 0xee, 0x4c, 0x3a, 0xa7, 0x8f, 0x7d, 0x80, 0xfd, 0xe6, 0x0d, 0x71, 0x64, 0x80, 0xd3, 0x59, 0x3f, 0x74, 0xd4, 0xf6, 0x53, 0xae, 0x83, 0xf4, 0x10, 0x32, 0x46, 0xdb, 0x2e, 0x8d, 0x65],
+    [0x2a, 0x6c, 0xf5, 0xe9, 0xaa, 0x03, 0xd4, 0x33, 0x63, 0x49, 0xad, 0x6f, 0xb8, 0xed, 0x22, 0x69, 0xc7, 0xbe, 0xf5, 0x4b, 0x88, 0x22, 0xcc, 0x76, 0xd0, 0x84, 0x95, 0xc1, 0x2e, 0xfd, 0xe1, 0x87],
+    [0x23, 0x04, 0xd3, 0x1e, 0xaa, 0xb9, 0x60, 0xba, 0x92, 0x74, 0xda, 0x43, 0xe1, 0x9d, 0xde, 0xb7, 0xf7, 0x92, 0x18, 0x08, 0x08, 0xfd, 0x6e, 0x43, 0xba, 0xae, 0x48, 0xd7, 0xef, 0xcb, 0xa3, 0xf3],
+    [0x03, 0xfd, 0x9a, 0xc8, 0x65, 0xa4, 0xb2, 0xa6, 0xd5, 0xe7, 0x00, 0x97, 0x85, 0x81, 0x72, 0x49, 0xbf, 0xf0, 0x8a, 0x7e, 0x07, 0x26, 0xfc, 0xb4, 0xe1, 0xc1, 0x1d, 0x39, 0xd1, 0x99, 0xf0, 0xb0],
+    [0x00, 0xb7, 0x25, 0x8d, 0xed, 0x52, 0xbb, 0xda, 0x22, 0x48, 0x40, 0x4d, 0x55, 0xee, 0x50, 0x44, 0x79, 0x8a, 0xfc, 0x3a, 0x20, 0x91, 0x93, 0x07, 0x3f, 0x79, 0x54, 0xd4, 0xd6, 0x3b, 0x0b, 0x64],
+    [0x15, 0x9f, 0x81, 0xad, 0xa0, 0x77, 0x17, 0x99, 0xec, 0x38, 0xfc, 0xa2, 0xd4, 0xbf, 0x65, 0xeb, 0xb1, 0x3d, 0x3a, 0x74, 0xf3, 0x29, 0x8d, 0xb3, 0x62, 0x72, 0xc5, 0xca, 0x65, 0xe9, 0x2d, 0x9a],
+    [0x1e, 0xf9, 0x0e, 0x67, 0x43, 0x7f, 0xbc, 0x85, 0x50, 0x23, 0x7a, 0x75, 0xbc, 0x28, 0xe3, 0xbb, 0x90, 0x00, 0x13, 0x0e, 0xa2, 0x5f, 0x0c, 0x54, 0x71, 0xe1, 0x44, 0xcf, 0x42, 0x64, 0x43, 0x1f],
+    [0x1e, 0x65, 0xf8, 0x38, 0x51, 0x5e, 0x5f, 0xf0, 0x19, 0x6b, 0x49, 0xaa, 0x41, 0xa2, 0xd2, 0x56, 0x8d, 0xf7, 0x39, 0xbc, 0x17, 0x6b, 0x08, 0xec, 0x95, 0xa7, 0x9e, 0xd8, 0x29, 0x32, 0xe3, 0x0d],
+    [0x2b, 0x1b, 0x04, 0x5d, 0xef, 0x3a, 0x16, 0x6c, 0xec, 0x6c, 0xe7, 0x68, 0xd0, 0x79, 0xba, 0x74, 0xb1, 0x8c, 0x84, 0x4e, 0x57, 0x0e, 0x1f, 0x82, 0x65, 0x75, 0xc1, 0x06, 0x8c, 0x94, 0xc3, 0x3f],
+    [0x08, 0x32, 0xe5, 0x75, 0x3c, 0xeb, 0x0f, 0xf6, 0x40, 0x25, 0x43, 0xb1, 0x10, 0x92, 0x29, 0xc1, 0x65, 0xdc, 0x2d, 0x73, 0xbe, 0xf7, 0x15, 0xe3, 0xf1, 0xc6, 0xe0, 0x7c, 0x16, 0x8b, 0xb1, 0x73],
+    [0x02, 0xf6, 0x14, 0xe9, 0xce, 0xdf, 0xb3, 0xdc, 0x6b, 0x76, 0x2a, 0xe0, 0xa3, 0x7d, 0x41, 0xba, 0xb1, 0xb8, 0x41, 0xc2, 0xe8, 0xb6, 0x45, 0x1b, 0xc5, 0xa8, 0xe3, 0xc3, 0x90, 0xb6, 0xad, 0x16],
+    [0x0e, 0x24, 0x27, 0xd3, 0x8b, 0xd4, 0x6a, 0x60, 0xdd, 0x64, 0x0b, 0x8e, 0x36, 0x2c, 0xad, 0x96, 0x73, 0x70, 0xeb, 0xb7, 0x77, 0xbe, 0xdf, 0xf4, 0x0f, 0x6a, 0x0b, 0xe2, 0x7e, 0x7e, 0xd7, 0x05],
+    [0x04, 0x93, 0x63, 0x0b, 0x7c, 0x67, 0x0b, 0x6d, 0xeb, 0x7c, 0x84, 0xd4, 0x14, 0xe7, 0xce, 0x79, 0x04, 0x9f, 0x0e, 0xc0, 0x98, 0xc3, 0xc7, 0xc5, 0x07, 0x68, 0xbb, 0xe2, 0x92, 0x14, 0xa5, 0x3a],
+    [0x22, 0xea, 0xd1, 0x00, 0xe8, 0xe4, 0x82, 0x67, 0x4d, 0xec, 0xda, 0xb1, 0x70, 0x66, 0xc5, 0xa2, 0x6b, 0xb1, 0x51, 0x53, 0x55, 0xd5, 0x46, 0x1a, 0x3d, 0xc0, 0x6c, 0xc8, 0x53, 0x27, 0xce, 0xa9],
+    [0x25, 0xb3, 0xe5, 0x6e, 0x65, 0x5b, 0x42, 0xcd, 0xaa, 0xe2, 0x62, 0x6e, 0xd2, 0x55, 0x4d, 0x48, 0x58, 0x3f, 0x1a, 0xe3, 0x56, 0x26, 0xd0, 0x4d, 0xe5, 0x08, 0x4e, 0x0b, 0x6d, 0x2a, 0x6f, 0x16],
+    [0x1e, 0x32, 0x75, 0x2a, 0xda, 0x88, 0x36, 0xef, 0x58, 0x37, 0xa6, 0xcd, 0xe8, 0xff, 0x13, 0xdb, 0xb5, 0x99, 0xc3, 0x36, 0x34, 0x9e, 0x4c, 0x58, 0x4b, 0x4f, 0xdc, 0x0a, 0x0c, 0xf6, 0xf9, 0xd0],
+    [0x2f, 0xa2, 0xa8, 0x71, 0xc1, 0x5a, 0x38, 0x7c, 0xc5, 0x0f, 0x68, 0xf6, 0xf3, 0xc3, 0x45, 0x5b, 0x23, 0xc0, 0x09, 0x95, 0xf0, 0x50, 0x78, 0xf6, 0x72, 0xa9, 0x86, 0x40, 0x74, 0xd4, 0x12, 0xe5],
+    [0x2f, 0x56, 0x9b, 0x8a, 0x9a, 0x44, 0x24, 0xc9, 0x27, 0x8e, 0x1d, 0xb7, 0x31, 0x1e, 0x88, 0x9f, 0x54, 0xcc, 0xbf, 0x10, 0x66, 0x1b, 0xab, 0x7f, 0xcd, 0x18, 0xe7, 0xc7, 0xa7, 0xd8, 0x35, 0x05],
+    [0x04, 0x4c, 0xb4, 0x55, 0x11, 0x0a, 0x8f, 0xdd, 0x53, 0x1a, 0xde, 0x53, 0x02, 0x34, 0xc5, 0x18, 0xa7, 0xdf, 0x93, 0xf7, 0x33, 0x2f, 0xfd, 0x21, 0x44, 0x16, 0x53, 0x74, 0xb2, 0x46, 0xb4, 0x3d],
+    [0x22, 0x78, 0x08, 0xde, 0x93, 0x90, 0x6d, 0x5d, 0x42, 0x02, 0x46, 0x15, 0x7f, 0x2e, 0x42, 0xb1, 0x91, 0xfe, 0x8c, 0x90, 0xad, 0xfe, 0x11, 0x81, 0x78, 0xdd, 0xc7, 0x23, 0xa5, 0x31, 0x90, 0x25],
+    [0x02, 0xfc, 0xca, 0x29, 0x34, 0xe0, 0x46, 0xbc, 0x62, 0x3a, 0xde, 0xad, 0x87, 0x35, 0x79, 0x86, 0x5d, 0x03, 0x78, 0x1a, 0xe0, 0x90, 0xad, 0x4a, 0x85, 0x79, 0xd2, 0xe7, 0xa6, 0x80, 0x03, 0x55],
+    [0x0e, 0xf9, 0x15, 0xf0, 0xac, 0x12, 0x0b, 0x87, 0x6a, 0xbc, 0xcc, 0xeb, 0x34, 0x4a, 0x1d, 0x36, 0xba, 0xd3, 0xf3, 0xc5, 0xab, 0x91, 0xa8, 0xdd, 0xcb, 0xec, 0x2e, 0x06, 0x0d, 0x8b, 0xef, 0xac],
+    [0x17, 0x97, 0x13, 0x0f, 0x4b, 0x7a, 0x3e, 0x17, 0x77, 0xeb, 0x75, 0x7b, 0xc6, 0xf2, 0x87, 0xf6, 0xab, 0x0f, 0xb8, 0x5f, 0x6b, 0xe6, 0x3b, 0x09, 0xf3, 0xb1, 0x6e, 0xf2, 0xb1, 0x40, 0x5d, 0x38],
+    [0x0a, 0x76, 0x22, 0x5d, 0xc0, 0x41, 0x70, 0xae, 0x33, 0x06, 0xc8, 0x5a, 0xba, 0xb5, 0x9e, 0x60, 0x8c, 0x7f, 0x49, 0x7c, 0x20, 0x15, 0x6d, 0x4d, 0x36, 0xc6, 0x68, 0x55, 0x5d, 0xec, 0xc6, 0xe5],
+    [0x1f, 0xff, 0xb9, 0xec, 0x19, 0x92, 0xd6, 0x6b, 0xa1, 0xe7, 0x7a, 0x7b, 0x93, 0x20, 0x9a, 0xf6, 0xf8, 0xfa, 0x76, 0xd4, 0x8a, 0xcb, 0x66, 0x47, 0x96, 0x17, 0x4b, 0x53, 0x26, 0xa3, 0x1a, 0x5c],
+    [0x25, 0x72, 0x1c, 0x4f, 0xc1, 0x5a, 0x3f, 0x28, 0x53, 0xb5, 0x7c, 0x33, 0x8f, 0xa5, 0x38, 0xd8, 0x5f, 0x8f, 0xbb, 0xa6, 0xc6, 0xb9, 0xc6, 0x09, 0x06, 0x11, 0x88, 0x9b, 0x79, 0x7b, 0x9c, 0x5f],
+    [0x0c, 0x81, 0x7f, 0xd4, 0x2d, 0x5f, 0x7a, 0x41, 0x21, 0x5e, 0x3d, 0x07, 0xba, 0x19, 0x72, 0x16, 0xad, 0xb4, 0xc3, 0x79, 0x07, 0x05, 0xda, 0x95, 0xeb, 0x63, 0xb9, 0x82, 0xbf, 0xca, 0xf7, 0x5a],
+    [0x13, 0xab, 0xe3, 0xf5, 0x23, 0x99, 0x15, 0xd3, 0x9f, 0x7e, 0x13, 0xc2, 0xc2, 0x49, 0x70, 0xb6, 0xdf, 0x8c, 0xf8, 0x6c, 0xe0, 0x0a, 0x22, 0x00, 0x2b, 0xc1, 0x58, 0x66, 0xe5, 0x2b, 0x5a, 0x96],
+    [0x21, 0x06, 0xfe, 0xea, 0x54, 0x62, 0x24, 0xea, 0x12, 0xef, 0x7f, 0x39, 0x98, 0x7a, 0x46, 0xc8, 0x5c, 0x1b, 0xc3, 0xdc, 0x29, 0xbd, 0xbd, 0x7a, 0x92, 0xcd, 0x60, 0xac, 0xb4, 0xd3, 0x91, 0xce],
+    [0x21, 0xca, 0x85, 0x94, 0x68, 0xa7, 0x46, 0xb6, 0xaa, 0xa7, 0x94, 0x74, 0xa3, 0x7d, 0xab, 0x49, 0xf1, 0xca, 0x5a, 0x28, 0xc7, 0x48, 0xbc, 0x71, 0x57, 0xe1, 0xb3, 0x34, 0x5b, 0xb0, 0xf9, 0x59],
+    [0x05, 0xcc, 0xd6, 0x25, 0x5c, 0x1e, 0x6f, 0x0c, 0x5c, 0xf1, 0xf0, 0xdf, 0x93, 0x41, 0x94, 0xc6, 0x29, 0x11, 0xd1, 0x4d, 0x03, 0x21, 0x66, 0x2a, 0x8f, 0x1a, 0x48, 0x99, 0x9e, 0x34, 0x18, 0x5b],
+    [0x0f, 0x0e, 0x34, 0xa6, 0x4b, 0x70, 0xa6, 0x26, 0xe4, 0x64, 0xd8, 0x46, 0x67, 0x4c, 0x4c, 0x88, 0x16, 0xc4, 0xfb, 0x26, 0x7f, 0xe4, 0x4f, 0xe6, 0xea, 0x28, 0x67, 0x8c, 0xb0, 0x94, 0x90, 0xa4],
+    [0x05, 0x58, 0x53, 0x1a, 0x4e, 0x25, 0x47, 0x0c, 0x61, 0x57, 0x79, 0x4c, 0xa3, 0x6d, 0x0e, 0x96, 0x47, 0xdb, 0xfc, 0xfe, 0x35, 0x0d, 0x64, 0x83, 0x8f, 0x5b, 0x1a, 0x8a, 0x2d, 0xe0, 0xd4, 0xbf],
+    [0x09, 0xd3, 0xdc, 0xa9, 0x17, 0x3e, 0xd2, 0xfa, 0xce, 0xea, 0x12, 0x51, 0x57, 0x68, 0x3d, 0x18, 0x92, 0x4c, 0xad, 0xad, 0x3f, 0x65, 0x5a, 0x60, 0xb7, 0x2f, 0x58, 0x64, 0x96, 0x1f, 0x14, 0x55],
+    [0x03, 0x28, 0xcb, 0xd5, 0x4e, 0x8c, 0x09, 0x13, 0x49, 0x3f, 0x86, 0x6e, 0xd0, 0x3d, 0x21, 0x8b, 0xf2, 0x3f, 0x92, 0xd6, 0x8a, 0xae, 0xc4, 0x86, 0x17, 0xd4, 0xc7, 0x22, 0xe5, 0xbd, 0x43, 0x35],
+    [0x2b, 0xf0, 0x72, 0x16, 0xe2, 0xaf, 0xf0, 0xa2, 0x23, 0xa4, 0x87, 0xb1, 0xa7, 0x09, 0x4e, 0x07, 0xe7, 0x9e, 0x7b, 0xcc, 0x97, 0x98, 0xc6, 0x48, 0xee, 0x33, 0x47, 0xdd, 0x53, 0x29, 0xd3, 0x4b],
+    [0x1d, 0xaf, 0x34, 0x5a, 0x58, 0x00, 0x6b, 0x73, 0x64, 0x99, 0xc5, 0x83, 0xcb, 0x76, 0xc3, 0x16, 0xd6, 0xf7, 0x8e, 0xd6, 0xa6, 0xdf, 0xfc, 0x82, 0x11, 0x1e, 0x11, 0xa6, 0x3f, 0xe4, 0x12, 0xdf],
+    [0x17, 0x65, 0x63, 0x47, 0x24, 0x56, 0xaa, 0xa7, 0x46, 0xb6, 0x94, 0xc6, 0x0e, 0x18, 0x23, 0x61, 0x1e, 0xf3, 0x90, 0x39, 0xb2, 0xed, 0xc7, 0xff, 0x39, 0x1e, 0x6f, 0x22, 0x93, 0xd2, 0xc4, 0x04],
+    [0x2e, 0xf1, 0xe0, 0xfa, 0xd9, 0xf0, 0x8e, 0x87, 0xa3, 0xbb, 0x5e, 0x47, 0xd7, 0xe3, 0x35, 0x38, 0xca, 0x96, 0x4d, 0x2b, 0x7d, 0x10, 0x83, 0xd4, 0xfb, 0x02, 0x25, 0x03, 0x5b, 0xd3, 0xf8, 0xdb],
+    [0x22, 0x6c, 0x9b, 0x1a, 0xf9, 0x5b, 0xab, 0xcf, 0x17, 0xb2, 0xb1, 0xf5, 0x7c, 0x73, 0x10, 0x17, 0x9c, 0x18, 0x03, 0xde, 0xc5, 0xae, 0x8f, 0x0a, 0x17, 0x79, 0xed, 0x36, 0xc8, 0x17, 0xae, 0x2a],
+    [0x14, 0xbc, 0xe3, 0x54, 0x9c, 0xc3, 0xdb, 0x74, 0x28, 0x12, 0x6b, 0x4c, 0x3a, 0x15, 0xae, 0x0f, 0xf8, 0x14, 0x8c, 0x89, 0xf1, 0x3f, 0xb3, 0x5d, 0x35, 0x73, 0x4e, 0xb5, 0xd4, 0xad, 0x0d, 0xef],
+    [0x2d, 0xeb, 0xff, 0x15, 0x6e, 0x27, 0x6b, 0xb5, 0x74, 0x2c, 0x33, 0x73, 0xf2, 0x63, 0x5b, 0x48, 0xb8, 0xe9, 0x23, 0xd3, 0x01, 0xf3, 0x72, 0xf8, 0xe5, 0x50, 0xcf, 0xd4, 0x03, 0x42, 0x12, 0xc7],
+    [0x2d, 0x40, 0x83, 0xcf, 0x5a, 0x87, 0xf5, 0xb6, 0xfc, 0x23, 0x95, 0xb2, 0x2e, 0x35, 0x6b, 0x64, 0x41, 0xaf, 0xe1, 0xb6, 0xb2, 0x9c, 0x47, 0xad, 0xd7, 0xd0, 0x43, 0x2d, 0x1d, 0x47, 0x60, 0xc7],
+    [0x0c, 0x22, 0x5b, 0x7b, 0xcd, 0x04, 0xbf, 0x9c, 0x34, 0xb9, 0x11, 0x26, 0x2f, 0xdc, 0x9c, 0x1b, 0x91, 0xbf, 0x79, 0xa1, 0x0c, 0x01, 0x84, 0xd8, 0x9c, 0x31, 0x7c, 0x53, 0xd7, 0x16, 0x1c, 0x29],
+    [0x03, 0x15, 0x21, 0x69, 0xd4, 0xf3, 0xd0, 0x6e, 0xc3, 0x3a, 0x79, 0xbf, 0xac, 0x91, 0xa0, 0x2c, 0x99, 0xaa, 0x02, 0x00, 0xdb, 0x66, 0xd5, 0xaa, 0x7b, 0x83, 0x52, 0x65, 0xf9, 0xc9, 0xc8, 0xf3],
+    [0x0b, 0x61, 0x81, 0x1a, 0x92, 0x10, 0xbe, 0x78, 0xb0, 0x59, 0x74, 0x58, 0x74, 0x86, 0xd5, 0x8b, 0xdd, 0xc8, 0xf5, 0x1b, 0xfd, 0xfe, 0xbb, 0xb8, 0x7a, 0xfe, 0x8b, 0x7a, 0xa7, 0xd3, 0x19, 0x9c],
+    [0x20, 0x3e, 0x00, 0x0c, 0xad, 0x29, 0x8d, 0xaa, 0xf7, 0xeb, 0xa6, 0xa5, 0xc5, 0x92, 0x18, 0x78, 0xb8, 0xae, 0x48, 0xac, 0xf7, 0x04, 0x8f, 0x16, 0x04, 0x6d, 0x63, 0x7a, 0x53, 0x3b, 0x6f, 0x78],
+    [0x1a, 0x44, 0xbf, 0x09, 0x37, 0xc7, 0x22, 0xd1, 0x37, 0x66, 0x72, 0xb6, 0x9f, 0x6c, 0x96, 0x55, 0xba, 0x7e, 0xe3, 0x86, 0xfd, 0xa1, 0x11, 0x2c, 0x07, 0x57, 0x14, 0x3d, 0x1b, 0xfa, 0x91, 0x46],
+    [0x03, 0x76, 0xb4, 0xfa, 0xe0, 0x8c, 0xb0, 0x3d, 0x35, 0x00, 0xaf, 0xec, 0x1a, 0x1f, 0x56, 0xac, 0xb8, 0xe0, 0xfd, 0xe7, 0x5a, 0x21, 0x06, 0xd7, 0x00, 0x2f, 0x59, 0xc5, 0x61, 0x1d, 0x4d, 0xaa],
+    [0x00, 0x78, 0x0a, 0xf2, 0xca, 0x1c, 0xad, 0x64, 0x65, 0xa2, 0x17, 0x12, 0x50, 0xfd, 0xfc, 0x32, 0xd6, 0xfc, 0x24, 0x1d, 0x32, 0x14, 0x17, 0x7f, 0x3d, 0x55, 0x3e, 0xf3, 0x63, 0x18, 0x21, 0x85],
+    [0x10, 0x77, 0x4d, 0x9a, 0xb8, 0x0c, 0x25, 0xbd, 0xeb, 0x80, 0x8b, 0xed, 0xfd, 0x72, 0xa8, 0xd9, 0xb7, 0x5d, 0xbe, 0x18, 0xd5, 0x22, 0x1c, 0x87, 0xe9, 0xd8, 0x57, 0x07, 0x9b, 0xdc, 0x31, 0xd5],
+    [0x10, 0xdc, 0x6e, 0x9c, 0x00, 0x6e, 0xa3, 0x8b, 0x04, 0xb1, 0xe0, 0x3b, 0x4b, 0xd9, 0x49, 0x0c, 0x0d, 0x03, 0xf9, 0x89, 0x29, 0xca, 0x1d, 0x7f, 0xb5, 0x68, 0x21, 0xfd, 0x19, 0xd3, 0xb6, 0xe8],
+    [0x00, 0x54, 0x4b, 0x83, 0x38, 0x79, 0x15, 0x18, 0xb2, 0xc7, 0x64, 0x5a, 0x50, 0x39, 0x27, 0x98, 0xb2, 0x1f, 0x75, 0xbb, 0x60, 0xe3, 0x59, 0x61, 0x70, 0x06, 0x7d, 0x00, 0x14, 0x1c, 0xac, 0x16],
+    [0x22, 0x2c, 0x01, 0x17, 0x57, 0x18, 0x38, 0x6f, 0x2e, 0x2e, 0x82, 0xeb, 0x12, 0x27, 0x89, 0xe3, 0x52, 0xe1, 0x05, 0xa3, 0xb8, 0xfa, 0x85, 0x26, 0x13, 0xbc, 0x53, 0x44, 0x33, 0xee, 0x42, 0x8c],
+    [0x28, 0x40, 0xd0, 0x45, 0xe9, 0xbc, 0x22, 0xb2, 0x59, 0xcf, 0xb8, 0x81, 0x1b, 0x1e, 0x0f, 0x45, 0xb7, 0x7f, 0x7b, 0xdb, 0x7f, 0x7e, 0x2b, 0x46, 0x15, 0x1a, 0x14, 0x30, 0xf6, 0x08, 0xe3, 0xc5],
+    [0x06, 0x27, 0x52, 0xf8, 0x6e, 0xeb, 0xe1, 0x1a, 0x00, 0x9c, 0x93, 0x7e, 0x46, 0x8c, 0x33, 0x5b, 0x04, 0x55, 0x45, 0x74, 0xc2, 0x99, 0x01, 0x96, 0x50, 0x8e, 0x01, 0xfa, 0x58, 0x60, 0x18, 0x6b],
+    [0x06, 0x04, 0x1b, 0xda, 0xc4, 0x82, 0x05, 0xac, 0x87, 0xad, 0xb8, 0x7c, 0x20, 0xa4, 0x78, 0xa7, 0x1c, 0x99, 0x50, 0xc1, 0x2a, 0x80, 0xbc, 0x0a, 0x55, 0xa8, 0xe8, 0x3e, 0xaa, 0xf0, 0x47, 0x46],
+    [0x04, 0xa5, 0x33, 0xf2, 0x36, 0xc4, 0x22, 0xd1, 0xff, 0x90, 0x0a, 0x36, 0x89, 0x49, 0xb0, 0x02, 0x2c, 0x7a, 0x2a, 0xe0, 0x92, 0xf3, 0x08, 0xd8, 0x2b, 0x1d, 0xcb, 0xbf, 0x51, 0xf5, 0x00, 0x0d],
+    [0x13, 0xe3, 0x1d, 0x7a, 0x67, 0x23, 0x2f, 0xd8, 0x11, 0xd6, 0xa9, 0x55, 0xb3, 0xd4, 0xf2, 0x5d, 0xfe, 0x06, 0x6d, 0x1e, 0x7d, 0xc3, 0x3d, 0xf0, 0x4b, 0xde, 0x50, 0xa2, 0xb2, 0xd0, 0x5b, 0x2a],
+    [0x01, 0x1c, 0x26, 0x83, 0xae, 0x91, 0xeb, 0x4d, 0xfb, 0xc1, 0x3d, 0x63, 0x57, 0xe8, 0x59, 0x9a, 0x92, 0x79, 0xd1, 0x64, 0x8f, 0xf2, 0xc9, 0x5d, 0x2f, 0x79, 0x90, 0x5b, 0xb1, 0x39, 0x20, 0xf1],
+    [0x0b, 0x0d, 0x21, 0x93, 0x46, 0xb8, 0x57, 0x45, 0x25, 0xb1, 0xa2, 0x70, 0xe0, 0xb4, 0xcb, 0xa5, 0xd5, 0x6c, 0x92, 0x8e, 0x3e, 0x2c, 0x2b, 0xd0, 0xa1, 0xec, 0xae, 0xd0, 0x15, 0xaa, 0xf6, 0xae],
+    [0x14, 0xab, 0xde, 0xc8, 0xdb, 0x9c, 0x6d, 0xc9, 0x70, 0x29, 0x1e, 0xe6, 0x38, 0x69, 0x02, 0x09, 0xb6, 0x50, 0x80, 0x78, 0x1e, 0xf9, 0xfd, 0x13, 0xd8, 0x4c, 0x7a, 0x72, 0x6b, 0x5f, 0x13, 0x64],
+    [0x1a, 0x0b, 0x70, 0xb4, 0xb2, 0x6f, 0xdc, 0x28, 0xfc, 0xd3, 0x2a, 0xa3, 0xd2, 0x66, 0x47, 0x88, 0x01, 0xeb, 0x12, 0x20, 0x2e, 0xf4, 0x7c, 0xed, 0x98, 0x8d, 0x03, 0x76, 0x61, 0x0b, 0xe1, 0x06],
+    [0x27, 0x85, 0x43, 0x72, 0x1f, 0x96, 0xd1, 0x30, 0x7b, 0x69, 0x43, 0xf9, 0x80, 0x4e, 0x7f, 0xe5, 0x64, 0x01, 0xde, 0xb2, 0xef, 0x99, 0xc4, 0xd1, 0x27, 0x04, 0x88, 0x2e, 0x72, 0x78, 0xb6, 0x07],
+    [0x16, 0xeb, 0x59, 0x49, 0x4a, 0x97, 0x76, 0xcf, 0x57, 0x86, 0x62, 0x14, 0xdb, 0xd1, 0x47, 0x3f, 0x3f, 0x07, 0x38, 0xa3, 0x25, 0x63, 0x8d, 0x8b, 0xa3, 0x65, 0x35, 0xe0, 0x11, 0xd5, 0x82, 0x59],
+    [0x25, 0x67, 0xa6, 0x58, 0xa8, 0x1f, 0xfb, 0x44, 0x4f, 0x24, 0x00, 0x88, 0xfa, 0x55, 0x24, 0xc6, 0x9a, 0x9e, 0x53, 0xee, 0xab, 0x6b, 0x7f, 0x8c, 0x41, 0xc3, 0x47, 0x9d, 0xcf, 0x8c, 0x64, 0x4a],
+    [0x29, 0xaa, 0x1d, 0x7c, 0x15, 0x1e, 0x9a, 0xd0, 0xa7, 0xab, 0x39, 0xf1, 0xab, 0xd9, 0xcf, 0x77, 0xab, 0x78, 0xe0, 0x21, 0x5a, 0x57, 0x15, 0xa6, 0xb8, 0x82, 0xad, 0xe8, 0x40, 0xbb, 0x13, 0xd8],
+    [0x15, 0xc0, 0x91, 0x23, 0x3e, 0x60, 0xef, 0xe0, 0xd4, 0xbb, 0xfc, 0xe2, 0xb3, 0x64, 0x15, 0x00, 0x6a, 0x4f, 0x01, 0x7f, 0x9a, 0x85, 0x38, 0x8c, 0xe2, 0x06, 0xb9, 0x1f, 0x99, 0xf2, 0xc9, 0x84],
+    [0x16, 0xbd, 0x7d, 0x22, 0xff, 0x85, 0x8e, 0x5e, 0x08, 0x82, 0xc2, 0xc9, 0x99, 0x55, 0x8d, 0x77, 0xe7, 0x67, 0x3a, 0xd5, 0xf1, 0x91, 0x5f, 0x9f, 0xeb, 0x67, 0x9a, 0x81, 0x15, 0xf0, 0x14, 0xcf],
+    [0x02, 0xdb, 0x50, 0x48, 0x0a, 0x07, 0xbe, 0x0e, 0xb2, 0xc2, 0xe1, 0x3e, 0xd6, 0xef, 0x40, 0x74, 0xc0, 0x18, 0x2d, 0x9b, 0x66, 0x8b, 0x8e, 0x08, 0xff, 0xe6, 0x76, 0x92, 0x50, 0x04, 0x20, 0x25],
+    [0x05, 0xe4, 0xa2, 0x20, 0xe6, 0xa3, 0xbc, 0x9f, 0x7b, 0x68, 0x06, 0xec, 0x9d, 0x6c, 0xdb, 0xa1, 0x86, 0x33, 0x0e, 0xf2, 0xbf, 0x7a, 0xdb, 0x4c, 0x13, 0xba, 0x86, 0x63, 0x43, 0xb7, 0x31, 0x19],
+    [0x1d, 0xda, 0x05, 0xeb, 0xc3, 0x01, 0x70, 0xbc, 0x98, 0xcb, 0xf2, 0xa5, 0xee, 0x3b, 0x50, 0xe8, 0xb5, 0xf7, 0x0b, 0xc4, 0x24, 0xd3, 0x9f, 0xa4, 0x10, 0x4d, 0x37, 0xf1, 0xcb, 0xcf, 0x7a, 0x42],
+    [0x01, 0x84, 0xbe, 0xf7, 0x21, 0x88, 0x81, 0x87, 0xf6, 0x45, 0xb6, 0xfe, 0xe3, 0x66, 0x7f, 0x3c, 0x91, 0xda, 0x21, 0x44, 0x14, 0xd8, 0x9b, 0xa5, 0xcd, 0x30, 0x1f, 0x22, 0xb0, 0xde, 0x89, 0x90],
+    [0x14, 0x98, 0xa3, 0x07, 0xe6, 0x89, 0x00, 0x06, 0x5f, 0x5e, 0x82, 0x76, 0xf6, 0x2a, 0xef, 0x1c, 0x37, 0x41, 0x4b, 0x84, 0x49, 0x4e, 0x15, 0x77, 0xad, 0x1a, 0x6d, 0x64, 0x34, 0x1b, 0x78, 0xec],
+    [0x25, 0xf4, 0x0f, 0x82, 0xb3, 0x1d, 0xac, 0xc4, 0xf4, 0x93, 0x98, 0x00, 0xb9, 0xd2, 0xc3, 0xea, 0xce, 0xf7, 0x37, 0xb8, 0xfa, 0xb1, 0xf8, 0x64, 0xfe, 0x33, 0x54, 0x8a, 0xd4, 0x6b, 0xd4, 0x9d],
+    [0x09, 0xd3, 0x17, 0xcc, 0x67, 0x02, 0x51, 0x94, 0x3f, 0x6f, 0x58, 0x62, 0xa3, 0x0d, 0x2e, 0xa9, 0xe8, 0x30, 0x56, 0xce, 0x49, 0x07, 0xbf, 0xbb, 0xcb, 0x1f, 0xf3, 0x1c, 0xe5, 0xbb, 0x96, 0x50],
+    [0x2f, 0x77, 0xd7, 0x77, 0x86, 0xd9, 0x79, 0xb2, 0x3b, 0xa4, 0xce, 0x4a, 0x4c, 0x1b, 0x3b, 0xd0, 0xa4, 0x11, 0x32, 0xcd, 0x46, 0x7a, 0x86, 0xab, 0x29, 0xb9, 0x13, 0xb6, 0xcf, 0x31, 0x49, 0xd0],
+    [0x0f, 0x53, 0xda, 0xfd, 0x53, 0x5a, 0x9f, 0x44, 0x73, 0xdc, 0x26, 0x6b, 0x6f, 0xcc, 0xc6, 0x84, 0x1b, 0xbd, 0x33, 0x69, 0x63, 0xf2, 0x54, 0xc1, 0x52, 0xf8, 0x9e, 0x78, 0x5f, 0x72, 0x9b, 0xbf],
+    [0x25, 0xc1, 0xfd, 0x72, 0xe2, 0x23, 0x04, 0x52, 0x65, 0xc3, 0xa0, 0x99, 0xe1, 0x75, 0x26, 0xfa, 0x0e, 0x69, 0x76, 0xe1, 0xc0, 0x0b, 0xaf, 0x16, 0xde, 0x96, 0xde, 0x85, 0xde, 0xef, 0x2f, 0xa2],
+    [0x2a, 0x90, 0x2c, 0x89, 0x80, 0xc1, 0x7f, 0xaa, 0xe3, 0x68, 0xd3, 0x85, 0xd5, 0x2d, 0x16, 0xbe, 0x41, 0xaf, 0x95, 0xc8, 0x4e, 0xae, 0xa3, 0xcf, 0x89, 0x3e, 0x65, 0xd6, 0xce, 0x4a, 0x8f, 0x62],
+    [0x1c, 0xe1, 0x58, 0x0a, 0x34, 0x52, 0xec, 0xf3, 0x02, 0x87, 0x8c, 0x89, 0x76, 0xb8, 0x2b, 0xe9, 0x66, 0x76, 0xdd, 0x11, 0x4d, 0x1d, 0xc8, 0xd2, 0x55, 0x27, 0x40, 0x57, 0x62, 0xf8, 0x35, 0x29],
+    [0x24, 0xa6, 0x07, 0x3f, 0x91, 0xad, 0xdc, 0x33, 0xa4, 0x9a, 0x1f, 0xa3, 0x06, 0xdf, 0x00, 0x88, 0x01, 0xc5, 0xec, 0x56, 0x96, 0x09, 0x03, 0x4d, 0x2f, 0xc5, 0x0f, 0x7f, 0x0f, 0x4d, 0x00, 0x56],
+    [0x25, 0xe5, 0x2d, 0xbd, 0x61, 0x24, 0x53, 0x0d, 0x9f, 0xc2, 0x7f, 0xe3, 0x06, 0xd7, 0x1d, 0x45, 0x83, 0xe0, 0x7c, 0xa5, 0x54, 0xb5, 0xd1, 0x57, 0x7f, 0x25, 0x6c, 0x68, 0xb0, 0xbe, 0x2b, 0x74],
+    [0x23, 0xdf, 0xfa, 0xe3, 0xc4, 0x23, 0xfa, 0x7a, 0x93, 0x46, 0x8d, 0xbc, 0xcf, 0xb0, 0x29, 0x85, 0x59, 0x74, 0xbe, 0x4d, 0x0a, 0x7b, 0x29, 0x94, 0x67, 0x96, 0xe5, 0xb6, 0xcd, 0x70, 0xf1, 0x5d],
+    [0x06, 0x34, 0x2d, 0xa3, 0x70, 0xcc, 0x0d, 0x8c, 0x49, 0xb7, 0x75, 0x94, 0xf6, 0xb0, 0x27, 0xc4, 0x80, 0x61, 0x5d, 0x50, 0xbe, 0x36, 0x24, 0x3a, 0x99, 0x59, 0x1b, 0xc9, 0x92, 0x4e, 0xd6, 0xf5],
+    [0x27, 0x54, 0x11, 0x42, 0x81, 0x28, 0x65, 0x46, 0xb7, 0x5f, 0x09, 0xf1, 0x15, 0xfc, 0x75, 0x1b, 0x47, 0x78, 0x30, 0x3d, 0x04, 0x05, 0xc1, 0xb4, 0xcc, 0x7d, 0xf0, 0xd8, 0xe9, 0xf6, 0x39, 0x25],
+    [0x15, 0xc1, 0x9e, 0x85, 0x34, 0xc5, 0xc1, 0xa8, 0x86, 0x2c, 0x2b, 0xc1, 0xd1, 0x19, 0xed, 0xde, 0xab, 0xf2, 0x14, 0x15, 0x38, 0x33, 0xd7, 0xbd, 0xb5, 0x9e, 0xe1, 0x97, 0xf8, 0x18, 0x7c, 0xf5],
+    [0x26, 0x5f, 0xe0, 0x62, 0x76, 0x6d, 0x08, 0xfa, 0xb4, 0xc7, 0x8d, 0x0d, 0x9e, 0xf3, 0xca, 0xbe, 0x36, 0x6f, 0x3b, 0xe0, 0xa8, 0x21, 0x06, 0x16, 0x79, 0xb4, 0xb3, 0xd2, 0xd7, 0x7d, 0x5f, 0x3e],
+    [0x13, 0xcc, 0xf6, 0x89, 0xd6, 0x7a, 0x3e, 0xc9, 0xf2, 0x2c, 0xb7, 0xcd, 0x0a, 0xc3, 0xa3, 0x27, 0xd3, 0x77, 0xac, 0x5c, 0xd0, 0x14, 0x6f, 0x04, 0x8d, 0xeb, 0xfd, 0x09, 0x8d, 0x3e, 0xc7, 0xbe],
+    [0x17, 0x66, 0x2f, 0x74, 0x56, 0x78, 0x97, 0x39, 0xf8, 0x1c, 0xd3, 0x97, 0x48, 0x27, 0xa8, 0x87, 0xd9, 0x2a, 0x5e, 0x05, 0xbd, 0xf3, 0xfe, 0x6b, 0x9f, 0xbc, 0xcc, 0xa4, 0x52, 0x4a, 0xae, 0xbd],
+    [0x21, 0xb2, 0x9c, 0x76, 0x32, 0x9b, 0x31, 0xc8, 0xef, 0x18, 0x63, 0x1e, 0x51, 0x5f, 0x7f, 0x2f, 0x82, 0xca, 0x6a, 0x5c, 0xca, 0x70, 0xce, 0xe4, 0xe8, 0x09, 0xfd, 0x62, 0x4b, 0xe7, 0xad, 0x5d],
+    [0x18, 0x13, 0x74, 0x78, 0x38, 0x2a, 0xad, 0xba, 0x44, 0x1e, 0xb9, 0x7f, 0xe2, 0x79, 0x01, 0x98, 0x9c, 0x06, 0x73, 0x81, 0x65, 0x21, 0x53, 0x19, 0x93, 0x9e, 0xb1, 0x7b, 0x01, 0xfa, 0x97, 0x5c],
+    [0x2b, 0xc0, 0x7e, 0xa2, 0xbf, 0xad, 0x68, 0xe8, 0xdc, 0x72, 0x4f, 0x5f, 0xef, 0x2b, 0x37, 0xc2, 0xd3, 0x4f, 0x76, 0x19, 0x35, 0xff, 0xd3, 0xb7, 0x39, 0xce, 0xec, 0x46, 0x68, 0xf3, 0x7e, 0x88],
+    [0x2d, 0xdb, 0x2e, 0x37, 0x6f, 0x54, 0xd6, 0x4a, 0x56, 0x38, 0x40, 0x48, 0x0d, 0xf9, 0x93, 0xfe, 0xb4, 0x17, 0x32, 0x03, 0xc2, 0xbd, 0x94, 0xad, 0x0e, 0x60, 0x20, 0x77, 0xae, 0xf9, 0xa0, 0x3e],
+    [0x27, 0x7e, 0xb5, 0x0f, 0x2b, 0xaa, 0x70, 0x61, 0x06, 0xb4, 0x1c, 0xb2, 0x4c, 0x60, 0x26, 0x09, 0xe8, 0xa2, 0x0f, 0x8d, 0x72, 0xf6, 0x13, 0x70, 0x8a, 0xdb, 0x25, 0x37, 0x35, 0x96, 0xc3, 0xf7],
+    [0x0d, 0x4d, 0xe4, 0x7e, 0x1a, 0xba, 0x34, 0x26, 0x9d, 0x0c, 0x62, 0x09, 0x04, 0xf0, 0x1a, 0x56, 0xb3, 0x3f, 0xc4, 0xb4, 0x50, 0xc0, 0xdb, 0x50, 0xbb, 0x7f, 0x87, 0x73, 0x4c, 0x9a, 0x1f, 0xe5],
+    [0x0b, 0x84, 0x42, 0xbf, 0xe9, 0xe4, 0xa1, 0xb4, 0x42, 0x86, 0x73, 0xb6, 0xbd, 0x3e, 0xea, 0x6f, 0x9f, 0x44, 0x56, 0x97, 0x05, 0x8f, 0x13, 0x4a, 0xae, 0x90, 0x8d, 0x02, 0x79, 0xa2, 0x9f, 0x0c],
+    [0x11, 0xfe, 0x5b, 0x18, 0xfb, 0xbe, 0xa1, 0xa8, 0x6e, 0x06, 0x93, 0x0c, 0xb8, 0x9f, 0x7d, 0x4a, 0x26, 0xe1, 0x86, 0xa6, 0x59, 0x45, 0xe9, 0x65, 0x74, 0x24, 0x7f, 0xdd, 0xb7, 0x20, 0xf8, 0xf5],
+    [0x22, 0x40, 0x26, 0xf6, 0xdf, 0xaf, 0x71, 0xe2, 0x4d, 0x25, 0xd8, 0xf6, 0xd9, 0xf9, 0x00, 0x21, 0xdf, 0x5b, 0x77, 0x4d, 0xca, 0xd4, 0xd8, 0x83, 0x17, 0x0e, 0x4a, 0xd8, 0x9c, 0x33, 0xa0, 0xd6],
+    [0x0b, 0x2c, 0xa6, 0xa9, 0x99, 0xfe, 0x68, 0x87, 0xe0, 0x70, 0x4d, 0xad, 0x58, 0xd0, 0x34, 0x65, 0xa9, 0x6b, 0xc9, 0xe3, 0x7d, 0x10, 0x91, 0xf6, 0x1b, 0xc9, 0xf9, 0xc6, 0x2b, 0xbe, 0xb8, 0x24],
+    [0x22, 0x1b, 0x63, 0xd6, 0x6f, 0x0b, 0x45, 0xf9, 0xd4, 0x0c, 0x54, 0x05, 0x3a, 0x28, 0xa0, 0x6b, 0x1d, 0x0a, 0x4c, 0xe4, 0x1d, 0x36, 0x47, 0x97, 0xa1, 0xa7, 0xe0, 0xc9, 0x65, 0x29, 0xf4, 0x21],
+    [0x30, 0x18, 0x5c, 0x48, 0xb7, 0xb2, 0xf1, 0xd5, 0x3d, 0x41, 0x20, 0x80, 0x1b, 0x04, 0x7d, 0x08, 0x74, 0x93, 0xbc, 0xe6, 0x4d, 0x4d, 0x24, 0xae, 0xdc, 0xe2, 0xf4, 0x83, 0x6b, 0xb8, 0x4a, 0xd4],
+    [0x23, 0xf5, 0xd3, 0x72, 0xa3, 0xf0, 0xe3, 0xcb, 0xa9, 0x89, 0xe2, 0x23, 0x05, 0x62, 0x27, 0xd3, 0x53, 0x33, 0x56, 0xf0, 0xfa, 0xa4, 0x8f, 0x27, 0xf8, 0x26, 0x73, 0x18, 0x63, 0x2a, 0x61, 0xf0],
+    [0x27, 0x16, 0x68, 0x3b, 0x32, 0xc7, 0x55, 0xfd, 0x1b, 0xf8, 0x23, 0x5e, 0xa1, 0x62, 0xb1, 0xf3, 0x88, 0xe1, 0xe0, 0x09, 0x0d, 0x06, 0x16, 0x2e, 0x8e, 0x6d, 0xfb, 0xe4, 0x32, 0x8f, 0x3e, 0x3b],
+    [0x09, 0x77, 0x54, 0x58, 0x36, 0x86, 0x6f, 0xa2, 0x04, 0xca, 0x1d, 0x85, 0x3e, 0xc0, 0x90, 0x9e, 0x3d, 0x14, 0x07, 0x70, 0xc8, 0x0a, 0xc6, 0x7d, 0xc9, 0x30, 0xc6, 0x97, 0x48, 0xd5, 0xd4, 0xbc],
+    [0x14, 0x44, 0xe8, 0xf5, 0x92, 0xbd, 0xbf, 0xd8, 0x02, 0x5d, 0x91, 0xab, 0x49, 0x82, 0xdd, 0x42, 0x5f, 0x51, 0x68, 0x2d, 0x31, 0x47, 0x2b, 0x05, 0xe8, 0x1c, 0x43, 0xc0, 0xf9, 0x43, 0x4b, 0x31],
+    [0x26, 0xe0, 0x4b, 0x65, 0xe9, 0xca, 0x82, 0x70, 0xbe, 0xb7, 0x4a, 0x1c, 0x5c, 0xb8, 0xfe, 0xe8, 0xbe, 0x3f, 0xfb, 0xfe, 0x58, 0x3f, 0x70, 0x12, 0xa0, 0x0f, 0x87, 0x4e, 0x77, 0x18, 0xfb, 0xe3],
+    [0x22, 0xa5, 0xc2, 0xfa, 0x86, 0x0d, 0x11, 0xfe, 0x34, 0xee, 0x47, 0xa5, 0xcd, 0x9f, 0x86, 0x98, 0x00, 0xf4, 0x8f, 0x4f, 0xeb, 0xe2, 0x9a, 0xd6, 0xdf, 0x69, 0x81, 0x6f, 0xb1, 0xa9, 0x14, 0xd2],
+    [0x17, 0x4b, 0x54, 0xd9, 0x90, 0x7d, 0x8f, 0x5c, 0x6a, 0xfd, 0x67, 0x2a, 0x73, 0x8f, 0x42, 0x73, 0x7e, 0xc3, 0x38, 0xf3, 0xa0, 0x96, 0x4c, 0x62, 0x9f, 0x74, 0x74, 0xdd, 0x44, 0xc5, 0xc8, 0xd7],
+    [0x1d, 0xb1, 0xdb, 0x8a, 0xa4, 0x52, 0x83, 0xf3, 0x11, 0x68, 0xfa, 0x66, 0x69, 0x4c, 0xf2, 0x80, 0x8d, 0x21, 0x89, 0xb8, 0x7c, 0x8c, 0x81, 0x43, 0xd5, 0x6c, 0x87, 0x19, 0x07, 0xb3, 0x9b, 0x87],
+    [0x15, 0x30, 0xbf, 0x0f, 0x46, 0x52, 0x7e, 0x88, 0x90, 0x30, 0xb8, 0xc7, 0xb7, 0xdf, 0xde, 0x12, 0x6f, 0x65, 0xfa, 0xf8, 0xcc, 0xe0, 0xab, 0x66, 0x38, 0x73, 0x41, 0xd8, 0x13, 0xd1, 0xbf, 0xd1],
+    [0x0b, 0x73, 0xf6, 0x13, 0x99, 0x32, 0x29, 0xf5, 0x9f, 0x01, 0xc1, 0xce, 0xc8, 0x76, 0x0e, 0x99, 0x36, 0xea, 0xd9, 0xed, 0xc8, 0xf2, 0x81, 0x48, 0x89, 0x33, 0x0a, 0x2f, 0x2b, 0xad, 0xe4, 0x57],
+    [0x29, 0xc2, 0x5a, 0x22, 0xfe, 0x21, 0x64, 0x60, 0x45, 0x52, 0xaa, 0xea, 0x37, 0x7f, 0x44, 0x8d, 0x58, 0x7a, 0xb9, 0x77, 0xfc, 0x82, 0x27, 0x78, 0x7b, 0xd2, 0xdc, 0x0f, 0x36, 0xbc, 0xf4, 0x1e],
+    [0x2b, 0x30, 0xd5, 0x3e, 0xd1, 0x75, 0x9b, 0xfb, 0x85, 0x03, 0xda, 0x66, 0xc9, 0x2c, 0xf4, 0x07, 0x7a, 0xbe, 0x82, 0x79, 0x5d, 0xc2, 0x72, 0xb3, 0x77, 0xdf, 0x57, 0xd7, 0x7c, 0x87, 0x55, 0x26],
+    [0x12, 0xf6, 0xd7, 0x03, 0xb5, 0x70, 0x2a, 0xab, 0x7b, 0x7b, 0x7e, 0x69, 0x35, 0x9d, 0x53, 0xa2, 0x75, 0x6c, 0x08, 0xc8, 0x5e, 0xde, 0x72, 0x27, 0xcf, 0x5f, 0x0a, 0x29, 0x16, 0x78, 0x7c, 0xd2],
+    [0x25, 0x20, 0xe1, 0x83, 0x00, 0xaf, 0xda, 0x3f, 0x61, 0xa4, 0x0a, 0x0b, 0x88, 0x37, 0x29, 0x3a, 0x55, 0xad, 0x01, 0x07, 0x10, 0x28, 0xd4, 0x84, 0x1f, 0xfa, 0x9a, 0xc7, 0x06, 0x36, 0x41, 0x13],
+    [0x1e, 0xc9, 0xda, 0xea, 0x86, 0x09, 0x71, 0xec, 0xdd, 0xa8, 0xed, 0x4f, 0x34, 0x6f, 0xa9, 0x67, 0xac, 0x9b, 0xc5, 0x92, 0x78, 0x27, 0x73, 0x93, 0xc6, 0x8f, 0x09, 0xfa, 0x03, 0xb8, 0xb9, 0x5f],
+    [0x0a, 0x99, 0xb3, 0xe1, 0x78, 0xdb, 0x2e, 0x2e, 0x43, 0x2f, 0x5c, 0xd5, 0xbe, 0xf8, 0xfe, 0x44, 0x83, 0xbf, 0x5c, 0xbf, 0x70, 0xed, 0x40, 0x7c, 0x08, 0xaa, 0xe2, 0x4b, 0x83, 0x0a, 0xd7, 0x25],
+    [0x07, 0xcd, 0xa9, 0xe6, 0x3d, 0xb6, 0xe3, 0x9f, 0x08, 0x6b, 0x89, 0xb6, 0x01, 0xc2, 0xbb, 0xe4, 0x07, 0xee, 0x0a, 0xba, 0xc3, 0xc8, 0x17, 0xa1, 0x31, 0x7a, 0xba, 0xd7, 0xc5, 0x77, 0x84, 0x92],
+    [0x08, 0xc9, 0xc6, 0x5a, 0x4f, 0x95, 0x5e, 0x89, 0x52, 0xd5, 0x71, 0xb1, 0x91, 0xbb, 0x0a, 0xdb, 0x49, 0xbd, 0x82, 0x90, 0x96, 0x32, 0x03, 0xb3, 0x5d, 0x48, 0xaa, 0xb3, 0x8f, 0x8f, 0xc3, 0xa3],
+    [0x27, 0x37, 0xf8, 0xce, 0x1d, 0x5a, 0x67, 0xb3, 0x49, 0x59, 0x0d, 0xdb, 0xfb, 0xd7, 0x09, 0xed, 0x9a, 0xf5, 0x4a, 0x2a, 0x3f, 0x27, 0x19, 0xd3, 0x38, 0x01, 0xc9, 0xc1, 0x7b, 0xdd, 0x9c, 0x9e],
+    [0x10, 0x49, 0xa6, 0xc6, 0x5f, 0xf0, 0x19, 0xf0, 0xd2, 0x87, 0x70, 0x07, 0x27, 0x98, 0xe8, 0xb7, 0x90, 0x94, 0x32, 0xbd, 0x0c, 0x12, 0x98, 0x13, 0xa9, 0xf1, 0x79, 0xba, 0x62, 0x7f, 0x7d, 0x6a],
+    [0x18, 0xb4, 0xfe, 0x96, 0x87, 0x32, 0xc4, 0x62, 0xc0, 0xea, 0x5a, 0x9b, 0xeb, 0x27, 0xce, 0xcb, 0xde, 0x88, 0x68, 0x94, 0x4f, 0xdf, 0x64, 0xee, 0x60, 0xa5, 0x12, 0x23, 0x61, 0xda, 0xed, 0xdb],
+    [0x2f, 0xf2, 0xb6, 0xfd, 0x22, 0xdf, 0x49, 0xd2, 0x44, 0x0b, 0x2e, 0xae, 0xee, 0xfa, 0x8c, 0x02, 0xa6, 0xf4, 0x78, 0xcf, 0xcf, 0x11, 0xf1, 0xb2, 0xa4, 0xf7, 0x47, 0x34, 0x83, 0x88, 0x5d, 0x19],
+    [0x2e, 0xc5, 0xf2, 0xf1, 0x92, 0x8f, 0xe9, 0x32, 0xe5, 0x6c, 0x78, 0x9b, 0x8f, 0x6b, 0xbc, 0xb3, 0xe8, 0xbe, 0x40, 0x57, 0xcb, 0xd8, 0xdb, 0xd1, 0x8a, 0x1b, 0x35, 0x2f, 0x5c, 0xef, 0x42, 0xff],
+    [0x26, 0x5a, 0x5e, 0xcc, 0xd8, 0xb9, 0x29, 0x75, 0xe3, 0x3a, 0xd9, 0xf7, 0x5b, 0xf3, 0x42, 0x6d, 0x42, 0x4a, 0x4c, 0x6a, 0x77, 0x94, 0xee, 0x3f, 0x08, 0xc1, 0xd1, 0x00, 0x37, 0x8e, 0x54, 0x5e],
+    [0x24, 0x05, 0xea, 0xa4, 0xc0, 0xbd, 0xe1, 0x12, 0x9d, 0x62, 0x42, 0xbb, 0x5a, 0xda, 0x0e, 0x68, 0x77, 0x8e, 0x65, 0x6c, 0xfc, 0xb3, 0x66, 0xbf, 0x20, 0x51, 0x7d, 0xa1, 0xdf, 0xd4, 0x27, 0x9c],
+    [0x09, 0x4c, 0x97, 0xd8, 0xc1, 0x94, 0xc4, 0x2e, 0x88, 0x01, 0x80, 0x04, 0xcb, 0xbf, 0x2b, 0xc5, 0xfd, 0xb5, 0x19, 0x55, 0xd8, 0xb2, 0xd6, 0x6b, 0x76, 0xdd, 0x98, 0xa2, 0xdb, 0xf6, 0x04, 0x17],
+    [0x2c, 0x30, 0xd5, 0xf3, 0x3b, 0xb3, 0x2c, 0x5c, 0x22, 0xb9, 0x97, 0x9a, 0x60, 0x5b, 0xf6, 0x4d, 0x50, 0x8b, 0x70, 0x52, 0x21, 0xe6, 0xa6, 0x86, 0x33, 0x0c, 0x96, 0x25, 0xc2, 0xaf, 0xe0, 0xb8],
+    [0x01, 0xa7, 0x56, 0x66, 0xf6, 0x24, 0x1f, 0x68, 0x25, 0xd0, 0x1c, 0xc6, 0xdc, 0xb1, 0x62, 0x2d, 0x48, 0x86, 0xea, 0x58, 0x3e, 0x87, 0x29, 0x9e, 0x6a, 0xa2, 0xfc, 0x71, 0x6f, 0xdb, 0x6c, 0xf5],
+    [0x0a, 0x32, 0x90, 0xe8, 0x39, 0x81, 0x13, 0xea, 0x4d, 0x12, 0xac, 0x09, 0x1e, 0x87, 0xbe, 0x7c, 0x6d, 0x35, 0x9a, 0xb9, 0xa6, 0x69, 0x79, 0xfc, 0xf4, 0x7b, 0xf2, 0xe8, 0x7d, 0x38, 0x2f, 0xcb],
+    [0x15, 0x4a, 0xde, 0x9c, 0xa3, 0x6e, 0x26, 0x8d, 0xfe, 0xb3, 0x84, 0x61, 0x42, 0x5b, 0xb0, 0xd8, 0xc3, 0x12, 0x19, 0xd8, 0xfa, 0x0d, 0xfc, 0x75, 0xec, 0xd2, 0x1b, 0xf6, 0x9a, 0xa0, 0xcc, 0x74],
+    [0x27, 0xaa, 0x8d, 0x3e, 0x25, 0x38, 0x0c, 0x0b, 0x1b, 0x17, 0x2d, 0x79, 0xc6, 0xf2, 0x2e, 0xee, 0x99, 0x23, 0x1e, 0xf5, 0xdc, 0x69, 0xd8, 0xdc, 0x13, 0xa4, 0xb5, 0x09, 0x5d, 0x02, 0x87, 0x72],
+    [0x2c, 0xf4, 0x05, 0x1e, 0x6c, 0xab, 0x48, 0x30, 0x1a, 0x8b, 0x2e, 0x3b, 0xca, 0x60, 0x99, 0xd7, 0x56, 0xbb, 0xdf, 0x48, 0x5a, 0xfa, 0x1f, 0x54, 0x9d, 0x39, 0x5b, 0xbc, 0xbd, 0x80, 0x64, 0x61],
+    [0x30, 0x1e, 0x70, 0xf7, 0x29, 0xf3, 0xc9, 0x4b, 0x1d, 0x3f, 0x51, 0x7d, 0xdf, 0xf9, 0xf2, 0x01, 0x51, 0x31, 0xfe, 0xab, 0x8a, 0xfa, 0x5e, 0xeb, 0xb0, 0x84, 0x3d, 0x7f, 0x84, 0xb2, 0x3e, 0x71],
+    [0x29, 0x8b, 0xeb, 0x64, 0xf8, 0x12, 0xd2, 0x5d, 0x8b, 0x4d, 0x96, 0x20, 0x34, 0x7a, 0xb0, 0x23, 0x32, 0xdc, 0x4c, 0xef, 0x11, 0x3a, 0xe6, 0x0d, 0x17, 0xa8, 0xd7, 0xa4, 0xc9, 0x1f, 0x83, 0xbc],
+    [0x1b, 0x36, 0x2e, 0x72, 0xa5, 0xf8, 0x47, 0xf8, 0x4d, 0x03, 0xfd, 0x29, 0x1c, 0x3c, 0x47, 0x1e, 0xd1, 0xc1, 0x4a, 0x15, 0xb2, 0x21, 0x68, 0x0a, 0xcf, 0x11, 0xa3, 0xf0, 0x2e, 0x46, 0xaa, 0x95],
+    [0x0d, 0xc8, 0xa2, 0x14, 0x61, 0x10, 0xc0, 0xb3, 0x75, 0x43, 0x29, 0x02, 0x99, 0x92, 0x23, 0xd5, 0xaa, 0x1e, 0xf6, 0xe7, 0x8e, 0x1e, 0x5e, 0xbc, 0xbc, 0x1d, 0x9b, 0xa4, 0x1d, 0xc1, 0xc7, 0x37],
+    [0x0a, 0x48, 0x66, 0x3b, 0x34, 0xce, 0x5e, 0x1c, 0x05, 0xdc, 0x93, 0x09, 0x2c, 0xb6, 0x97, 0x78, 0xcb, 0x21, 0x72, 0x9a, 0x72, 0xdd, 0xc0, 0x3a, 0x08, 0xaf, 0xa1, 0xeb, 0x92, 0x2f, 0xf2, 0x79],
+    [0x0a, 0x87, 0x39, 0x1f, 0xb1, 0xcd, 0x8c, 0xdf, 0x60, 0x96, 0xb6, 0x4a, 0x82, 0xf9, 0xe9, 0x5f, 0x0f, 0xe4, 0x6f, 0x14, 0x3b, 0x70, 0x2d, 0x74, 0x54, 0x5b, 0xb3, 0x14, 0x88, 0x10, 0x98, 0xee],
+    [0x1b, 0x5b, 0x29, 0x46, 0xf7, 0xc2, 0x89, 0x75, 0xf0, 0x51, 0x2f, 0xf8, 0xe6, 0xca, 0x36, 0x2f, 0x88, 0x26, 0xed, 0xd7, 0xea, 0x9c, 0x29, 0xf3, 0x82, 0xba, 0x8a, 0x2a, 0x08, 0x92, 0xfd, 0x5d],
+    [0x01, 0x00, 0x1c, 0xf5, 0x12, 0xac, 0x24, 0x1d, 0x47, 0xeb, 0xe2, 0x23, 0x92, 0x19, 0xbc, 0x6a, 0x17, 0x3a, 0x8b, 0xbc, 0xb8, 0xa5, 0xb9, 0x87, 0xb4, 0xea, 0xc1, 0xf5, 0x33, 0x31, 0x5b, 0x6b],
+    [0x2f, 0xd9, 0x77, 0xc7, 0x0f, 0x64, 0x5d, 0xb4, 0xf7, 0x04, 0xfa, 0x7d, 0x76, 0x93, 0xda, 0x72, 0x7a, 0xc0, 0x93, 0xd3, 0xfb, 0x5f, 0x5f, 0xeb, 0xc7, 0x2b, 0xeb, 0x17, 0xd8, 0x35, 0x8a, 0x32],
+    [0x23, 0xc0, 0x03, 0x9a, 0x3f, 0xab, 0x4a, 0xd3, 0xc2, 0xd7, 0xcc, 0x68, 0x81, 0x64, 0xf3, 0x9e, 0x76, 0x1d, 0x53, 0x55, 0xc0, 0x54, 0x44, 0xd9, 0x9b, 0xe7, 0x63, 0xa9, 0x77, 0x93, 0xa9, 0xc4],
+    [0x19, 0xd4, 0x3e, 0xe0, 0xc6, 0x08, 0x1c, 0x05, 0x2c, 0x9c, 0x0d, 0xf6, 0x16, 0x1e, 0xaa, 0xc1, 0xae, 0xc3, 0x56, 0xcf, 0x43, 0x58, 0x88, 0xe7, 0x9f, 0x27, 0xf2, 0x2f, 0xf0, 0x3f, 0xa2, 0x5d],
+    [0x2d, 0x9b, 0x10, 0xc2, 0xf2, 0xe7, 0xac, 0x1a, 0xfd, 0xdc, 0xcf, 0xfd, 0x94, 0xa5, 0x63, 0x02, 0x8b, 0xf2, 0x9b, 0x64, 0x6d, 0x02, 0x08, 0x30, 0x91, 0x9f, 0x9d, 0x5c, 0xa1, 0xce, 0xfe, 0x59],
+    [0x24, 0x57, 0xca, 0x6c, 0x2f, 0x2a, 0xa3, 0x0e, 0xc4, 0x7e, 0x4a, 0xff, 0x5a, 0x66, 0xf5, 0xce, 0x27, 0x99, 0x28, 0x3e, 0x16, 0x6f, 0xc8, 0x1c, 0xda, 0xe2, 0xf2, 0xb9, 0xf8, 0x3e, 0x42, 0x67],
+    [0x0a, 0xbc, 0x39, 0x2f, 0xe8, 0x5e, 0xda, 0x85, 0x58, 0x20, 0x59, 0x24, 0x45, 0x09, 0x40, 0x22, 0x81, 0x1e, 0xe8, 0x67, 0x6e, 0xd6, 0xf0, 0xc3, 0x04, 0x4d, 0xfb, 0x54, 0xa7, 0xc1, 0x0b, 0x35],
+    [0x19, 0xd2, 0xcc, 0x5c, 0xa5, 0x49, 0xd1, 0xd4, 0x0c, 0xeb, 0xcd, 0x37, 0xf3, 0xea, 0x54, 0xf3, 0x11, 0x61, 0xac, 0x39, 0x93, 0xac, 0xf3, 0x10, 0x1d, 0x2c, 0x2b, 0xc3, 0x0e, 0xac, 0x1e, 0xb0],
+    [0x0f, 0x97, 0xae, 0x30, 0x33, 0xff, 0xa0, 0x16, 0x08, 0xaa, 0xfb, 0x26, 0xae, 0x13, 0xcd, 0x39, 0x3e, 0xe0, 0xe4, 0xec, 0x04, 0x1b, 0xa6, 0x44, 0xa3, 0xd3, 0xab, 0x54, 0x6e, 0x98, 0xc9, 0xc8],
+    [0x16, 0xdb, 0xc7, 0x8f, 0xd2, 0x8b, 0x7f, 0xb8, 0x26, 0x0e, 0x40, 0x4c, 0xf1, 0xd4, 0x27, 0xa7, 0xfa, 0x15, 0x53, 0x7e, 0xa4, 0xe1, 0x68, 0xe8, 0x8a, 0x16, 0x64, 0x96, 0xe8, 0x8c, 0xfe, 0xca],
+    [0x24, 0x0f, 0xaf, 0x28, 0xf1, 0x14, 0x99, 0xb9, 0x16, 0xf0, 0x85, 0xf7, 0x3b, 0xc4, 0xf2, 0x2e, 0xef, 0x83, 0x44, 0xe5, 0x76, 0xf8, 0xad, 0x3d, 0x18, 0x27, 0x82, 0x03, 0x66, 0xd5, 0xe0, 0x7b],
+    [0x0a, 0x1b, 0xb0, 0x75, 0xaa, 0x37, 0xff, 0x0c, 0xfe, 0x6c, 0x85, 0x31, 0xe5, 0x5e, 0x17, 0x70, 0xea, 0xba, 0x80, 0x8c, 0x8f, 0xdb, 0x6d, 0xbf, 0x46, 0xf8, 0xca, 0xb5, 0x8d, 0x9e, 0xf1, 0xaf],
+    [0x2e, 0x47, 0xe1, 0x5e, 0xa4, 0xa4, 0x7f, 0xf1, 0xa6, 0xa8, 0x53, 0xaa, 0xf3, 0xa6, 0x44, 0xca, 0x38, 0xd5, 0xb0, 0x85, 0xac, 0x10, 0x42, 0xfd, 0xc4, 0xa7, 0x05, 0xa7, 0xce, 0x08, 0x9f, 0x4d],
+    [0x16, 0x6e, 0x5b, 0xf0, 0x73, 0x37, 0x83, 0x48, 0x86, 0x0c, 0xa4, 0xa9, 0xc0, 0x9d, 0x39, 0xe1, 0x67, 0x3a, 0xb0, 0x59, 0x93, 0x5f, 0x4d, 0xf3, 0x5f, 0xb1, 0x45, 0x28, 0x37, 0x57, 0x72, 0xb6],
+    [0x18, 0xb4, 0x2d, 0x7f, 0xfd, 0xd2, 0xea, 0x4f, 0xaf, 0x23, 0x59, 0x02, 0xf0, 0x57, 0xa2, 0x74, 0x0c, 0xac, 0xcc, 0xd0, 0x27, 0x23, 0x30, 0x01, 0xed, 0x10, 0xf9, 0x65, 0x38, 0xf0, 0x91, 0x6f],
+    [0x08, 0x9c, 0xb1, 0xb0, 0x32, 0x23, 0x8f, 0x5e, 0x49, 0x14, 0x78, 0x8e, 0x3e, 0x3c, 0x7e, 0xad, 0x4f, 0xc3, 0x68, 0x02, 0x0b, 0x3e, 0xd3, 0x82, 0x21, 0xde, 0xab, 0x10, 0x51, 0xc3, 0x77, 0x02],
+    [0x24, 0x2a, 0xcd, 0x3e, 0xb3, 0xa2, 0xf7, 0x2b, 0xaf, 0x7c, 0x70, 0x76, 0xdd, 0x16, 0x5a, 0xdf, 0x89, 0xf9, 0x33, 0x9c, 0x7b, 0x97, 0x19, 0x21, 0xd9, 0xe7, 0x08, 0x63, 0x45, 0x1d, 0xd8, 0xd1],
+    [0x17, 0x4f, 0xbb, 0x10, 0x4a, 0x4e, 0xe3, 0x02, 0xbf, 0x47, 0xf2, 0xbd, 0x82, 0xfc, 0xe8, 0x96, 0xea, 0xc9, 0xa0, 0x68, 0x28, 0x3f, 0x32, 0x64, 0x74, 0xaf, 0x86, 0x04, 0x57, 0x24, 0x5c, 0x3b],
+    [0x17, 0x34, 0x0e, 0x71, 0xd9, 0x6f, 0x46, 0x6d, 0x61, 0xf3, 0x05, 0x8c, 0xe0, 0x92, 0xc6, 0x7d, 0x28, 0x91, 0xfb, 0x2b, 0xb3, 0x18, 0x61, 0x3f, 0x78, 0x0c, 0x27, 0x5f, 0xe1, 0x11, 0x6c, 0x6b],
+    [0x1e, 0x8e, 0x40, 0xac, 0x85, 0x3b, 0x7d, 0x42, 0xf0, 0x0f, 0x2e, 0x38, 0x39, 0x82, 0xd0, 0x24, 0xf0, 0x98, 0xb9, 0xf8, 0xfd, 0x45, 0x59, 0x53, 0xa2, 0xfd, 0x38, 0x0c, 0x4d, 0xf7, 0xf6, 0xb2],
+    [0x05, 0x29, 0x89, 0x8d, 0xc0, 0x64, 0x99, 0x07, 0xe1, 0xd4, 0xd5, 0xe2, 0x84, 0xb8, 0xd1, 0x07, 0x51, 0x98, 0xc5, 0x5c, 0xad, 0x66, 0xe8, 0xa9, 0xbf, 0x40, 0xf9, 0x29, 0x38, 0xe2, 0xe9, 0x61],
+    [0x21, 0x62, 0x75, 0x4d, 0xb0, 0xba, 0xa0, 0x30, 0xbf, 0x7d, 0xe5, 0xbb, 0x79, 0x73, 0x64, 0xdc, 0xe8, 0xc7, 0x7a, 0xa0, 0x17, 0xee, 0x1d, 0x7b, 0xf6, 0x5f, 0x21, 0xc4, 0xd4, 0xe5, 0xdf, 0x8f],
+    [0x12, 0xc7, 0x55, 0x36, 0x98, 0xc4, 0xbf, 0x6f, 0x3c, 0xeb, 0x25, 0x0a, 0xe0, 0x0c, 0x58, 0xc2, 0xa9, 0xf9, 0x29, 0x1e, 0xfb, 0xde, 0x4c, 0x84, 0x21, 0xbe, 0xf4, 0x47, 0x41, 0x75, 0x2e, 0xc6],
+    [0x29, 0x26, 0x43, 0xe3, 0xba, 0x20, 0x26, 0xaf, 0xfc, 0xb8, 0xc5, 0x27, 0x93, 0x13, 0xbd, 0x51, 0xa7, 0x33, 0xc9, 0x33, 0x53, 0xe9, 0xd9, 0xc7, 0x9c, 0xb7, 0x23, 0x13, 0x65, 0x26, 0x50, 0x8e],
+    [0x00, 0xcc, 0xf1, 0x3e, 0x0c, 0xb6, 0xf9, 0xd8, 0x1d, 0x52, 0x95, 0x1b, 0xea, 0x99, 0x0b, 0xd5, 0xb6, 0xc0, 0x7c, 0x5d, 0x98, 0xe6, 0x6f, 0xf7, 0x1d, 0xb6, 0xe7, 0x4d, 0x5b, 0x87, 0xd1, 0x58],
+    [0x18, 0x5d, 0x1e, 0x20, 0xe2, 0x3b, 0x09, 0x17, 0xdd, 0x65, 0x41, 0x28, 0xcf, 0x2f, 0x3a, 0xaa, 0xb6, 0x72, 0x38, 0x73, 0xcb, 0x30, 0xfc, 0x22, 0xb0, 0xf8, 0x6c, 0x15, 0xab, 0x64, 0x5b, 0x4b],
+    [0x14, 0xc6, 0x1c, 0x83, 0x6d, 0x55, 0xd3, 0xdf, 0x74, 0x2b, 0xdf, 0x11, 0xc6, 0x0e, 0xfa, 0x18, 0x67, 0x78, 0xe3, 0xde, 0x0f, 0x02, 0x4c, 0x0f, 0x13, 0xfe, 0x53, 0xf8, 0xd8, 0x76, 0x4e, 0x1f],
+    [0x0f, 0x35, 0x68, 0x41, 0xb3, 0xf5, 0x56, 0xfc, 0xe5, 0xdb, 0xe4, 0x68, 0x04, 0x57, 0x69, 0x1c, 0x29, 0x19, 0xe2, 0xaf, 0x53, 0x00, 0x81, 0x84, 0xd0, 0x3e, 0xe1, 0x19, 0x5d, 0x72, 0x44, 0x9e],
+    [0x1b, 0x8f, 0xd9, 0xff, 0x39, 0x71, 0x4e, 0x07, 0x5d, 0xf1, 0x24, 0xf8, 0x87, 0xbf, 0x40, 0xb3, 0x83, 0x14, 0x33, 0x74, 0xfd, 0x20, 0x80, 0xba, 0x0c, 0x0a, 0x6b, 0x6e, 0x8f, 0xa5, 0xb3, 0xe8],
+    [0x0e, 0x86, 0xa8, 0xc2, 0x00, 0x9c, 0x14, 0x0c, 0xa3, 0xf8, 0x73, 0x92, 0x4e, 0x2a, 0xaa, 0x14, 0xfc, 0x3c, 0x8a, 0xe0, 0x4e, 0x9d, 0xf0, 0xb3, 0xe9, 0x10, 0x34, 0x18, 0x79, 0x6f, 0x60, 0x24],
+    [0x2e, 0x6c, 0x5e, 0x89, 0x8f, 0x55, 0x47, 0x77, 0x0e, 0x54, 0x62, 0xad, 0x93, 0x2f, 0xcd, 0xd2, 0x37, 0x3f, 0xc4, 0x38, 0x20, 0xca, 0x2b, 0x16, 0xb0, 0x86, 0x14, 0x21, 0xe7, 0x91, 0x55, 0xc8],
+    [0x05, 0xd7, 0x97, 0xf1, 0xab, 0x36, 0x47, 0x23, 0x7c, 0x14, 0xf9, 0xd1, 0xdf, 0x03, 0x2b, 0xc9, 0xff, 0x9f, 0xe1, 0xa0, 0xec, 0xd3, 0x77, 0x97, 0x2c, 0xe5, 0xfd, 0x5a, 0x0c, 0x01, 0x46, 0x04],
+    [0x29, 0xa3, 0x11, 0x04, 0x63, 0xa5, 0xaa, 0xe7, 0x6c, 0x3d, 0x15, 0x28, 0x75, 0x98, 0x1d, 0x0c, 0x1d, 0xaf, 0x2d, 0xcd, 0x65, 0x51, 0x9e, 0xf5, 0xca, 0x89, 0x29, 0x85, 0x1d, 0xa8, 0xc0, 0x08],
+    [0x29, 0x74, 0xda, 0x7b, 0xc0, 0x74, 0x32, 0x22, 0x73, 0xc3, 0xa4, 0xb9, 0x1c, 0x05, 0x35, 0x4c, 0xdc, 0x71, 0x64, 0x0a, 0x8b, 0xbd, 0x1f, 0x86, 0x4b, 0x73, 0x2f, 0x81, 0x63, 0x88, 0x33, 0x14],
+    [0x1e, 0xd0, 0xfb, 0x06, 0x69, 0x9b, 0xa2, 0x49, 0xb2, 0xa3, 0x06, 0x21, 0xc0, 0x5e, 0xb1, 0x2c, 0xa2, 0x9c, 0xb9, 0x1a, 0xa0, 0x82, 0xc8, 0xbf, 0xcc, 0xe9, 0xc5, 0x22, 0x88, 0x9b, 0x47, 0xdc],
+    [0x1c, 0x79, 0x3e, 0xf0, 0xdc, 0xc5, 0x11, 0x23, 0x65, 0x4f, 0xf2, 0x6d, 0x8d, 0x86, 0x3f, 0xee, 0xae, 0x29, 0xe8, 0xc5, 0x72, 0xec, 0xa9, 0x12, 0xd8, 0x0c, 0x8a, 0xe3, 0x6e, 0x40, 0xfe, 0x9b],
+    [0x1e, 0x6a, 0xac, 0x1c, 0x6d, 0x3d, 0xd3, 0x15, 0x79, 0x56, 0x25, 0x7d, 0x3d, 0x23, 0x4e, 0xf1, 0x8c, 0x91, 0xe8, 0x25, 0x89, 0xa7, 0x81, 0x69, 0xfb, 0xb4, 0xa8, 0x77, 0x09, 0x77, 0xdc, 0x2f],
+    [0x1a, 0x20, 0xad, 0xa7, 0x57, 0x62, 0x34, 0xee, 0xe6, 0x27, 0x3d, 0xd6, 0xfa, 0x98, 0xb2, 0x5e, 0xd0, 0x37, 0x74, 0x80, 0x80, 0xa4, 0x7d, 0x94, 0x8f, 0xcd, 0xa3, 0x32, 0x56, 0xfb, 0x6b, 0xf5],
+    [0x19, 0x10, 0x33, 0xd6, 0xd8, 0x5c, 0xea, 0xa6, 0xfc, 0x7a, 0x9a, 0x23, 0xa6, 0xfd, 0x99, 0x96, 0x64, 0x2d, 0x77, 0x20, 0x45, 0xec, 0xe5, 0x13, 0x35, 0xd4, 0x93, 0x06, 0x72, 0x8a, 0xf9, 0x6c],
+    [0x00, 0x6e, 0x59, 0x79, 0xda, 0x7e, 0x7e, 0xf5, 0x3a, 0x82, 0x5a, 0xa6, 0xfd, 0xdc, 0x3a, 0xbf, 0xc7, 0x6f, 0x20, 0x0b, 0x37, 0x40, 0xb8, 0xb2, 0x32, 0xef, 0x48, 0x1f, 0x5d, 0x06, 0x29, 0x7b],
+    [0x0b, 0x0d, 0x7e, 0x69, 0xc6, 0x51, 0x91, 0x0b, 0xbe, 0xf3, 0xe6, 0x8d, 0x41, 0x7e, 0x9f, 0xa0, 0xfb, 0xd5, 0x7f, 0x59, 0x6c, 0x8f, 0x29, 0x83, 0x1e, 0xff, 0x8c, 0x01, 0x74, 0xcd, 0xb0, 0x6d],
+    [0x25, 0xca, 0xf5, 0xb0, 0xc1, 0xb9, 0x3b, 0xc5, 0x16, 0x43, 0x5e, 0xc0, 0x84, 0xe2, 0xec, 0xd4, 0x4a, 0xc4, 0x6d, 0xbb, 0xb0, 0x33, 0xc5, 0x11, 0x2c, 0x4b, 0x20, 0xa2, 0x5c, 0x9c, 0xdf, 0x9d],
+    [0x12, 0xc1, 0xea, 0x89, 0x2c, 0xc3, 0x1e, 0x0d, 0x9a, 0xf8, 0xb7, 0x96, 0xd9, 0x64, 0x58, 0x72, 0xf7, 0xf7, 0x74, 0x42, 0xd6, 0x2f, 0xd4, 0xc8, 0x08, 0x5b, 0x2f, 0x15, 0x0f, 0x72, 0x47, 0x2a],
+    [0x16, 0xaf, 0x29, 0x69, 0x51, 0x57, 0xab, 0xa9, 0xb8, 0xbb, 0xe3, 0xaf, 0xeb, 0x24, 0x5f, 0xee, 0xe5, 0xa9, 0x29, 0xd9, 0xf9, 0x28, 0xb9, 0xb8, 0x1d, 0xe6, 0xda, 0xdc, 0x78, 0xc3, 0x2a, 0xae],
+    [0x01, 0x36, 0xdf, 0x45, 0x7c, 0x80, 0x58, 0x8d, 0xd6, 0x87, 0xfb, 0x2f, 0x3b, 0xe1, 0x86, 0x91, 0x70, 0x5b, 0x87, 0xec, 0x5a, 0x4c, 0xfd, 0xc1, 0x68, 0xd3, 0x10, 0x84, 0x25, 0x6b, 0x67, 0xdc],
+    [0x16, 0x39, 0xa2, 0x8c, 0x5b, 0x4c, 0x81, 0x16, 0x6a, 0xea, 0x98, 0x4f, 0xba, 0x6e, 0x71, 0x47, 0x9e, 0x07, 0xb1, 0xef, 0xbc, 0x74, 0x43, 0x4d, 0xb9, 0x5a, 0x28, 0x50, 0x60, 0xe7, 0xb0, 0x89],
+    [0x03, 0xd6, 0x2f, 0xbf, 0x82, 0xfd, 0x1d, 0x43, 0x13, 0xf8, 0xe6, 0x50, 0xf5, 0x87, 0xec, 0x06, 0x81, 0x6c, 0x28, 0xb7, 0x00, 0xbd, 0xc5, 0x0f, 0x7e, 0x23, 0x2b, 0xd9, 0xb5, 0xca, 0x9b, 0x76],
+    [0x11, 0xae, 0xeb, 0x52, 0x7d, 0xc8, 0xce, 0x44, 0xb4, 0xd1, 0x4a, 0xad, 0xdc, 0xa3, 0xcf, 0xe2, 0xf7, 0x7a, 0x1e, 0x40, 0xfc, 0x6d, 0xa9, 0x7c, 0x24, 0x98, 0x30, 0xde, 0x1e, 0xdf, 0xde, 0x54],
+    [0x13, 0xf9, 0xb9, 0xa4, 0x12, 0x74, 0x12, 0x94, 0x79, 0xc5, 0xe6, 0x13, 0x8c, 0x6c, 0x8e, 0xe3, 0x6a, 0x67, 0x0e, 0x6b, 0xc6, 0x8c, 0x7a, 0x49, 0x64, 0x2b, 0x64, 0x58, 0x07, 0xbf, 0xc8, 0x24],
+    [0x0e, 0x47, 0x72, 0xfa, 0x3d, 0x75, 0x17, 0x9d, 0xc8, 0x48, 0x4c, 0xd2, 0x6c, 0x7c, 0x1f, 0x63, 0x5d, 0xde, 0xee, 0xd7, 0xa9, 0x39, 0x44, 0x0c, 0x50, 0x6c, 0xae, 0x8b, 0x7e, 0xbc, 0xd1, 0x5b],
+    [0x1b, 0x39, 0xa0, 0x0c, 0xbc, 0x81, 0xe4, 0x27, 0xde, 0x4b, 0xde, 0xc5, 0x8f, 0xeb, 0xe8, 0xd8, 0xb5, 0x97, 0x17, 0x52, 0x06, 0x7a, 0x61, 0x2b, 0x39, 0xfc, 0x46, 0xa6, 0x8c, 0x5d, 0x4d, 0xb4],
+    [0x2b, 0xed, 0xb6, 0x6e, 0x1a, 0xd5, 0xa1, 0xd5, 0x71, 0xe1, 0x6e, 0x29, 0x53, 0xf4, 0x87, 0x31, 0xf6, 0x64, 0x63, 0xc2, 0xeb, 0x54, 0xa2, 0x45, 0x44, 0x4d, 0x1c, 0x0a, 0x3a, 0x25, 0x70, 0x7e],
+    [0x2c, 0xf0, 0xa0, 0x9a, 0x55, 0xca, 0x93, 0xaf, 0x8a, 0xbd, 0x06, 0x8f, 0x06, 0xa7, 0x28, 0x7f, 0xb0, 0x8b, 0x19, 0x3b, 0x60, 0x85, 0x82, 0xa2, 0x73, 0x79, 0xce, 0x35, 0xda, 0x91, 0x5d, 0xec],
+    [0x2d, 0x1b, 0xd7, 0x8f, 0xa9, 0x0e, 0x77, 0xaa, 0x88, 0x83, 0x0c, 0xab, 0xfe, 0xf2, 0xf8, 0xd2, 0x7d, 0x1a, 0x51, 0x20, 0x50, 0xba, 0x7d, 0xb0, 0x75, 0x3c, 0x8f, 0xb8, 0x63, 0xef, 0xb3, 0x87],
+    [0x06, 0x56, 0x10, 0xc6, 0xf4, 0xf9, 0x24, 0x91, 0xf4, 0x23, 0xd3, 0x07, 0x1e, 0xb8, 0x35, 0x39, 0xf7, 0xc0, 0xd4, 0x9c, 0x13, 0x87, 0x06, 0x2e, 0x63, 0x0d, 0x7f, 0xd2, 0x83, 0xdc, 0x33, 0x94],
+    [0x2d, 0x93, 0x3f, 0xf1, 0x92, 0x17, 0xa5, 0x54, 0x50, 0x13, 0xb1, 0x28, 0x73, 0x45, 0x2b, 0xeb, 0xcc, 0x5f, 0x99, 0x69, 0x03, 0x3f, 0x15, 0xec, 0x64, 0x2f, 0xb4, 0x64, 0xbd, 0x60, 0x73, 0x68],
+    [0x1a, 0xa9, 0xd3, 0xfe, 0x4c, 0x64, 0x49, 0x10, 0xf7, 0x6b, 0x92, 0xb3, 0xe1, 0x3b, 0x30, 0xd5, 0x00, 0xda, 0xe5, 0x35, 0x4e, 0x79, 0x50, 0x8c, 0x3c, 0x49, 0xc8, 0xaa, 0x99, 0xe0, 0x25, 0x8b],
+    [0x02, 0x7e, 0xf0, 0x48, 0x69, 0xe4, 0x82, 0xb1, 0xc7, 0x48, 0x63, 0x8c, 0x59, 0x11, 0x1c, 0x6b, 0x27, 0x09, 0x5f, 0xa7, 0x73, 0xe1, 0xac, 0xa0, 0x78, 0xce, 0xa1, 0xf1, 0xc8, 0x45, 0x0b, 0xdd],
+    [0x2b, 0x7d, 0x52, 0x4c, 0x51, 0x72, 0xcb, 0xbb, 0x15, 0xdb, 0x4e, 0x00, 0x66, 0x8a, 0x8c, 0x44, 0x9f, 0x67, 0xa2, 0x60, 0x5d, 0x9e, 0xc0, 0x38, 0x02, 0xe3, 0xfa, 0x13, 0x6a, 0xd0, 0xb8, 0xfb],
+    [0x0c, 0x7c, 0x38, 0x24, 0x43, 0xc6, 0xaa, 0x78, 0x7c, 0x87, 0x18, 0xd8, 0x67, 0x47, 0xc7, 0xf7, 0x46, 0x93, 0xae, 0x25, 0xb1, 0xe5, 0x5d, 0xf1, 0x3f, 0x7c, 0x3c, 0x1d, 0xd7, 0x35, 0xdb, 0x0f],
+    [0x00, 0xb4, 0x56, 0x71, 0x86, 0xbc, 0x3f, 0x7c, 0x62, 0xa7, 0xb5, 0x6a, 0xcf, 0x4f, 0x76, 0x20, 0x7a, 0x1f, 0x43, 0xc2, 0xd3, 0x0d, 0x0f, 0xe4, 0xa6, 0x27, 0xdc, 0xdd, 0x9b, 0xd7, 0x90, 0x78],
+    [0x1e, 0x41, 0xfc, 0x29, 0xb8, 0x25, 0x45, 0x4f, 0xe6, 0xd6, 0x17, 0x37, 0xfe, 0x08, 0xb4, 0x7f, 0xb0, 0x7f, 0xe7, 0x39, 0xe4, 0xc1, 0xe6, 0x1d, 0x03, 0x37, 0x49, 0x08, 0x83, 0xdb, 0x4f, 0xd5],
+    [0x12, 0x50, 0x7c, 0xd5, 0x56, 0xb7, 0xbb, 0xcc, 0x72, 0xee, 0x6d, 0xaf, 0xc6, 0x16, 0x58, 0x44, 0x21, 0xe1, 0xaf, 0x87, 0x2d, 0x8c, 0x0e, 0x89, 0x00, 0x2a, 0xe8, 0xd3, 0xba, 0x06, 0x53, 0xb6],
+    [0x13, 0xd4, 0x37, 0x08, 0x35, 0x53, 0x00, 0x6b, 0xce, 0xf3, 0x12, 0xe5, 0xe6, 0xf5, 0x2a, 0x5d, 0x97, 0xeb, 0x36, 0x61, 0x7e, 0xf3, 0x6f, 0xe4, 0xd7, 0x7d, 0x3e, 0x97, 0xf7, 0x1c, 0xb5, 0xdb],
+    [0x16, 0x3e, 0xc7, 0x32, 0x51, 0xf8, 0x54, 0x43, 0x68, 0x72, 0x22, 0x48, 0x7d, 0xda, 0x9a, 0x65, 0x46, 0x7d, 0x90, 0xb2, 0x2f, 0x0b, 0x38, 0x66, 0x46, 0x86, 0x07, 0x7c, 0x6a, 0x44, 0x86, 0xd5],
+];
@@ -0,0 +1,134 @@
+#![no_std]
+
+//! Circom-compatible Poseidon over BN254 for 1 and 3 inputs (state sizes 2 and 4)
+//!
+//! Same permutation and parameters as circomlib's `Poseidon(n)` and
+//! `soroban_poseidon::poseidon_hash::<T, BnScalar>`, but the constants are kept
+//! as byte tables instead of being expanded into code. Calling
+//! `soroban_poseidon` directly inlines its constants as `U256` constructions,
+//! which costs the registry about 48 KiB of contract size; the tables cost
+//! about 13 KiB of data instead.
+
+use soroban_sdk::{symbol_short, vec, Bytes, Env, Vec, U256};
+
+mod constants;
+
+use constants::{MDS_T2, MDS_T4, ROUND_CONSTANTS_T2, ROUND_CONSTANTS_T4};
+
+const SBOX_D: u32 = 5;
+const ROUNDS_F: u32 = 8;
+const ROUNDS_P: u32 = 56;
+
+/// Hash one field element: `Poseidon(a)`
+///
+/// The input must already be reduced modulo the BN254 scalar field.
+pub fn poseidon1(env: &Env, a: U256) -> U256 {
+    let state = vec![env, U256::from_u32(env, 0), a];
+    permute(env, state, &MDS_T2, &ROUND_CONSTANTS_T2)
+}
+
+/// Hash three field elements: `Poseidon(a, b, c)`
+///
+/// Inputs must already be reduced modulo the BN254 scalar field.
+pub fn poseidon3(env: &Env, a: U256, b: U256, c: U256) -> U256 {
+    let state = vec![env, U256::from_u32(env, 0), a, b, c];
+    permute(env, state, &MDS_T4, &ROUND_CONSTANTS_T4)
+}
+
+/// Run the Poseidon permutation and return the first state element
+fn permute(env: &Env, state: Vec<U256>, mds: &[[u8; 32]], rc: &[[u8; 32]]) -> U256 {
+    let t = state.len();
+    env.crypto_hazmat()
+        .poseidon_permutation(
+            &state,
+            symbol_short!("BN254"),
+            t,
+            SBOX_D,
+            ROUNDS_F,
+            ROUNDS_P,
+            &to_matrix(env, mds, t),
+            &to_matrix(env, rc, t),
+        )
+        .get_unchecked(0)
+}
+
+/// Split a flat constant table into rows of `t` field elements
+fn to_matrix(env: &Env, table: &[[u8; 32]], t: u32) -> Vec<Vec<U256>> {
+    let mut matrix = vec![env];
+    for chunk in table.chunks(t as usize) {
+        let mut row = vec![env];
+        for value in chunk {
+            row.push_back(U256::from_be_bytes(env, &Bytes::from_array(env, value)));
+        }
+        matrix.push_back(row);
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_poseidon::poseidon_hash;
+    use soroban_sdk::crypto::BnScalar;
+
+    fn field(env: &Env, bytes: [u8; 32]) -> U256 {
+        U256::from_be_bytes(env, &Bytes::from_array(env, &bytes))
+    }
+
+    #[test]
+    fn test_poseidon1_matches_circomlib() {
+        let env = Env::default();
+
+        // circomlib Poseidon([1])
+        let expected = field(
+            &env,
+            [
+                0x29, 0x17, 0x61, 0x00, 0xea, 0xa9, 0x62, 0xbd, 0xc1, 0xfe, 0x6c, 0x65, 0x4d, 0x6a,
+                0x3c, 0x13, 0x0e, 0x96, 0xa4, 0xd1, 0x16, 0x8b, 0x33, 0x84, 0x8b, 0x89, 0x7d, 0xc5,
+                0x02, 0x82, 0x01, 0x33,
+            ],
+        );
+        assert_eq!(poseidon1(&env, U256::from_u32(&env, 1)), expected);
+    }
+
+    #[test]
+    fn test_poseidon3_matches_circomlib() {
+        let env = Env::default();
+
+        // circomlib Poseidon([1, 2, 3])
+        let expected = field(
+            &env,
+            [
+                0x0e, 0x77, 0x32, 0xd8, 0x9e, 0x69, 0x39, 0xc0, 0xff, 0x03, 0xd5, 0xe5, 0x8d, 0xab,
+                0x63, 0x02, 0xf3, 0x23, 0x0e, 0x26, 0x9d, 0xc5, 0xb9, 0x68, 0xf7, 0x25, 0xdf, 0x34,
+                0xab, 0x36, 0xd7, 0x32,
+            ],
+        );
+        let hash = poseidon3(
+            &env,
+            U256::from_u32(&env, 1),
+            U256::from_u32(&env, 2),
+            U256::from_u32(&env, 3),
+        );
+        assert_eq!(hash, expected);
+    }
+
+    #[test]
+    fn test_matches_soroban_poseidon() {
+        let env = Env::default();
+
+        // Inputs near the top of the field exercise the full constant tables
+        let a = field(&env, [0x30; 32]);
+        let b = U256::from_u128(&env, u64::MAX as u128);
+        let c = U256::from_u32(&env, 0);
+
+        assert_eq!(
+            poseidon1(&env, a.clone()),
+            poseidon_hash::<2, BnScalar>(&env, &vec![&env, a.clone()])
+        );
+        assert_eq!(
+            poseidon3(&env, a.clone(), b.clone(), c.clone()),
+            poseidon_hash::<4, BnScalar>(&env, &vec![&env, a, b, c])
+        );
+    }
+}
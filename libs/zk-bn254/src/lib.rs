@@ -48,7 +48,7 @@ impl VerificationKeyBN254 {
         bytes.append(&Bytes::from_slice(env, self.gamma.to_array().as_slice()));
         bytes.append(&Bytes::from_slice(env, self.delta.to_array().as_slice()));
         // Serialize ic length as u32 (big endian)
-        let ic_len = self.ic.len() as u32;
+        let ic_len = self.ic.len();
         let ic_len_bytes = ic_len.to_be_bytes();
         bytes.append(&Bytes::from_slice(env, &ic_len_bytes));
         for g1 in self.ic.iter() {
@@ -162,7 +162,7 @@ impl PublicSignalsBN254 {
     /// Serialize public signals to bytes
     pub fn to_bytes(&self, env: &Env) -> Bytes {
         let mut bytes = Bytes::new(env);
-        let len = self.signals.len() as u32;
+        let len = self.signals.len();
         let len_bytes = len.to_be_bytes();
         bytes.append(&Bytes::from_slice(env, &len_bytes));
         for signal in self.signals.iter() {